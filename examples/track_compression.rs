@@ -0,0 +1,61 @@
+//! Size / accuracy report for `Track::compress` at different precisions.
+//!
+//! Run with `cargo run --release --example track_compression`
+
+use geolocation_utils::{Coordinate, DistanceUnit, Track, TrackPoint};
+use std::time::Instant;
+
+fn main() {
+    // A synthetic one hour, 1 Hz walk with a little jitter in direction and speed
+    let mut latitude = 52.520008;
+    let mut longitude = 13.404954;
+    let points: Vec<TrackPoint> = (0..3600)
+        .map(|i| {
+            let heading = (i as f64 / 300.0).sin() * std::f64::consts::PI;
+            let step = 0.000012 + (i as f64 * 0.37).sin().abs() * 0.000004;
+            latitude += heading.cos() * step;
+            longitude += heading.sin() * step;
+            TrackPoint::new(
+                Coordinate::new(latitude, longitude),
+                1_700_000_000_000 + i * 1000,
+            )
+        })
+        .collect();
+    let track = Track::new(points);
+    let raw_size = track.points.len() * 24;
+
+    println!(
+        "{} points, {} bytes uncompressed",
+        track.points.len(),
+        raw_size
+    );
+    println!("precision | bytes | bytes/point | ratio | max error (m) | compress + decompress");
+
+    for precision in 4..=8 {
+        let start = Instant::now();
+        let compressed = track.compress(precision);
+        let decompressed = Track::decompress(&compressed).unwrap();
+        let elapsed = start.elapsed();
+
+        let max_error = track
+            .points
+            .iter()
+            .zip(&decompressed.points)
+            .map(|(original, restored)| {
+                original
+                    .coordinate
+                    .get_distance_from(restored.coordinate, &DistanceUnit::Meters)
+            })
+            .fold(0.0, f64::max);
+
+        println!(
+            "{:>9} | {:>5} | {:>11.2} | {:>5.1} | {:>13.4} | {:?}",
+            precision,
+            compressed.len(),
+            compressed.len() as f64 / track.points.len() as f64,
+            raw_size as f64 / compressed.len() as f64,
+            max_error,
+            elapsed
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Coordinate, DistanceUnit};
+
+/// # Summary
+/// Assign every point to its nearest center, returning the index of the center for each point
+///
+/// ## Notes
+/// - Ties go to the center with the lower index
+/// - Returns an empty list if there are no centers
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{assign_to_nearest, Coordinate, DistanceUnit};
+///
+/// let hubs = vec![Coordinate::new(52.52, 13.405), Coordinate::new(48.137, 11.575)];
+/// let customers = vec![
+///     Coordinate::new(48.35, 11.79),
+///     Coordinate::new(52.39, 13.06),
+///     Coordinate::new(49.45, 11.08),
+/// ];
+///
+/// assert_eq!(vec![1, 0, 1], assign_to_nearest(&customers, &hubs, &DistanceUnit::Kilometers));
+/// ```
+pub fn assign_to_nearest(
+    points: &[Coordinate],
+    centers: &[Coordinate],
+    unit: &DistanceUnit,
+) -> Vec<usize> {
+    if centers.is_empty() {
+        return Vec::new();
+    }
+
+    points
+        .iter()
+        .map(|point| {
+            centers
+                .iter()
+                .map(|center| point.get_distance_from(center, unit))
+                .enumerate()
+                .fold((0, f64::INFINITY), |best, (index, distance)| {
+                    if distance < best.1 {
+                        (index, distance)
+                    } else {
+                        best
+                    }
+                })
+                .0
+        })
+        .collect()
+}
+
+/// # Summary
+/// Assign points to centers which can each take at most `capacities[center]` points, such as
+/// customers to service hubs. Returns the index of the center for each point, or `None` for points
+/// left over once every center within reach is full
+///
+/// ## Notes
+/// - Pairs are assigned closest first, a fast greedy allocation which is not guaranteed to
+///   minimise the total distance
+/// - Centers without an entry in `capacities` take no points
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{assign_with_capacity, Coordinate, DistanceUnit};
+///
+/// let hubs = vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)];
+/// let customers = vec![
+///     Coordinate::new(0.0, 0.1),
+///     Coordinate::new(0.0, 0.2),
+///     Coordinate::new(0.0, 0.3),
+/// ];
+///
+/// assert_eq!(
+///     vec![Some(0), Some(0), Some(1)],
+///     assign_with_capacity(&customers, &hubs, &[2, 5], &DistanceUnit::Kilometers)
+/// );
+/// assert_eq!(
+///     vec![Some(0), None, None],
+///     assign_with_capacity(&customers, &hubs, &[1, 0], &DistanceUnit::Kilometers)
+/// );
+/// ```
+pub fn assign_with_capacity(
+    points: &[Coordinate],
+    centers: &[Coordinate],
+    capacities: &[usize],
+    unit: &DistanceUnit,
+) -> Vec<Option<usize>> {
+    let mut remaining: Vec<usize> = (0..centers.len())
+        .map(|center| capacities.get(center).copied().unwrap_or(0))
+        .collect();
+
+    let mut pairs: Vec<(usize, usize, f64)> = points
+        .iter()
+        .enumerate()
+        .flat_map(|(point_index, point)| {
+            centers
+                .iter()
+                .enumerate()
+                .filter(|(center_index, _)| remaining[*center_index] > 0)
+                .map(move |(center_index, center)| {
+                    (
+                        point_index,
+                        center_index,
+                        point.get_distance_from(center, unit),
+                    )
+                })
+        })
+        .collect();
+    // Stable, so equal distances keep point then center order
+    pairs.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    let mut assignments = vec![None; points.len()];
+    let mut unassigned = points.len();
+    for (point_index, center_index, _) in pairs {
+        if unassigned == 0 {
+            break;
+        }
+        if assignments[point_index].is_some() || remaining[center_index] == 0 {
+            continue;
+        }
+        assignments[point_index] = Some(center_index);
+        remaining[center_index] -= 1;
+        unassigned -= 1;
+    }
+
+    assignments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_capacity_is_respected() {
+        let point = |seed: u64| Coordinate::new(random(seed * 2, 2.0), random(seed * 2 + 1, 2.0));
+        let customers: Vec<Coordinate> = (0..200).map(point).collect();
+        let hubs: Vec<Coordinate> = (1000..1005).map(point).collect();
+        let unit = DistanceUnit::Kilometers;
+
+        let nearest = assign_to_nearest(&customers, &hubs, &unit);
+        let unlimited = assign_with_capacity(&customers, &hubs, &[usize::MAX; 5], &unit);
+        assert_eq!(
+            nearest,
+            unlimited.iter().map(|hub| hub.unwrap()).collect::<Vec<_>>()
+        );
+
+        let capacities = [30, 50, 10, 60, 20];
+        let limited = assign_with_capacity(&customers, &hubs, &capacities, &unit);
+        for (hub, capacity) in capacities.iter().enumerate() {
+            let count = limited.iter().filter(|&&other| other == Some(hub)).count();
+            assert_eq!(*capacity, count);
+        }
+        assert_eq!(30, limited.iter().filter(|hub| hub.is_none()).count());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::spherical::{dot, from_unit_vector, to_unit_vector};
+use crate::Coordinate;
+
+/// # Summary
+/// Get the weighted center of a set of points, such as a demand-weighted facility location from
+/// order volumes. Points are averaged as unit vectors from the Earth's center, so the result is
+/// correct across the antimeridian and near the poles
+///
+/// ## Notes
+/// - Points with a weight that is not positive and finite are ignored
+/// - Returns `None` if no point has a usable weight, or if the points balance out with no single
+///   center, e.g. two equally weighted antipodal points
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{weighted_centroid, Coordinate};
+///
+/// let orders = vec![(Coordinate::new(0.0, 179.0), 3.0), (Coordinate::new(0.0, -179.0), 1.0)];
+/// let center = weighted_centroid(&orders).unwrap();
+///
+/// assert!(center.latitude.abs() < 1e-9);
+/// assert_eq!(179.5, (center.longitude * 1000.0).round() / 1000.0);
+/// assert!(weighted_centroid(&[]).is_none());
+/// ```
+pub fn weighted_centroid(points: &[(Coordinate, f64)]) -> Option<Coordinate> {
+    let sum = points
+        .iter()
+        .filter(|(_, weight)| weight.is_finite() && *weight > 0.0)
+        .fold([0.0; 3], |sum, (coordinate, weight)| {
+            let vector = to_unit_vector(coordinate);
+            [
+                sum[0] + vector[0] * weight,
+                sum[1] + vector[1] * weight,
+                sum[2] + vector[2] * weight,
+            ]
+        });
+    let total: f64 = points
+        .iter()
+        .map(|(_, weight)| *weight)
+        .filter(|weight| weight.is_finite() && *weight > 0.0)
+        .sum();
+
+    // Resultants much shorter than the total weight have no meaningful direction
+    if total == 0.0 || dot(sum, sum).sqrt() < total * 1e-12 {
+        return None;
+    }
+
+    Some(from_unit_vector(sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{mix64, random};
+
+    #[test]
+    fn test_integer_weights_match_repeated_points() {
+        for seed in 0..50u64 {
+            let weighted: Vec<(Coordinate, f64)> = (0..5)
+                .map(|i| {
+                    let base = seed * 20 + i * 3;
+                    let coordinate =
+                        Coordinate::new(random(base, 60.0), 100.0 + random(base + 1, 40.0));
+                    (coordinate, (mix64(base + 2) % 4 + 1) as f64)
+                })
+                .collect();
+            let repeated: Vec<(Coordinate, f64)> = weighted
+                .iter()
+                .flat_map(|(coordinate, weight)| {
+                    std::iter::repeat_n((*coordinate, 1.0), *weight as usize)
+                })
+                .collect();
+
+            let a = weighted_centroid(&weighted).unwrap();
+            let b = weighted_centroid(&repeated).unwrap();
+            assert!((a.latitude - b.latitude).abs() < 1e-9, "{}", seed);
+            assert!((a.longitude - b.longitude).abs() < 1e-9, "{}", seed);
+        }
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let north = Coordinate::new(90.0, 0.0);
+        let south = Coordinate::new(-90.0, 0.0);
+
+        assert!(weighted_centroid(&[(north, 1.0), (south, 1.0)]).is_none());
+        assert!(weighted_centroid(&[(north, 0.0), (south, -2.0)]).is_none());
+        let centroid = weighted_centroid(&[(north, 2.0), (south, 1.0), (south, f64::NAN)]);
+        assert!((centroid.unwrap().latitude - 90.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::math;
+use crate::utils::{linear_divisor, wrap_to_bounds, WGS84_FLATTENING, WGS84_SEMI_MAJOR_AXIS_M};
+use crate::{Coordinate, CoordinateBoundaries, DistanceUnit};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// The closest point of a line to a given coordinate, see `CheapRuler::point_on_line`
+pub struct PointOnLine {
+    pub point: Coordinate,
+    /// Index of the line segment start the point lies on
+    pub index: usize,
+    /// Position of the point along that segment, from 0 (segment start) to 1 (segment end)
+    pub t: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// Fast approximations of geodesic measurements around a fixed latitude, a port of Mapbox's
+/// [cheap-ruler](https://github.com/mapbox/cheap-ruler)
+///
+/// ## Notes
+/// - Scale factors come from the WGS84 ellipsoid at the ruler's latitude, measurements are then
+///   plain planar math, often 20x faster than Haversine
+/// - Errors stay below 0.1% for distances up to ~500 km when the points are near the ruler's
+///   latitude, making it ideal for city-scale data
+pub struct CheapRuler {
+    unit: DistanceUnit,
+    kx: f64,
+    ky: f64,
+}
+
+impl CheapRuler {
+    /// # Summary
+    /// Construct a new CheapRuler for measurements around `latitude`, in `unit`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(52.52, DistanceUnit::Kilometers);
+    /// let distance = ruler.distance(&Coordinate::new(52.52, 13.40), &Coordinate::new(52.53, 13.41));
+    ///
+    /// assert_eq!(1.3, (distance * 100.0).round() / 100.0);
+    /// ```
+    pub fn new(latitude: f64, unit: DistanceUnit) -> Self {
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let meters_per_radian = WGS84_SEMI_MAJOR_AXIS_M / linear_divisor(&unit);
+        let m = 1f64.to_radians() * meters_per_radian;
+
+        let cos_lat = math::cos(latitude.to_radians());
+        let w2 = 1.0 / (1.0 - e2 * (1.0 - cos_lat * cos_lat));
+        let w = w2.sqrt();
+
+        Self {
+            unit,
+            // Normal and meridional radius of curvature at the latitude
+            kx: m * w * cos_lat,
+            ky: m * w * w2 * (1.0 - e2),
+        }
+    }
+
+    /// # Summary
+    /// Get the unit the ruler measures in
+    pub fn unit(&self) -> DistanceUnit {
+        self.unit
+    }
+
+    /// # Summary
+    /// Get the length of a degree of longitude and of a degree of latitude at the ruler's latitude
+    pub(crate) fn degree_lengths(&self) -> (f64, f64) {
+        (self.kx, self.ky)
+    }
+
+    /// # Summary
+    /// Get the approximate distance between two coordinates
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Meters);
+    /// let distance = ruler.distance(&Coordinate::new(0.0, 179.999), &Coordinate::new(0.0, -179.999));
+    ///
+    /// assert_eq!(223.0, distance.round());
+    /// ```
+    pub fn distance(&self, a: &Coordinate, b: &Coordinate) -> f64 {
+        let dx = wrap_to_bounds(a.longitude - b.longitude, 180.0) * self.kx;
+        let dy = (a.latitude - b.latitude) * self.ky;
+        math::hypot(dx, dy)
+    }
+
+    /// # Summary
+    /// Get the approximate bearing from `a` to `b`, in degrees clockwise from north (-180 - 180)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Meters);
+    /// let bearing = ruler.bearing(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, -0.1));
+    ///
+    /// assert_eq!(-90.0, bearing);
+    /// ```
+    pub fn bearing(&self, a: &Coordinate, b: &Coordinate) -> f64 {
+        let dx = wrap_to_bounds(b.longitude - a.longitude, 180.0) * self.kx;
+        let dy = (b.latitude - a.latitude) * self.ky;
+        math::atan2(dx, dy).to_degrees()
+    }
+
+    /// # Summary
+    /// Get the coordinate reached by travelling `distance` from `origin` towards `bearing`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(52.52, DistanceUnit::Kilometers);
+    /// let origin = Coordinate::new(52.52, 13.40);
+    /// let destination = ruler.destination(&origin, 1.0, 90.0);
+    ///
+    /// assert!((ruler.distance(&origin, &destination) - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn destination(&self, origin: &Coordinate, distance: f64, bearing: f64) -> Coordinate {
+        let (sin, cos) = math::sin_cos(bearing.to_radians());
+        self.offset(origin, sin * distance, cos * distance)
+    }
+
+    /// # Summary
+    /// Get the coordinate offset from `origin` by `east` and `north` distances
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Meters);
+    /// let moved = ruler.offset(&Coordinate::new(0.0, 0.0), 0.0, 1105.74);
+    ///
+    /// assert_eq!(0.01, (moved.latitude * 1e6).round() / 1e6);
+    /// ```
+    pub fn offset(&self, origin: &Coordinate, east: f64, north: f64) -> Coordinate {
+        Coordinate::new(
+            origin.latitude + north / self.ky,
+            origin.longitude + east / self.kx,
+        )
+    }
+
+    /// # Summary
+    /// Get the approximate length of a line through `points`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    /// let line = vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.1), Coordinate::new(0.1, 0.1)];
+    ///
+    /// assert_eq!(22.19, (ruler.line_distance(&line) * 100.0).round() / 100.0);
+    /// ```
+    pub fn line_distance(&self, points: &[Coordinate]) -> f64 {
+        points
+            .windows(2)
+            .map(|segment| self.distance(&segment[0], &segment[1]))
+            .sum()
+    }
+
+    /// # Summary
+    /// Get the coordinate `distance` along a line through `points`, clamped to the line ends.
+    /// Returns `None` for an empty line
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    /// let line = vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.1), Coordinate::new(0.1, 0.1)];
+    /// let halfway = ruler.along(&line, ruler.line_distance(&line) / 2.0).unwrap();
+    ///
+    /// assert!((halfway.longitude - 0.1).abs() < 1e-3);
+    /// ```
+    pub fn along(&self, points: &[Coordinate], distance: f64) -> Option<Coordinate> {
+        let first = points.first()?;
+        if distance <= 0.0 {
+            return Some(*first);
+        }
+
+        let mut travelled = 0.0;
+        for segment in points.windows(2) {
+            let length = self.distance(&segment[0], &segment[1]);
+            travelled += length;
+            if travelled > distance {
+                return Some(self.interpolate(
+                    &segment[0],
+                    &segment[1],
+                    (distance - (travelled - length)) / length,
+                ));
+            }
+        }
+
+        points.last().copied()
+    }
+
+    /// # Summary
+    /// Get the point of a line through `points` closest to `coordinate`.
+    /// Returns `None` for an empty line
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    /// let line = vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.1), Coordinate::new(0.1, 0.1)];
+    ///
+    /// let closest = ruler.point_on_line(&line, &Coordinate::new(0.01, 0.05)).unwrap();
+    /// assert_eq!(0, closest.index);
+    /// assert_eq!(0.5, (closest.t * 1e6).round() / 1e6);
+    /// ```
+    pub fn point_on_line(
+        &self,
+        points: &[Coordinate],
+        coordinate: &Coordinate,
+    ) -> Option<PointOnLine> {
+        let first = points.first()?;
+        let mut closest = PointOnLine {
+            point: *first,
+            index: 0,
+            t: 0.0,
+        };
+        let mut min_distance = self.distance(first, coordinate);
+
+        for (index, segment) in points.windows(2).enumerate() {
+            let t = self.segment_fraction(&segment[0], &segment[1], coordinate);
+            let point = self.interpolate(&segment[0], &segment[1], t);
+            let distance = self.distance(&point, coordinate);
+            if distance < min_distance {
+                min_distance = distance;
+                closest = PointOnLine { point, index, t };
+            }
+        }
+
+        Some(closest)
+    }
+
+    /// # Summary
+    /// Get the approximate distance from `coordinate` to the segment between `a` and `b`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(0.0, DistanceUnit::Kilometers);
+    /// let distance = ruler.point_to_segment_distance(
+    ///     &Coordinate::new(0.01, 0.05),
+    ///     &Coordinate::new(0.0, 0.0),
+    ///     &Coordinate::new(0.0, 0.1),
+    /// );
+    ///
+    /// assert_eq!(1.11, (distance * 100.0).round() / 100.0);
+    /// ```
+    pub fn point_to_segment_distance(
+        &self,
+        coordinate: &Coordinate,
+        a: &Coordinate,
+        b: &Coordinate,
+    ) -> f64 {
+        self.distance(&self.nearest_on_segment(coordinate, a, b), coordinate)
+    }
+
+    /// # Summary
+    /// Get the bounds extending `buffer` around `coordinate` in every direction.
+    /// Returns `None` if the bounds would reach past a pole
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CheapRuler, Coordinate, DistanceUnit};
+    ///
+    /// let ruler = CheapRuler::new(52.52, DistanceUnit::Meters);
+    /// let bounds = ruler.buffer_point(&Coordinate::new(52.52, 13.40), 100.0).unwrap();
+    ///
+    /// assert!(bounds.contains(&ruler.destination(&Coordinate::new(52.52, 13.40), 99.0, 45.0)));
+    /// ```
+    pub fn buffer_point(
+        &self,
+        coordinate: &Coordinate,
+        buffer: f64,
+    ) -> Option<CoordinateBoundaries> {
+        let v = buffer / self.ky;
+        let h = buffer / self.kx;
+        CoordinateBoundaries::from_extents(
+            coordinate.latitude - v,
+            coordinate.latitude + v,
+            coordinate.longitude - h,
+            coordinate.longitude + h,
+        )
+    }
+
+    /// # Summary
+    /// Fraction along the segment `a` to `b` of the point closest to `coordinate`, clamped to 0 - 1
+    fn segment_fraction(&self, a: &Coordinate, b: &Coordinate, coordinate: &Coordinate) -> f64 {
+        let dx = wrap_to_bounds(b.longitude - a.longitude, 180.0) * self.kx;
+        let dy = (b.latitude - a.latitude) * self.ky;
+        if dx == 0.0 && dy == 0.0 {
+            return 0.0;
+        }
+        let px = wrap_to_bounds(coordinate.longitude - a.longitude, 180.0) * self.kx;
+        let py = (coordinate.latitude - a.latitude) * self.ky;
+
+        ((px * dx + py * dy) / (dx * dx + dy * dy)).clamp(0.0, 1.0)
+    }
+
+    /// # Summary
+    /// Point of the segment `a` to `b` closest to `coordinate`
+    pub(crate) fn nearest_on_segment(
+        &self,
+        coordinate: &Coordinate,
+        a: &Coordinate,
+        b: &Coordinate,
+    ) -> Coordinate {
+        self.interpolate(a, b, self.segment_fraction(a, b, coordinate))
+    }
+
+    fn interpolate(&self, a: &Coordinate, b: &Coordinate, t: f64) -> Coordinate {
+        let d_lon = wrap_to_bounds(b.longitude - a.longitude, 180.0);
+        Coordinate::new(
+            a.latitude + (b.latitude - a.latitude) * t,
+            a.longitude + d_lon * t,
+        )
+    }
+}
@@ -0,0 +1,336 @@
+use crate::math;
+use crate::spherical::{angle_to_distance, destination, distance_to_angle};
+use crate::{Coordinate, CoordinateBoundaries, Distance, DistanceUnit, Polygon};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+const MIN_POLYGON_VERTICES: usize = 3;
+const MAX_POLYGON_VERTICES: usize = 65_536;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Struct representing a circular region around a center coordinate
+pub struct Circle {
+    pub center: Coordinate,
+    pub radius: f64,
+    pub unit: DistanceUnit,
+}
+
+impl Circle {
+    /// # Summary
+    /// Construct a new Circle
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 5.0, DistanceUnit::Kilometers);
+    /// assert_eq!(5.0, circle.radius);
+    /// ```
+    pub fn new(center: impl Into<Coordinate>, radius: f64, unit: DistanceUnit) -> Self {
+        Self {
+            center: center.into(),
+            radius,
+            unit,
+        }
+    }
+
+    /// # Summary
+    /// Construct a Circle from a location and its accuracy radius in kilometers, as returned by
+    /// MaxMind-style IP geolocation lookups
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::from_accuracy(Coordinate::new(37.751, -97.822), 1000.0);
+    ///
+    /// assert_eq!(1000.0, circle.radius);
+    /// assert_eq!(DistanceUnit::Kilometers, circle.unit);
+    /// ```
+    pub fn from_accuracy(center: impl Into<Coordinate>, accuracy_radius_km: f64) -> Self {
+        Self::new(center, accuracy_radius_km, DistanceUnit::Kilometers)
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the circle, edge included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 200.0, DistanceUnit::Kilometers);
+    ///
+    /// assert!(circle.contains(&Coordinate::new(1.0, 1.0)));
+    /// assert!(!circle.contains(&Coordinate::new(2.0, 2.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        self.center.get_distance_from(coordinate, &self.unit) <= self.radius
+    }
+
+    /// # Summary
+    /// Get the bounding box of the circle. Returns `None` if the center is invalid
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Miles);
+    /// let bounds = circle.bounds().unwrap();
+    ///
+    /// assert!(bounds.contains(&Coordinate::new(0.01, 0.01)));
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        CoordinateBoundaries::new(self.center, self.radius, Some(self.unit))
+    }
+
+    /// # Summary
+    /// Checks if any part of the circle overlaps the bounds, edges included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, CoordinateBoundaries, DistanceUnit};
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(0.0, 1.0, 0.0, 1.0).unwrap();
+    ///
+    /// assert!(Circle::new(Coordinate::new(0.5, 1.5), 60.0, DistanceUnit::Kilometers).intersects_bounds(&bounds));
+    /// assert!(!Circle::new(Coordinate::new(0.5, 1.5), 50.0, DistanceUnit::Kilometers).intersects_bounds(&bounds));
+    /// ```
+    pub fn intersects_bounds(&self, bounds: &CoordinateBoundaries) -> bool {
+        bounds.distance_to(&self.center, &self.unit) <= self.radius
+    }
+
+    /// # Summary
+    /// Checks if any part of the circle overlaps the polygon, edges included
+    ///
+    /// ## Notes
+    /// - A circle sitting entirely inside a hole does not intersect the polygon
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert!(Circle::new(Coordinate::new(0.25, 0.25), 1.0, DistanceUnit::Kilometers).intersects_polygon(&triangle));
+    /// assert!(Circle::new(Coordinate::new(-0.5, 0.5), 60.0, DistanceUnit::Kilometers).intersects_polygon(&triangle));
+    /// assert!(!Circle::new(Coordinate::new(1.0, 1.0), 60.0, DistanceUnit::Kilometers).intersects_polygon(&triangle));
+    /// ```
+    pub fn intersects_polygon(&self, polygon: &Polygon) -> bool {
+        polygon.distance_to(&self.center, &self.unit) <= self.radius
+    }
+
+    /// # Summary
+    /// Approximate the circle with a polygon of `vertices` points (at least 3) spaced evenly
+    /// around the center, starting due north and going clockwise
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers);
+    /// let polygon = circle.to_polygon(32);
+    ///
+    /// assert_eq!(32, polygon.exterior.len());
+    /// assert!(polygon.contains(&Coordinate::new(0.05, 0.05)));
+    /// ```
+    pub fn to_polygon(&self, vertices: usize) -> Polygon {
+        let vertices = vertices.max(MIN_POLYGON_VERTICES);
+        let angle = distance_to_angle(self.radius, &self.unit);
+
+        Polygon::new(
+            (0..vertices)
+                .map(|index| {
+                    destination(&self.center, 360.0 * index as f64 / vertices as f64, angle)
+                })
+                .collect(),
+        )
+    }
+
+    /// # Summary
+    /// Approximate the circle with a polygon using the fewest vertices keeping every edge within
+    /// `max_error` of the circle, see `to_polygon`
+    ///
+    /// ## Notes
+    /// - The error is the largest ground distance between the circle and an edge, found at the
+    ///   middle of the edge, with edges following great circles between the vertices
+    /// - Uses at least 3 and at most 65536 vertices, a non-positive or non-finite `max_error`
+    ///   gives the most vertices
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(23, circle.to_polygon_with_tolerance(100.0, &DistanceUnit::Meters).exterior.len());
+    /// assert_eq!(71, circle.to_polygon_with_tolerance(10.0, &DistanceUnit::Meters).exterior.len());
+    /// ```
+    pub fn to_polygon_with_tolerance(&self, max_error: f64, unit: &DistanceUnit) -> Polygon {
+        let radius = distance_to_angle(self.radius, &self.unit);
+        let max_error = distance_to_angle(max_error, unit);
+        // Angular distance from the center to the middle of an edge between two vertices
+        let error = |vertices: usize| {
+            let (sin_radius, cos_radius) = math::sin_cos(radius);
+            radius - math::atan2(sin_radius * math::cos(PI / vertices as f64), cos_radius)
+        };
+        let within = |vertices: usize| error(vertices) <= max_error;
+
+        let mut high = MIN_POLYGON_VERTICES;
+        while high < MAX_POLYGON_VERTICES && !within(high) {
+            high = (high * 2).min(MAX_POLYGON_VERTICES);
+        }
+        let mut low = (high / 2).max(MIN_POLYGON_VERTICES - 1);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if within(middle) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+
+        self.to_polygon(high)
+    }
+
+    /// # Summary
+    /// Estimate the probability that a location uniformly distributed within this circle also lies
+    /// within `other`, i.e. the fraction of this circle's area covered by `other`
+    ///
+    /// ## Notes
+    /// - Treats both circles as flat discs, which is accurate while the radii are small compared
+    ///   to the Earth's radius (true for typical IP geolocation accuracy radii)
+    /// - Not symmetric: a small circle inside a large one has probability 1, the reverse does not
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate};
+    ///
+    /// let large = Circle::from_accuracy(Coordinate::new(0.0, 0.0), 100.0);
+    /// let small = Circle::from_accuracy(Coordinate::new(0.0, 0.1), 5.0);
+    /// let far = Circle::from_accuracy(Coordinate::new(10.0, 10.0), 5.0);
+    ///
+    /// assert_eq!(1.0, small.overlap_probability(&large));
+    /// assert_eq!(0.0025, (large.overlap_probability(&small) * 10000.0).round() / 10000.0);
+    /// assert_eq!(0.0, large.overlap_probability(&far));
+    /// ```
+    pub fn overlap_probability(&self, other: &Circle) -> f64 {
+        let unit = DistanceUnit::Kilometers;
+        let r1 = self.radius_in(&unit);
+        let r2 = other.radius_in(&unit);
+        if r1 <= 0.0 {
+            return if other.contains(&self.center) {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        let d = self.center.get_distance_from(other.center, &unit);
+
+        let intersection = if d >= r1 + r2 {
+            0.0
+        } else if d <= (r1 - r2).abs() {
+            PI * r1.min(r2).powi(2)
+        } else {
+            let alpha = math::acos(((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1)).clamp(-1.0, 1.0));
+            let beta = math::acos(((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2)).clamp(-1.0, 1.0));
+            let kite = ((-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2))
+                .max(0.0)
+                .sqrt();
+            r1 * r1 * alpha + r2 * r2 * beta - 0.5 * kite
+        };
+
+        (intersection / (PI * r1 * r1)).clamp(0.0, 1.0)
+    }
+
+    /// # Summary
+    /// Get the area of the circle on the spherical Earth, in square `unit`s
+    ///
+    /// ## Notes
+    /// - The circle is a spherical cap, slightly smaller than a flat disc of the same radius
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(3.1416, (circle.area(&DistanceUnit::Kilometers) * 10000.0).round() / 10000.0);
+    /// ```
+    pub fn area(&self, unit: &DistanceUnit) -> f64 {
+        self.sector_area(360.0, unit)
+    }
+
+    /// # Summary
+    /// Get the area of the sector spanning `angle` degrees of the circle, between two radii and the
+    /// arc joining them, in square `unit`s. Such as the coverage of a directional antenna or a
+    /// camera cone
+    ///
+    /// ## Notes
+    /// - `angle` is clamped to 0 - 360
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// // A 120 degree antenna reaching 5 km
+    /// let antenna = Circle::new(Coordinate::new(52.52, 13.405), 5.0, DistanceUnit::Kilometers);
+    /// let coverage = antenna.sector_area(120.0, &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(26.18, (coverage * 100.0).round() / 100.0);
+    /// ```
+    pub fn sector_area(&self, angle: f64, unit: &DistanceUnit) -> f64 {
+        let radius = angle_to_distance(1.0, unit);
+        let cap = 2.0 * PI * (1.0 - math::cos(self.angular_radius()));
+
+        cap * angle.clamp(0.0, 360.0) / 360.0 * radius * radius
+    }
+
+    /// # Summary
+    /// Get the area of the segment spanning `angle` degrees of the circle, between the arc and the
+    /// great circle chord joining its ends, in square `unit`s
+    ///
+    /// ## Notes
+    /// - `angle` is clamped to 0 - 360, segments over 180 degrees include the center
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers);
+    /// let unit = DistanceUnit::Kilometers;
+    ///
+    /// assert_eq!(0.2854, (circle.segment_area(90.0, &unit) * 10000.0).round() / 10000.0);
+    /// assert_eq!(
+    ///     (circle.area(&unit) * 1e6).round(),
+    ///     ((circle.segment_area(90.0, &unit) + circle.segment_area(270.0, &unit)) * 1e6).round()
+    /// );
+    /// ```
+    pub fn segment_area(&self, angle: f64, unit: &DistanceUnit) -> f64 {
+        let radius = angle_to_distance(1.0, unit);
+        // Spherical excess of the triangle between the center and the ends of the arc, negative
+        // past 180 degrees where the triangle lies outside the segment
+        let tangent = math::tan(self.angular_radius() / 2.0).powi(2);
+        let (sin, cos) = math::sin_cos(angle.clamp(0.0, 360.0).to_radians());
+        let triangle = 2.0 * math::atan2(tangent * sin, 1.0 + tangent * cos);
+
+        self.sector_area(angle, unit) - triangle * radius * radius
+    }
+
+    /// # Summary
+    /// Get the radius as an angle in radians on the spherical Earth, at most half way around it
+    fn angular_radius(&self) -> f64 {
+        distance_to_angle(self.radius, &self.unit).clamp(0.0, PI)
+    }
+
+    fn radius_in(&self, unit: &DistanceUnit) -> f64 {
+        Distance::new(self.radius, self.unit).to_unit(unit).value
+    }
+}
@@ -0,0 +1,247 @@
+use crate::Coordinate;
+use std::fmt;
+
+/// Highest supported number of decimal places, 1e-9 degrees is already well below a millimeter
+const MAX_PRECISION: u8 = 9;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while decoding a compact coordinate buffer
+pub enum CodecError {
+    /// The buffer ended in the middle of a value
+    UnexpectedEnd,
+    /// The precision header is above the supported maximum of 9 decimal places
+    InvalidPrecision(u8),
+    /// A varint was longer than 10 bytes or did not fit in 64 bits
+    VarintOverflow,
+    /// A decoded latitude was outside of ±90 or a longitude outside of ±180 degrees
+    CoordinateOutOfRange,
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::UnexpectedEnd => write!(f, "buffer ended unexpectedly"),
+            CodecError::InvalidPrecision(precision) => write!(
+                f,
+                "precision {} is above the supported maximum of {}",
+                precision, MAX_PRECISION
+            ),
+            CodecError::VarintOverflow => write!(f, "varint does not fit in 64 bits"),
+            CodecError::CoordinateOutOfRange => {
+                write!(f, "decoded coordinate is outside of the valid range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+/// # Summary
+/// Encode coordinates into a compact binary buffer
+///
+/// ## Notes
+/// - Latitude and longitude are stored as fixed-point integers with `precision` decimal places,
+///   every point is stored as the zigzag varint encoded delta from the previous point
+///   (the same scheme as OSM PBF and Google's encoded polylines)
+/// - Precision 5 keeps ~1.1 m, 6 keeps ~11 cm and 7 keeps ~1.1 cm of accuracy at the equator
+/// - `precision` is clamped to at most 9 decimal places
+/// - Layout: `[precision: u8][count: varint]` followed by `[d_lat: varint][d_lon: varint]` per point
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{decode_coordinates, encode_coordinates, Coordinate};
+///
+/// let coordinates = vec![
+///     Coordinate::new(48.13721, 11.57561),
+///     Coordinate::new(48.13735, 11.57602),
+///     Coordinate::new(48.13750, 11.57648),
+/// ];
+///
+/// let encoded = encode_coordinates(&coordinates, 5);
+/// assert!(encoded.len() < 20);
+///
+/// let decoded = decode_coordinates(&encoded).unwrap();
+/// assert_eq!(coordinates, decoded);
+/// ```
+pub fn encode_coordinates(coordinates: &[Coordinate], precision: u8) -> Vec<u8> {
+    let precision = precision.min(MAX_PRECISION);
+    let mut buffer = Vec::with_capacity(2 + coordinates.len() * 4);
+    buffer.push(precision);
+    write_varint(&mut buffer, coordinates.len() as u64);
+
+    let mut previous = (0i64, 0i64);
+    for coordinate in coordinates {
+        let current = (
+            to_fixed(coordinate.latitude, precision),
+            to_fixed(coordinate.longitude, precision),
+        );
+        write_varint(&mut buffer, zigzag_encode(current.0 - previous.0));
+        write_varint(&mut buffer, zigzag_encode(current.1 - previous.1));
+        previous = current;
+    }
+
+    buffer
+}
+
+/// # Summary
+/// Decode a buffer produced by `encode_coordinates`
+///
+/// ## Notes
+/// - Points decoding outside of ±90 latitude or ±180 longitude are rejected with
+///   `CodecError::CoordinateOutOfRange` instead of being wrapped
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{decode_coordinates, CodecError};
+///
+/// assert_eq!(Err(CodecError::UnexpectedEnd), decode_coordinates(&[5, 2, 0]));
+///
+/// // A latitude delta of 91 degrees at precision 0
+/// assert_eq!(
+///     Err(CodecError::CoordinateOutOfRange),
+///     decode_coordinates(&[0, 1, 182, 1, 0])
+/// );
+/// ```
+pub fn decode_coordinates(bytes: &[u8]) -> Result<Vec<Coordinate>, CodecError> {
+    let (&precision, mut bytes) = bytes.split_first().ok_or(CodecError::UnexpectedEnd)?;
+    if precision > MAX_PRECISION {
+        return Err(CodecError::InvalidPrecision(precision));
+    }
+    let count = read_varint(&mut bytes)?;
+
+    // Every point takes at least 2 bytes, never trust the header for the allocation size
+    let mut coordinates = Vec::with_capacity((count as usize).min(bytes.len() / 2));
+    let mut previous = (0i64, 0i64);
+    for _ in 0..count {
+        coordinates.push(read_fixed_coordinate(&mut bytes, &mut previous, precision)?);
+    }
+
+    Ok(coordinates)
+}
+
+/// # Summary
+/// Read the next latitude / longitude delta pair, add it onto `previous` and convert the result
+/// into a coordinate, rejecting sums which overflow or leave the valid coordinate range
+pub(crate) fn read_fixed_coordinate(
+    bytes: &mut &[u8],
+    previous: &mut (i64, i64),
+    precision: u8,
+) -> Result<Coordinate, CodecError> {
+    let scale = 10i64.pow(precision as u32);
+    let mut next = |previous: i64, bound: i64| {
+        previous
+            .checked_add(zigzag_decode(read_varint(bytes)?))
+            .filter(|value| value.abs() <= bound * scale)
+            .ok_or(CodecError::CoordinateOutOfRange)
+    };
+    let latitude = next(previous.0, 90)?;
+    let longitude = next(previous.1, 180)?;
+    *previous = (latitude, longitude);
+
+    Ok(Coordinate::new(
+        from_fixed(latitude, precision),
+        from_fixed(longitude, precision),
+    ))
+}
+
+pub(crate) fn to_fixed(value: f64, precision: u8) -> i64 {
+    (value * 10f64.powi(precision as i32)).round() as i64
+}
+
+pub(crate) fn from_fixed(value: i64, precision: u8) -> f64 {
+    value as f64 / 10f64.powi(precision as i32)
+}
+
+pub(crate) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub(crate) fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+pub(crate) fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+pub(crate) fn read_varint(bytes: &mut &[u8]) -> Result<u64, CodecError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first().ok_or(CodecError::UnexpectedEnd)?;
+        *bytes = rest;
+        if shift == 63 && byte > 1 {
+            return Err(CodecError::VarintOverflow);
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(CodecError::VarintOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zigzag_round_trip() {
+        for value in [0, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+            assert_eq!(value, zigzag_decode(zigzag_encode(value)));
+        }
+        assert_eq!(1, zigzag_encode(-1));
+        assert_eq!(2, zigzag_encode(1));
+    }
+
+    #[test]
+    fn test_varint_round_trip() {
+        for value in [0, 1, 127, 128, 300, u64::MAX] {
+            let mut buffer = Vec::new();
+            write_varint(&mut buffer, value);
+            let mut bytes = buffer.as_slice();
+            assert_eq!(Ok(value), read_varint(&mut bytes));
+            assert!(bytes.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_deltas_are_rejected() {
+        // Deltas summing past i64::MAX
+        let mut buffer = vec![9];
+        write_varint(&mut buffer, 2);
+        for _ in 0..2 {
+            write_varint(&mut buffer, zigzag_encode(i64::MAX));
+            write_varint(&mut buffer, 0);
+        }
+        assert_eq!(
+            Err(CodecError::CoordinateOutOfRange),
+            decode_coordinates(&buffer)
+        );
+
+        // A longitude just past 180 degrees
+        let mut buffer = vec![5, 1];
+        write_varint(&mut buffer, 0);
+        write_varint(&mut buffer, zigzag_encode(18_000_001));
+        assert_eq!(
+            Err(CodecError::CoordinateOutOfRange),
+            decode_coordinates(&buffer)
+        );
+        assert!(
+            decode_coordinates(&encode_coordinates(&[Coordinate::new(-90.0, 180.0)], 9)).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_precision_is_respected() {
+        let coordinates = vec![Coordinate::new(-33.868820, 151.209290)];
+        let decoded = decode_coordinates(&encode_coordinates(&coordinates, 3)).unwrap();
+
+        assert_eq!(-33.869, decoded[0].latitude);
+        assert_eq!(151.209, decoded[0].longitude);
+    }
+}
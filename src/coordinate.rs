@@ -1,14 +1,29 @@
-use crate::utils::{linear_divisor, EARTH_RADIUS_KM, wrap_to_bounds};
-use crate::DistanceUnit;
+use crate::geodesic;
+use crate::geohash;
+use crate::math;
+use crate::spherical::{
+    destination, dot, from_unit_vector, interpolate, normalize, to_unit_vector,
+};
+use crate::utils::{
+    linear_divisor, mix64, wrap_to_bounds, EARTH_RADIUS_KM, WGS84_FLATTENING,
+    WGS84_SEMI_MAJOR_AXIS_M,
+};
+use crate::{
+    DistanceUnit, InverseResult, ProximityQuery, RadiusResult, ValidationError, ValidationLevel,
+    ValidationReport,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 /// ## Summary
 /// Struct representing a latlon coordinate
 pub struct Coordinate {
+    #[cfg_attr(feature = "schemars", schemars(range(min = -90.0, max = 90.0)))]
     pub latitude: f64,
+    #[cfg_attr(feature = "schemars", schemars(range(min = -180.0, max = 180.0)))]
     pub longitude: f64,
 }
 
@@ -23,7 +38,7 @@ impl Coordinate {
     /// let coordinate = Coordinate::new(34.8, -2.8);
     /// assert_eq!(34.8, coordinate.latitude);
     /// assert_eq!(-2.8, coordinate.longitude);
-    /// 
+    ///
     /// // Overflowing coordinate
     /// let coordinate = Coordinate::new(91.6275, -181.875);
     /// assert_eq!(-88.3725, coordinate.latitude);
@@ -36,9 +51,79 @@ impl Coordinate {
         }
     }
 
+    /// # Summary
+    /// Construct a new Coordinate, checking the input against a `ValidationLevel` instead of
+    /// silently wrapping it
+    ///
+    /// ## Notes
+    /// - `ValidationLevel::Wrap` accepts any finite input and behaves like `Coordinate::new`
+    /// - Non-finite input is always rejected
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, ValidationError, ValidationLevel};
+    ///
+    /// let coordinate = Coordinate::try_new(91.6275, -181.875, ValidationLevel::Wrap).unwrap();
+    /// assert_eq!(-88.3725, coordinate.latitude);
+    ///
+    /// let error = Coordinate::try_new(91.6275, -181.875, ValidationLevel::Strict).unwrap_err();
+    /// assert_eq!(ValidationError::LatitudeOutOfRange(91.6275), error);
+    ///
+    /// let error = Coordinate::try_new(f64::NAN, 0.0, ValidationLevel::Wrap).unwrap_err();
+    /// assert_eq!(ValidationError::NonFinite, error);
+    /// ```
+    pub fn try_new(lat: f64, lon: f64, level: ValidationLevel) -> Result<Self, ValidationError> {
+        let report = ValidationReport::new(lat, lon);
+        if !report.is_finite {
+            return Err(ValidationError::NonFinite);
+        }
+
+        match level {
+            ValidationLevel::Wrap => {}
+            ValidationLevel::Strict if !report.latitude_in_range => {
+                return Err(ValidationError::LatitudeOutOfRange(lat));
+            }
+            ValidationLevel::WebMercator if !report.within_web_mercator => {
+                return Err(ValidationError::LatitudeOutOfRange(lat));
+            }
+            _ if !report.longitude_in_range => {
+                return Err(ValidationError::LongitudeOutOfRange(lon));
+            }
+            _ => {}
+        }
+
+        Ok(Self::new(lat, lon))
+    }
+
+    /// # Summary
+    /// Get detailed validation results for this coordinate
+    ///
+    /// ## Notes
+    /// - Useful for coordinates built directly through the public fields, which skip the wrapping
+    ///   done by `Coordinate::new`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, ValidationLevel};
+    ///
+    /// let coordinate = Coordinate { latitude: 86.0, longitude: 200.0 };
+    /// let report = coordinate.validate();
+    ///
+    /// assert!(report.latitude_in_range);
+    /// assert!(!report.longitude_in_range);
+    /// assert!(!report.within_web_mercator);
+    /// assert!(!report.is_valid(ValidationLevel::Strict));
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        ValidationReport::new(self.latitude, self.longitude)
+    }
+
     /// # Summary
     /// Checks if a coordinate is within the radius of another coordinate.
     ///
+    /// ## Notes
+    /// - `radius` is in `distance_unit`, the same check as `Coordinate::radius_check`
+    ///
     /// ## Example
     /// ```rust
     /// use geolocation_utils::{Coordinate, DistanceUnit};
@@ -50,24 +135,73 @@ impl Coordinate {
     ///
     /// let is_in_radius = coordinate.in_radius(&coordinate2, radius, &radius_unit);
     /// assert_eq!(true, is_in_radius);
+    ///
+    /// // 157 km apart
+    /// assert!(!coordinate.in_radius(&coordinate2, 150.0, &DistanceUnit::Kilometers));
     /// ```
     pub fn in_radius(
         &self,
-        other_coordinate: &Coordinate,
+        other_coordinate: impl Into<Coordinate>,
         radius: f64,
         distance_unit: &DistanceUnit,
     ) -> bool {
-        let distance = self.get_distance_from(other_coordinate, distance_unit);
-        let radius = radius * linear_divisor(distance_unit);
+        self.radius_check(other_coordinate, radius, distance_unit)
+            .inside
+    }
+
+    /// # Summary
+    /// Check if a coordinate is within `radius` of this coordinate, also returning the distance
+    /// between them and how far inside or outside of the radius it is
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let depot = Coordinate::new(0.0, 0.0);
+    /// let result = depot.radius_check(&Coordinate::new(1.0, 1.0), 150.0, &DistanceUnit::Kilometers);
+    ///
+    /// assert!(!result.inside);
+    /// assert_eq!(157.25, (result.distance * 100.0).round() / 100.0);
+    /// assert_eq!(-7.25, (result.margin * 100.0).round() / 100.0);
+    /// ```
+    pub fn radius_check(
+        &self,
+        other: impl Into<Coordinate>,
+        radius: f64,
+        unit: &DistanceUnit,
+    ) -> RadiusResult {
+        let distance = self.get_distance_from(other, unit);
+
+        RadiusResult {
+            inside: distance <= radius,
+            distance,
+            margin: radius - distance,
+        }
+    }
 
-        return distance <= radius;
+    /// # Summary
+    /// Start a fluent proximity query for coordinates within `radius` of this coordinate
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let stores = vec![Coordinate::new(0.02, 0.0), Coordinate::new(0.01, 0.0)];
+    /// let nearest = Coordinate::new(0.0, 0.0)
+    ///     .within(5.0, DistanceUnit::Kilometers)
+    ///     .closest(&stores);
+    ///
+    /// assert_eq!(Some(1), nearest.map(|found| found.index));
+    /// ```
+    pub fn within(&self, radius: f64, unit: DistanceUnit) -> ProximityQuery {
+        ProximityQuery::new(*self, radius, unit)
     }
 
     /// # Summary
     /// Gets the distance between 2 coordinates
     ///
     /// ## Notes
-    /// - Uses the Haversine formula
+    /// - Uses the Haversine formula, in its `atan2` form so precision holds up for nearly antipodal points
     /// - Implementation taken from https://www.geeksforgeeks.org/program-distance-two-points-earth/
     ///
     /// ## Example
@@ -75,15 +209,45 @@ impl Coordinate {
     /// use geolocation_utils::{Coordinate, DistanceUnit};
     /// let coordinate1 = Coordinate::new(1.0, 1.0);
     /// let coordinate2 = Coordinate::new(0.0, 0.0);
-    /// 
+    ///
     /// let distance = coordinate1.get_distance_from(&coordinate2, &DistanceUnit::Kilometers);
-    /// 
-    /// // Rounding because output number is 157.24938127194397
+    ///
+    /// // Rounding because output number is 157.2495984740402
     /// let rounded_distance = (distance * 100.0).round() / 100.0;
-    /// 
+    ///
     /// assert_eq!(157.25, rounded_distance);
     /// ```
-    pub fn get_distance_from(&self, other: &Coordinate, unit: &DistanceUnit) -> f64 {
+    pub fn get_distance_from(&self, other: impl Into<Coordinate>, unit: &DistanceUnit) -> f64 {
+        self.get_distance_from_with_radius(other, unit, EARTH_RADIUS_KM)
+    }
+
+    /// # Summary
+    /// Get the Haversine distance to another coordinate on a sphere of `radius_km`, to match
+    /// results from systems using a different Earth radius
+    ///
+    /// ## Notes
+    /// - `get_distance_from` uses the IUGG mean radius of 6371.0088 km, the same as PostGIS'
+    ///   spherical distances. Other common choices are 6371 km and 6378.137 km (the WGS84
+    ///   equatorial radius)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let a = Coordinate::new(1.0, 1.0);
+    /// let b = Coordinate::new(0.0, 0.0);
+    /// let unit = DistanceUnit::Kilometers;
+    ///
+    /// assert_eq!(a.get_distance_from(&b, &unit), a.get_distance_from_with_radius(&b, &unit, 6371.0088));
+    /// assert_eq!(157.43, (a.get_distance_from_with_radius(&b, &unit, 6378.137) * 100.0).round() / 100.0);
+    /// ```
+    pub fn get_distance_from_with_radius(
+        &self,
+        other: impl Into<Coordinate>,
+        unit: &DistanceUnit,
+        radius_km: f64,
+    ) -> f64 {
+        let other = other.into();
         // Formula from https://www.geeksforgeeks.org/program-distance-two-points-earth/
         let pi = std::f64::consts::PI;
 
@@ -95,12 +259,641 @@ impl Coordinate {
         let d_lon = lon2 - lon1;
         let d_lat = lat2 - lat1;
 
-        let a = (((d_lat / 2.0).sin()).powi(2))
-            + (lat1.cos() * lat2.cos()) * ((d_lon / 2.0).sin()).powi(2);
+        let a = math::sin(d_lat / 2.0).powi(2)
+            + (math::cos(lat1) * math::cos(lat2)) * math::sin(d_lon / 2.0).powi(2);
 
-        let c = 2.0 * (a.sqrt()).asin();
+        // atan2 keeps full precision near antipodal points, where asin(sqrt(a)) flattens out and
+        // floating point error can push `a` past 1.0
+        let a = a.clamp(0.0, 1.0);
+        let c = 2.0 * math::atan2(a.sqrt(), (1.0 - a).sqrt());
 
+        let distance_meters = (c * radius_km) * linear_divisor(&DistanceUnit::Kilometers);
+        distance_meters / linear_divisor(unit)
+    }
+
+    /// # Summary
+    /// Get the distance to another coordinate on the WGS84 ellipsoid, accurate to about 15
+    /// nanometers where `get_distance_from` can be off by up to 0.5% from assuming a spherical Earth
+    ///
+    /// ## Notes
+    /// - Slower than `get_distance_from`, which remains the better choice for ranking and filtering
+    /// - Uses Karney's algorithm, see `geodesic::inverse` for the bearings as well
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// // Flinders Peak to Buninyong, the test case of Vincenty's paper
+    /// let flinders_peak = Coordinate::new(-37.95103342, 144.42486789);
+    /// let buninyong = Coordinate::new(-37.65282114, 143.92649554);
+    /// let distance = flinders_peak.geodesic_distance_from(&buninyong, &DistanceUnit::Meters);
+    ///
+    /// assert_eq!(54972.271, (distance * 1000.0).round() / 1000.0);
+    ///
+    /// // A quarter of the equator
+    /// let distance = Coordinate::new(0.0, 0.0).geodesic_distance_from((0.0, 90.0), &DistanceUnit::Kilometers);
+    /// assert_eq!(10018.754, (distance * 1000.0).round() / 1000.0);
+    ///
+    /// // Nearly antipodal, where Vincenty's formula does not converge
+    /// let distance = Coordinate::new(0.0, 0.0).geodesic_distance_from((0.5, 179.7), &DistanceUnit::Kilometers);
+    /// assert_eq!(19944.127, (distance * 1000.0).round() / 1000.0);
+    /// ```
+    pub fn geodesic_distance_from(&self, other: impl Into<Coordinate>, unit: &DistanceUnit) -> f64 {
+        geodesic::inverse(self, &other.into(), unit).distance
+    }
+
+    /// # Summary
+    /// Get the coordinate reached by travelling `distance` from this coordinate with an initial
+    /// `bearing` in degrees clockwise from north, on the WGS84 ellipsoid. Also returns the bearing
+    /// on arrival, in degrees between 0 and 360
+    ///
+    /// ## Notes
+    /// - Accurate to about 15 nanometers, the counterpart of `geodesic_distance_from` for
+    ///   surveying work where spherical destinations are off by meters
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// // Flinders Peak to Buninyong, the test case of Vincenty's paper
+    /// let flinders_peak = Coordinate::new(-37.95103342, 144.42486789);
+    /// let bearing = 306.0 + 52.0 / 60.0 + 5.37 / 3600.0;
+    /// let (buninyong, final_bearing) = flinders_peak.geodesic_destination(bearing, 54972.271, &DistanceUnit::Meters);
+    ///
+    /// assert_eq!(-37.6528211, (buninyong.latitude * 1e7).round() / 1e7);
+    /// assert_eq!(143.9264955, (buninyong.longitude * 1e7).round() / 1e7);
+    /// assert_eq!(307.1736, (final_bearing * 1e4).round() / 1e4);
+    ///
+    /// let distance = flinders_peak.geodesic_distance_from(&buninyong, &DistanceUnit::Meters);
+    /// assert!((distance - 54972.271).abs() < 1e-6);
+    /// ```
+    pub fn geodesic_destination(
+        &self,
+        bearing: f64,
+        distance: f64,
+        unit: &DistanceUnit,
+    ) -> (Coordinate, f64) {
+        geodesic::direct(self, bearing, distance, unit)
+    }
+
+    /// # Summary
+    /// Get the antipode of this coordinate, the point on the exact opposite side of the Earth
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let antipode = Coordinate::new(40.4168, -3.7038).antipode();
+    ///
+    /// assert_eq!(-40.4168, antipode.latitude);
+    /// assert_eq!(176.2962, (antipode.longitude * 1e4).round() / 1e4);
+    /// ```
+    pub fn antipode(&self) -> Coordinate {
+        Coordinate::new(-self.latitude, self.longitude + 180.0)
+    }
+
+    /// # Summary
+    /// Checks if another coordinate is within `tolerance` of this coordinate's antipode
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let madrid = Coordinate::new(40.4168, -3.7038);
+    /// let weber = Coordinate::new(-40.4, 176.3);
+    ///
+    /// assert!(madrid.is_antipodal_to(&weber, 5.0, &DistanceUnit::Kilometers));
+    /// assert!(!madrid.is_antipodal_to(&weber, 1.0, &DistanceUnit::Kilometers));
+    /// ```
+    pub fn is_antipodal_to(
+        &self,
+        other: impl Into<Coordinate>,
+        tolerance: f64,
+        distance_unit: &DistanceUnit,
+    ) -> bool {
+        self.antipode().get_distance_from(other, distance_unit) <= tolerance
+    }
+
+    /// # Summary
+    /// Get the initial bearing of the great circle path to another coordinate, in degrees
+    /// clockwise from north between 0 and 360
+    ///
+    /// ## Notes
+    /// - The bearing changes along the path, use `inverse` to also get the final bearing
+    /// - Returns 0 for identical coordinates
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let new_york = Coordinate::new(40.7128, -74.006);
+    /// let london = Coordinate::new(51.5074, -0.1278);
+    ///
+    /// assert_eq!(51.2, (new_york.bearing_to(&london) * 10.0).round() / 10.0);
+    /// assert_eq!(270.0, Coordinate::new(0.0, 1.0).bearing_to(&Coordinate::new(0.0, 0.0)));
+    /// ```
+    pub fn bearing_to(&self, other: impl Into<Coordinate>) -> f64 {
+        let other = other.into();
+        let (sin_lat1, cos_lat1) = math::sin_cos(self.latitude.to_radians());
+        let (sin_lat2, cos_lat2) = math::sin_cos(other.latitude.to_radians());
+        let (sin_d_lon, cos_d_lon) = math::sin_cos((other.longitude - self.longitude).to_radians());
+
+        math::atan2(
+            sin_d_lon * cos_lat2,
+            cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_d_lon,
+        )
+        .to_degrees()
+        .rem_euclid(360.0)
+    }
+
+    /// # Summary
+    /// Get the bearing on arrival at another coordinate when following the great circle path from
+    /// this one, in degrees clockwise from north between 0 and 360
+    ///
+    /// ## Notes
+    /// - The reverse of the initial bearing from `other` back to this coordinate, matching
+    ///   `inverse`'s final bearing
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let new_york = Coordinate::new(40.7128, -74.006);
+    /// let london = Coordinate::new(51.5074, -0.1278);
+    ///
+    /// assert_eq!(51.2, (new_york.bearing_to(&london) * 10.0).round() / 10.0);
+    /// assert_eq!(108.3, (new_york.final_bearing_to(&london) * 10.0).round() / 10.0);
+    /// ```
+    pub fn final_bearing_to(&self, other: impl Into<Coordinate>) -> f64 {
+        (other.into().bearing_to(self) + 180.0).rem_euclid(360.0)
+    }
+
+    /// # Summary
+    /// Get the distance to another coordinate along the rhumb line between them, the path
+    /// keeping a constant bearing the whole way
+    ///
+    /// ## Notes
+    /// - Rhumb lines are longer than great circles, only slightly over short distances and by up
+    ///   to half again between far apart coordinates at high latitudes
+    /// - Crosses the antimeridian when that is shorter, like `get_distance_from`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let dover = Coordinate::new(51.127, 1.338);
+    /// let calais = Coordinate::new(50.964, 1.853);
+    ///
+    /// let distance = dover.rhumb_distance_from(&calais, &DistanceUnit::Kilometers);
+    /// assert_eq!(40.31, (distance * 100.0).round() / 100.0);
+    ///
+    /// // Along the equator both paths are the same
+    /// let equator = Coordinate::new(0.0, 0.0);
+    /// let distance = equator.rhumb_distance_from((0.0, 90.0), &DistanceUnit::Kilometers);
+    /// assert!((distance - equator.get_distance_from((0.0, 90.0), &DistanceUnit::Kilometers)).abs() < 1e-9);
+    /// ```
+    pub fn rhumb_distance_from(&self, other: impl Into<Coordinate>, unit: &DistanceUnit) -> f64 {
+        let other = other.into();
+        let (d_lat, d_psi, d_lon) = self.rhumb_deltas(&other);
+        // Along a parallel the stretched latitude difference vanishes, use the parallel's scale
+        let q = if d_psi.abs() > 1e-12 {
+            d_lat / d_psi
+        } else {
+            math::cos(self.latitude.to_radians())
+        };
+        let angle = (d_lat * d_lat + q * q * d_lon * d_lon).sqrt();
+
+        angle * EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers) / linear_divisor(unit)
+    }
+
+    /// # Summary
+    /// Get the constant bearing of the rhumb line to another coordinate, in degrees clockwise
+    /// from north between 0 and 360
+    ///
+    /// ## Notes
+    /// - Returns 0 for identical coordinates
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let dover = Coordinate::new(51.127, 1.338);
+    /// let calais = Coordinate::new(50.964, 1.853);
+    ///
+    /// assert_eq!(116.722, (dover.rhumb_bearing_to(&calais) * 1000.0).round() / 1000.0);
+    /// assert_eq!(270.0, Coordinate::new(10.0, 1.0).rhumb_bearing_to((10.0, 0.0)));
+    /// ```
+    pub fn rhumb_bearing_to(&self, other: impl Into<Coordinate>) -> f64 {
+        let (_, d_psi, d_lon) = self.rhumb_deltas(&other.into());
+        math::atan2(d_lon, d_psi).to_degrees().rem_euclid(360.0)
+    }
+
+    /// # Summary
+    /// Get the latitude difference, the difference in Mercator stretched latitude and the
+    /// shorter way longitude difference to another coordinate, in radians
+    fn rhumb_deltas(&self, other: &Coordinate) -> (f64, f64, f64) {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let stretched = |latitude: f64| math::asinh(math::tan(latitude));
+        let d_lon = wrap_to_bounds(other.longitude - self.longitude, 180.0).to_radians();
+
+        (lat2 - lat1, stretched(lat2) - stretched(lat1), d_lon)
+    }
+
+    /// # Summary
+    /// Get the point halfway between this coordinate and another along the great circle between
+    /// them
+    ///
+    /// ## Notes
+    /// - Unlike averaging latitudes and longitudes this holds across the antimeridian and near the
+    ///   poles
+    /// - Every great circle between antipodal coordinates is equally short, for those the midpoint
+    ///   is taken due north of this coordinate
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let midpoint = Coordinate::new(0.0, 170.0).midpoint(&Coordinate::new(0.0, -170.0));
+    /// assert_eq!(180.0, midpoint.longitude.abs().round());
+    ///
+    /// // The great circle from Paris to Vancouver passes far north of both
+    /// let midpoint = Coordinate::new(48.8566, 2.3522).midpoint(&Coordinate::new(49.2827, -123.1207));
+    /// assert_eq!(68.3, (midpoint.latitude * 10.0).round() / 10.0);
+    /// assert_eq!(-59.9, (midpoint.longitude * 10.0).round() / 10.0);
+    /// ```
+    pub fn midpoint(&self, other: impl Into<Coordinate>) -> Coordinate {
+        let (a, b) = (to_unit_vector(self), to_unit_vector(&other.into()));
+        let sum = [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+        if dot(sum, sum) < 1e-24 {
+            return destination(self, 0.0, std::f64::consts::FRAC_PI_2);
+        }
+
+        from_unit_vector(normalize(sum))
+    }
+
+    /// # Summary
+    /// Get the point `fraction` of the way from this coordinate to another along the great circle
+    /// between them, such as for animating movement between two positions
+    ///
+    /// ## Notes
+    /// - Moves at a constant speed along the path, a `fraction` of 0.5 is the `midpoint`
+    /// - Fractions outside 0 to 1 continue along the same great circle past either end
+    /// - Antipodal coordinates are joined through the point due north of this coordinate, like
+    ///   `midpoint`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let paris = Coordinate::new(48.8566, 2.3522);
+    /// let vancouver = Coordinate::new(49.2827, -123.1207);
+    ///
+    /// let halfway = paris.intermediate_point(&vancouver, 0.5);
+    /// let midpoint = paris.midpoint(&vancouver);
+    /// assert!((halfway.latitude - midpoint.latitude).abs() < 1e-9);
+    /// assert!((halfway.longitude - midpoint.longitude).abs() < 1e-9);
+    ///
+    /// assert_eq!(paris, paris.intermediate_point(&vancouver, 0.0));
+    /// ```
+    pub fn intermediate_point(&self, other: impl Into<Coordinate>, fraction: f64) -> Coordinate {
+        let other = other.into();
+        let (a, b) = (to_unit_vector(self), to_unit_vector(&other));
+        let sum = [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+        if dot(sum, sum) < 1e-24 {
+            return destination(self, 0.0, fraction * std::f64::consts::PI);
+        }
+
+        interpolate(self, &other, fraction)
+    }
+
+    /// # Summary
+    /// Get the distance, initial bearing and final bearing to another coordinate in a single call,
+    /// sharing the trigonometry between all three
+    ///
+    /// ## Notes
+    /// - Uses the same spherical model and Haversine distance as `get_distance_from`
+    /// - Bearings are in degrees clockwise from north, between 0 and 360
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let new_york = Coordinate::new(40.7128, -74.006);
+    /// let london = Coordinate::new(51.5074, -0.1278);
+    ///
+    /// let result = new_york.inverse(&london, &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(5570.0, result.distance.round());
+    /// assert_eq!(51.2, (result.initial_bearing * 10.0).round() / 10.0);
+    /// assert_eq!(108.3, (result.final_bearing * 10.0).round() / 10.0);
+    /// ```
+    pub fn inverse(&self, other: impl Into<Coordinate>, unit: &DistanceUnit) -> InverseResult {
+        let other = other.into();
+        let (sin_lat1, cos_lat1) = math::sin_cos(self.latitude.to_radians());
+        let (sin_lat2, cos_lat2) = math::sin_cos(other.latitude.to_radians());
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let (sin_d_lon, cos_d_lon) = math::sin_cos(d_lon);
+        let d_lat = (other.latitude - self.latitude).to_radians();
+
+        let a =
+            math::sin(d_lat / 2.0).powi(2) + cos_lat1 * cos_lat2 * math::sin(d_lon / 2.0).powi(2);
+        let a = a.clamp(0.0, 1.0);
+        let c = 2.0 * math::atan2(a.sqrt(), (1.0 - a).sqrt());
         let distance_meters = (c * EARTH_RADIUS_KM) * linear_divisor(&DistanceUnit::Kilometers);
-        return distance_meters / linear_divisor(unit);
+
+        let initial_bearing = math::atan2(
+            sin_d_lon * cos_lat2,
+            cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_d_lon,
+        )
+        .to_degrees();
+        // The final bearing is the reverse of the initial bearing from the destination back to the origin
+        let reverse_bearing = math::atan2(
+            -sin_d_lon * cos_lat1,
+            cos_lat2 * sin_lat1 - sin_lat2 * cos_lat1 * cos_d_lon,
+        )
+        .to_degrees();
+
+        InverseResult {
+            distance: distance_meters / linear_divisor(unit),
+            initial_bearing: initial_bearing.rem_euclid(360.0),
+            final_bearing: (reverse_bearing + 180.0).rem_euclid(360.0),
+        }
+    }
+
+    /// # Summary
+    /// Render the coordinate as a PostGIS EWKT point string in the given SRID
+    ///
+    /// ## Notes
+    /// - Coordinates are written in `longitude latitude` order as PostGIS expects
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(48.1372, 11.5756);
+    /// assert_eq!("SRID=4326;POINT(11.5756 48.1372)", coordinate.to_ewkt(4326));
+    /// ```
+    pub fn to_ewkt(&self, srid: u32) -> String {
+        format!("SRID={};POINT({} {})", srid, self.longitude, self.latitude)
+    }
+
+    /// # Summary
+    /// Encode the coordinate as a base32 geohash string of `precision` characters (at most 12)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(57.64911, 10.40744);
+    /// assert_eq!("u4pruydqqvj", coordinate.geohash(11));
+    /// ```
+    pub fn geohash(&self, precision: usize) -> String {
+        geohash::encode(self, precision)
+    }
+
+    /// # Summary
+    /// Get a stable 64 bit cache key for the coordinate quantized to `precision` decimal places
+    /// (at most 7), so services in any language agree on keys for nearby lookups
+    ///
+    /// ## Notes
+    /// The algorithm is stable across releases and simple to reimplement:
+    /// 1. Quantize both values with `round(value * 10^precision)`, rounding half away from zero,
+    ///    and truncate each to a two's complement 32 bit integer
+    /// 2. Pack them as `(latitude << 32) | longitude` into an unsigned 64 bit integer
+    /// 3. Mix it with the SplitMix64 finalizer:
+    ///    `z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9`, `z = (z ^ (z >> 27)) * 0x94d049bb133111eb`,
+    ///    `z = z ^ (z >> 31)` using wrapping 64 bit multiplication
+    ///
+    /// Precision 4 groups coordinates into cells of roughly 11 m, precision 3 roughly 110 m.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let key = Coordinate::new(51.5074, -0.1278).cache_key(4);
+    /// assert_eq!(0x87371c279dd5783c, key);
+    ///
+    /// // Quantized to the same cell, same key
+    /// assert_eq!(key, Coordinate::new(51.50741, -0.12779).cache_key(4));
+    /// ```
+    pub fn cache_key(&self, precision: u8) -> u64 {
+        let scale = 10f64.powi(precision.min(7) as i32);
+        let latitude = (self.latitude * scale).round() as i64 as u32 as u64;
+        let longitude = (self.longitude * scale).round() as i64 as u32 as u64;
+
+        mix64((latitude << 32) | longitude)
+    }
+
+    /// # Summary
+    /// Convert the coordinate into Earth-Centered, Earth-Fixed (ECEF) cartesian coordinates in meters
+    ///
+    /// ## Notes
+    /// - Uses the WGS84 ellipsoid
+    /// - `altitude` is the height above the ellipsoid in meters
+    /// - The returned `[x, y, z]` array converts directly into `nalgebra::Vector3` or `glam::DVec3` via `.into()`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let [x, y, z] = Coordinate::new(0.0, 90.0).to_ecef(0.0);
+    ///
+    /// assert!(x.abs() < 1e-6);
+    /// assert_eq!(6378137.0, y);
+    /// assert_eq!(0.0, z);
+    /// ```
+    pub fn to_ecef(&self, altitude: f64) -> [f64; 3] {
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let lat = self.latitude.to_radians();
+        let lon = self.longitude.to_radians();
+
+        let prime_vertical_radius =
+            WGS84_SEMI_MAJOR_AXIS_M / (1.0 - e2 * math::sin(lat).powi(2)).sqrt();
+
+        [
+            (prime_vertical_radius + altitude) * math::cos(lat) * math::cos(lon),
+            (prime_vertical_radius + altitude) * math::cos(lat) * math::sin(lon),
+            (prime_vertical_radius * (1.0 - e2) + altitude) * math::sin(lat),
+        ]
+    }
+
+    /// # Summary
+    /// Convert Earth-Centered, Earth-Fixed (ECEF) cartesian coordinates in meters back into a
+    /// coordinate and its altitude above the WGS84 ellipsoid in meters
+    ///
+    /// ## Notes
+    /// - Accepts anything convertible into `[x, y, z]`, such as `nalgebra::Vector3` or `glam::DVec3`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let ecef = Coordinate::new(45.0, 7.5).to_ecef(120.0);
+    /// let (coordinate, altitude) = Coordinate::from_ecef(ecef);
+    ///
+    /// assert!((coordinate.latitude - 45.0).abs() < 1e-9);
+    /// assert!((coordinate.longitude - 7.5).abs() < 1e-9);
+    /// assert!((altitude - 120.0).abs() < 1e-6);
+    /// ```
+    pub fn from_ecef(ecef: impl Into<[f64; 3]>) -> (Coordinate, f64) {
+        let [x, y, z] = ecef.into();
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+        let p = (x * x + y * y).sqrt();
+        let lon = math::atan2(y, x);
+
+        // Iterative solution, converges to sub-millimeter precision within a handful of steps
+        let mut lat = math::atan2(z, p * (1.0 - e2));
+        let mut altitude = 0.0;
+        for _ in 0..10 {
+            let prime_vertical_radius =
+                WGS84_SEMI_MAJOR_AXIS_M / (1.0 - e2 * math::sin(lat).powi(2)).sqrt();
+            altitude = if math::cos(lat).abs() > 1e-12 {
+                p / math::cos(lat) - prime_vertical_radius
+            } else {
+                z.abs() - prime_vertical_radius * (1.0 - e2)
+            };
+            lat = math::atan2(
+                z,
+                p * (1.0 - e2 * prime_vertical_radius / (prime_vertical_radius + altitude)),
+            );
+        }
+
+        (
+            Coordinate::new(lat.to_degrees(), lon.to_degrees()),
+            altitude,
+        )
+    }
+
+    /// # Summary
+    /// Get the position of this coordinate in the local East-North-Up tangent plane of `origin`, in meters
+    ///
+    /// ## Notes
+    /// - Both coordinates are assumed to lie on the WGS84 ellipsoid surface
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let origin = Coordinate::new(0.0, 0.0);
+    /// let [east, north, _up] = Coordinate::new(0.0, 0.001).to_local_tangent(&origin);
+    ///
+    /// assert_eq!(111.0, east.round());
+    /// assert!(north.abs() < 1e-6);
+    /// ```
+    pub fn to_local_tangent(&self, origin: &Coordinate) -> [f64; 3] {
+        let [x, y, z] = self.to_ecef(0.0);
+        let [ox, oy, oz] = origin.to_ecef(0.0);
+        let (dx, dy, dz) = (x - ox, y - oy, z - oz);
+
+        let (sin_lat, cos_lat) = math::sin_cos(origin.latitude.to_radians());
+        let (sin_lon, cos_lon) = math::sin_cos(origin.longitude.to_radians());
+
+        [
+            -sin_lon * dx + cos_lon * dy,
+            -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz,
+            cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz,
+        ]
+    }
+
+    /// # Summary
+    /// Convert an East-North-Up position in meters, relative to `origin`, back into a coordinate
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let origin = Coordinate::new(51.5, -0.12);
+    /// let enu = Coordinate::new(51.51, -0.1).to_local_tangent(&origin);
+    /// let coordinate = Coordinate::from_local_tangent(&origin, enu);
+    ///
+    /// assert!((coordinate.latitude - 51.51).abs() < 1e-9);
+    /// assert!((coordinate.longitude + 0.1).abs() < 1e-9);
+    /// ```
+    pub fn from_local_tangent(origin: &Coordinate, enu: impl Into<[f64; 3]>) -> Coordinate {
+        let [east, north, up] = enu.into();
+        let (sin_lat, cos_lat) = math::sin_cos(origin.latitude.to_radians());
+        let (sin_lon, cos_lon) = math::sin_cos(origin.longitude.to_radians());
+        let [ox, oy, oz] = origin.to_ecef(0.0);
+
+        let ecef = [
+            ox - sin_lon * east - sin_lat * cos_lon * north + cos_lat * cos_lon * up,
+            oy + cos_lon * east - sin_lat * sin_lon * north + cos_lat * sin_lon * up,
+            oz + cos_lat * north + sin_lat * up,
+        ];
+
+        Coordinate::from_ecef(ecef).0
+    }
+}
+
+impl From<(f64, f64)> for Coordinate {
+    /// # Summary
+    /// Create a coordinate from a `(latitude, longitude)` pair, in the same order and with the same
+    /// wrapping as `Coordinate::new`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// assert_eq!(Coordinate::new(34.8, -2.8), Coordinate::from((34.8, -2.8)));
+    ///
+    /// let distance = Coordinate::new(1.0, 1.0).get_distance_from((0.0, 0.0), &DistanceUnit::Kilometers);
+    /// assert_eq!(157.25, (distance * 100.0).round() / 100.0);
+    /// ```
+    fn from((latitude, longitude): (f64, f64)) -> Self {
+        Coordinate::new(latitude, longitude)
+    }
+}
+
+impl From<[f64; 2]> for Coordinate {
+    /// # Summary
+    /// Create a coordinate from a `[latitude, longitude]` pair, in the same order and with the same
+    /// wrapping as `Coordinate::new`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new([52.52, 13.405], 1.0, DistanceUnit::Kilometers);
+    /// assert_eq!(Coordinate::new(52.52, 13.405), circle.center);
+    /// ```
+    fn from([latitude, longitude]: [f64; 2]) -> Self {
+        Coordinate::new(latitude, longitude)
+    }
+}
+
+impl From<&Coordinate> for Coordinate {
+    fn from(value: &Coordinate) -> Self {
+        *value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_radius_matches_radius_check() {
+        let origin = Coordinate::new(0.0, 0.0);
+        let other = Coordinate::new(1.0, 1.0);
+
+        for unit in [
+            DistanceUnit::Kilometers,
+            DistanceUnit::Miles,
+            DistanceUnit::NauticalMiles,
+            DistanceUnit::Meters,
+        ] {
+            let distance = origin.get_distance_from(other, &unit);
+            for radius in [distance * 0.5, distance * 0.99, distance, distance * 1.01] {
+                assert_eq!(
+                    origin.radius_check(other, radius, &unit).inside,
+                    origin.in_radius(other, radius, &unit),
+                    "{:?} {}",
+                    unit,
+                    radius
+                );
+            }
+        }
+        assert!(!origin.in_radius(other, 150.0, &DistanceUnit::Kilometers));
+        assert!(origin.in_radius(other, 160.0, &DistanceUnit::Kilometers));
     }
 }
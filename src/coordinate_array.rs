@@ -0,0 +1,404 @@
+use crate::math;
+use crate::utils::{linear_divisor, EARTH_RADIUS_KM};
+use crate::{Coordinate, CoordinateBoundaries, DistanceUnit};
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// Precomputed radian values and latitude cosines used by the batch distance methods
+struct RadianCache {
+    latitudes: Vec<f64>,
+    longitudes: Vec<f64>,
+    latitude_cosines: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// ## Summary
+/// A structure-of-arrays collection of coordinates, storing latitudes and longitudes in separate
+/// contiguous `Vec`s for cache efficient batch operations over large point sets
+pub struct CoordinateArray {
+    latitudes: Vec<f64>,
+    longitudes: Vec<f64>,
+    radians: Option<RadianCache>,
+}
+
+impl CoordinateArray {
+    /// # Summary
+    /// Construct a new, empty CoordinateArray
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateArray;
+    ///
+    /// let array = CoordinateArray::new();
+    /// assert!(array.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Summary
+    /// Construct a CoordinateArray from separate latitude and longitude columns.
+    /// Returns `None` if the columns differ in length
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateArray;
+    ///
+    /// let array = CoordinateArray::from_columns(vec![1.0, 2.0], vec![3.0, 4.0]).unwrap();
+    /// assert_eq!(2, array.len());
+    ///
+    /// assert!(CoordinateArray::from_columns(vec![1.0], vec![]).is_none());
+    /// ```
+    pub fn from_columns(latitudes: Vec<f64>, longitudes: Vec<f64>) -> Option<Self> {
+        if latitudes.len() != longitudes.len() {
+            return None;
+        }
+        Some(Self {
+            latitudes,
+            longitudes,
+            radians: None,
+        })
+    }
+
+    /// # Summary
+    /// Append a coordinate to the end of the array
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray};
+    ///
+    /// let mut array = CoordinateArray::new();
+    /// array.push(Coordinate::new(1.0, 2.0));
+    ///
+    /// assert_eq!(Some(Coordinate::new(1.0, 2.0)), array.get(0));
+    /// ```
+    pub fn push(&mut self, coordinate: impl Into<Coordinate>) {
+        let coordinate = coordinate.into();
+        self.latitudes.push(coordinate.latitude);
+        self.longitudes.push(coordinate.longitude);
+        if let Some(cache) = &mut self.radians {
+            let latitude = coordinate.latitude.to_radians();
+            cache.latitudes.push(latitude);
+            cache.longitudes.push(coordinate.longitude.to_radians());
+            cache.latitude_cosines.push(math::cos(latitude));
+        }
+    }
+
+    /// # Summary
+    /// Get the number of coordinates in the array
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray};
+    ///
+    /// let array = CoordinateArray::from(vec![Coordinate::new(1.0, 2.0)].as_slice());
+    /// assert_eq!(1, array.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.latitudes.len()
+    }
+
+    /// # Summary
+    /// Checks if the array holds no coordinates
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateArray;
+    ///
+    /// assert!(CoordinateArray::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.latitudes.is_empty()
+    }
+
+    /// # Summary
+    /// Get the coordinate at `index`, or `None` if out of range
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray};
+    ///
+    /// let array = CoordinateArray::from(vec![Coordinate::new(1.0, 2.0)].as_slice());
+    ///
+    /// assert_eq!(Some(Coordinate::new(1.0, 2.0)), array.get(0));
+    /// assert_eq!(None, array.get(1));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<Coordinate> {
+        Some(Coordinate {
+            latitude: *self.latitudes.get(index)?,
+            longitude: *self.longitudes.get(index)?,
+        })
+    }
+
+    /// # Summary
+    /// Borrow the latitude column without copying
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateArray;
+    ///
+    /// let array = CoordinateArray::from_columns(vec![1.0, 2.0], vec![3.0, 4.0]).unwrap();
+    /// assert_eq!(&[1.0, 2.0], array.latitudes());
+    /// ```
+    pub fn latitudes(&self) -> &[f64] {
+        &self.latitudes
+    }
+
+    /// # Summary
+    /// Borrow the longitude column without copying
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateArray;
+    ///
+    /// let array = CoordinateArray::from_columns(vec![1.0, 2.0], vec![3.0, 4.0]).unwrap();
+    /// assert_eq!(&[3.0, 4.0], array.longitudes());
+    /// ```
+    pub fn longitudes(&self) -> &[f64] {
+        &self.longitudes
+    }
+
+    /// # Summary
+    /// Iterate over the coordinates of the array
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray};
+    ///
+    /// let coordinates = vec![Coordinate::new(1.0, 2.0), Coordinate::new(3.0, 4.0)];
+    /// let array = CoordinateArray::from(coordinates.as_slice());
+    ///
+    /// assert_eq!(coordinates, array.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        self.latitudes
+            .iter()
+            .zip(&self.longitudes)
+            .map(|(&latitude, &longitude)| Coordinate {
+                latitude,
+                longitude,
+            })
+    }
+
+    /// # Summary
+    /// Precompute radian values and latitude cosines, speeding up repeated batch distance queries
+    /// at the cost of 3 extra `f64` columns. The cache is kept up to date by `push`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray, DistanceUnit};
+    ///
+    /// let mut array = CoordinateArray::from(vec![Coordinate::new(1.0, 1.0)].as_slice());
+    /// let before = array.distances_from(&Coordinate::new(0.0, 0.0), &DistanceUnit::Kilometers);
+    ///
+    /// array.precompute_radians();
+    /// let after = array.distances_from(&Coordinate::new(0.0, 0.0), &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(before, after);
+    /// ```
+    pub fn precompute_radians(&mut self) {
+        let latitudes: Vec<f64> = self.latitudes.iter().map(|lat| lat.to_radians()).collect();
+        let latitude_cosines = latitudes.iter().map(|lat| math::cos(*lat)).collect();
+        self.radians = Some(RadianCache {
+            longitudes: self.longitudes.iter().map(|lon| lon.to_radians()).collect(),
+            latitudes,
+            latitude_cosines,
+        });
+    }
+
+    /// # Summary
+    /// Get the distance from `origin` to every coordinate of the array, in the same order
+    ///
+    /// ## Notes
+    /// - Uses the Haversine formula, matching `Coordinate::get_distance_from`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray, DistanceUnit};
+    ///
+    /// let array = CoordinateArray::from(vec![Coordinate::new(1.0, 1.0), Coordinate::new(0.0, 0.0)].as_slice());
+    /// let distances = array.distances_from(&Coordinate::new(0.0, 0.0), &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(157.25, (distances[0] * 100.0).round() / 100.0);
+    /// assert_eq!(0.0, distances[1]);
+    ///
+    /// // Antipodal points are half the circumference away rather than NaN
+    /// let antipode = array.distances_from(&Coordinate::new(-1.0, -179.0), &DistanceUnit::Kilometers);
+    /// assert_eq!(20015.0, antipode[0].round());
+    /// ```
+    pub fn distances_from(&self, origin: &Coordinate, unit: &DistanceUnit) -> Vec<f64> {
+        let mut distances = Vec::with_capacity(self.len());
+        self.for_each_angle(origin, |_, angle| distances.push(angle));
+
+        let scale =
+            EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers) / linear_divisor(unit);
+        distances.iter_mut().for_each(|angle| *angle *= scale);
+        distances
+    }
+
+    /// # Summary
+    /// Get the indices of all coordinates within `radius` of `origin`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray, DistanceUnit};
+    ///
+    /// let array = CoordinateArray::from(vec![Coordinate::new(1.0, 1.0), Coordinate::new(0.1, 0.1)].as_slice());
+    /// let indices = array.indices_in_radius(&Coordinate::new(0.0, 0.0), 50.0, &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(vec![1], indices);
+    /// ```
+    pub fn indices_in_radius(
+        &self,
+        origin: &Coordinate,
+        radius: f64,
+        unit: &DistanceUnit,
+    ) -> Vec<usize> {
+        let max_angle = radius * linear_divisor(unit)
+            / (EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers));
+        let mut indices = Vec::new();
+        self.for_each_angle(origin, |index, angle| {
+            if angle <= max_angle {
+                indices.push(index);
+            }
+        });
+        indices
+    }
+
+    /// # Summary
+    /// Get the indices of all coordinates within `radius` of `origin` whose initial bearing from
+    /// `origin` lies in `bearings`, swept clockwise from its start to its end in degrees
+    ///
+    /// ## Notes
+    /// - Sectors may wrap through north, `330.0..=30.0` covers the 60° ahead of a northbound ship
+    /// - A sector spanning 360° or more covers every bearing
+    /// - Coordinates at the origin itself have no bearing and are always included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray, DistanceUnit};
+    ///
+    /// let ships = CoordinateArray::from(vec![
+    ///     Coordinate::new(0.1, 0.0),  // dead ahead
+    ///     Coordinate::new(0.0, 0.1),  // starboard beam
+    ///     Coordinate::new(0.0, -0.1), // port beam
+    ///     Coordinate::new(1.0, 0.0),  // ahead but too far
+    /// ].as_slice());
+    /// let own_ship = Coordinate::new(0.0, 0.0);
+    ///
+    /// // Heading north: ahead and to starboard within 10 nautical miles
+    /// let found = ships.indices_in_sector(&own_ship, 10.0, &DistanceUnit::NauticalMiles, 0.0..=90.0);
+    /// assert_eq!(vec![0, 1], found);
+    ///
+    /// let found = ships.indices_in_sector(&own_ship, 10.0, &DistanceUnit::NauticalMiles, 270.0..=0.0);
+    /// assert_eq!(vec![0, 2], found);
+    /// ```
+    pub fn indices_in_sector(
+        &self,
+        origin: &Coordinate,
+        radius: f64,
+        unit: &DistanceUnit,
+        bearings: RangeInclusive<f64>,
+    ) -> Vec<usize> {
+        let (start, end) = bearings.into_inner();
+        let full_circle = end - start >= 360.0;
+        let width = (end - start).rem_euclid(360.0);
+
+        self.indices_in_radius(origin, radius, unit)
+            .into_iter()
+            .filter(|&index| {
+                let coordinate = Coordinate {
+                    latitude: self.latitudes[index],
+                    longitude: self.longitudes[index],
+                };
+                if full_circle || coordinate == *origin {
+                    return true;
+                }
+                (origin.bearing_to(coordinate) - start).rem_euclid(360.0) <= width
+            })
+            .collect()
+    }
+
+    /// # Summary
+    /// Get the indices of all coordinates inside `bounds`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateArray, CoordinateBoundaries};
+    ///
+    /// let array = CoordinateArray::from(vec![Coordinate::new(1.0, 1.0), Coordinate::new(0.1, 0.1)].as_slice());
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 20.0, None).unwrap();
+    ///
+    /// assert_eq!(vec![1], array.indices_in_bounds(&bounds));
+    /// ```
+    pub fn indices_in_bounds(&self, bounds: &CoordinateBoundaries) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, coordinate)| bounds.contains(coordinate))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// # Summary
+    /// Calls `visit` with the index and central angle in radians between `origin` and every
+    /// coordinate, using the radian cache when present
+    fn for_each_angle(&self, origin: &Coordinate, mut visit: impl FnMut(usize, f64)) {
+        let origin_lat = origin.latitude.to_radians();
+        let origin_lon = origin.longitude.to_radians();
+        let origin_cos = math::cos(origin_lat);
+
+        let haversine = |lat: f64, lon: f64, cos_lat: f64| {
+            let a = math::sin((lat - origin_lat) / 2.0).powi(2)
+                + origin_cos * cos_lat * math::sin((lon - origin_lon) / 2.0).powi(2);
+            // Same clamped atan2 form as `Coordinate::get_distance_from`, rounding can push `a`
+            // past 1.0 near antipodal points
+            let a = a.clamp(0.0, 1.0);
+            2.0 * math::atan2(a.sqrt(), (1.0 - a).sqrt())
+        };
+
+        match &self.radians {
+            Some(cache) => {
+                for index in 0..self.len() {
+                    visit(
+                        index,
+                        haversine(
+                            cache.latitudes[index],
+                            cache.longitudes[index],
+                            cache.latitude_cosines[index],
+                        ),
+                    );
+                }
+            }
+            None => {
+                for index in 0..self.len() {
+                    let lat = self.latitudes[index].to_radians();
+                    visit(
+                        index,
+                        haversine(lat, self.longitudes[index].to_radians(), math::cos(lat)),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl From<&[Coordinate]> for CoordinateArray {
+    fn from(coordinates: &[Coordinate]) -> Self {
+        Self {
+            latitudes: coordinates.iter().map(|c| c.latitude).collect(),
+            longitudes: coordinates.iter().map(|c| c.longitude).collect(),
+            radians: None,
+        }
+    }
+}
+
+impl FromIterator<Coordinate> for CoordinateArray {
+    fn from_iter<T: IntoIterator<Item = Coordinate>>(iter: T) -> Self {
+        let mut array = CoordinateArray::new();
+        for coordinate in iter {
+            array.push(coordinate);
+        }
+        array
+    }
+}
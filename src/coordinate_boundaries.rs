@@ -1,6 +1,15 @@
-use crate::{Coordinate, DistanceUnit};
-use crate::utils::{divisor};
+use crate::math;
+use crate::{
+    meters_per_degree_latitude, meters_per_degree_longitude, Coordinate, DistanceUnit, EdgePolicy,
+};
+use crate::utils::{linear_divisor, wrap_to_bounds};
+use crate::spherical::{angle_to_distance, angular_distance};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct CoordinateBoundaries {
     latitude: f64,
     longitude: f64,
@@ -25,7 +34,12 @@ impl CoordinateBoundaries {
     ///
     /// assert!(bounds.is_some());
     /// ```
-    pub fn new(origin: Coordinate, distance: f64, unit: Option<DistanceUnit>) -> Option<Self> {
+    pub fn new(
+        origin: impl Into<Coordinate>,
+        distance: f64,
+        unit: Option<DistanceUnit>,
+    ) -> Option<Self> {
+        let origin = origin.into();
         if !Self::validate(&origin) {
             return None;
         }
@@ -35,7 +49,7 @@ impl CoordinateBoundaries {
 
         let to_return = Self {
             distance,
-            distance_unit: unit.clone(),
+            distance_unit: unit,
             latitude: origin.latitude,
             longitude: origin.longitude,
             max_lat,
@@ -46,6 +60,60 @@ impl CoordinateBoundaries {
         Some(to_return)
     }
 
+    /// # Summary
+    /// Create bounds from explicit extents. Returns `None` if a latitude is outside -90 to 90 or a
+    /// minimum is greater than its maximum
+    ///
+    /// ## Notes
+    /// - Longitudes may reach past -180 / 180 for bounds crossing the antimeridian
+    /// - The origin becomes the center of the extents and the distance half of the latitude span,
+    ///   so `set_coords` / `set_distance` recalculate a box around that center
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries};
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(47.27, 55.06, 5.87, 15.04).unwrap();
+    ///
+    /// assert_eq!(47.27, bounds.min_latitude());
+    /// assert!(bounds.contains(&Coordinate::new(52.52, 13.405)));
+    /// assert!(CoordinateBoundaries::from_extents(10.0, 5.0, 0.0, 1.0).is_none());
+    /// ```
+    pub fn from_extents(min_lat: f64, max_lat: f64, min_lon: f64, max_lon: f64) -> Option<Self> {
+        if min_lat < -90.0 || max_lat > 90.0 || min_lat > max_lat || min_lon > max_lon {
+            return None;
+        }
+        let unit = DistanceUnit::Miles;
+
+        Some(Self {
+            latitude: (min_lat + max_lat) / 2.0,
+            longitude: (min_lon + max_lon) / 2.0,
+            distance: (max_lat - min_lat) / 2.0
+                * meters_per_degree_latitude((min_lat + max_lat) / 2.0)
+                / linear_divisor(&unit),
+            distance_unit: unit,
+            max_lat,
+            max_lon,
+            min_lat,
+            min_lon,
+        })
+    }
+
+    /// # Summary
+    /// Create bounds around a location and its accuracy radius in kilometers, as returned by
+    /// MaxMind-style IP geolocation lookups. Returns `None` if `Coordinate` is invalid
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries};
+    ///
+    /// let bounds = CoordinateBoundaries::from_accuracy(Coordinate::new(37.751, -97.822), 50.0).unwrap();
+    /// assert!(bounds.contains(&Coordinate::new(38.0, -97.822)));
+    /// ```
+    pub fn from_accuracy(origin: impl Into<Coordinate>, accuracy_radius_km: f64) -> Option<Self> {
+        Self::new(origin, accuracy_radius_km, Some(DistanceUnit::Kilometers))
+    }
+
     /// # Summary
     /// Get the max longitude for the coords bounds
     /// # Example
@@ -56,7 +124,7 @@ impl CoordinateBoundaries {
     /// let bounds = CoordinateBoundaries::new(coord, 1.0, None).unwrap();
     /// 
     /// let max_lon = bounds.max_longitude();
-    /// assert_eq!(max_lon, 0.014492753623188406);
+    /// assert_eq!(max_lon, 0.01445698738490085);
     /// ```
     pub fn max_longitude(&self) -> f64 {
         self.max_lon
@@ -72,7 +140,7 @@ impl CoordinateBoundaries {
     /// let bounds = CoordinateBoundaries::new(coord, 1.0, None).unwrap();
     /// 
     /// let min_lon = bounds.min_longitude();
-    /// assert_eq!(min_lon, -0.014492753623188406);
+    /// assert_eq!(min_lon, -0.01445698738490085);
     /// ```
     pub fn min_longitude(&self) -> f64 {
         self.min_lon
@@ -88,7 +156,7 @@ impl CoordinateBoundaries {
     /// let bounds = CoordinateBoundaries::new(coord, 1.0, None).unwrap();
     /// 
     /// let max_lat = bounds.max_latitude();
-    /// assert_eq!(max_lat, 0.014492753623188406);
+    /// assert_eq!(max_lat, 0.014554416327623741);
     /// ```
     pub fn max_latitude(&self) -> f64 {
         self.max_lat
@@ -104,12 +172,137 @@ impl CoordinateBoundaries {
     /// let bounds = CoordinateBoundaries::new(coord, 1.0, None).unwrap();
     /// 
     /// let min_lat = bounds.min_latitude();
-    /// assert_eq!(min_lat, -0.014492753623188406);
+    /// assert_eq!(min_lat, -0.014554416327623741);
     /// ```
     pub fn min_latitude(&self) -> f64 {
         self.min_lat
     }
 
+    /// # Summary
+    /// Checks if a coordinate lies within the bounds, edges included
+    ///
+    /// ## Notes
+    /// - Bounds reaching past the antimeridian wrap around, so a coordinate at longitude `-179.9` is
+    ///   inside bounds spanning longitudes `179.0` to `181.0`
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries};
+    ///
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 10.0, None).unwrap();
+    ///
+    /// assert!(bounds.contains(&Coordinate::new(0.1, -0.1)));
+    /// assert!(!bounds.contains(&Coordinate::new(1.0, 0.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        if coordinate.latitude < self.min_lat || coordinate.latitude > self.max_lat {
+            return false;
+        }
+        let longitude_span = self.max_lon - self.min_lon;
+        if longitude_span >= 360.0 {
+            return true;
+        }
+
+        (coordinate.longitude - self.min_lon).rem_euclid(360.0) <= longitude_span
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the bounds, with coordinates exactly on an edge
+    /// included or excluded per side according to `policy`
+    ///
+    /// ## Notes
+    /// - Bounds spanning every longitude have no east or west edge
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, EdgePolicy};
+    ///
+    /// let west = CoordinateBoundaries::from_extents(0.0, 1.0, 0.0, 1.0).unwrap();
+    /// let east = CoordinateBoundaries::from_extents(0.0, 1.0, 1.0, 2.0).unwrap();
+    /// let on_border = Coordinate::new(0.5, 1.0);
+    ///
+    /// assert!(west.contains(&on_border) && east.contains(&on_border));
+    /// assert!(!west.contains_with_policy(&on_border, &EdgePolicy::half_open()));
+    /// assert!(east.contains_with_policy(&on_border, &EdgePolicy::half_open()));
+    /// ```
+    pub fn contains_with_policy(&self, coordinate: &Coordinate, policy: &EdgePolicy) -> bool {
+        if !self.contains(coordinate) {
+            return false;
+        }
+        let mut included = true;
+        if coordinate.latitude == self.max_lat {
+            included &= policy.includes(0.0, 1.0);
+        }
+        if coordinate.latitude == self.min_lat {
+            included &= policy.includes(0.0, -1.0);
+        }
+        let longitude_span = self.max_lon - self.min_lon;
+        if longitude_span < 360.0 {
+            let offset = (coordinate.longitude - self.min_lon).rem_euclid(360.0);
+            if offset == 0.0 {
+                included &= policy.includes(-1.0, 0.0);
+            }
+            if offset == longitude_span {
+                included &= policy.includes(1.0, 0.0);
+            }
+        }
+        included
+    }
+
+    /// # Summary
+    /// Get the great-circle distance from a coordinate to the nearest point of the bounds, or `0`
+    /// if the coordinate is inside them
+    ///
+    /// ## Notes
+    /// - East and west edges are meridians, so the nearest point on them is found geodesically
+    ///   rather than by clamping latitude and longitude independently
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, DistanceUnit};
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(0.0, 10.0, 0.0, 10.0).unwrap();
+    ///
+    /// assert_eq!(0.0, bounds.distance_to(&Coordinate::new(5.0, 5.0), &DistanceUnit::Kilometers));
+    /// assert_eq!(111.0, bounds.distance_to(&Coordinate::new(11.0, 5.0), &DistanceUnit::Kilometers).round());
+    /// assert_eq!(111.0, bounds.distance_to(&Coordinate::new(0.0, -1.0), &DistanceUnit::Kilometers).round());
+    /// ```
+    pub fn distance_to(&self, coordinate: &Coordinate, unit: &DistanceUnit) -> f64 {
+        if self.contains(coordinate) {
+            return 0.0;
+        }
+        let longitude_span = self.max_lon - self.min_lon;
+        let angle = if longitude_span >= 360.0
+            || (coordinate.longitude - self.min_lon).rem_euclid(360.0) <= longitude_span
+        {
+            // Directly north or south of the bounds, the nearest point shares its meridian
+            (coordinate.latitude - coordinate.latitude.clamp(self.min_lat, self.max_lat))
+                .abs()
+                .to_radians()
+        } else {
+            // The nearest point of the whole meridian great circle, clamped to the edge. When that
+            // point lies past a pole the distance along the edge is no longer unimodal, so both
+            // corners are checked as well
+            let to_meridian = |longitude: f64| {
+                let delta = (coordinate.longitude - longitude).to_radians();
+                let latitude = coordinate.latitude.to_radians();
+                let nearest =
+                    math::atan2(math::sin(latitude), math::cos(latitude) * math::cos(delta))
+                        .to_degrees()
+                        .clamp(self.min_lat, self.max_lat);
+                [nearest, self.min_lat, self.max_lat]
+                    .into_iter()
+                    .map(|latitude| {
+                        angular_distance(coordinate, &Coordinate::new(latitude, longitude))
+                    })
+                    .fold(f64::INFINITY, f64::min)
+            };
+            to_meridian(self.min_lon).min(to_meridian(self.max_lon))
+        };
+
+        angle_to_distance(angle, unit)
+    }
+
     /// # Summary
     /// Sets the coordinates used to calculate bounds
     /// # Example
@@ -125,7 +318,8 @@ impl CoordinateBoundaries {
     /// 
     /// assert_ne!(min_lat, new_min_lat);
     /// ```
-    pub fn set_coords(&mut self, coords: Coordinate) {
+    pub fn set_coords(&mut self, coords: impl Into<Coordinate>) {
+        let coords = coords.into();
         self.latitude = coords.latitude;
         self.longitude = coords.longitude;
         let (min_lat, max_lat, min_lon, max_lon) = Self::calculate(
@@ -170,14 +364,125 @@ impl CoordinateBoundaries {
         self.max_lon = max_lon;
     }
 
+    /// # Summary
+    /// Render the bounds as a PostGIS EWKT polygon string in the given SRID
+    ///
+    /// ## Notes
+    /// - Coordinates are written in `longitude latitude` order as PostGIS expects
+    /// - Latitudes are clamped to -90 / 90 and bounds crossing the antimeridian are split into a
+    ///   `MULTIPOLYGON` of the parts on either side of it
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateBoundaries;
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(-1.0, 1.0, -2.5, 2.5).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "SRID=4326;POLYGON((-2.5 -1,2.5 -1,2.5 1,-2.5 1,-2.5 -1))",
+    ///     bounds.to_ewkt(4326)
+    /// );
+    ///
+    /// let crossing = CoordinateBoundaries::from_extents(0.0, 1.0, 170.0, 190.0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "SRID=4326;MULTIPOLYGON(((170 0,180 0,180 1,170 1,170 0)),((-180 0,-170 0,-170 1,-180 1,-180 0)))",
+    ///     crossing.to_ewkt(4326)
+    /// );
+    /// ```
+    pub fn to_ewkt(&self, srid: u32) -> String {
+        let polygons: Vec<String> = self
+            .envelopes()
+            .iter()
+            .map(|(min_lon, min_lat, max_lon, max_lat)| {
+                format!(
+                    "(({min_lon} {min_lat},{max_lon} {min_lat},{max_lon} {max_lat},{min_lon} {max_lat},{min_lon} {min_lat}))"
+                )
+            })
+            .collect();
+
+        match polygons.as_slice() {
+            [polygon] => format!("SRID={srid};POLYGON{polygon}"),
+            _ => format!("SRID={srid};MULTIPOLYGON({})", polygons.join(",")),
+        }
+    }
+
+    /// # Summary
+    /// Render an `ST_MakeEnvelope` SQL fragment covering the bounds in the given SRID
+    ///
+    /// ## Notes
+    /// - Latitudes are clamped to -90 / 90 and bounds crossing the antimeridian are split into an
+    ///   `ST_Collect` of the envelopes on either side of it
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateBoundaries;
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(-1.0, 1.0, -2.5, 2.5).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "ST_MakeEnvelope(-2.5, -1, 2.5, 1, 4326)",
+    ///     bounds.to_st_make_envelope(4326)
+    /// );
+    ///
+    /// let crossing = CoordinateBoundaries::from_extents(0.0, 1.0, -190.0, -170.0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "ST_Collect(ST_MakeEnvelope(170, 0, 180, 1, 4326), ST_MakeEnvelope(-180, 0, -170, 1, 4326))",
+    ///     crossing.to_st_make_envelope(4326)
+    /// );
+    /// ```
+    pub fn to_st_make_envelope(&self, srid: u32) -> String {
+        let envelopes: Vec<String> = self
+            .envelopes()
+            .iter()
+            .map(|(min_lon, min_lat, max_lon, max_lat)| {
+                format!("ST_MakeEnvelope({min_lon}, {min_lat}, {max_lon}, {max_lat}, {srid})")
+            })
+            .collect();
+
+        match envelopes.as_slice() {
+            [envelope] => envelope.clone(),
+            _ => format!("ST_Collect({})", envelopes.join(", ")),
+        }
+    }
+
+    /// # Summary
+    /// Render an `ST_DWithin` SQL fragment matching rows of `column` within the bounds distance of
+    /// the bounds origin
+    ///
+    /// ## Notes
+    /// - `column` is inserted verbatim, it must be a trusted identifier and never user input
+    /// - The comparison is done on `geography` (SRID 4326) so the distance is expressed in meters
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, DistanceUnit};
+    ///
+    /// let coords = Coordinate::new(48.1372, 11.5756);
+    /// let bounds = CoordinateBoundaries::new(coords, 2.5, Some(DistanceUnit::Kilometers)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "ST_DWithin(location::geography, ST_SetSRID(ST_MakePoint(11.5756, 48.1372), 4326)::geography, 2500)",
+    ///     bounds.to_st_dwithin("location")
+    /// );
+    /// ```
+    pub fn to_st_dwithin(&self, column: &str) -> String {
+        let meters = self.distance * linear_divisor(&self.distance_unit);
+        format!(
+            "ST_DWithin({}::geography, ST_SetSRID(ST_MakePoint({}, {}), 4326)::geography, {})",
+            column, self.longitude, self.latitude, meters
+        )
+    }
+
     /// # Summary
     /// Calculate min_lat, max_lat, min_lon, and max_lon bounds
     fn calculate(unit: &DistanceUnit, distance: f64, lat: f64, lon: f64) -> (f64, f64, f64, f64) {
-        let divisor = divisor(unit);
+        let meters = distance * linear_divisor(unit);
 
-        let latitude_conversion_factor = distance / divisor;
+        let latitude_conversion_factor = meters / meters_per_degree_latitude(lat);
 
-        let longitude_conversion_factor = distance / divisor / lat.to_radians().cos().abs();
+        let longitude_conversion_factor = meters / meters_per_degree_longitude(lat);
 
         let min_latitude = lat - latitude_conversion_factor;
         let max_latitude = lat + latitude_conversion_factor;
@@ -187,6 +492,32 @@ impl CoordinateBoundaries {
         (min_latitude, max_latitude, min_longitude, max_longitude)
     }
 
+    /// # Summary
+    /// Split the bounds into `(min_lon, min_lat, max_lon, max_lat)` envelopes within -180 / 180
+    /// and -90 / 90, one on either side of the antimeridian when the bounds cross it
+    fn envelopes(&self) -> Vec<(f64, f64, f64, f64)> {
+        let min_lat = self.min_lat.max(-90.0);
+        let max_lat = self.max_lat.min(90.0);
+        let longitude_span = self.max_lon - self.min_lon;
+        if longitude_span >= 360.0 {
+            return vec![(-180.0, min_lat, 180.0, max_lat)];
+        }
+
+        let mut west = wrap_to_bounds(self.min_lon, 180.0);
+        if west == 180.0 {
+            west = -180.0;
+        }
+        let east = west + longitude_span;
+        if east <= 180.0 {
+            vec![(west, min_lat, east, max_lat)]
+        } else {
+            vec![
+                (west, min_lat, 180.0, max_lat),
+                (-180.0, min_lat, east - 360.0, max_lat),
+            ]
+        }
+    }
+
     fn validate(coord: &Coordinate) -> bool {
         if coord.latitude < -90.0 || coord.latitude > 90.0 {
             return false;
@@ -195,6 +526,84 @@ impl CoordinateBoundaries {
             return false;
         }
 
-        return true;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_sql_output_stays_within_valid_extents() {
+        let polar = CoordinateBoundaries::new(
+            Coordinate::new(89.9, 10.0),
+            100.0,
+            Some(DistanceUnit::Kilometers),
+        )
+        .unwrap();
+        let envelope = polar.to_st_make_envelope(4326);
+        assert!(
+            envelope.starts_with("ST_MakeEnvelope(-180, 89.0"),
+            "{envelope}"
+        );
+        assert!(envelope.ends_with(", 180, 90, 4326)"), "{envelope}");
+
+        let shifted = CoordinateBoundaries::from_extents(0.0, 1.0, 530.0, 540.0).unwrap();
+        assert_eq!(
+            "SRID=4326;POLYGON((170 0,180 0,180 1,170 1,170 0))",
+            shifted.to_ewkt(4326)
+        );
+    }
+
+    #[test]
+    fn test_distance_to_matches_sampled_edges() {
+        let unit = DistanceUnit::Kilometers;
+        let boxes = [
+            CoordinateBoundaries::from_extents(40.0, 60.0, -10.0, 30.0).unwrap(),
+            CoordinateBoundaries::from_extents(-80.0, -50.0, 170.0, 200.0).unwrap(),
+            CoordinateBoundaries::from_extents(70.0, 90.0, 0.0, 120.0).unwrap(),
+        ];
+
+        for (box_index, bounds) in boxes.iter().enumerate() {
+            let steps = 2000;
+            let edges: Vec<Coordinate> = (0..=steps)
+                .flat_map(|step| {
+                    let fraction = step as f64 / steps as f64;
+                    let latitude = bounds.min_lat + (bounds.max_lat - bounds.min_lat) * fraction;
+                    let longitude = bounds.min_lon + (bounds.max_lon - bounds.min_lon) * fraction;
+                    [
+                        Coordinate::new(latitude, bounds.min_lon),
+                        Coordinate::new(latitude, bounds.max_lon),
+                        Coordinate::new(bounds.min_lat, longitude),
+                        Coordinate::new(bounds.max_lat, longitude),
+                    ]
+                })
+                .collect();
+
+            for seed in 0..200 {
+                let seed = seed * 2 + box_index as u64 * 1000;
+                let point = Coordinate::new(random(seed, 180.0), random(seed + 1, 360.0));
+                let expected = if bounds.contains(&point) {
+                    0.0
+                } else {
+                    edges
+                        .iter()
+                        .map(|edge| point.get_distance_from(edge, &unit))
+                        .fold(f64::INFINITY, f64::min)
+                };
+
+                let distance = bounds.distance_to(&point, &unit);
+                assert!(
+                    distance <= expected + 1e-6,
+                    "{point:?}: {distance} > {expected}"
+                );
+                assert!(
+                    expected - distance < 2.0,
+                    "{point:?}: {distance} < {expected}"
+                );
+            }
+        }
     }
 }
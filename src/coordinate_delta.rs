@@ -0,0 +1,162 @@
+use crate::utils::wrap_to_bounds;
+use crate::Coordinate;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+/// ## Summary
+/// Struct representing an offset between two coordinates, in degrees of latitude and longitude
+pub struct CoordinateDelta {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl CoordinateDelta {
+    /// # Summary
+    /// Construct a new CoordinateDelta from offsets in degrees
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateDelta};
+    ///
+    /// let moved = Coordinate::new(10.0, 20.0) + CoordinateDelta::new(0.5, -1.0);
+    /// assert_eq!(Coordinate::new(10.5, 19.0), moved);
+    /// ```
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = CoordinateDelta;
+
+    /// # Summary
+    /// Get the delta which moves `rhs` onto `self`. The longitude delta takes the shortest way
+    /// around the globe, so it is always within -180 to 180 degrees
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateDelta};
+    ///
+    /// let delta = Coordinate::new(1.0, -179.0) - Coordinate::new(0.0, 179.0);
+    /// assert_eq!(CoordinateDelta::new(1.0, 2.0), delta);
+    /// ```
+    fn sub(self, rhs: Coordinate) -> CoordinateDelta {
+        CoordinateDelta {
+            latitude: self.latitude - rhs.latitude,
+            longitude: wrap_to_bounds(self.longitude - rhs.longitude, 180.0),
+        }
+    }
+}
+
+impl Add<CoordinateDelta> for Coordinate {
+    type Output = Coordinate;
+
+    /// # Summary
+    /// Offset the coordinate by a delta, wrapping the result the same way `Coordinate::new` does
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateDelta};
+    ///
+    /// let moved = Coordinate::new(0.0, 179.0) + CoordinateDelta::new(0.0, 2.0);
+    /// assert_eq!(Coordinate::new(0.0, -179.0), moved);
+    /// ```
+    fn add(self, rhs: CoordinateDelta) -> Coordinate {
+        Coordinate::new(self.latitude + rhs.latitude, self.longitude + rhs.longitude)
+    }
+}
+
+impl Sub<CoordinateDelta> for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, rhs: CoordinateDelta) -> Coordinate {
+        self + -rhs
+    }
+}
+
+impl AddAssign<CoordinateDelta> for Coordinate {
+    fn add_assign(&mut self, rhs: CoordinateDelta) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<CoordinateDelta> for Coordinate {
+    fn sub_assign(&mut self, rhs: CoordinateDelta) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for CoordinateDelta {
+    type Output = CoordinateDelta;
+
+    fn neg(self) -> CoordinateDelta {
+        CoordinateDelta::new(-self.latitude, -self.longitude)
+    }
+}
+
+impl Add for CoordinateDelta {
+    type Output = CoordinateDelta;
+
+    fn add(self, rhs: CoordinateDelta) -> CoordinateDelta {
+        CoordinateDelta::new(self.latitude + rhs.latitude, self.longitude + rhs.longitude)
+    }
+}
+
+impl Sub for CoordinateDelta {
+    type Output = CoordinateDelta;
+
+    fn sub(self, rhs: CoordinateDelta) -> CoordinateDelta {
+        self + -rhs
+    }
+}
+
+impl Mul<f64> for CoordinateDelta {
+    type Output = CoordinateDelta;
+
+    /// # Summary
+    /// Scale the delta, e.g. to step part of the way between two coordinates
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let start = Coordinate::new(10.0, 20.0);
+    /// let end = Coordinate::new(12.0, 24.0);
+    ///
+    /// assert_eq!(Coordinate::new(11.0, 22.0), start + (end - start) * 0.5);
+    /// ```
+    fn mul(self, rhs: f64) -> CoordinateDelta {
+        CoordinateDelta::new(self.latitude * rhs, self.longitude * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_operators() {
+        let mut coordinate = Coordinate::new(10.0, 20.0);
+        coordinate += CoordinateDelta::new(1.0, 1.0);
+        assert_eq!(Coordinate::new(11.0, 21.0), coordinate);
+
+        coordinate -= CoordinateDelta::new(2.0, 2.0);
+        assert_eq!(Coordinate::new(9.0, 19.0), coordinate);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let a = Coordinate::new(-33.5, 170.25);
+        let b = Coordinate::new(12.75, -160.5);
+
+        assert_eq!(b, a + (b - a));
+        assert_eq!(a, b - (b - a));
+    }
+}
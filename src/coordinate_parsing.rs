@@ -0,0 +1,191 @@
+use crate::{Coordinate, ValidationError, ValidationLevel};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// ## Summary
+/// The character separating the integer and fractional parts of numbers in parsed text, see
+/// `Coordinate::parse`
+pub enum DecimalSeparator {
+    /// `48.1372, 11.5756`, latitude and longitude separated by a comma, semicolon or whitespace
+    #[default]
+    Point,
+    /// `48,1372; 11,5756`, latitude and longitude separated by a semicolon or whitespace
+    Comma,
+}
+
+impl DecimalSeparator {
+    fn as_char(&self) -> char {
+        match self {
+            DecimalSeparator::Point => '.',
+            DecimalSeparator::Comma => ',',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// Errors which can occur while parsing a coordinate from text
+pub enum CoordinateParseError {
+    /// The text did not hold exactly a latitude and a longitude, holds the number of parts found
+    ComponentCount(usize),
+    /// A part was not a plain decimal number using the configured separator
+    InvalidNumber(String),
+    /// The parsed latitude or longitude is out of range
+    Invalid(ValidationError),
+}
+
+impl fmt::Display for CoordinateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateParseError::ComponentCount(count) => write!(
+                f,
+                "expected a latitude and a longitude, found {} values",
+                count
+            ),
+            CoordinateParseError::InvalidNumber(number) => {
+                write!(f, "\"{}\" is not a decimal number", number)
+            }
+            CoordinateParseError::Invalid(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CoordinateParseError {}
+
+impl Coordinate {
+    /// # Summary
+    /// Parse a `latitude, longitude` pair of decimal degrees, independently of the system locale
+    ///
+    /// ## Notes
+    /// - With `DecimalSeparator::Point` the values may be separated by a comma, semicolon or
+    ///   whitespace. With `DecimalSeparator::Comma` only a semicolon or whitespace can separate them
+    /// - Numbers are an optional sign followed by digits and at most one decimal separator,
+    ///   thousands separators and exponents are rejected
+    /// - Values are checked with `ValidationLevel::Strict` rather than wrapped
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DecimalSeparator};
+    ///
+    /// let munich = Coordinate::new(48.1372, 11.5756);
+    ///
+    /// assert_eq!(Ok(munich), Coordinate::parse("48.1372, 11.5756", DecimalSeparator::Point));
+    /// assert_eq!(Ok(munich), Coordinate::parse("48,1372; 11,5756", DecimalSeparator::Comma));
+    /// assert_eq!(Ok(munich), Coordinate::parse("48,1372 11,5756", DecimalSeparator::Comma));
+    /// assert!(Coordinate::parse("48.1372; 11.5756", DecimalSeparator::Comma).is_err());
+    /// ```
+    pub fn parse(
+        input: &str,
+        separator: DecimalSeparator,
+    ) -> Result<Coordinate, CoordinateParseError> {
+        let input = input.trim();
+        let parts: Vec<&str> = if input.contains(';') {
+            input.split(';').map(str::trim).collect()
+        } else if separator == DecimalSeparator::Point && input.contains(',') {
+            input.split(',').map(str::trim).collect()
+        } else {
+            input.split_whitespace().collect()
+        };
+        let count = parts.iter().filter(|part| !part.is_empty()).count();
+        let [latitude, longitude] = parts[..] else {
+            return Err(CoordinateParseError::ComponentCount(count));
+        };
+        if count != 2 {
+            return Err(CoordinateParseError::ComponentCount(count));
+        }
+
+        let number = |input: &str| {
+            parse_decimal(input, separator)
+                .ok_or_else(|| CoordinateParseError::InvalidNumber(input.to_string()))
+        };
+        let latitude = number(latitude)?;
+        let longitude = number(longitude)?;
+        Coordinate::try_new(latitude, longitude, ValidationLevel::Strict)
+            .map_err(CoordinateParseError::Invalid)
+    }
+}
+
+impl FromStr for Coordinate {
+    type Err = CoordinateParseError;
+
+    /// # Summary
+    /// Parse a coordinate using `DecimalSeparator::Point`, see `Coordinate::parse`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let coordinate: Coordinate = "-33.8688 151.2093".parse().unwrap();
+    /// assert_eq!(Coordinate::new(-33.8688, 151.2093), coordinate);
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Coordinate::parse(input, DecimalSeparator::Point)
+    }
+}
+
+/// # Summary
+/// Parse a plain decimal number, an optional sign followed by digits and at most one `separator`.
+/// Returns `None` for anything else, such as exponents, thousands separators or `inf`
+pub(crate) fn parse_decimal(input: &str, separator: DecimalSeparator) -> Option<f64> {
+    let unsigned = input.strip_prefix(['-', '+']).unwrap_or(input);
+    let separator = separator.as_char();
+
+    let digits = unsigned.chars().filter(char::is_ascii_digit).count();
+    let separators = unsigned.chars().filter(|&c| c == separator).count();
+    if digits == 0 || separators > 1 || digits + separators != unsigned.len() {
+        return None;
+    }
+
+    input.replace(separator, ".").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let point = DecimalSeparator::Point;
+        let comma = DecimalSeparator::Comma;
+
+        assert_eq!(
+            Err(CoordinateParseError::ComponentCount(3)),
+            Coordinate::parse("1.0, 2.0, 3.0", point)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::ComponentCount(1)),
+            Coordinate::parse("1.0;", point)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::ComponentCount(0)),
+            Coordinate::parse("  ", comma)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::InvalidNumber("1,000.5".to_string())),
+            Coordinate::parse("1,000.5; 2", point)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::InvalidNumber("1e1".to_string())),
+            Coordinate::parse("1e1 2", point)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::InvalidNumber("-".to_string())),
+            Coordinate::parse("- 2", point)
+        );
+        assert_eq!(
+            Err(CoordinateParseError::Invalid(
+                ValidationError::LongitudeOutOfRange(181.5)
+            )),
+            Coordinate::parse("0 181,5", comma)
+        );
+        assert_eq!(
+            Ok(Coordinate::new(-0.5, 3.0)),
+            Coordinate::parse("-,5 +3", comma)
+        );
+    }
+}
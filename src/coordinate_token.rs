@@ -0,0 +1,198 @@
+use crate::Coordinate;
+use std::fmt;
+
+/// URL and filename safe base64 alphabet from RFC 4648
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Highest supported number of decimal places, the packed latitude and longitude of precision 7
+/// still fit in 64 bits
+const MAX_TOKEN_PRECISION: u8 = 7;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while decoding a coordinate token
+pub enum TokenError {
+    /// The token has no value after the precision character
+    TooShort,
+    /// The precision character is above the supported maximum of 7 decimal places
+    InvalidPrecision(char),
+    /// The token contains a character outside of the base64url alphabet
+    InvalidCharacter(char),
+    /// The value is too large to be a coordinate at the token's precision
+    OutOfRange,
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::TooShort => write!(f, "token is too short"),
+            TokenError::InvalidPrecision(precision) => write!(
+                f,
+                "precision '{}' is above the supported maximum of {}",
+                precision, MAX_TOKEN_PRECISION
+            ),
+            TokenError::InvalidCharacter(character) => {
+                write!(f, "'{}' is not a base64url character", character)
+            }
+            TokenError::OutOfRange => write!(f, "token value is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+/// Number of distinct quantized longitudes at `scale` steps per degree
+fn longitude_steps(scale: u64) -> u64 {
+    360 * scale
+}
+
+impl Coordinate {
+    /// # Summary
+    /// Encode the coordinate as a short URL safe token, keeping `precision` decimal places
+    ///
+    /// ## Notes
+    /// - `precision` is clamped to at most 7 decimal places. Precision 4 keeps ~11 m, 5 keeps
+    ///   ~1.1 m and 7 keeps ~1.1 cm of accuracy at the equator
+    /// - The first character holds the precision, the rest is the quantized latitude and longitude
+    ///   packed into a single integer and written in base64url, e.g. 10 characters at precision 5
+    ///   against 17 for `"52.52000,13.40500"`
+    /// - Latitudes are clamped to ±90 and longitudes wrapped into [-180, 180)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let berlin = Coordinate::new(52.52, 13.405);
+    /// let token = berlin.to_token(5);
+    ///
+    /// assert_eq!(10, token.len());
+    /// assert_eq!(berlin, Coordinate::from_token(&token).unwrap());
+    /// ```
+    pub fn to_token(&self, precision: u8) -> String {
+        let precision = precision.min(MAX_TOKEN_PRECISION);
+        let scale = 10u64.pow(precision as u32);
+        let steps = longitude_steps(scale);
+
+        let latitude = ((self.latitude.clamp(-90.0, 90.0) + 90.0) * scale as f64).round() as u64;
+        let longitude = ((self.longitude + 180.0) * scale as f64).round() as i64;
+        let mut value = latitude * steps + longitude.rem_euclid(steps as i64) as u64;
+
+        let mut digits = Vec::new();
+        loop {
+            digits.push(ALPHABET[(value % 64) as usize]);
+            value /= 64;
+            if value == 0 {
+                break;
+            }
+        }
+        digits.push(ALPHABET[precision as usize]);
+        digits.reverse();
+
+        String::from_utf8(digits).expect("alphabet is ASCII")
+    }
+
+    /// # Summary
+    /// Decode a token created by `Coordinate::to_token`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, TokenError};
+    ///
+    /// let token = Coordinate::new(-33.8688, 151.2093).to_token(4);
+    /// assert_eq!(Coordinate::new(-33.8688, 151.2093), Coordinate::from_token(&token).unwrap());
+    ///
+    /// assert_eq!(Err(TokenError::TooShort), Coordinate::from_token("E"));
+    /// assert_eq!(Err(TokenError::InvalidCharacter('+')), Coordinate::from_token("E+A"));
+    /// ```
+    pub fn from_token(token: &str) -> Result<Coordinate, TokenError> {
+        let mut characters = token.chars();
+        let precision_character = characters.next().ok_or(TokenError::TooShort)?;
+        let precision = match alphabet_index(precision_character) {
+            Some(precision) if precision <= MAX_TOKEN_PRECISION as u64 => precision as u32,
+            _ => return Err(TokenError::InvalidPrecision(precision_character)),
+        };
+        if characters.as_str().is_empty() {
+            return Err(TokenError::TooShort);
+        }
+
+        let mut value: u64 = 0;
+        for character in characters {
+            let digit = alphabet_index(character).ok_or(TokenError::InvalidCharacter(character))?;
+            value = value
+                .checked_mul(64)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or(TokenError::OutOfRange)?;
+        }
+
+        let scale = 10u64.pow(precision);
+        let steps = longitude_steps(scale);
+        let latitude = value / steps;
+        if latitude > 180 * scale {
+            return Err(TokenError::OutOfRange);
+        }
+        let longitude = value % steps;
+
+        // Removing the offsets before dividing keeps e.g. 13.405 from decoding as 13.405000000000001
+        let scale = scale as i64;
+        Ok(Coordinate {
+            latitude: (latitude as i64 - 90 * scale) as f64 / scale as f64,
+            longitude: (longitude as i64 - 180 * scale) as f64 / scale as f64,
+        })
+    }
+}
+
+fn alphabet_index(character: char) -> Option<u64> {
+    ALPHABET
+        .iter()
+        .position(|&byte| byte as char == character)
+        .map(|index| index as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_round_trip_within_precision() {
+        for precision in 0..=MAX_TOKEN_PRECISION {
+            let tolerance = 0.5 / 10f64.powi(precision as i32) + 1e-9;
+            for seed in 0..200u64 {
+                let coordinate =
+                    Coordinate::new(random(seed * 2, 180.0), random(seed * 2 + 1, 360.0));
+                let decoded = Coordinate::from_token(&coordinate.to_token(precision)).unwrap();
+
+                assert!((decoded.latitude - coordinate.latitude).abs() <= tolerance);
+                let error =
+                    (decoded.longitude - coordinate.longitude + 180.0).rem_euclid(360.0) - 180.0;
+                assert!(error.abs() <= tolerance, "{} {}", precision, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        for coordinate in [
+            Coordinate::new(90.0, 180.0),
+            Coordinate::new(-90.0, -180.0),
+            Coordinate::new(0.0, 0.0),
+        ] {
+            let decoded = Coordinate::from_token(&coordinate.to_token(7)).unwrap();
+            assert_eq!(coordinate.latitude, decoded.latitude);
+            assert_eq!(
+                coordinate.longitude.rem_euclid(360.0),
+                decoded.longitude.rem_euclid(360.0)
+            );
+        }
+
+        assert_eq!(
+            Err(TokenError::InvalidPrecision('I')),
+            Coordinate::from_token("IA")
+        );
+        assert_eq!(
+            Err(TokenError::OutOfRange),
+            Coordinate::from_token("H___________")
+        );
+        assert_eq!(Err(TokenError::OutOfRange), Coordinate::from_token("A____"));
+    }
+}
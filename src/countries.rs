@@ -0,0 +1,1070 @@
+use crate::{Coordinate, CoordinateBoundaries};
+
+type CountryRecord = (&'static str, [f64; 4], [f64; 2]);
+
+/// ISO 3166-1 alpha-2 code, `[min_lat, max_lat, min_lon, max_lon]` and `[latitude, longitude]`
+/// of the centroid, sorted by code
+///
+/// Extents cover the main territory only: overseas regions with their own code (`GF`, `RE`, ...)
+/// and remote islands are left out so the boxes stay useful as default map views. Countries
+/// crossing the antimeridian have a maximum longitude past 180
+const COUNTRIES: &[CountryRecord] = &[
+    ("AD", [42.43, 42.66, 1.41, 1.79], [42.546245, 1.601554]),
+    (
+        "AE",
+        [22.4969475367, 26.055464179, 51.5795186705, 56.3968473651],
+        [23.424076, 53.847818],
+    ),
+    (
+        "AF",
+        [29.318572496, 38.4862816432, 60.5284298033, 75.1580277851],
+        [33.93911, 67.709953],
+    ),
+    (
+        "AG",
+        [16.93, 17.73, -62.35, -61.66],
+        [17.060816, -61.796428],
+    ),
+    (
+        "AL",
+        [39.624997667, 42.6882473822, 19.3044861183, 21.0200403175],
+        [41.153332, 20.168331],
+    ),
+    (
+        "AM",
+        [38.7412014837, 41.2481285671, 43.5827458026, 46.5057198423],
+        [40.069099, 45.038189],
+    ),
+    (
+        "AO",
+        [-17.9306364885, -4.43802336998, 11.6400960629, 24.0799052263],
+        [-11.202692, 17.873887],
+    ),
+    (
+        "AQ",
+        [-90.0, -63.2706604895, -180.0, 180.0],
+        [-75.250973, -0.071389],
+    ),
+    (
+        "AR",
+        [-55.25, -21.8323104794, -73.4154357571, -53.628348965],
+        [-38.416097, -63.616672],
+    ),
+    (
+        "AT",
+        [46.4318173285, 49.0390742051, 9.47996951665, 16.9796667823],
+        [47.516231, 14.550072],
+    ),
+    (
+        "AU",
+        [-43.6345972634, -10.6681857235, 113.338953078, 153.569469029],
+        [-25.274398, 133.775136],
+    ),
+    (
+        "AZ",
+        [38.2703775091, 41.8606751572, 44.7939896991, 50.3928210793],
+        [40.143105, 47.576927],
+    ),
+    (
+        "BA",
+        [42.65, 45.2337767604, 15.7500260759, 19.59976],
+        [43.915886, 17.679076],
+    ),
+    (
+        "BB",
+        [13.04, 13.34, -59.65, -59.42],
+        [13.193887, -59.543198],
+    ),
+    (
+        "BD",
+        [20.670883287, 26.4465255803, 88.0844222351, 92.6727209818],
+        [23.684994, 90.356331],
+    ),
+    (
+        "BE",
+        [49.5294835476, 51.4750237087, 2.51357303225, 6.15665815596],
+        [50.503887, 4.469936],
+    ),
+    (
+        "BF",
+        [9.61083486576, 15.1161577418, -5.47056494793, 2.17710778159],
+        [12.238333, -1.561593],
+    ),
+    (
+        "BG",
+        [41.2344859889, 44.2349230007, 22.3805257504, 28.5580814959],
+        [42.733883, 25.48583],
+    ),
+    ("BH", [25.79, 26.29, 50.38, 50.82], [25.930414, 50.637772]),
+    (
+        "BI",
+        [-4.49998341229, -2.34848683025, 29.0249263852, 30.752262811],
+        [-3.373056, 29.918886],
+    ),
+    (
+        "BJ",
+        [6.14215770103, 12.2356358912, 0.772335646171, 3.79711225751],
+        [9.30769, 2.315834],
+    ),
+    (
+        "BN",
+        [4.007636827, 5.44772980389, 114.204016555, 115.450710484],
+        [4.535277, 114.727669],
+    ),
+    (
+        "BO",
+        [
+            -22.8729187965,
+            -9.76198780685,
+            -69.5904237535,
+            -57.4983711412,
+        ],
+        [-16.290154, -63.588653],
+    ),
+    (
+        "BR",
+        [
+            -33.7683777809,
+            5.24448639569,
+            -73.9872354804,
+            -34.7299934555,
+        ],
+        [-14.235004, -51.92528],
+    ),
+    ("BS", [23.71, 27.04, -78.98, -77.0], [25.03428, -77.39628]),
+    (
+        "BT",
+        [26.7194029811, 28.2964385035, 88.8142484883, 92.1037117859],
+        [27.514162, 90.433601],
+    ),
+    (
+        "BW",
+        [-26.8285429827, -17.6618156877, 19.8954577979, 29.4321883481],
+        [-22.328474, 24.684866],
+    ),
+    (
+        "BY",
+        [51.3195034857, 56.1691299506, 23.1994938494, 32.6936430193],
+        [53.709807, 27.953389],
+    ),
+    (
+        "BZ",
+        [15.8869375676, 18.4999822047, -89.2291216703, -88.1068129138],
+        [17.189877, -88.49765],
+    ),
+    (
+        "CA",
+        [41.6751050889, 83.23324, -140.99778, -52.6480987209],
+        [56.130366, -106.346771],
+    ),
+    (
+        "CD",
+        [-13.2572266578, 5.25608775474, 12.1823368669, 31.1741492042],
+        [-4.038333, 21.758664],
+    ),
+    (
+        "CF",
+        [2.2676396753, 11.1423951278, 14.4594071794, 27.3742261085],
+        [6.611111, 20.939444],
+    ),
+    (
+        "CG",
+        [-5.03798674888, 3.72819651938, 11.0937728207, 18.4530652198],
+        [-0.228021, 15.827659],
+    ),
+    (
+        "CH",
+        [45.7769477403, 47.8308275417, 6.02260949059, 10.4427014502],
+        [46.818188, 8.227512],
+    ),
+    (
+        "CI",
+        [4.33828847902, 10.5240607772, -8.60288021487, -2.56218950033],
+        [7.539989, -5.54708],
+    ),
+    (
+        "CL",
+        [-55.61183, -17.5800118954, -75.6443953112, -66.95992],
+        [-35.675147, -71.542969],
+    ),
+    (
+        "CM",
+        [1.72767263428, 12.8593962671, 8.48881554529, 16.0128524106],
+        [7.369722, 12.354722],
+    ),
+    (
+        "CN",
+        [18.197700914, 53.4588044297, 73.6753792663, 135.026311477],
+        [35.86166, 104.195397],
+    ),
+    (
+        "CO",
+        [
+            -4.29818694419,
+            12.4373031682,
+            -78.9909352282,
+            -66.8763258531,
+        ],
+        [4.570868, -74.297333],
+    ),
+    (
+        "CR",
+        [8.22502798099, 11.2171192489, -85.94172543, -82.5461962552],
+        [9.748917, -83.753428],
+    ),
+    (
+        "CU",
+        [19.8554808619, 23.1886107447, -84.9749110583, -74.1780248685],
+        [21.521757, -77.781167],
+    ),
+    (
+        "CV",
+        [14.80, 17.21, -25.36, -22.66],
+        [16.002082, -24.013197],
+    ),
+    ("CY", [34.56, 35.70, 32.27, 34.60], [35.126413, 33.429859]),
+    (
+        "CZ",
+        [48.5553052842, 51.1172677679, 12.2401111182, 18.8531441586],
+        [49.817492, 15.472962],
+    ),
+    (
+        "DE",
+        [47.3024876979, 54.983104153, 5.98865807458, 15.0169958839],
+        [51.165691, 10.451526],
+    ),
+    (
+        "DJ",
+        [10.9268785669, 12.6996385767, 41.66176, 43.3178524107],
+        [11.825138, 42.590275],
+    ),
+    ("DK", [54.56, 57.75, 8.07, 15.20], [56.26392, 9.501785]),
+    (
+        "DM",
+        [15.20, 15.64, -61.49, -61.24],
+        [15.414999, -61.370976],
+    ),
+    (
+        "DO",
+        [17.598564358, 19.8849105901, -71.9451120673, -68.3179432848],
+        [18.735693, -70.162651],
+    ),
+    (
+        "DZ",
+        [19.0573642034, 37.1183806422, -8.68439978681, 11.9995056495],
+        [28.033886, 1.659626],
+    ),
+    (
+        "EC",
+        [-4.95912851321, 1.3809237736, -80.9677654691, -75.2337227037],
+        [-1.831239, -78.183406],
+    ),
+    (
+        "EE",
+        [57.4745283067, 59.6110903998, 23.3397953631, 28.1316992531],
+        [58.595272, 25.013607],
+    ),
+    (
+        "EG",
+        [22.0, 31.58568, 24.70007, 36.86623],
+        [26.820553, 30.802498],
+    ),
+    ("EH", [20.77, 27.67, -17.10, -8.67], [24.215527, -12.885834]),
+    (
+        "ER",
+        [12.4554157577, 17.9983074, 36.3231889178, 43.0812260272],
+        [15.179384, 39.782334],
+    ),
+    (
+        "ES",
+        [35.946850084, 43.7483377142, -9.39288367353, 3.03948408368],
+        [40.463667, -3.74922],
+    ),
+    (
+        "ET",
+        [3.42206, 14.95943, 32.95418, 47.78942],
+        [9.145, 40.489673],
+    ),
+    (
+        "FI",
+        [59.846373196, 70.1641930203, 20.6455928891, 31.5160921567],
+        [61.92411, 25.748151],
+    ),
+    ("FJ", [-19.2, -16.0, 176.9, 181.6], [-16.578193, 179.414413]),
+    (
+        "FK",
+        [-52.3, -51.1, -61.2, -57.75],
+        [-51.796253, -59.523613],
+    ),
+    ("FM", [0.92, 9.97, 138.05, 163.04], [7.425554, 150.550812]),
+    ("FR", [41.33, 51.09, -5.14, 9.56], [46.227638, 2.213749]),
+    (
+        "GA",
+        [-3.97882659263, 2.32675751384, 8.79799563969, 14.4254557634],
+        [-0.803689, 11.609444],
+    ),
+    ("GB", [49.86, 60.86, -8.65, 1.77], [55.378051, -3.435973]),
+    (
+        "GD",
+        [11.98, 12.54, -61.80, -61.38],
+        [12.262776, -61.604171],
+    ),
+    (
+        "GE",
+        [41.0644446885, 43.553104153, 39.9550085793, 46.6379081561],
+        [42.315407, 43.356892],
+    ),
+    ("GF", [2.11, 5.78, -54.55, -51.63], [3.933889, -53.125782]),
+    (
+        "GH",
+        [4.71046214438, 11.0983409693, -3.24437008301, 1.0601216976],
+        [7.946527, -1.023194],
+    ),
+    (
+        "GL",
+        [60.03676, 83.64513, -73.297, -12.20855],
+        [71.706936, -42.604303],
+    ),
+    (
+        "GM",
+        [13.1302841252, 13.8764918075, -16.8415246241, -13.8449633448],
+        [13.443182, -15.310139],
+    ),
+    (
+        "GN",
+        [7.3090373804, 12.5861829696, -15.1303112452, -7.83210038902],
+        [9.945587, -9.696645],
+    ),
+    (
+        "GQ",
+        [1.01011953369, 2.28386607504, 9.3056132341, 11.285078973],
+        [1.650801, 10.267895],
+    ),
+    (
+        "GR",
+        [34.9199876979, 41.8269046087, 20.1500159034, 26.6041955909],
+        [39.074208, 21.824312],
+    ),
+    (
+        "GT",
+        [13.7353376327, 17.8193260767, -92.2292486234, -88.2250227526],
+        [15.783471, -90.230759],
+    ),
+    (
+        "GW",
+        [11.0404116887, 12.6281700708, -16.6774519516, -13.7004760401],
+        [11.803749, -15.180413],
+    ),
+    (
+        "GY",
+        [1.26808828369, 8.36703481692, -61.4103029039, -56.5393857489],
+        [4.860416, -58.93018],
+    ),
+    (
+        "HK",
+        [22.15, 22.56, 113.83, 114.44],
+        [22.396428, 114.109497],
+    ),
+    (
+        "HN",
+        [12.9846857772, 16.0054057886, -89.3533259753, -83.147219001],
+        [15.199999, -86.241905],
+    ),
+    (
+        "HR",
+        [42.47999136, 46.5037509222, 13.6569755388, 19.3904757016],
+        [45.1, 15.2],
+    ),
+    (
+        "HT",
+        [18.0309927434, 19.9156839055, -74.4580336168, -71.6248732164],
+        [18.971187, -72.285215],
+    ),
+    (
+        "HU",
+        [45.7594811061, 48.6238540716, 16.2022982113, 22.710531447],
+        [47.162494, 19.503304],
+    ),
+    (
+        "ID",
+        [-10.3599874813, 5.47982086834, 95.2930261576, 141.03385176],
+        [-0.789275, 113.921327],
+    ),
+    (
+        "IE",
+        [51.6693012559, 55.1316222195, -9.97708574059, -6.03298539878],
+        [53.41291, -8.24389],
+    ),
+    (
+        "IL",
+        [29.5013261988, 33.2774264593, 34.2654333839, 35.8363969256],
+        [31.046051, 34.851612],
+    ),
+    (
+        "IN",
+        [7.96553477623, 35.4940095078, 68.1766451354, 97.4025614766],
+        [20.593684, 78.96288],
+    ),
+    (
+        "IQ",
+        [29.0990251735, 37.3852635768, 38.7923405291, 48.5679712258],
+        [33.223191, 43.679291],
+    ),
+    (
+        "IR",
+        [25.0782370061, 39.7130026312, 44.1092252948, 63.3166317076],
+        [32.427908, 53.688046],
+    ),
+    (
+        "IS",
+        [63.4963829617, 66.5267923041, -24.3261840479, -13.609732225],
+        [64.963051, -19.020835],
+    ),
+    (
+        "IT",
+        [36.619987291, 47.1153931748, 6.7499552751, 18.4802470232],
+        [41.87194, 12.56738],
+    ),
+    (
+        "JM",
+        [17.7011162379, 18.5242184514, -78.3377192858, -76.1996585761],
+        [18.109581, -77.297508],
+    ),
+    (
+        "JO",
+        [29.1974946152, 33.3786864284, 34.9226025734, 39.1954683774],
+        [30.585164, 36.238414],
+    ),
+    (
+        "JP",
+        [31.0295791692, 45.5514834662, 129.408463169, 145.543137242],
+        [36.204824, 138.252924],
+    ),
+    (
+        "KE",
+        [-4.67677, 5.506, 33.8935689697, 41.8550830926],
+        [-0.023559, 37.906193],
+    ),
+    (
+        "KG",
+        [39.2794632025, 43.2983393418, 69.464886916, 80.2599902689],
+        [41.20438, 74.766098],
+    ),
+    (
+        "KH",
+        [10.4865436874, 14.5705838078, 102.3480994, 107.614547968],
+        [12.565679, 104.990963],
+    ),
+    (
+        "KI",
+        [-11.45, 4.72, 169.53, 209.85],
+        [-3.370417, -168.734039],
+    ),
+    (
+        "KM",
+        [-12.42, -11.36, 43.21, 44.54],
+        [-11.875001, 43.872219],
+    ),
+    (
+        "KN",
+        [17.09, 17.42, -62.87, -62.54],
+        [17.357822, -62.782998],
+    ),
+    (
+        "KP",
+        [37.669070543, 42.9853868678, 124.265624628, 130.780007359],
+        [40.339852, 127.510093],
+    ),
+    (
+        "KR",
+        [34.3900458847, 38.6122429469, 126.117397903, 129.468304478],
+        [35.907757, 127.766922],
+    ),
+    (
+        "KW",
+        [28.5260627304, 30.0590699326, 46.5687134133, 48.4160941913],
+        [29.31166, 47.481766],
+    ),
+    (
+        "KZ",
+        [40.6623245306, 55.3852501491, 46.4664457538, 87.3599703308],
+        [48.019573, 66.923684],
+    ),
+    (
+        "LA",
+        [13.88109101, 22.4647531194, 100.115987583, 107.564525181],
+        [19.85627, 102.495496],
+    ),
+    (
+        "LB",
+        [33.0890400254, 34.6449140488, 35.1260526873, 36.6117501157],
+        [33.854721, 35.862285],
+    ),
+    (
+        "LC",
+        [13.71, 14.11, -61.08, -60.87],
+        [13.909444, -60.978893],
+    ),
+    ("LI", [47.05, 47.27, 9.47, 9.64], [47.166, 9.555373]),
+    (
+        "LK",
+        [5.96836985923, 9.82407766361, 79.6951668639, 81.7879590189],
+        [7.873054, 80.771797],
+    ),
+    (
+        "LR",
+        [4.35575511313, 8.54105520267, -11.4387794662, -7.53971513511],
+        [6.428055, -9.429499],
+    ),
+    (
+        "LS",
+        [-30.6451058896, -28.6475017229, 26.9992619158, 29.3251664568],
+        [-29.609988, 28.233608],
+    ),
+    (
+        "LT",
+        [53.9057022162, 56.3725283881, 21.0558004086, 26.5882792498],
+        [55.169438, 23.881275],
+    ),
+    (
+        "LU",
+        [49.4426671413, 50.1280516628, 5.67405195478, 6.24275109216],
+        [49.815273, 6.129583],
+    ),
+    (
+        "LV",
+        [55.61510692, 57.9701569688, 21.0558004086, 28.1767094256],
+        [56.879635, 24.603189],
+    ),
+    (
+        "LY",
+        [19.58047, 33.1369957545, 9.31941084152, 25.16482],
+        [26.3351, 17.228331],
+    ),
+    ("MA", [27.66, 35.92, -13.17, -1.0], [31.791702, -7.09262]),
+    ("MC", [43.72, 43.76, 7.40, 7.44], [43.750298, 7.412841]),
+    (
+        "MD",
+        [45.4882831895, 48.4671194525, 26.6193367856, 30.0246586443],
+        [47.411631, 28.369885],
+    ),
+    (
+        "ME",
+        [41.87755, 43.52384, 18.45, 20.3398],
+        [42.708678, 19.37439],
+    ),
+    (
+        "MG",
+        [-25.6014344215, -12.0405567359, 43.2541870461, 50.4765368996],
+        [-18.766947, 46.869107],
+    ),
+    ("MH", [4.57, 14.62, 160.80, 172.17], [7.131474, 171.184478]),
+    (
+        "MK",
+        [40.8427269557, 42.3202595078, 20.46315, 22.9523771502],
+        [41.608635, 21.745275],
+    ),
+    (
+        "ML",
+        [10.0963607854, 24.9745740829, -12.1707502914, 4.27020999514],
+        [17.570692, -3.996166],
+    ),
+    (
+        "MM",
+        [9.93295990645, 28.335945136, 92.3032344909, 101.180005324],
+        [21.913965, 95.956223],
+    ),
+    (
+        "MN",
+        [41.5974095729, 52.0473660345, 87.7512642761, 119.772823928],
+        [46.862496, 103.846656],
+    ),
+    (
+        "MO",
+        [22.11, 22.22, 113.53, 113.60],
+        [22.198745, 113.543873],
+    ),
+    (
+        "MR",
+        [14.6168342147, 27.3957441269, -17.0634232243, -4.92333736817],
+        [21.00789, -10.940835],
+    ),
+    ("MT", [35.79, 36.08, 14.18, 14.58], [35.937496, 14.375416]),
+    (
+        "MU",
+        [-20.53, -19.98, 57.30, 57.81],
+        [-20.348404, 57.552152],
+    ),
+    ("MV", [-0.70, 7.11, 72.64, 73.76], [3.202778, 73.22068]),
+    (
+        "MW",
+        [-16.8012997372, -9.23059905359, 32.6881653175, 35.7719047381],
+        [-13.254308, 34.301525],
+    ),
+    (
+        "MX",
+        [14.5388286402, 32.72083, -117.12776, -86.811982388],
+        [23.634501, -102.552784],
+    ),
+    (
+        "MY",
+        [0.773131415201, 6.92805288332, 100.085756871, 119.181903925],
+        [4.210484, 101.975766],
+    ),
+    (
+        "MZ",
+        [-26.7421916643, -10.3170960425, 30.1794812355, 40.7754752948],
+        [-18.665695, 35.529562],
+    ),
+    (
+        "NA",
+        [-29.045461928, -16.9413428687, 11.7341988461, 25.0844433937],
+        [-22.95764, 18.49041],
+    ),
+    (
+        "NC",
+        [-22.3999760881, -20.1056458473, 164.029605748, 167.120011428],
+        [-20.904305, 165.618042],
+    ),
+    (
+        "NE",
+        [11.6601671412, 23.4716684026, 0.295646396495, 15.9032466977],
+        [17.607789, 8.081666],
+    ),
+    (
+        "NG",
+        [4.24059418377, 13.8659239771, 2.69170169436, 14.5771777686],
+        [9.081999, 8.675277],
+    ),
+    (
+        "NI",
+        [10.7268390975, 15.0162671981, -87.6684934151, -83.147219001],
+        [12.865416, -85.207229],
+    ),
+    (
+        "NL",
+        [50.803721015, 53.5104033474, 3.31497114423, 7.09205325687],
+        [52.132633, 5.291266],
+    ),
+    (
+        "NO",
+        [58.0788841824, 80.6571442736, 4.99207807783, 31.29341841],
+        [60.472024, 8.468946],
+    ),
+    (
+        "NP",
+        [26.3978980576, 30.4227169866, 80.0884245137, 88.1748043151],
+        [28.394857, 84.124008],
+    ),
+    (
+        "NR",
+        [-0.56, -0.50, 166.90, 166.96],
+        [-0.522778, 166.931503],
+    ),
+    (
+        "NZ",
+        [-46.641235447, -34.4506617165, 166.509144322, 178.517093541],
+        [-40.900557, 174.885971],
+    ),
+    (
+        "OM",
+        [16.6510511337, 26.3959343531, 52.0000098, 59.8080603372],
+        [21.512583, 55.923255],
+    ),
+    (
+        "PA",
+        [7.2205414901, 9.61161001224, -82.9657830472, -77.2425664944],
+        [8.537981, -80.782127],
+    ),
+    (
+        "PE",
+        [
+            -18.3479753557,
+            -0.0572054988649,
+            -81.4109425524,
+            -68.6650797187,
+        ],
+        [-9.189967, -75.015152],
+    ),
+    (
+        "PG",
+        [-10.6524760881, -2.50000212973, 141.000210403, 156.019965448],
+        [-6.314993, 143.95555],
+    ),
+    (
+        "PH",
+        [5.58100332277, 18.5052273625, 117.17427453, 126.537423944],
+        [12.879721, 121.774017],
+    ),
+    (
+        "PK",
+        [23.6919650335, 37.1330309108, 60.8742484882, 77.8374507995],
+        [30.375321, 69.345116],
+    ),
+    (
+        "PL",
+        [49.0273953314, 54.8515359564, 14.0745211117, 24.0299857927],
+        [51.919438, 19.145136],
+    ),
+    (
+        "PR",
+        [17.946553453, 18.5206011011, -67.2424275377, -65.5910037909],
+        [18.220833, -66.590149],
+    ),
+    ("PS", [31.22, 32.55, 34.22, 35.57], [31.952162, 35.233154]),
+    (
+        "PT",
+        [36.838268541, 42.280468655, -9.52657060387, -6.3890876937],
+        [39.399872, -8.224454],
+    ),
+    ("PW", [2.80, 8.10, 131.12, 134.73], [7.51498, 134.58252]),
+    (
+        "PY",
+        [
+            -27.5484990374,
+            -19.3427466773,
+            -62.6850571357,
+            -54.2929595608,
+        ],
+        [-23.442503, -58.443832],
+    ),
+    (
+        "QA",
+        [24.5563308782, 26.1145820175, 50.7439107603, 51.6067004738],
+        [25.354826, 51.183884],
+    ),
+    (
+        "RE",
+        [-21.39, -20.87, 55.21, 55.84],
+        [-21.115141, 55.536384],
+    ),
+    (
+        "RO",
+        [43.6884447292, 48.2208812526, 20.2201924985, 29.62654341],
+        [45.943161, 24.96676],
+    ),
+    (
+        "RS",
+        [42.2452243971, 46.1717298447, 18.82982, 22.9860185076],
+        [44.016521, 21.005859],
+    ),
+    (
+        "RU",
+        [41.151416124, 81.86, 19.64, 190.95],
+        [61.52401, 105.318756],
+    ),
+    (
+        "RW",
+        [-2.91785776125, -1.13465911215, 29.0249263852, 30.8161348813],
+        [-1.940278, 29.873888],
+    ),
+    (
+        "SA",
+        [16.3478913436, 32.161008816, 34.6323360532, 55.6666593769],
+        [23.885942, 45.079162],
+    ),
+    (
+        "SB",
+        [-10.8263672828, -6.59933847415, 156.491357864, 162.398645868],
+        [-9.64571, 160.156194],
+    ),
+    ("SC", [-10.23, -3.71, 46.20, 56.29], [-4.679574, 55.491977]),
+    (
+        "SD",
+        [8.61972971293, 22.0, 21.93681, 38.4100899595],
+        [12.862807, 30.217636],
+    ),
+    (
+        "SE",
+        [55.3617373725, 69.1062472602, 11.0273686052, 23.9033785336],
+        [60.128161, 18.643501],
+    ),
+    ("SG", [1.16, 1.47, 103.60, 104.09], [1.352083, 103.819836]),
+    (
+        "SI",
+        [45.4523163926, 46.8523859727, 13.6981099789, 16.5648083839],
+        [46.151241, 14.995463],
+    ),
+    (
+        "SK",
+        [47.7584288601, 49.5715740017, 16.8799829444, 22.5581376482],
+        [48.669026, 19.699024],
+    ),
+    (
+        "SL",
+        [6.78591685631, 10.0469839543, -13.2465502588, -10.2300935531],
+        [8.460555, -11.779889],
+    ),
+    ("SM", [43.89, 43.99, 12.40, 12.52], [43.94236, 12.457777]),
+    (
+        "SN",
+        [12.332089952, 16.5982636581, -17.6250426905, -11.4678991358],
+        [14.497401, -14.452362],
+    ),
+    (
+        "SO",
+        [-1.68325, 12.02464, 40.98105, 51.13387],
+        [5.152149, 46.199616],
+    ),
+    (
+        "SR",
+        [1.81766714112, 6.0252914494, -58.0446943834, -53.9580446031],
+        [3.919305, -56.027783],
+    ),
+    (
+        "SS",
+        [3.50917, 12.2480077571, 23.8869795809, 35.2980071182],
+        [7.862685, 29.694923],
+    ),
+    ("ST", [0.02, 1.70, 6.46, 7.47], [0.18636, 6.613081]),
+    (
+        "SV",
+        [13.1490168319, 14.4241327987, -90.0955545723, -87.7235029772],
+        [13.794185, -88.89653],
+    ),
+    (
+        "SY",
+        [32.312937527, 37.2298725449, 35.7007979673, 42.3495910988],
+        [34.802075, 38.996815],
+    ),
+    (
+        "SZ",
+        [-27.2858794085, -25.660190525, 30.6766085141, 32.0716654803],
+        [-26.522503, 31.465866],
+    ),
+    (
+        "TD",
+        [7.42192454674, 23.40972, 13.5403935076, 23.88689],
+        [15.454166, 18.732207],
+    ),
+    (
+        "TF",
+        [-49.7752, -48.625, 68.72, 70.56],
+        [-49.280366, 69.348557],
+    ),
+    (
+        "TG",
+        [
+            5.92883738853,
+            11.0186817489,
+            -0.0497847151599,
+            1.86524051271,
+        ],
+        [8.619543, 0.824782],
+    ),
+    (
+        "TH",
+        [5.69138418215, 20.4178496363, 97.3758964376, 105.589038527],
+        [15.870032, 100.992541],
+    ),
+    (
+        "TJ",
+        [36.7381712916, 40.9602133245, 67.4422196796, 74.9800024759],
+        [38.861034, 71.276093],
+    ),
+    (
+        "TL",
+        [-9.39317310958, -8.27334482181, 124.968682489, 127.335928176],
+        [-8.874217, 125.727539],
+    ),
+    (
+        "TM",
+        [35.2706639674, 42.7515510117, 52.5024597512, 66.5461503437],
+        [38.969719, 59.556278],
+    ),
+    (
+        "TN",
+        [30.3075560572, 37.3499944118, 7.52448164229, 11.4887874691],
+        [33.886917, 9.537499],
+    ),
+    (
+        "TO",
+        [-22.35, -15.56, -175.68, -173.70],
+        [-21.178986, -175.198242],
+    ),
+    (
+        "TR",
+        [35.8215347357, 42.1414848903, 26.0433512713, 44.7939896991],
+        [38.963745, 35.243322],
+    ),
+    (
+        "TT",
+        [10.0, 10.89, -61.95, -60.895],
+        [10.691803, -61.222503],
+    ),
+    (
+        "TV",
+        [-10.80, -5.64, 176.06, 179.87],
+        [-7.109535, 177.64933],
+    ),
+    (
+        "TW",
+        [21.9705713974, 25.2954588893, 120.106188593, 121.951243931],
+        [23.69781, 120.960515],
+    ),
+    (
+        "TZ",
+        [-11.7209380022, -0.95, 29.3399975929, 40.31659],
+        [-6.369028, 34.888822],
+    ),
+    (
+        "UA",
+        [44.3614785833, 52.3350745713, 22.0856083513, 40.0807890155],
+        [48.379433, 31.16558],
+    ),
+    (
+        "UG",
+        [-1.44332244223, 4.24988494736, 29.5794661801, 35.03599],
+        [1.373333, 32.290275],
+    ),
+    (
+        "US",
+        [18.91619, 71.3577635769, -171.791110603, -66.96466],
+        [37.09024, -95.712891],
+    ),
+    (
+        "UY",
+        [
+            -34.9526465797,
+            -30.1096863746,
+            -58.4270741441,
+            -53.209588996,
+        ],
+        [-32.522779, -55.765835],
+    ),
+    (
+        "UZ",
+        [37.1449940049, 45.5868043076, 55.9289172707, 73.055417108],
+        [41.377491, 64.585262],
+    ),
+    (
+        "VA",
+        [41.900, 41.907, 12.445, 12.458],
+        [41.902916, 12.453389],
+    ),
+    (
+        "VC",
+        [12.58, 13.38, -61.46, -61.11],
+        [12.984305, -61.287228],
+    ),
+    (
+        "VE",
+        [
+            0.724452215982,
+            12.1623070337,
+            -73.3049515449,
+            -59.7582848782,
+        ],
+        [6.42375, -66.58973],
+    ),
+    (
+        "VN",
+        [8.59975962975, 23.3520633001, 102.170435826, 109.33526981],
+        [14.058324, 108.277199],
+    ),
+    (
+        "VU",
+        [-16.5978496233, -14.6264970842, 166.629136998, 167.844876744],
+        [-15.376706, 166.959158],
+    ),
+    (
+        "WS",
+        [-14.08, -13.43, -172.80, -171.40],
+        [-13.759029, -172.104629],
+    ),
+    ("XK", [41.85, 43.27, 20.01, 21.79], [42.602636, 20.902977]),
+    (
+        "YE",
+        [12.5859504257, 19.0000033635, 42.6048726743, 53.1085726255],
+        [15.552727, 48.516388],
+    ),
+    (
+        "ZA",
+        [-34.8191663551, -22.0913127581, 16.3449768409, 32.830120477],
+        [-30.559482, 22.937506],
+    ),
+    (
+        "ZM",
+        [-17.9612289364, -8.23825652429, 21.887842645, 33.4856876971],
+        [-13.133897, 27.849332],
+    ),
+    (
+        "ZW",
+        [-22.2716118303, -15.5077869605, 25.2642257016, 32.8498608742],
+        [-19.015438, 29.154857],
+    ),
+];
+
+fn lookup(code: &str) -> Option<&'static CountryRecord> {
+    let code = code.to_ascii_uppercase();
+    COUNTRIES
+        .binary_search_by(|(candidate, ..)| candidate.cmp(&code.as_str()))
+        .ok()
+        .map(|index| &COUNTRIES[index])
+}
+
+impl CoordinateBoundaries {
+    /// # Summary
+    /// Get the approximate bounding box of a country by its ISO 3166-1 alpha-2 code, ignoring
+    /// case. Returns `None` for unknown codes
+    ///
+    /// ## Notes
+    /// - Extents cover the main territory only, remote islands and overseas regions are left out
+    /// - Intended for rough validation and default map views, not for point in country checks
+    ///
+    /// # Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries};
+    ///
+    /// let germany = CoordinateBoundaries::for_country("DE").unwrap();
+    ///
+    /// assert!(germany.contains(&Coordinate::new(52.52, 13.405)));
+    /// assert!(!germany.contains(&Coordinate::new(48.8566, 2.3522)));
+    /// assert_eq!(Some(germany), CoordinateBoundaries::for_country("de"));
+    /// assert!(CoordinateBoundaries::for_country("ZZ").is_none());
+    /// ```
+    pub fn for_country(code: &str) -> Option<Self> {
+        let (_, [min_lat, max_lat, min_lon, max_lon], _) = lookup(code)?;
+        Self::from_extents(*min_lat, *max_lat, *min_lon, *max_lon)
+    }
+}
+
+impl Coordinate {
+    /// # Summary
+    /// Get the approximate centroid of a country by its ISO 3166-1 alpha-2 code, ignoring case.
+    /// Returns `None` for unknown codes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries};
+    ///
+    /// let centroid = Coordinate::country_centroid("FR").unwrap();
+    ///
+    /// assert!(CoordinateBoundaries::for_country("FR").unwrap().contains(&centroid));
+    /// assert!(Coordinate::country_centroid("ZZ").is_none());
+    /// ```
+    pub fn country_centroid(code: &str) -> Option<Self> {
+        let (_, _, [latitude, longitude]) = lookup(code)?;
+        Some(Self::new(*latitude, *longitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_countries_are_sorted_and_consistent() {
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        for (code, ..) in COUNTRIES {
+            let bounds = CoordinateBoundaries::for_country(code)
+                .unwrap_or_else(|| panic!("invalid extents for {code}"));
+            let centroid = Coordinate::country_centroid(code).unwrap();
+            assert!(
+                bounds.contains(&centroid),
+                "{code} centroid outside its bounds"
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::math;
+use crate::spherical::{cross, dot, from_unit_vector, normalize, to_unit_vector};
+use crate::Coordinate;
+use std::f64::consts::TAU;
+
+/// # Summary
+/// Find every point where a path, following great circles between its points, crosses the
+/// parallel at `latitude`
+///
+/// ## Notes
+/// - Crossings are returned in path order
+/// - A leg can cross a parallel twice, e.g. a transatlantic leg rising above and falling back
+///   below a latitude
+/// - A crossing exactly on a path point is reported once
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{crossing_parallels, Coordinate};
+///
+/// let path = vec![Coordinate::new(-10.0, 20.0), Coordinate::new(10.0, 20.0), Coordinate::new(10.0, 30.0)];
+/// let equator = crossing_parallels(&path, 0.0);
+///
+/// assert_eq!(1, equator.len());
+/// assert!(equator[0].latitude.abs() < 1e-9);
+/// assert!((equator[0].longitude - 20.0).abs() < 1e-9);
+/// ```
+pub fn crossing_parallels(path: &[Coordinate], latitude: f64) -> Vec<Coordinate> {
+    let target = math::sin(latitude.to_radians());
+    crossings(path, |start, tangent| {
+        // z(θ) = start.z cos θ + tangent.z sin θ = R cos(θ - α)
+        let amplitude = math::hypot(start[2], tangent[2]);
+        if amplitude < 1e-15 || target.abs() > amplitude {
+            return vec![];
+        }
+        let alpha = math::atan2(tangent[2], start[2]);
+        let offset = math::acos((target / amplitude).clamp(-1.0, 1.0));
+        vec![alpha - offset, alpha + offset]
+    })
+    .into_iter()
+    .map(|coordinate| Coordinate::new(latitude, coordinate.longitude))
+    .collect()
+}
+
+/// # Summary
+/// Find every point where a path, following great circles between its points, crosses the
+/// meridian at `longitude`
+///
+/// ## Notes
+/// - Crossings are returned in path order
+/// - Legs running exactly along the meridian are not reported
+/// - Useful to find antimeridian crossings with a longitude of `180.0`
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{crossing_meridian, Coordinate};
+///
+/// let path = vec![Coordinate::new(50.0, 170.0), Coordinate::new(50.0, -170.0)];
+/// let antimeridian = crossing_meridian(&path, 180.0);
+///
+/// assert_eq!(1, antimeridian.len());
+/// assert_eq!(180.0, antimeridian[0].longitude.abs());
+/// // The great circle bulges towards the pole
+/// assert!(antimeridian[0].latitude > 50.0);
+/// ```
+pub fn crossing_meridian(path: &[Coordinate], longitude: f64) -> Vec<Coordinate> {
+    let (sin_lon, cos_lon) = math::sin_cos(longitude.to_radians());
+    let normal = [-sin_lon, cos_lon, 0.0];
+    let direction = [cos_lon, sin_lon, 0.0];
+
+    crossings(path, |start, tangent| {
+        // p(θ) · normal = 0, keeping the solution on the `longitude` side of the meridian plane
+        let (a, b) = (dot(start, normal), dot(tangent, normal));
+        if math::hypot(a, b) < 1e-15 {
+            return vec![];
+        }
+        let theta = math::atan2(-a, b);
+        [theta, theta + std::f64::consts::PI]
+            .into_iter()
+            .filter(|theta| {
+                let point = along(start, tangent, *theta);
+                dot(point, direction) > 0.0
+            })
+            .collect()
+    })
+    .into_iter()
+    .map(|coordinate| Coordinate::new(coordinate.latitude, longitude))
+    .collect()
+}
+
+/// # Summary
+/// Walk every leg of `path` and collect the points at the angles returned by `solve`.
+///
+/// `solve` receives the leg start and the unit tangent at the start pointing towards the leg end,
+/// so the great circle is `start cos θ + tangent sin θ`, and returns candidate angles `θ`. Angles
+/// within `[0, leg length)` are kept, plus the final path point when it is itself a solution.
+fn crossings(
+    path: &[Coordinate],
+    solve: impl Fn([f64; 3], [f64; 3]) -> Vec<f64>,
+) -> Vec<Coordinate> {
+    let mut found = Vec::new();
+
+    for (index, leg) in path.windows(2).enumerate() {
+        let start = to_unit_vector(&leg[0]);
+        let end = to_unit_vector(&leg[1]);
+        let normal = cross(start, end);
+        if dot(normal, normal) < 1e-24 {
+            continue;
+        }
+        let tangent = normalize(cross(normal, start));
+        let length = math::acos(dot(start, end).clamp(-1.0, 1.0));
+        let is_last = index == path.len() - 2;
+
+        let mut angles: Vec<f64> = solve(start, tangent)
+            .into_iter()
+            .map(|theta| theta.rem_euclid(TAU))
+            .map(|theta| if TAU - theta < 1e-12 { 0.0 } else { theta })
+            .filter(|&theta| theta < length || (is_last && theta - length < 1e-12))
+            .collect();
+        angles.sort_by(f64::total_cmp);
+        angles.dedup_by(|a, b| (*a - *b).abs() < 1e-12);
+
+        found.extend(
+            angles
+                .into_iter()
+                .map(|theta| from_unit_vector(along(start, tangent, theta))),
+        );
+    }
+
+    found
+}
+
+fn along(start: [f64; 3], tangent: [f64; 3], theta: f64) -> [f64; 3] {
+    let (sin, cos) = math::sin_cos(theta);
+    [
+        start[0] * cos + tangent[0] * sin,
+        start[1] * cos + tangent[1] * sin,
+        start[2] * cos + tangent[2] * sin,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_crossed_twice_by_one_leg() {
+        // New York to Madrid peaks above 45°N
+        let path = vec![Coordinate::new(40.71, -74.0), Coordinate::new(40.42, -3.7)];
+        let crossings = crossing_parallels(&path, 45.0);
+
+        assert_eq!(2, crossings.len());
+        assert!(crossings[0].longitude < crossings[1].longitude);
+        assert!(crossing_parallels(&path, 60.0).is_empty());
+    }
+
+    #[test]
+    fn test_crossing_on_shared_point_reported_once() {
+        let path = vec![
+            Coordinate::new(0.0, -1.0),
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 1.0),
+        ];
+        assert_eq!(1, crossing_meridian(&path, 0.0).len());
+
+        let ending_on_meridian = vec![Coordinate::new(0.0, -1.0), Coordinate::new(0.0, 0.0)];
+        assert_eq!(1, crossing_meridian(&ending_on_meridian, 0.0).len());
+    }
+
+    #[test]
+    fn test_meridian_not_crossed() {
+        let path = vec![Coordinate::new(10.0, 10.0), Coordinate::new(20.0, 20.0)];
+        assert!(crossing_meridian(&path, 30.0).is_empty());
+        assert!(crossing_meridian(&path, -165.0).is_empty());
+    }
+}
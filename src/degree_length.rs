@@ -0,0 +1,86 @@
+use crate::math;
+
+/// # Summary
+/// Get the length of one degree of latitude in meters at the given latitude on the WGS84 ellipsoid
+///
+/// ## Notes
+/// - Uses the standard series expansion
+///   `111132.92 - 559.82 cos(2φ) + 1.175 cos(4φ) - 0.0023 cos(6φ)`
+/// - A degree of latitude grows from ~110574 m at the equator to ~111694 m at the poles
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::meters_per_degree_latitude;
+///
+/// assert_eq!(110574.0, meters_per_degree_latitude(0.0).round());
+/// assert_eq!(111694.0, meters_per_degree_latitude(90.0).round());
+/// ```
+pub fn meters_per_degree_latitude(latitude: f64) -> f64 {
+    let lat = latitude.to_radians();
+
+    111132.92 - 559.82 * math::cos(2.0 * lat) + 1.175 * math::cos(4.0 * lat)
+        - 0.0023 * math::cos(6.0 * lat)
+}
+
+/// # Summary
+/// Get the length of one degree of longitude in meters at the given latitude on the WGS84 ellipsoid
+///
+/// ## Notes
+/// - Uses the standard series expansion `111412.84 cos(φ) - 93.5 cos(3φ) + 0.118 cos(5φ)`
+/// - Never negative, reaching 0 at the poles
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::meters_per_degree_longitude;
+///
+/// assert_eq!(111319.0, meters_per_degree_longitude(0.0).round());
+/// assert_eq!(55800.0, meters_per_degree_longitude(60.0).round());
+/// ```
+pub fn meters_per_degree_longitude(latitude: f64) -> f64 {
+    let lat = latitude.to_radians();
+
+    (111412.84 * math::cos(lat) - 93.5 * math::cos(3.0 * lat) + 0.118 * math::cos(5.0 * lat))
+        .max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{linear_divisor, WGS84_FLATTENING, WGS84_SEMI_MAJOR_AXIS_M};
+    use crate::DistanceUnit;
+
+    #[test]
+    fn test_degree_length_matches_ellipsoid() {
+        // WGS84 meridian quadrant is 10001965.729 m, the rounded series coefficients sum to within
+        // a few meters of it
+        let quadrant: f64 = (0..90)
+            .map(|degree| meters_per_degree_latitude(degree as f64 + 0.5))
+            .sum();
+        assert!((quadrant - 10_001_965.729).abs() < 5.0, "{}", quadrant);
+
+        let mean =
+            |unit: &DistanceUnit| (quadrant / 90.0 / linear_divisor(unit) * 1e3).round() / 1e3;
+        assert_eq!(111.133, mean(&DistanceUnit::Kilometers));
+        assert_eq!(69.055, mean(&DistanceUnit::Miles));
+        assert_eq!(60.007, mean(&DistanceUnit::NauticalMiles));
+    }
+
+    #[test]
+    fn test_matches_ellipsoid_radii_of_curvature() {
+        let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+
+        for latitude in (0..=90).step_by(5).map(f64::from) {
+            let sin2 = latitude.to_radians().sin().powi(2);
+            let w = (1.0 - e2 * sin2).sqrt();
+            let meridional = WGS84_SEMI_MAJOR_AXIS_M * (1.0 - e2) / w.powi(3);
+            let prime_vertical = WGS84_SEMI_MAJOR_AXIS_M / w;
+
+            let expected_latitude = meridional.to_radians();
+            let expected_longitude = (prime_vertical * latitude.to_radians().cos()).to_radians();
+
+            // The published series coefficients are rounded, agreeing within ~6 cm
+            assert!((meters_per_degree_latitude(latitude) - expected_latitude).abs() < 0.1);
+            assert!((meters_per_degree_longitude(latitude) - expected_longitude).abs() < 0.1);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::coordinate_parsing::{parse_decimal, DecimalSeparator};
+use crate::utils::linear_divisor;
+use crate::DistanceUnit;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while parsing a distance or distance unit from text
+pub enum DistanceParseError {
+    /// The value was not a plain decimal number
+    InvalidNumber(String),
+    /// No unit followed the value
+    MissingUnit,
+    /// The unit is not one of the supported abbreviations or names
+    UnknownUnit(String),
+}
+
+impl fmt::Display for DistanceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistanceParseError::InvalidNumber(number) => {
+                write!(f, "\"{}\" is not a decimal number", number)
+            }
+            DistanceParseError::MissingUnit => write!(f, "distance is missing a unit"),
+            DistanceParseError::UnknownUnit(unit) => {
+                write!(f, "unknown distance unit \"{}\"", unit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DistanceParseError {}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Struct representing a distance value along with the unit it is measured in
+pub struct Distance {
+    pub value: f64,
+    pub unit: DistanceUnit,
+}
+
+impl Distance {
+    /// # Summary
+    /// Construct a new Distance
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Distance, DistanceUnit};
+    ///
+    /// let distance = Distance::new(1.5, DistanceUnit::Kilometers);
+    /// assert_eq!(1.5, distance.value);
+    /// assert_eq!(DistanceUnit::Kilometers, distance.unit);
+    /// ```
+    pub const fn new(value: f64, unit: DistanceUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// # Summary
+    /// Convert this distance into another unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Distance, DistanceUnit};
+    ///
+    /// let distance = Distance::new(1.5, DistanceUnit::Kilometers);
+    /// let meters = distance.to_unit(&DistanceUnit::Meters);
+    ///
+    /// assert_eq!(1500.0, meters.value);
+    /// assert_eq!(DistanceUnit::Meters, meters.unit);
+    /// ```
+    pub const fn to_unit(&self, unit: &DistanceUnit) -> Distance {
+        Distance::new(self.unit.convert(self.value, unit), *unit)
+    }
+
+    /// # Summary
+    /// Format the distance for display using consistent, unit-aware rounding rules
+    ///
+    /// ## Notes
+    /// - Metric distances below 1 km are shown in whole meters (nearest 10 m from 100 m up),
+    ///   otherwise in kilometers with 1 decimal below 10 km and none above
+    /// - Miles and nautical miles keep their unit and use 2 decimals below 0.1,
+    ///   1 decimal below 10 and none above
+    /// - Output never depends on the system locale
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Distance, DistanceUnit};
+    ///
+    /// assert_eq!("1.2 km", Distance::new(1234.0, DistanceUnit::Meters).humanize());
+    /// assert_eq!("350 m", Distance::new(0.347, DistanceUnit::Kilometers).humanize());
+    /// assert_eq!("0.8 mi", Distance::new(0.8, DistanceUnit::Miles).humanize());
+    /// assert_eq!("24 km", Distance::new(23.6, DistanceUnit::Kilometers).humanize());
+    /// ```
+    pub fn humanize(&self) -> String {
+        match self.unit {
+            DistanceUnit::Meters | DistanceUnit::Kilometers => {
+                let meters = self.to_unit(&DistanceUnit::Meters).value;
+                let rounded_meters = if meters.abs() < 100.0 {
+                    meters.round()
+                } else {
+                    (meters / 10.0).round() * 10.0
+                };
+                if rounded_meters.abs() < 1000.0 {
+                    return format!("{:.0} {}", rounded_meters, DistanceUnit::Meters);
+                }
+                let kilometers = meters / linear_divisor(&DistanceUnit::Kilometers);
+                format!(
+                    "{:.*} {}",
+                    decimals_for(kilometers, false),
+                    kilometers,
+                    DistanceUnit::Kilometers
+                )
+            }
+            DistanceUnit::Miles | DistanceUnit::NauticalMiles => format!(
+                "{:.*} {}",
+                decimals_for(self.value, true),
+                self.value,
+                self.unit
+            ),
+        }
+    }
+
+    /// # Summary
+    /// Snap the distance to a human-friendly value, such as 1.5 km, 500 m or 0.25 mi, for
+    /// approximate figures in user interfaces
+    ///
+    /// ## Notes
+    /// - Values are rounded to the nearest half of their leading power of ten, so 1.47 becomes 1.5,
+    ///   523 becomes 500 and 0.27 becomes 0.25
+    /// - Metric distances are expressed in meters below 1 km and in kilometers from 1 km up, miles
+    ///   and nautical miles keep their unit
+    /// - Zero and non-finite values are returned unchanged
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Distance, DistanceUnit};
+    ///
+    /// assert_eq!(Distance::new(1.5, DistanceUnit::Kilometers), Distance::new(1470.0, DistanceUnit::Meters).round_human());
+    /// assert_eq!(Distance::new(500.0, DistanceUnit::Meters), Distance::new(0.523, DistanceUnit::Kilometers).round_human());
+    /// assert_eq!(Distance::new(0.25, DistanceUnit::Miles), Distance::new(0.27, DistanceUnit::Miles).round_human());
+    /// assert_eq!(Distance::new(1.0, DistanceUnit::Kilometers), Distance::new(980.0, DistanceUnit::Meters).round_human());
+    /// ```
+    pub fn round_human(&self) -> Distance {
+        match self.unit {
+            DistanceUnit::Meters | DistanceUnit::Kilometers => {
+                let meters = snap_to_half_decade(self.to_unit(&DistanceUnit::Meters).value);
+                let unit = if meters.abs() < 1000.0 {
+                    DistanceUnit::Meters
+                } else {
+                    DistanceUnit::Kilometers
+                };
+                let value = snap_to_half_decade(DistanceUnit::Meters.convert(meters, &unit));
+                Distance::new(value, unit)
+            }
+            DistanceUnit::Miles | DistanceUnit::NauticalMiles => {
+                Distance::new(snap_to_half_decade(self.value), self.unit)
+            }
+        }
+    }
+}
+
+/// # Summary
+/// Round a value to the nearest multiple of half its leading power of ten, so the result is the
+/// closest float to a short decimal such as 0.25 rather than 0.25000000000000006
+fn snap_to_half_decade(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let magnitude = value.abs();
+    let mut exponent = magnitude.log10().floor() as i32;
+    if 10f64.powi(exponent) > magnitude {
+        exponent -= 1;
+    } else if 10f64.powi(exponent + 1) <= magnitude {
+        exponent += 1;
+    }
+
+    if exponent >= 0 {
+        let decade = 10f64.powi(exponent);
+        (value * 2.0 / decade).round() * decade / 2.0
+    } else {
+        let inverse_decade = 10f64.powi(-exponent);
+        (value * 2.0 * inverse_decade).round() / (2.0 * inverse_decade)
+    }
+}
+
+/// # Summary
+/// Number of decimals to show for a humanized value, based on its magnitude
+pub(crate) fn decimals_for(value: f64, allow_hundredths: bool) -> usize {
+    let magnitude = value.abs();
+    if allow_hundredths && magnitude < 0.1 && magnitude != 0.0 {
+        2
+    } else if magnitude < 9.95 {
+        1
+    } else {
+        0
+    }
+}
+
+impl FromStr for Distance {
+    type Err = DistanceParseError;
+
+    /// # Summary
+    /// Parse a distance written as a decimal number followed by a unit, such as `5km`, `3.2 mi`,
+    /// `1500 m` or `2 nmi`. See `DistanceUnit::from_str` for the accepted units
+    ///
+    /// ## Notes
+    /// - Numbers always use `.` as the decimal separator, exponents and thousands separators are
+    ///   rejected
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Distance, DistanceParseError, DistanceUnit};
+    ///
+    /// assert_eq!(Ok(Distance::new(5.0, DistanceUnit::Kilometers)), "5km".parse());
+    /// assert_eq!(Ok(Distance::new(3.2, DistanceUnit::Miles)), "3.2 mi".parse());
+    /// assert_eq!(Ok(Distance::new(2.0, DistanceUnit::NauticalMiles)), " 2 nmi ".parse());
+    ///
+    /// assert_eq!(Err(DistanceParseError::MissingUnit), "1500".parse::<Distance>());
+    /// assert_eq!(Err(DistanceParseError::UnknownUnit("ft".to_string())), "12 ft".parse::<Distance>());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let split = input
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(input.len());
+        let (number, unit) = input.split_at(split);
+
+        let value = parse_decimal(number, DecimalSeparator::Point)
+            .ok_or_else(|| DistanceParseError::InvalidNumber(number.to_string()))?;
+        Ok(Distance::new(value, unit.parse()?))
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
@@ -1,11 +1,111 @@
+use crate::utils::linear_divisor;
+use crate::DistanceParseError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DistanceUnit {
     Miles,
     NauticalMiles,
     Kilometers,
     Meters,
 }
+
+impl DistanceUnit {
+    /// # Summary
+    /// Get the short symbol used when displaying a value in this unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::DistanceUnit;
+    ///
+    /// assert_eq!("km", DistanceUnit::Kilometers.abbreviation());
+    /// assert_eq!("nmi", DistanceUnit::NauticalMiles.abbreviation());
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::NauticalMiles => "nmi",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Meters => "m",
+        }
+    }
+
+    /// # Summary
+    /// Get the number of meters in one of this unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::DistanceUnit;
+    ///
+    /// const NAUTICAL_MILE: f64 = DistanceUnit::NauticalMiles.meters();
+    ///
+    /// assert_eq!(1852.0, NAUTICAL_MILE);
+    /// assert_eq!(1609.344, DistanceUnit::Miles.meters());
+    /// ```
+    pub const fn meters(&self) -> f64 {
+        linear_divisor(self)
+    }
+
+    /// # Summary
+    /// Convert a `value` measured in this unit into `unit`, at compile time when used in a constant
+    ///
+    /// ## Notes
+    /// - Gives exactly the same result as `Distance::to_unit`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::DistanceUnit;
+    ///
+    /// const ALERT_RADIUS_M: f64 = DistanceUnit::Miles.convert(2.0, &DistanceUnit::Meters);
+    ///
+    /// assert_eq!(3218.688, ALERT_RADIUS_M);
+    /// assert_eq!(1.5, DistanceUnit::Meters.convert(1500.0, &DistanceUnit::Kilometers));
+    /// ```
+    pub const fn convert(&self, value: f64, unit: &DistanceUnit) -> f64 {
+        value * self.meters() / unit.meters()
+    }
+}
+
+impl fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+impl FromStr for DistanceUnit {
+    type Err = DistanceParseError;
+
+    /// # Summary
+    /// Parse a unit from its abbreviation or name, ignoring case
+    ///
+    /// ## Notes
+    /// - Accepts `m`, `km`, `mi` and `nmi`, the singular and plural names with either spelling of
+    ///   meter / metre, and `nm` for nautical miles
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::DistanceUnit;
+    ///
+    /// assert_eq!(Ok(DistanceUnit::Kilometers), "km".parse());
+    /// assert_eq!(Ok(DistanceUnit::NauticalMiles), "Nautical Miles".parse());
+    /// assert!("furlong".parse::<DistanceUnit>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let unit = match input.trim().to_ascii_lowercase().as_str() {
+            "m" | "meter" | "meters" | "metre" | "metres" => DistanceUnit::Meters,
+            "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => {
+                DistanceUnit::Kilometers
+            }
+            "mi" | "mile" | "miles" => DistanceUnit::Miles,
+            "nmi" | "nm" | "nautical mile" | "nautical miles" => DistanceUnit::NauticalMiles,
+            "" => return Err(DistanceParseError::MissingUnit),
+            _ => return Err(DistanceParseError::UnknownUnit(input.trim().to_string())),
+        };
+        Ok(unit)
+    }
+}
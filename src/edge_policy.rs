@@ -0,0 +1,112 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// ## Summary
+/// Whether coordinates lying exactly on an edge count as contained
+pub enum EdgeInclusion {
+    #[default]
+    Inclusive,
+    Exclusive,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// ## Summary
+/// Per side inclusion of coordinates lying exactly on the edges of a region, used by
+/// `contains_with_policy` so regions sharing borders, such as adjacent tiles or zones, don't
+/// count the same point twice
+///
+/// ## Notes
+/// - A coordinate on a corner, or on several edges, is contained only if every edge it lies on
+///   is inclusive
+/// - With `EdgePolicy::half_open`, every point of a plane tiled by adjacent regions is contained
+///   in exactly one of them
+pub struct EdgePolicy {
+    pub north: EdgeInclusion,
+    pub south: EdgeInclusion,
+    pub east: EdgeInclusion,
+    pub west: EdgeInclusion,
+}
+
+impl EdgePolicy {
+    /// # Summary
+    /// Every edge is inclusive, matching `contains`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{EdgeInclusion, EdgePolicy};
+    ///
+    /// assert_eq!(EdgeInclusion::Inclusive, EdgePolicy::inclusive().north);
+    /// assert_eq!(EdgePolicy::default(), EdgePolicy::inclusive());
+    /// ```
+    pub const fn inclusive() -> Self {
+        Self::uniform(EdgeInclusion::Inclusive)
+    }
+
+    /// # Summary
+    /// Every edge is exclusive, only the strict interior is contained
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{EdgeInclusion, EdgePolicy};
+    ///
+    /// assert_eq!(EdgeInclusion::Exclusive, EdgePolicy::exclusive().west);
+    /// ```
+    pub const fn exclusive() -> Self {
+        Self::uniform(EdgeInclusion::Exclusive)
+    }
+
+    /// # Summary
+    /// South and west edges are inclusive, north and east edges exclusive, the usual convention
+    /// for tiles and grid cells
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{EdgeInclusion, EdgePolicy};
+    ///
+    /// let policy = EdgePolicy::half_open();
+    ///
+    /// assert_eq!(EdgeInclusion::Inclusive, policy.south);
+    /// assert_eq!(EdgeInclusion::Exclusive, policy.east);
+    /// ```
+    pub const fn half_open() -> Self {
+        Self {
+            north: EdgeInclusion::Exclusive,
+            south: EdgeInclusion::Inclusive,
+            east: EdgeInclusion::Exclusive,
+            west: EdgeInclusion::Inclusive,
+        }
+    }
+
+    const fn uniform(inclusion: EdgeInclusion) -> Self {
+        Self {
+            north: inclusion,
+            south: inclusion,
+            east: inclusion,
+            west: inclusion,
+        }
+    }
+
+    /// # Summary
+    /// Checks if an edge whose outward normal is (`east`, `north`) in longitude / latitude space is
+    /// inclusive. Normals are assigned to the side they point most towards, diagonals count as
+    /// north or south
+    pub(crate) fn includes(&self, east: f64, north: f64) -> bool {
+        let inclusion = if north.abs() >= east.abs() {
+            if north > 0.0 {
+                self.north
+            } else {
+                self.south
+            }
+        } else if east > 0.0 {
+            self.east
+        } else {
+            self.west
+        };
+        inclusion == EdgeInclusion::Inclusive
+    }
+}
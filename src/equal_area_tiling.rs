@@ -0,0 +1,152 @@
+use crate::spherical::distance_to_angle;
+use crate::{math, CoordinateBoundaries, DistanceUnit};
+
+impl CoordinateBoundaries {
+    /// # Summary
+    /// Split the bounds into cells of roughly `cell_size` by `cell_size`, keeping their area about
+    /// equal at every latitude instead of narrowing into slivers towards the poles like a uniform
+    /// latitude / longitude grid
+    ///
+    /// ## Notes
+    /// - Cells come from a fixed global grid of latitude bands, each band split into as many
+    ///   columns as fit its width at its middle latitude. Tilings of different bounds share their
+    ///   cells, only clipped to the bounds at the edges
+    /// - The bands around the poles shrink to three columns, wedges meeting at the pole
+    /// - Cells are ordered south to north, then west to east
+    /// - Returns no cells for a `cell_size` which is not positive and finite
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CoordinateBoundaries, DistanceUnit};
+    ///
+    /// let arctic = CoordinateBoundaries::from_extents(80.0, 90.0, -180.0, 180.0).unwrap();
+    /// let cells = arctic.equal_area_tiles(500.0, &DistanceUnit::Kilometers);
+    ///
+    /// // Columns thin out towards the pole, down to three wedges
+    /// let top: Vec<_> = cells.iter().filter(|cell| cell.max_latitude() == 90.0).collect();
+    /// assert_eq!(3, top.len());
+    /// assert_eq!((60.0, 180.0), (top[2].min_longitude(), top[2].max_longitude()));
+    /// assert!(cells.iter().filter(|cell| cell.min_latitude() < 81.0).count() > 4);
+    /// ```
+    pub fn equal_area_tiles(
+        &self,
+        cell_size: f64,
+        unit: &DistanceUnit,
+    ) -> Vec<CoordinateBoundaries> {
+        if !(cell_size > 0.0 && cell_size.is_finite()) {
+            return Vec::new();
+        }
+        let bands = (180.0 / distance_to_angle(cell_size, unit).to_degrees())
+            .ceil()
+            .max(1.0);
+        let band_height = 180.0 / bands;
+        let band_edge = |band: f64| (band * band_height - 90.0).min(90.0);
+
+        let first_band = ((self.min_latitude() + 90.0) / band_height)
+            .floor()
+            .max(0.0);
+        let last_band = ((self.max_latitude() + 90.0) / band_height)
+            .ceil()
+            .min(bands);
+
+        let mut cells = Vec::new();
+        let mut band = first_band;
+        while band < last_band {
+            let (south, north) = (band_edge(band), band_edge(band + 1.0));
+            band += 1.0;
+            let min_latitude = south.max(self.min_latitude());
+            let max_latitude = north.min(self.max_latitude());
+            if min_latitude >= max_latitude && self.min_latitude() < self.max_latitude() {
+                continue;
+            }
+
+            let middle = ((south + north) / 2.0).to_radians();
+            let columns = (360.0 * math::cos(middle) / band_height).round().max(1.0);
+            let column_width = 360.0 / columns;
+            let column_edge = |column: f64| column * column_width - 180.0;
+
+            let mut column = ((self.min_longitude() + 180.0) / column_width).floor();
+            let last_column = ((self.max_longitude() + 180.0) / column_width).ceil();
+            while column < last_column {
+                let min_longitude = column_edge(column).max(self.min_longitude());
+                let max_longitude = column_edge(column + 1.0).min(self.max_longitude());
+                column += 1.0;
+                if min_longitude >= max_longitude && self.min_longitude() < self.max_longitude() {
+                    continue;
+                }
+                cells.extend(CoordinateBoundaries::from_extents(
+                    min_latitude,
+                    max_latitude,
+                    min_longitude,
+                    max_longitude,
+                ));
+            }
+        }
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Area of a latitude / longitude box on the unit sphere
+    fn area(cell: &CoordinateBoundaries) -> f64 {
+        (cell.max_longitude() - cell.min_longitude()).to_radians()
+            * (math::sin(cell.max_latitude().to_radians())
+                - math::sin(cell.min_latitude().to_radians()))
+    }
+
+    #[test]
+    fn test_cells_cover_bounds_with_equal_areas() {
+        let world = CoordinateBoundaries::from_extents(-90.0, 90.0, -180.0, 180.0).unwrap();
+        let cells = world.equal_area_tiles(300.0, &DistanceUnit::Kilometers);
+
+        let total: f64 = cells.iter().map(area).sum();
+        assert!(
+            (total - 4.0 * std::f64::consts::PI).abs() < 1e-9,
+            "{}",
+            total
+        );
+
+        // Away from the poles every cell is within 10% of the average, the bands next to the
+        // poles round to only a few columns
+        let typical = total / cells.len() as f64;
+        for cell in &cells {
+            let ratio = area(cell) / typical;
+            if cell.min_latitude() > -85.0 && cell.max_latitude() < 85.0 {
+                assert!((0.9..1.1).contains(&ratio), "{:?} {}", cell, ratio);
+            } else {
+                assert!((0.5..2.0).contains(&ratio), "{:?} {}", cell, ratio);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cells_clip_to_bounds() {
+        let bounds = CoordinateBoundaries::from_extents(59.3, 71.2, 4.5, 31.1).unwrap();
+        let cells = bounds.equal_area_tiles(100.0, &DistanceUnit::Kilometers);
+
+        let total: f64 = cells.iter().map(area).sum();
+        assert!((total - area(&bounds)).abs() < 1e-12);
+        for cell in &cells {
+            assert!(cell.min_latitude() >= 59.3 && cell.max_latitude() <= 71.2);
+            assert!(cell.min_longitude() >= 4.5 && cell.max_longitude() <= 31.1);
+        }
+
+        // Cells of smaller bounds are the same cells, only clipped
+        let inner = CoordinateBoundaries::from_extents(65.0, 66.0, 10.0, 20.0).unwrap();
+        for cell in inner.equal_area_tiles(100.0, &DistanceUnit::Kilometers) {
+            assert!(cells.iter().any(|outer| {
+                outer.min_latitude() <= cell.min_latitude()
+                    && outer.max_latitude() >= cell.max_latitude()
+                    && outer.min_longitude() <= cell.min_longitude()
+                    && outer.max_longitude() >= cell.max_longitude()
+            }));
+        }
+        assert!(bounds
+            .equal_area_tiles(0.0, &DistanceUnit::Kilometers)
+            .is_empty());
+    }
+}
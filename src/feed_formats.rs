@@ -0,0 +1,241 @@
+use crate::utils::linear_divisor;
+use crate::{Circle, Coordinate, CoordinateBoundaries, Polygon, Route};
+
+const GML_SRS_NAME: &str = "urn:ogc:def:crs:EPSG::4326";
+
+impl Coordinate {
+    /// # Summary
+    /// Render the coordinate as a GeoRSS Simple `georss:point` element
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(45.256, -71.92);
+    /// assert_eq!("<georss:point>45.256 -71.92</georss:point>", coordinate.to_georss());
+    /// ```
+    pub fn to_georss(&self) -> String {
+        format!("<georss:point>{}</georss:point>", pos(self))
+    }
+
+    /// # Summary
+    /// Render the coordinate as a GML `gml:Point` element in EPSG:4326
+    ///
+    /// ## Notes
+    /// - Positions are written in `latitude longitude` order, the axis order of EPSG:4326
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// let coordinate = Coordinate::new(45.256, -71.92);
+    /// assert_eq!(
+    ///     r#"<gml:Point srsName="urn:ogc:def:crs:EPSG::4326"><gml:pos>45.256 -71.92</gml:pos></gml:Point>"#,
+    ///     coordinate.to_gml()
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> String {
+        format!(
+            r#"<gml:Point srsName="{GML_SRS_NAME}"><gml:pos>{}</gml:pos></gml:Point>"#,
+            pos(self)
+        )
+    }
+}
+
+impl Route {
+    /// # Summary
+    /// Render the route as a GeoRSS Simple `georss:line` element
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![Coordinate::new(45.256, -110.45), Coordinate::new(46.46, -109.48)]);
+    /// assert_eq!("<georss:line>45.256 -110.45 46.46 -109.48</georss:line>", route.to_georss());
+    /// ```
+    pub fn to_georss(&self) -> String {
+        format!(
+            "<georss:line>{}</georss:line>",
+            pos_list(&self.points, false)
+        )
+    }
+
+    /// # Summary
+    /// Render the route as a GML `gml:LineString` element in EPSG:4326
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![Coordinate::new(45.256, -110.45), Coordinate::new(46.46, -109.48)]);
+    /// assert_eq!(
+    ///     r#"<gml:LineString srsName="urn:ogc:def:crs:EPSG::4326"><gml:posList>45.256 -110.45 46.46 -109.48</gml:posList></gml:LineString>"#,
+    ///     route.to_gml()
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> String {
+        format!(
+            r#"<gml:LineString srsName="{GML_SRS_NAME}"><gml:posList>{}</gml:posList></gml:LineString>"#,
+            pos_list(&self.points, false)
+        )
+    }
+}
+
+impl Polygon {
+    /// # Summary
+    /// Render the exterior ring of the polygon as a GeoRSS Simple `georss:polygon` element
+    ///
+    /// ## Notes
+    /// - GeoRSS Simple has no holes, interior rings are left out
+    /// - The ring is closed in the output if it was given open
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!("<georss:polygon>0 0 0 1 1 0 0 0</georss:polygon>", triangle.to_georss());
+    /// ```
+    pub fn to_georss(&self) -> String {
+        format!(
+            "<georss:polygon>{}</georss:polygon>",
+            pos_list(&self.exterior, true)
+        )
+    }
+
+    /// # Summary
+    /// Render the polygon, holes included, as a GML `gml:Polygon` element in EPSG:4326
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     concat!(
+    ///         r#"<gml:Polygon srsName="urn:ogc:def:crs:EPSG::4326">"#,
+    ///         "<gml:exterior><gml:LinearRing><gml:posList>0 0 0 1 1 0 0 0</gml:posList></gml:LinearRing></gml:exterior>",
+    ///         "</gml:Polygon>"
+    ///     ),
+    ///     triangle.to_gml()
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> String {
+        let ring = |points: &[Coordinate]| {
+            format!(
+                "<gml:LinearRing><gml:posList>{}</gml:posList></gml:LinearRing>",
+                pos_list(points, true)
+            )
+        };
+        let interiors: String = self
+            .interiors
+            .iter()
+            .map(|interior| format!("<gml:interior>{}</gml:interior>", ring(interior)))
+            .collect();
+
+        format!(
+            r#"<gml:Polygon srsName="{GML_SRS_NAME}"><gml:exterior>{}</gml:exterior>{}</gml:Polygon>"#,
+            ring(&self.exterior),
+            interiors
+        )
+    }
+}
+
+impl CoordinateBoundaries {
+    /// # Summary
+    /// Render the bounds as a GeoRSS Simple `georss:box` element
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateBoundaries;
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(42.943, 43.039, -71.032, -69.856).unwrap();
+    /// assert_eq!("<georss:box>42.943 -71.032 43.039 -69.856</georss:box>", bounds.to_georss());
+    /// ```
+    pub fn to_georss(&self) -> String {
+        format!(
+            "<georss:box>{} {} {} {}</georss:box>",
+            self.min_latitude(),
+            self.min_longitude(),
+            self.max_latitude(),
+            self.max_longitude()
+        )
+    }
+
+    /// # Summary
+    /// Render the bounds as a GML `gml:Envelope` element in EPSG:4326
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateBoundaries;
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(42.943, 43.039, -71.032, -69.856).unwrap();
+    /// assert_eq!(
+    ///     concat!(
+    ///         r#"<gml:Envelope srsName="urn:ogc:def:crs:EPSG::4326">"#,
+    ///         "<gml:lowerCorner>42.943 -71.032</gml:lowerCorner><gml:upperCorner>43.039 -69.856</gml:upperCorner>",
+    ///         "</gml:Envelope>"
+    ///     ),
+    ///     bounds.to_gml()
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> String {
+        format!(
+            r#"<gml:Envelope srsName="{GML_SRS_NAME}"><gml:lowerCorner>{} {}</gml:lowerCorner><gml:upperCorner>{} {}</gml:upperCorner></gml:Envelope>"#,
+            self.min_latitude(),
+            self.min_longitude(),
+            self.max_latitude(),
+            self.max_longitude()
+        )
+    }
+}
+
+impl Circle {
+    /// # Summary
+    /// Render the circle as a GeoRSS Simple `georss:circle` element, with the radius in meters
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit};
+    ///
+    /// let circle = Circle::new(Coordinate::new(42.943, -71.032), 0.5, DistanceUnit::Kilometers);
+    /// assert_eq!("<georss:circle>42.943 -71.032 500</georss:circle>", circle.to_georss());
+    /// ```
+    pub fn to_georss(&self) -> String {
+        format!(
+            "<georss:circle>{} {}</georss:circle>",
+            pos(&self.center),
+            self.radius * linear_divisor(&self.unit)
+        )
+    }
+}
+
+fn pos(coordinate: &Coordinate) -> String {
+    format!("{} {}", coordinate.latitude, coordinate.longitude)
+}
+
+/// # Summary
+/// Space separated `latitude longitude` pairs, repeating the first point at the end when `close`
+/// is set and the points are not already closed
+fn pos_list(points: &[Coordinate], close: bool) -> String {
+    let closing = points
+        .first()
+        .filter(|first| close && points.last() != Some(first));
+
+    points
+        .iter()
+        .chain(closing)
+        .map(pos)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
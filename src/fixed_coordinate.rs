@@ -0,0 +1,199 @@
+use crate::{Coordinate, CoordinateDelta, ValidationError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+/// Number of fixed point units in one degree
+const UNITS_PER_DEGREE: f64 = 1_000_000.0;
+const FULL_TURN: i64 = 360_000_000;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+/// ## Summary
+/// Struct representing a latlon coordinate as whole microdegrees, for compact storage and exact
+/// comparisons. Takes 8 bytes instead of the 16 of a `Coordinate`
+///
+/// ## Notes
+/// - Converting a `FixedCoordinate` into a `Coordinate` and back always gives the same value
+/// - Converting a `Coordinate` into a `FixedCoordinate` rounds to the nearest microdegree, an error
+///   of at most 0.0000005 degrees or about 5.6 cm
+/// - Ordered by latitude, then longitude
+pub struct FixedCoordinate {
+    /// Latitude in microdegrees, between -90 000 000 and 90 000 000
+    pub latitude: i32,
+    /// Longitude in microdegrees, between -180 000 000 and 180 000 000
+    pub longitude: i32,
+}
+
+impl FixedCoordinate {
+    /// # Summary
+    /// Construct a new FixedCoordinate from a latitude and longitude in microdegrees
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, FixedCoordinate};
+    ///
+    /// let fixed = FixedCoordinate::new(48_137_200, 11_575_600);
+    /// assert_eq!(Coordinate::new(48.1372, 11.5756), fixed.to_coordinate());
+    /// ```
+    pub const fn new(latitude: i32, longitude: i32) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// # Summary
+    /// Convert into a `Coordinate` in degrees, without any loss
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, FixedCoordinate};
+    ///
+    /// let coordinate = FixedCoordinate::new(-33_868_800, 151_209_300).to_coordinate();
+    ///
+    /// assert_eq!(-33.8688, coordinate.latitude);
+    /// assert_eq!(151.2093, coordinate.longitude);
+    /// ```
+    pub fn to_coordinate(&self) -> Coordinate {
+        Coordinate {
+            latitude: self.latitude as f64 / UNITS_PER_DEGREE,
+            longitude: self.longitude as f64 / UNITS_PER_DEGREE,
+        }
+    }
+}
+
+impl From<FixedCoordinate> for Coordinate {
+    fn from(value: FixedCoordinate) -> Self {
+        value.to_coordinate()
+    }
+}
+
+impl TryFrom<Coordinate> for FixedCoordinate {
+    type Error = ValidationError;
+
+    /// # Summary
+    /// Round a coordinate to the nearest microdegree
+    ///
+    /// ## Notes
+    /// - Rejects non-finite values, latitudes outside -90 to 90 and longitudes outside -180 to 180,
+    ///   the same checks as `ValidationLevel::Strict`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, FixedCoordinate, ValidationError};
+    ///
+    /// let fixed = FixedCoordinate::try_from(Coordinate::new(51.50735, -0.1277583)).unwrap();
+    /// assert_eq!(FixedCoordinate::new(51_507_350, -127_758), fixed);
+    ///
+    /// let error = FixedCoordinate::try_from(Coordinate { latitude: 95.0, longitude: 0.0 }).unwrap_err();
+    /// assert_eq!(ValidationError::LatitudeOutOfRange(95.0), error);
+    /// ```
+    fn try_from(value: Coordinate) -> Result<Self, Self::Error> {
+        if !value.latitude.is_finite() || !value.longitude.is_finite() {
+            return Err(ValidationError::NonFinite);
+        }
+        if !(-90.0..=90.0).contains(&value.latitude) {
+            return Err(ValidationError::LatitudeOutOfRange(value.latitude));
+        }
+        if !(-180.0..=180.0).contains(&value.longitude) {
+            return Err(ValidationError::LongitudeOutOfRange(value.longitude));
+        }
+
+        Ok(Self::new(
+            to_units(value.latitude),
+            to_units(value.longitude),
+        ))
+    }
+}
+
+impl Sub for FixedCoordinate {
+    type Output = CoordinateDelta;
+
+    /// # Summary
+    /// Get the delta which moves `rhs` onto `self`, computed exactly in microdegrees before being
+    /// converted to degrees. The longitude delta takes the shortest way around the globe, matching
+    /// `Coordinate - Coordinate`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CoordinateDelta, FixedCoordinate};
+    ///
+    /// let delta = FixedCoordinate::new(1_000_000, -179_000_000) - FixedCoordinate::new(0, 179_000_000);
+    /// assert_eq!(CoordinateDelta::new(1.0, 2.0), delta);
+    /// ```
+    fn sub(self, rhs: FixedCoordinate) -> CoordinateDelta {
+        let latitude = self.latitude as i64 - rhs.latitude as i64;
+        let mut longitude = (self.longitude as i64 - rhs.longitude as i64 + FULL_TURN / 2)
+            .rem_euclid(FULL_TURN)
+            - FULL_TURN / 2;
+        if longitude == -FULL_TURN / 2 {
+            longitude = FULL_TURN / 2;
+        }
+
+        CoordinateDelta::new(
+            latitude as f64 / UNITS_PER_DEGREE,
+            longitude as f64 / UNITS_PER_DEGREE,
+        )
+    }
+}
+
+impl Add<CoordinateDelta> for FixedCoordinate {
+    type Output = FixedCoordinate;
+
+    /// # Summary
+    /// Offset the coordinate by a delta, wrapping the result like `Coordinate + CoordinateDelta`
+    /// and rounding it to the nearest microdegree
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CoordinateDelta, FixedCoordinate};
+    ///
+    /// let moved = FixedCoordinate::new(0, 179_000_000) + CoordinateDelta::new(0.5, 2.0);
+    /// assert_eq!(FixedCoordinate::new(500_000, -179_000_000), moved);
+    /// ```
+    fn add(self, rhs: CoordinateDelta) -> FixedCoordinate {
+        let moved = self.to_coordinate() + rhs;
+        Self::new(to_units(moved.latitude), to_units(moved.longitude))
+    }
+}
+
+impl Sub<CoordinateDelta> for FixedCoordinate {
+    type Output = FixedCoordinate;
+
+    fn sub(self, rhs: CoordinateDelta) -> FixedCoordinate {
+        self + -rhs
+    }
+}
+
+/// Round degrees to whole microdegrees, the input must be within -180 to 180
+fn to_units(degrees: f64) -> i32 {
+    (degrees * UNITS_PER_DEGREE).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mix64;
+
+    #[test]
+    fn test_conversion_bounds() {
+        for seed in 0..10_000u64 {
+            let fixed = FixedCoordinate::new(
+                (mix64(seed) % 180_000_001) as i32 - 90_000_000,
+                (mix64(seed + 1_000_000) % 360_000_001) as i32 - 180_000_000,
+            );
+            let coordinate = fixed.to_coordinate();
+            assert_eq!(Ok(fixed), FixedCoordinate::try_from(coordinate));
+
+            let nudged = Coordinate {
+                latitude: (coordinate.latitude + 4.9e-7).min(90.0),
+                longitude: (coordinate.longitude - 4.9e-7).max(-180.0),
+            };
+            let rounded = FixedCoordinate::try_from(nudged).unwrap().to_coordinate();
+            assert!((rounded.latitude - nudged.latitude).abs() <= 5e-7);
+            assert!((rounded.longitude - nudged.longitude).abs() <= 5e-7);
+        }
+    }
+}
@@ -0,0 +1,1043 @@
+//! Geodesics on the WGS84 ellipsoid using the algorithms of C. F. F. Karney, "Algorithms for
+//! geodesics" (J. Geodesy, 2013), as implemented by GeographicLib.
+//!
+//! Unlike Vincenty's formulas these converge for every pair of points, including nearly antipodal
+//! ones, and are accurate to about 15 nanometers. Variable names follow the notation of the paper:
+//! `bet` for reduced latitudes, `alp` for azimuths, `sig` for arc lengths on the auxiliary sphere,
+//! `omg` and `lam` for longitudes on the auxiliary sphere and the ellipsoid, with `s` and `c`
+//! prefixes for sines and cosines.
+
+use crate::utils::{linear_divisor, WGS84_FLATTENING, WGS84_SEMI_MAJOR_AXIS_M};
+use crate::{math, Coordinate, DistanceUnit, InverseResult};
+
+/// Order of the series expansions, 6 is enough for full double precision with `f` up to 1/150
+const ORDER: usize = 6;
+/// Number of coefficients of the series for `I3`
+const C3_COUNT: usize = ORDER * (ORDER - 1) / 2;
+/// Newton iterations before falling back to bisection
+const NEWTON_ITERATIONS: usize = 20;
+/// Total iterations, enough for bisection to reach full precision
+const MAX_ITERATIONS: usize = NEWTON_ITERATIONS + f64::MANTISSA_DIGITS as usize + 10;
+
+/// Square root of the smallest normal number, standing in for zero where a sign is needed
+const TINY: f64 = 1.4916681462400413e-154;
+const TOL0: f64 = f64::EPSILON;
+const TOL1: f64 = 200.0 * TOL0;
+/// Square root of `TOL0`
+const TOL2: f64 = 1.4901161193847656e-8;
+const TOLB: f64 = TOL0 * TOL2;
+const XTHRESH: f64 = 1000.0 * TOL2;
+
+/// # Summary
+/// Solve the inverse problem between two coordinates on the WGS84 ellipsoid, the length of the
+/// shortest path between them and the bearings at both ends
+///
+/// ## Notes
+/// - Bearings are in degrees clockwise from north, between 0 and 360
+/// - Always converges, nearly antipodal coordinates included, and is accurate to about 15
+///   nanometers
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::geodesic::inverse;
+/// use geolocation_utils::{Coordinate, DistanceUnit};
+///
+/// // Wellington to Salamanca, nearly antipodal
+/// let wellington = Coordinate::new(-41.32, 174.81);
+/// let salamanca = Coordinate::new(40.96, -5.5);
+/// let result = inverse(&wellington, &salamanca, &DistanceUnit::Meters);
+///
+/// assert_eq!(19959679.267, (result.distance * 1000.0).round() / 1000.0);
+/// assert_eq!(161.067669986, (result.initial_bearing * 1e9).round() / 1e9);
+/// assert_eq!(18.825195123, (result.final_bearing * 1e9).round() / 1e9);
+/// ```
+pub fn inverse(from: &Coordinate, to: &Coordinate, unit: &DistanceUnit) -> InverseResult {
+    let (distance, initial_bearing, final_bearing) =
+        Geodesic::wgs84().inverse(from.latitude, from.longitude, to.latitude, to.longitude);
+
+    InverseResult {
+        distance: distance / linear_divisor(unit),
+        initial_bearing: to_bearing(initial_bearing),
+        final_bearing: to_bearing(final_bearing),
+    }
+}
+
+/// # Summary
+/// Solve the direct problem on the WGS84 ellipsoid, the coordinate reached by travelling
+/// `distance` from `from` with an initial `bearing` in degrees clockwise from north. Also returns
+/// the bearing on arrival, in degrees between 0 and 360
+///
+/// ## Notes
+/// - Accurate to about 15 nanometers for any distance, including paths past the antipode
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::geodesic::{direct, inverse};
+/// use geolocation_utils::{Coordinate, DistanceUnit};
+///
+/// let wellington = Coordinate::new(-41.32, 174.81);
+/// let (salamanca, final_bearing) = direct(&wellington, 161.067669986, 19959.679267, &DistanceUnit::Kilometers);
+///
+/// assert_eq!(40.96, (salamanca.latitude * 1e6).round() / 1e6);
+/// assert_eq!(-5.5, (salamanca.longitude * 1e6).round() / 1e6);
+/// assert_eq!(18.825195, (final_bearing * 1e6).round() / 1e6);
+///
+/// let back = inverse(&wellington, &salamanca, &DistanceUnit::Kilometers);
+/// assert!((back.distance - 19959.679267).abs() < 1e-9);
+/// ```
+pub fn direct(
+    from: &Coordinate,
+    bearing: f64,
+    distance: f64,
+    unit: &DistanceUnit,
+) -> (Coordinate, f64) {
+    let (latitude, longitude, final_bearing) = Geodesic::wgs84().direct(
+        from.latitude,
+        from.longitude,
+        bearing,
+        distance * linear_divisor(unit),
+    );
+
+    (
+        Coordinate::new(latitude, longitude),
+        to_bearing(final_bearing),
+    )
+}
+
+/// # Summary
+/// Convert an azimuth between -180 and 180 degrees into a bearing between 0 and 360
+fn to_bearing(azimuth: f64) -> f64 {
+    azimuth.rem_euclid(360.0) + 0.0
+}
+
+/// ## Summary
+/// Constants of an ellipsoid, with the coefficients of the series which only depend on the
+/// third flattening
+struct Geodesic {
+    a: f64,
+    f: f64,
+    /// `1 - f`
+    f1: f64,
+    /// Second eccentricity squared
+    ep2: f64,
+    /// Third flattening
+    n: f64,
+    /// Semi-minor axis
+    b: f64,
+    /// Threshold below which short lines skip Newton's method
+    etol2: f64,
+    /// Coefficients of `A3` in `eps`, highest power first
+    a3x: [f64; ORDER],
+    /// Coefficients of `C3` in `eps`, highest power first for each `C3[l]` in turn
+    c3x: [f64; C3_COUNT],
+}
+
+/// ## Summary
+/// Result of evaluating the longitude difference for a trial initial azimuth, see
+/// `Geodesic::lambda12`
+struct Lambda12 {
+    /// Longitude difference minus the target longitude difference
+    value: f64,
+    salp2: f64,
+    calp2: f64,
+    sig12: f64,
+    ssig1: f64,
+    csig1: f64,
+    ssig2: f64,
+    csig2: f64,
+    eps: f64,
+    /// Derivative of `value` with respect to the initial azimuth
+    dlam12: f64,
+}
+
+/// ## Summary
+/// Solution of a line short enough for `Geodesic::inverse_start` to skip Newton's method
+struct ShortLine {
+    sig12: f64,
+    salp2: f64,
+    calp2: f64,
+    /// Mean of `sqrt(1 + ep2 * sin(bet)^2)` along the line
+    dnm: f64,
+}
+
+impl Geodesic {
+    fn wgs84() -> Self {
+        let a = WGS84_SEMI_MAJOR_AXIS_M;
+        let f = WGS84_FLATTENING;
+        let f1 = 1.0 - f;
+        let e2 = f * (2.0 - f);
+        let n = f / (2.0 - f);
+
+        // Coefficients of A3 in eps, each a polynomial in n given by its coefficients and divisor
+        const A3: [f64; 18] = [
+            -3.0, 128.0, //
+            -2.0, -3.0, 64.0, //
+            -1.0, -3.0, -1.0, 16.0, //
+            3.0, -1.0, -2.0, 8.0, //
+            1.0, -1.0, 2.0, //
+            1.0, 1.0,
+        ];
+        // Coefficients of C3[l] in eps for l = 1 to 5, laid out the same way
+        const C3: [f64; 45] = [
+            3.0, 128.0, //
+            2.0, 5.0, 128.0, //
+            -1.0, 3.0, 3.0, 64.0, //
+            -1.0, 0.0, 1.0, 8.0, //
+            -1.0, 1.0, 4.0, //
+            5.0, 256.0, //
+            1.0, 3.0, 128.0, //
+            -3.0, -2.0, 3.0, 64.0, //
+            1.0, -3.0, 2.0, 32.0, //
+            7.0, 512.0, //
+            -10.0, 9.0, 384.0, //
+            5.0, -9.0, 5.0, 192.0, //
+            7.0, 512.0, //
+            -14.0, 7.0, 512.0, //
+            21.0, 2560.0,
+        ];
+
+        let mut a3x = [0.0; ORDER];
+        let mut offset = 0;
+        for (k, j) in (0..ORDER).rev().enumerate() {
+            let order = (ORDER - j - 1).min(j);
+            a3x[k] = polyval(order, &A3[offset..], n) / A3[offset + order + 1];
+            offset += order + 2;
+        }
+
+        let mut c3x = [0.0; C3_COUNT];
+        let (mut offset, mut k) = (0, 0);
+        for l in 1..ORDER {
+            for j in (l..ORDER).rev() {
+                let order = (ORDER - j - 1).min(j);
+                c3x[k] = polyval(order, &C3[offset..], n) / C3[offset + order + 1];
+                offset += order + 2;
+                k += 1;
+            }
+        }
+
+        Self {
+            a,
+            f,
+            f1,
+            ep2: e2 / (f1 * f1),
+            n,
+            b: a * f1,
+            etol2: 0.1 * TOL2 / (f.abs().max(0.001) * (1.0 - f / 2.0).min(1.0) / 2.0).sqrt(),
+            a3x,
+            c3x,
+        }
+    }
+
+    fn a3f(&self, eps: f64) -> f64 {
+        polyval(ORDER - 1, &self.a3x, eps)
+    }
+
+    fn c3f(&self, eps: f64) -> [f64; ORDER] {
+        let mut c = [0.0; ORDER];
+        let (mut mult, mut offset) = (1.0, 0);
+        for (l, coefficient) in c.iter_mut().enumerate().skip(1) {
+            let order = ORDER - l - 1;
+            mult *= eps;
+            *coefficient = mult * polyval(order, &self.c3x[offset..], eps);
+            offset += order + 1;
+        }
+        c
+    }
+
+    /// # Summary
+    /// Get the distance and the reduced length, both divided by `b`
+    #[allow(clippy::too_many_arguments)]
+    fn lengths(
+        &self,
+        eps: f64,
+        sig12: f64,
+        ssig1: f64,
+        csig1: f64,
+        dn1: f64,
+        ssig2: f64,
+        csig2: f64,
+        dn2: f64,
+    ) -> (f64, f64) {
+        let c1 = c1f(eps);
+        let c2 = c2f(eps);
+        let a1m1 = a1m1f(eps);
+        let a2m1 = a2m1f(eps);
+        let m0 = a1m1 - a2m1;
+        let (a1, a2) = (1.0 + a1m1, 1.0 + a2m1);
+
+        let b1 = sin_series(ssig2, csig2, &c1) - sin_series(ssig1, csig1, &c1);
+        let b2 = sin_series(ssig2, csig2, &c2) - sin_series(ssig1, csig1, &c2);
+        let s12b = a1 * (sig12 + b1);
+        let j12 = m0 * sig12 + (a1 * b1 - a2 * b2);
+        // Parentheses keep the cancellation exact for coincident points
+        let m12b = dn2 * (csig1 * ssig2) - dn1 * (ssig1 * csig2) - csig1 * csig2 * j12;
+
+        (s12b, m12b)
+    }
+
+    /// # Summary
+    /// Get a starting azimuth for Newton's method, returning `sig12` and the final azimuth too for
+    /// short lines which need no iteration
+    #[allow(clippy::too_many_arguments)]
+    fn inverse_start(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        lam12: f64,
+        slam12: f64,
+        clam12: f64,
+    ) -> (Option<ShortLine>, f64, f64) {
+        // bet12 = bet2 - bet1 in [0, pi), bet12a = bet2 + bet1 in (-pi, 0]
+        let sbet12 = sbet2 * cbet1 - cbet2 * sbet1;
+        let cbet12 = cbet2 * cbet1 + sbet2 * sbet1;
+        let sbet12a = sbet2 * cbet1 + cbet2 * sbet1;
+        let shortline = cbet12 >= 0.0 && sbet12 < 0.5 && cbet2 * lam12 < 0.5;
+
+        let mut dnm = 0.0;
+        let (mut somg12, mut comg12) = (slam12, clam12);
+        if shortline {
+            let mut sbetm2 = (sbet1 + sbet2).powi(2);
+            sbetm2 /= sbetm2 + (cbet1 + cbet2).powi(2);
+            dnm = (1.0 + self.ep2 * sbetm2).sqrt();
+            (somg12, comg12) = math::sin_cos(lam12 / (self.f1 * dnm));
+        }
+
+        let mut salp1 = cbet2 * somg12;
+        let mut calp1 = if comg12 >= 0.0 {
+            sbet12 + cbet2 * sbet1 * somg12 * somg12 / (1.0 + comg12)
+        } else {
+            sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12)
+        };
+        let ssig12 = math::hypot(salp1, calp1);
+        let csig12 = sbet1 * sbet2 + cbet1 * cbet2 * comg12;
+
+        let mut short = None;
+        if shortline && ssig12 < self.etol2 {
+            // Really short lines
+            let mut salp2 = cbet1 * somg12;
+            let mut calp2 = sbet12
+                - cbet1
+                    * sbet2
+                    * if comg12 >= 0.0 {
+                        somg12 * somg12 / (1.0 + comg12)
+                    } else {
+                        1.0 - comg12
+                    };
+            norm2(&mut salp2, &mut calp2);
+            short = Some(ShortLine {
+                sig12: math::atan2(ssig12, csig12),
+                salp2,
+                calp2,
+                dnm,
+            });
+        } else if self.n.abs() > 0.1
+            || csig12 >= 0.0
+            || ssig12 >= 6.0 * self.n.abs() * std::f64::consts::PI * cbet1 * cbet1
+        {
+            // The spherical approximation is good enough
+        } else {
+            // Nearly antipodal, scale to coordinates where the antipode is at the origin and the
+            // singular point at y = 0, x = -1
+            let lam12x = math::atan2(-slam12, -clam12);
+            let k2 = sbet1 * sbet1 * self.ep2;
+            let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+            let lamscale = self.f * cbet1 * self.a3f(eps) * std::f64::consts::PI;
+            let betscale = lamscale * cbet1;
+            let x = lam12x / lamscale;
+            let y = sbet12a / betscale;
+
+            if y > -TOL1 && x > -1.0 - XTHRESH {
+                // Strip near the cut
+                salp1 = (-x).min(1.0);
+                calp1 = -(1.0 - salp1 * salp1).sqrt();
+            } else {
+                // Estimate omg12 from the astroid problem, then alp1 from the spherical formula
+                let k = astroid(x, y);
+                let omg12a = lamscale * (-x * k / (1.0 + k));
+                let (sin_omg12a, cos_omg12a) = math::sin_cos(omg12a);
+                somg12 = sin_omg12a;
+                comg12 = -cos_omg12a;
+                salp1 = cbet2 * somg12;
+                calp1 = sbet12a - cbet2 * sbet1 * somg12 * somg12 / (1.0 - comg12);
+            }
+        }
+
+        // NaN is let through
+        if salp1 > 0.0 || salp1.is_nan() {
+            norm2(&mut salp1, &mut calp1);
+        } else {
+            salp1 = 1.0;
+            calp1 = 0.0;
+        }
+
+        (short, salp1, calp1)
+    }
+
+    /// # Summary
+    /// Get the longitude difference reached with initial azimuth `alp1`, less the target
+    /// difference `lam120`
+    #[allow(clippy::too_many_arguments)]
+    fn lambda12(
+        &self,
+        sbet1: f64,
+        cbet1: f64,
+        dn1: f64,
+        sbet2: f64,
+        cbet2: f64,
+        dn2: f64,
+        salp1: f64,
+        calp1: f64,
+        slam120: f64,
+        clam120: f64,
+        diffp: bool,
+    ) -> Lambda12 {
+        // Break the degeneracy of equatorial lines, which are handled before getting here
+        let calp1 = if sbet1 == 0.0 && calp1 == 0.0 {
+            -TINY
+        } else {
+            calp1
+        };
+
+        // sin(alp1) * cos(bet1) = sin(alp0)
+        let salp0 = salp1 * cbet1;
+        let calp0 = math::hypot(calp1, salp1 * sbet1);
+
+        // tan(bet1) = tan(sig1) * cos(alp1), tan(omg1) = sin(alp0) * tan(sig1)
+        let (mut ssig1, mut csig1) = (sbet1, calp1 * cbet1);
+        let (somg1, comg1) = (salp0 * sbet1, calp1 * cbet1);
+        norm2(&mut ssig1, &mut csig1);
+
+        // Enforce symmetries where abs(bet2) = -bet1, which is singular for Newton's method
+        let salp2 = if cbet2 != cbet1 { salp0 / cbet2 } else { salp1 };
+        let calp2 = if cbet2 != cbet1 || sbet2.abs() != -sbet1 {
+            ((calp1 * cbet1).powi(2)
+                + if cbet1 < -sbet1 {
+                    (cbet2 - cbet1) * (cbet1 + cbet2)
+                } else {
+                    (sbet1 - sbet2) * (sbet1 + sbet2)
+                })
+            .sqrt()
+                / cbet2
+        } else {
+            calp1.abs()
+        };
+
+        // tan(bet2) = tan(sig2) * cos(alp2), tan(omg2) = sin(alp0) * tan(sig2)
+        let (mut ssig2, mut csig2) = (sbet2, calp2 * cbet2);
+        let (somg2, comg2) = (salp0 * sbet2, calp2 * cbet2);
+        norm2(&mut ssig2, &mut csig2);
+
+        // sig12 = sig2 - sig1 and omg12 = omg2 - omg1, limited to [0, pi]
+        let sig12 = math::atan2(
+            (csig1 * ssig2 - ssig1 * csig2).max(0.0) + 0.0,
+            csig1 * csig2 + ssig1 * ssig2,
+        );
+        let somg12 = (comg1 * somg2 - somg1 * comg2).max(0.0) + 0.0;
+        let comg12 = comg1 * comg2 + somg1 * somg2;
+        // eta = omg12 - lam120
+        let eta = math::atan2(
+            somg12 * clam120 - comg12 * slam120,
+            comg12 * clam120 + somg12 * slam120,
+        );
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+        let c3 = self.c3f(eps);
+        let b312 = sin_series(ssig2, csig2, &c3) - sin_series(ssig1, csig1, &c3);
+        let domg12 = -self.f * self.a3f(eps) * salp0 * (sig12 + b312);
+
+        let mut dlam12 = 0.0;
+        if diffp {
+            dlam12 = if calp2 == 0.0 {
+                -2.0 * self.f1 * dn1 / sbet1
+            } else {
+                let (_, m12b) = self.lengths(eps, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2);
+                m12b * self.f1 / (calp2 * cbet2)
+            };
+        }
+
+        Lambda12 {
+            value: eta + domg12,
+            salp2,
+            calp2,
+            sig12,
+            ssig1,
+            csig1,
+            ssig2,
+            csig2,
+            eps,
+            dlam12,
+        }
+    }
+
+    /// # Summary
+    /// Get the distance in meters and the azimuths at both ends in degrees between two points
+    fn inverse(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> (f64, f64, f64) {
+        // Bring the points into the canonical form 0 <= lon12 <= 180, -90 <= lat1 <= -0 and
+        // lat1 <= lat2 <= -lat1, remembering the transformation in lonsign, swapp and latsign
+        let (lon12, lon12s) = ang_diff(lon1, lon2);
+        let mut lonsign = if lon12 >= 0.0 { 1.0 } else { -1.0 };
+        let lon12 = lonsign * ang_round(lon12);
+        let lon12s = ang_round((180.0 - lon12) - lonsign * lon12s);
+        let lam12 = lon12.to_radians();
+        let (slam12, clam12) = if lon12 > 90.0 {
+            let (slam12, clam12) = sin_cos_degrees(lon12s);
+            (slam12, -clam12)
+        } else {
+            sin_cos_degrees(lon12)
+        };
+
+        // Points really close to the equator are treated as on it
+        let (mut lat1, mut lat2) = (ang_round(lat1), ang_round(lat2));
+        let swapp = if lat1.abs() < lat2.abs() { -1.0 } else { 1.0 };
+        if swapp < 0.0 {
+            lonsign = -lonsign;
+            std::mem::swap(&mut lat1, &mut lat2);
+        }
+        let latsign = if lat1.is_sign_negative() { 1.0 } else { -1.0 };
+        lat1 *= latsign;
+        lat2 *= latsign;
+
+        let (mut sbet1, mut cbet1) = sin_cos_degrees(lat1);
+        sbet1 *= self.f1;
+        norm2(&mut sbet1, &mut cbet1);
+        // Keep cbet1 = +epsilon at the poles
+        cbet1 = cbet1.max(TINY);
+        let (mut sbet2, mut cbet2) = sin_cos_degrees(lat2);
+        sbet2 *= self.f1;
+        norm2(&mut sbet2, &mut cbet2);
+        cbet2 = cbet2.max(TINY);
+
+        // Force bet2 = +/- bet1 exactly when the better measure of their difference vanishes
+        if cbet1 < -sbet1 {
+            if cbet2 == cbet1 {
+                sbet2 = sbet1.copysign(sbet2);
+            }
+        } else if sbet2.abs() == -sbet1 {
+            cbet2 = cbet1;
+        }
+
+        let dn1 = (1.0 + self.ep2 * sbet1 * sbet1).sqrt();
+        let dn2 = (1.0 + self.ep2 * sbet2 * sbet2).sqrt();
+
+        let (mut salp1, mut calp1, mut salp2, mut calp2) = (0.0, 0.0, 0.0, 0.0);
+        let mut s12x = 0.0;
+        let mut meridian = lat1 == -90.0 || slam12 == 0.0;
+
+        if meridian {
+            // Both points lie on a single full meridian, the geodesic might follow it
+            (salp1, calp1) = (slam12, clam12);
+            (salp2, calp2) = (0.0, 1.0);
+
+            let (ssig1, csig1) = (sbet1, calp1 * cbet1);
+            let (ssig2, csig2) = (sbet2, calp2 * cbet2);
+            let sig12 = math::atan2(
+                (csig1 * ssig2 - ssig1 * csig2).max(0.0) + 0.0,
+                csig1 * csig2 + ssig1 * ssig2,
+            );
+            let (s12b, m12b) = self.lengths(self.n, sig12, ssig1, csig1, dn1, ssig2, csig2, dn2);
+
+            // A negative reduced length means the meridian is not the shortest path
+            if sig12 < 1.0 || m12b >= 0.0 {
+                // Coincident points might come out slightly negative
+                let coincident = sig12 < 3.0 * TINY || (sig12 < TOL0 && (s12b < 0.0 || m12b < 0.0));
+                s12x = if coincident { 0.0 } else { s12b * self.b };
+            } else {
+                meridian = false;
+            }
+        }
+
+        if !meridian && sbet1 == 0.0 && (self.f <= 0.0 || lon12s >= self.f * 180.0) {
+            // Both points on the equator, close enough for the geodesic to run along it
+            (salp1, calp1, salp2, calp2) = (1.0, 0.0, 1.0, 0.0);
+            s12x = self.a * lam12;
+        } else if !meridian {
+            let (short, start_salp1, start_calp1) =
+                self.inverse_start(sbet1, cbet1, sbet2, cbet2, lam12, slam12, clam12);
+            (salp1, calp1) = (start_salp1, start_calp1);
+
+            if let Some(short) = short {
+                (salp2, calp2) = (short.salp2, short.calp2);
+                s12x = short.sig12 * self.b * short.dnm;
+            } else {
+                // Newton's method on lambda12(alp1) - lam12 = 0, which has exactly one root in
+                // (0, pi) with a positive derivative. The root stays bracketed, and bisection
+                // takes over whenever a Newton step is not usable
+                let (mut salp1a, mut calp1a, mut salp1b, mut calp1b) = (TINY, 1.0, TINY, -1.0);
+                let (mut tripn, mut tripb) = (false, false);
+                let mut iteration = 0;
+                let last = loop {
+                    let lambda = self.lambda12(
+                        sbet1,
+                        cbet1,
+                        dn1,
+                        sbet2,
+                        cbet2,
+                        dn2,
+                        salp1,
+                        calp1,
+                        slam12,
+                        clam12,
+                        iteration < NEWTON_ITERATIONS,
+                    );
+                    let v = lambda.value;
+                    // Also stops on NaN
+                    if tripb
+                        || v.abs() < if tripn { 8.0 } else { 1.0 } * TOL0
+                        || v.is_nan()
+                        || iteration == MAX_ITERATIONS
+                    {
+                        break lambda;
+                    }
+
+                    if v > 0.0 && (iteration > NEWTON_ITERATIONS || calp1 / salp1 > calp1b / salp1b)
+                    {
+                        (salp1b, calp1b) = (salp1, calp1);
+                    } else if v < 0.0
+                        && (iteration > NEWTON_ITERATIONS || calp1 / salp1 < calp1a / salp1a)
+                    {
+                        (salp1a, calp1a) = (salp1, calp1);
+                    }
+                    iteration += 1;
+
+                    if iteration <= NEWTON_ITERATIONS && lambda.dlam12 > 0.0 {
+                        let dalp1 = -v / lambda.dlam12;
+                        if dalp1.abs() < std::f64::consts::PI {
+                            let (sdalp1, cdalp1) = math::sin_cos(dalp1);
+                            let nsalp1 = salp1 * cdalp1 + calp1 * sdalp1;
+                            if nsalp1 > 0.0 {
+                                calp1 = calp1 * cdalp1 - salp1 * sdalp1;
+                                salp1 = nsalp1;
+                                norm2(&mut salp1, &mut calp1);
+                                // Convergence can be linear where the slope goes to zero
+                                tripn = v.abs() <= 16.0 * TOL0;
+                                continue;
+                            }
+                        }
+                    }
+
+                    salp1 = (salp1a + salp1b) / 2.0;
+                    calp1 = (calp1a + calp1b) / 2.0;
+                    norm2(&mut salp1, &mut calp1);
+                    tripn = false;
+                    tripb = (salp1a - salp1).abs() + (calp1a - calp1) < TOLB
+                        || (salp1 - salp1b).abs() + (calp1 - calp1b) < TOLB;
+                };
+
+                (salp2, calp2) = (last.salp2, last.calp2);
+                let (s12b, _) = self.lengths(
+                    last.eps, last.sig12, last.ssig1, last.csig1, dn1, last.ssig2, last.csig2, dn2,
+                );
+                s12x = s12b * self.b;
+            }
+        }
+
+        // Undo the transformation to the canonical form
+        if swapp < 0.0 {
+            std::mem::swap(&mut salp1, &mut salp2);
+            std::mem::swap(&mut calp1, &mut calp2);
+        }
+        salp1 *= swapp * lonsign;
+        calp1 *= swapp * latsign;
+        salp2 *= swapp * lonsign;
+        calp2 *= swapp * latsign;
+
+        (
+            s12x + 0.0,
+            atan2_degrees(salp1, calp1),
+            atan2_degrees(salp2, calp2),
+        )
+    }
+
+    /// # Summary
+    /// Get the latitude, longitude and azimuth in degrees reached after `s12` meters from a point
+    fn direct(&self, lat1: f64, lon1: f64, azi1: f64, s12: f64) -> (f64, f64, f64) {
+        let azi1 = ang_normalize(azi1);
+        // Rounding guards against underflow in salp0
+        let (salp1, calp1) = sin_cos_degrees(ang_round(azi1));
+
+        let (mut sbet1, mut cbet1) = sin_cos_degrees(ang_round(lat1));
+        sbet1 *= self.f1;
+        norm2(&mut sbet1, &mut cbet1);
+        cbet1 = cbet1.max(TINY);
+
+        // sin(alp1) * cos(bet1) = sin(alp0)
+        let salp0 = salp1 * cbet1;
+        let calp0 = math::hypot(calp1, salp1 * sbet1);
+        // tan(bet1) = tan(sig1) * cos(alp1), tan(omg1) = sin(alp0) * tan(sig1), with sig = 0 at
+        // the nearest northward crossing of the equator
+        let (mut ssig1, somg1) = (sbet1, salp0 * sbet1);
+        let mut csig1 = if sbet1 != 0.0 || calp1 != 0.0 {
+            cbet1 * calp1
+        } else {
+            1.0
+        };
+        let comg1 = csig1;
+        norm2(&mut ssig1, &mut csig1);
+
+        let k2 = calp0 * calp0 * self.ep2;
+        let eps = k2 / (2.0 * (1.0 + (1.0 + k2).sqrt()) + k2);
+
+        let a1m1 = a1m1f(eps);
+        let c1 = c1f(eps);
+        let b11 = sin_series(ssig1, csig1, &c1);
+        let (sin_b11, cos_b11) = math::sin_cos(b11);
+        // tau1 = sig1 + B11
+        let stau1 = ssig1 * cos_b11 + csig1 * sin_b11;
+        let ctau1 = csig1 * cos_b11 - ssig1 * sin_b11;
+        let c1p = c1pf(eps);
+        let c3 = self.c3f(eps);
+        let a3c = -self.f * salp0 * self.a3f(eps);
+        let b31 = sin_series(ssig1, csig1, &c3);
+
+        // tau2 = tau1 + tau12, reverted into sig12 through the C1p series
+        let tau12 = s12 / (self.b * (1.0 + a1m1));
+        let (s, c) = math::sin_cos(tau12);
+        let b12 = -sin_series(stau1 * c + ctau1 * s, ctau1 * c - stau1 * s, &c1p);
+        let sig12 = tau12 - (b12 - b11);
+        let (ssig12, csig12) = math::sin_cos(sig12);
+
+        // sig2 = sig1 + sig12
+        let ssig2 = ssig1 * csig12 + csig1 * ssig12;
+        let mut csig2 = csig1 * csig12 - ssig1 * ssig12;
+        // sin(bet2) = cos(alp0) * sin(sig2)
+        let sbet2 = calp0 * ssig2;
+        let mut cbet2 = math::hypot(salp0, calp0 * csig2);
+        if cbet2 == 0.0 {
+            // salp0 = 0 and csig2 = 0, break the degeneracy
+            cbet2 = TINY;
+            csig2 = TINY;
+        }
+        // tan(alp0) = cos(sig2) * tan(alp2)
+        let (salp2, calp2) = (salp0, calp0 * csig2);
+
+        // tan(omg2) = sin(alp0) * tan(sig2)
+        let (somg2, comg2) = (salp0 * ssig2, csig2);
+        let omg12 = math::atan2(somg2 * comg1 - comg2 * somg1, comg2 * comg1 + somg2 * somg1);
+        let lam12 = omg12 + a3c * (sig12 + (sin_series(ssig2, csig2, &c3) - b31));
+        let lon12 = lam12.to_degrees();
+
+        (
+            atan2_degrees(sbet2, self.f1 * cbet2),
+            ang_normalize(ang_normalize(lon1) + ang_normalize(lon12)),
+            atan2_degrees(salp2, calp2),
+        )
+    }
+}
+
+/// # Summary
+/// Evaluate the polynomial of degree `order` with coefficients `p`, highest power first
+fn polyval(order: usize, p: &[f64], x: f64) -> f64 {
+    p[1..=order].iter().fold(p[0], |y, &p| y * x + p)
+}
+
+/// # Summary
+/// Evaluate `sum(c[l] * sin(2 * l * x))` for `l` from 1 by Clenshaw summation, `c[0]` is unused
+fn sin_series(sinx: f64, cosx: f64, c: &[f64]) -> f64 {
+    // 2 * cos(2 * x)
+    let ar = 2.0 * (cosx - sinx) * (cosx + sinx);
+    let (mut y0, mut y1) = (0.0, 0.0);
+    for &coefficient in c[1..].iter().rev() {
+        (y0, y1) = (ar * y0 - y1 + coefficient, y0);
+    }
+
+    2.0 * sinx * cosx * y0
+}
+
+/// # Summary
+/// `A1 - 1` of the distance integral `I1`
+fn a1m1f(eps: f64) -> f64 {
+    // (1 - eps) * A1 - 1 as a polynomial in eps^2
+    const COEFFICIENTS: [f64; 5] = [1.0, 4.0, 64.0, 0.0, 256.0];
+    let t = polyval(3, &COEFFICIENTS, eps * eps) / COEFFICIENTS[4];
+    (t + eps) / (1.0 - eps)
+}
+
+/// # Summary
+/// `A2 - 1` of the reduced length integral `I2`
+fn a2m1f(eps: f64) -> f64 {
+    // A2 / (1 - eps) - 1 as a polynomial in eps^2
+    const COEFFICIENTS: [f64; 5] = [25.0, 36.0, 64.0, 0.0, 256.0];
+    let t = polyval(3, &COEFFICIENTS, eps * eps) / COEFFICIENTS[4];
+    t * (1.0 - eps) - eps
+}
+
+/// # Summary
+/// Evaluate a table of Fourier coefficients, each `C[l] / eps^l` a polynomial in `eps^2` given by
+/// its coefficients and divisor
+fn fourier_coefficients(eps: f64, table: &[f64]) -> [f64; ORDER + 1] {
+    let eps2 = eps * eps;
+    let mut c = [0.0; ORDER + 1];
+    let (mut d, mut offset) = (eps, 0);
+    for (l, coefficient) in c.iter_mut().enumerate().skip(1) {
+        let order = (ORDER - l) / 2;
+        *coefficient = d * polyval(order, &table[offset..], eps2) / table[offset + order + 1];
+        offset += order + 2;
+        d *= eps;
+    }
+    c
+}
+
+/// # Summary
+/// Fourier coefficients `C1[l]` of the distance integral `I1`
+fn c1f(eps: f64) -> [f64; ORDER + 1] {
+    const COEFFICIENTS: [f64; 18] = [
+        -1.0, 6.0, -16.0, 32.0, //
+        -9.0, 64.0, -128.0, 2048.0, //
+        9.0, -16.0, 768.0, //
+        3.0, -5.0, 512.0, //
+        -7.0, 1280.0, //
+        -7.0, 2048.0,
+    ];
+    fourier_coefficients(eps, &COEFFICIENTS)
+}
+
+/// # Summary
+/// Fourier coefficients `C1'[l]` of the reverted distance integral
+fn c1pf(eps: f64) -> [f64; ORDER + 1] {
+    const COEFFICIENTS: [f64; 18] = [
+        205.0, -432.0, 768.0, 1536.0, //
+        4005.0, -4736.0, 3840.0, 12288.0, //
+        -225.0, 116.0, 384.0, //
+        -7173.0, 2695.0, 7680.0, //
+        3467.0, 7680.0, //
+        38081.0, 61440.0,
+    ];
+    fourier_coefficients(eps, &COEFFICIENTS)
+}
+
+/// # Summary
+/// Fourier coefficients `C2[l]` of the reduced length integral `I2`
+fn c2f(eps: f64) -> [f64; ORDER + 1] {
+    const COEFFICIENTS: [f64; 18] = [
+        1.0, 2.0, 16.0, 32.0, //
+        35.0, 64.0, 384.0, 2048.0, //
+        15.0, 80.0, 768.0, //
+        7.0, 35.0, 512.0, //
+        63.0, 1280.0, //
+        77.0, 2048.0,
+    ];
+    fourier_coefficients(eps, &COEFFICIENTS)
+}
+
+/// # Summary
+/// Solve `k^4 + 2k^3 - (x^2 + y^2 - 1)k^2 - 2y^2k - y^2 = 0` for its positive root
+fn astroid(x: f64, y: f64) -> f64 {
+    let p = x * x;
+    let q = y * y;
+    let r = (p + q - 1.0) / 6.0;
+    if q == 0.0 && r <= 0.0 {
+        // y = 0 with abs(x) <= 1
+        return 0.0;
+    }
+
+    // Multiplied through by powers of r to avoid dividing by zero
+    let s = p * q / 4.0;
+    let r2 = r * r;
+    let r3 = r * r2;
+    // Zero on the evolute p^(1/3) + q^(1/3) = 1
+    let disc = s * (s + 2.0 * r3);
+    let mut u = r;
+    if disc >= 0.0 {
+        // Pick the sign of the root which avoids cancellation
+        let mut t3 = s + r3;
+        t3 += if t3 < 0.0 { -disc.sqrt() } else { disc.sqrt() };
+        let t = math::cbrt(t3);
+        u += t + if t != 0.0 { r2 / t } else { 0.0 };
+    } else {
+        // Of the three complex cube roots, take the one giving a real u without cancellation
+        let angle = math::atan2((-disc).sqrt(), -(s + r3));
+        u += 2.0 * r * math::cos(angle / 3.0);
+    }
+    let v = (u * u + q).sqrt();
+    // u + v, rearranged to stay accurate for negative u
+    let uv = if u < 0.0 { q / (v - u) } else { u + v };
+    let w = (uv - q) / (2.0 * v);
+
+    uv / ((uv + w * w).sqrt() + w)
+}
+
+/// # Summary
+/// Normalize a vector to unit length in place
+fn norm2(x: &mut f64, y: &mut f64) {
+    let r = math::hypot(*x, *y);
+    *x /= r;
+    *y /= r;
+}
+
+/// # Summary
+/// Remainder of `x` divided by 360, between -180 and 180
+fn remainder_360(x: f64) -> f64 {
+    let y = x % 360.0;
+    if y < -180.0 {
+        y + 360.0
+    } else if y > 180.0 {
+        y - 360.0
+    } else {
+        y
+    }
+}
+
+/// # Summary
+/// Reduce an angle in degrees to the range (-180, 180]
+fn ang_normalize(x: f64) -> f64 {
+    let y = remainder_360(x);
+    if y == -180.0 {
+        180.0
+    } else {
+        y
+    }
+}
+
+/// # Summary
+/// Get `y - x` reduced to (-180, 180] as the exact sum of a result and its rounding error
+fn ang_diff(x: f64, y: f64) -> (f64, f64) {
+    let (d, t) = two_sum(ang_normalize(-x), ang_normalize(y));
+    let d = ang_normalize(d);
+    two_sum(if d == 180.0 && t > 0.0 { -180.0 } else { d }, t)
+}
+
+/// # Summary
+/// Error free sum, the rounded sum and its rounding error
+fn two_sum(u: f64, v: f64) -> (f64, f64) {
+    let s = u + v;
+    let up = s - v;
+    let vpp = s - up;
+    (s, -((up - u) + (vpp - v)))
+}
+
+/// # Summary
+/// Round tiny angles to multiples of 1/16 of their magnitude's precision, so values very close
+/// to zero become exactly zero and `ang_round(x) == 0` tests are robust
+fn ang_round(x: f64) -> f64 {
+    const Z: f64 = 1.0 / 16.0;
+    let y = x.abs();
+    let w = Z - y;
+    // The compiler keeps this order of operations, which is what rounds small values
+    let y = if w > 0.0 { Z - w } else { y };
+    y.copysign(x)
+}
+
+/// # Summary
+/// Sine and cosine of an angle in degrees, exact for multiples of 90 degrees
+fn sin_cos_degrees(x: f64) -> (f64, f64) {
+    let r = x % 360.0;
+    let q = (r / 90.0).round();
+    let (s, c) = math::sin_cos((r - 90.0 * q).to_radians());
+    let (sinx, cosx) = match (q as i64).rem_euclid(4) {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let sinx = if sinx == 0.0 { sinx.copysign(x) } else { sinx };
+
+    (sinx, cosx + 0.0)
+}
+
+/// # Summary
+/// `atan2(y, x)` in degrees between -180 and 180, exact where the result is a multiple of 90
+fn atan2_degrees(y: f64, x: f64) -> f64 {
+    // Reduce to the first octant for the best precision
+    let (mut x, mut y) = (x, y);
+    let mut q = 0;
+    if y.abs() > x.abs() {
+        std::mem::swap(&mut x, &mut y);
+        q = 2;
+    }
+    if x.is_sign_negative() {
+        x = -x;
+        q += 1;
+    }
+    let angle = math::atan2(y, x).to_degrees();
+    match q {
+        1 => 180.0f64.copysign(y) - angle,
+        2 => 90.0 - angle,
+        3 => -90.0 + angle,
+        _ => angle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_geographiclib_reference() {
+        // Case from the GeographicLib test suite
+        let geodesic = Geodesic::wgs84();
+        let (s12, azi1, azi2) = geodesic.inverse(35.60777, -139.44815, -11.17491, -69.95921);
+        assert!((s12 - 8_935_244.560_481_83).abs() < 1e-6, "{}", s12);
+        assert!((azi1 - 111.098_748_429_560_33).abs() < 1e-11, "{}", azi1);
+        assert!((azi2 - 129.289_270_889_708_77).abs() < 1e-11, "{}", azi2);
+
+        let (lat2, lon2, azi2) = geodesic.direct(35.60777, -139.44815, 111.098_748_429_560_33, s12);
+        assert!((lat2 + 11.17491).abs() < 1e-11, "{}", lat2);
+        assert!((lon2 + 69.95921).abs() < 1e-11, "{}", lon2);
+        assert!((azi2 - 129.289_270_889_708_77).abs() < 1e-11, "{}", azi2);
+    }
+
+    #[test]
+    fn test_round_trips_converge_everywhere() {
+        let geodesic = Geodesic::wgs84();
+
+        for i in 0..20_000u64 {
+            let lat1 = random(4 * i, 180.0);
+            let lon1 = random(4 * i + 1, 360.0);
+            // Half of the pairs are within a degree of antipodal
+            let (lat2, lon2) = if i % 2 == 0 {
+                (random(4 * i + 2, 180.0), random(4 * i + 3, 360.0))
+            } else {
+                (
+                    -lat1 + random(4 * i + 2, 2.0),
+                    lon1 + 180.0 + random(4 * i + 3, 2.0),
+                )
+            };
+            let lat2 = lat2.clamp(-90.0, 90.0);
+
+            let (s12, azi1, _) = geodesic.inverse(lat1, lon1, lat2, lon2);
+            assert!(
+                s12.is_finite() && s12 <= 20_003_931.5,
+                "{} {} {} {}",
+                lat1,
+                lon1,
+                lat2,
+                lon2
+            );
+
+            let (lat, lon, _) = geodesic.direct(lat1, lon1, azi1, s12);
+            let (error, _, _) = geodesic.inverse(lat, lon, lat2, lon2);
+            assert!(
+                error < 1e-7,
+                "{} {} {} {} {}",
+                lat1,
+                lon1,
+                lat2,
+                lon2,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_degenerate_paths() {
+        let geodesic = Geodesic::wgs84();
+
+        let (s12, azi1, azi2) = geodesic.inverse(20.0, 10.0, 20.0, 10.0);
+        assert_eq!((0.0, azi1), (s12, azi2));
+        // Pole to pole along a meridian, a quarter of the equator along it
+        let (s12, azi1, azi2) = geodesic.inverse(-90.0, 0.0, 90.0, 0.0);
+        assert!((s12 - 20_003_931.458625).abs() < 1e-5, "{}", s12);
+        assert_eq!((0.0, 0.0), (azi1, azi2));
+        let (s12, azi1, azi2) = geodesic.inverse(0.0, 0.0, 0.0, 90.0);
+        assert!((s12 - 10_018_754.171394).abs() < 1e-5, "{}", s12);
+        assert_eq!((90.0, 90.0), (azi1, azi2));
+        // Antipodal on the equator, the shortest path crosses a pole
+        let (s12, azi1, _) = geodesic.inverse(0.0, 0.0, 0.0, 180.0);
+        assert!((s12 - 20_003_931.458625).abs() < 1e-5, "{}", s12);
+        assert_eq!(0.0, azi1);
+    }
+}
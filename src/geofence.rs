@@ -0,0 +1,259 @@
+use crate::grid_index::GridIndex;
+use crate::{Bounded, Circle, Coordinate, CoordinateBoundaries, OrientedBounds, Polygon};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Default grid cell size of the fence index in degrees, roughly 11 km at the equator
+const DEFAULT_CELL_SIZE: f64 = 0.1;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A region which can be used as a geofence in a `GeofenceSet`
+pub enum Geofence {
+    Circle(Circle),
+    Polygon(Polygon),
+    Bounds(CoordinateBoundaries),
+    Oriented(OrientedBounds),
+}
+
+impl Geofence {
+    /// # Summary
+    /// Checks if a coordinate lies within the geofence
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Geofence};
+    ///
+    /// let fence = Geofence::from(Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers));
+    ///
+    /// assert!(fence.contains(&Coordinate::new(0.001, 0.001)));
+    /// assert!(!fence.contains(&Coordinate::new(0.1, 0.1)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        match self {
+            Geofence::Circle(circle) => circle.contains(coordinate),
+            Geofence::Polygon(polygon) => polygon.contains(coordinate),
+            Geofence::Bounds(bounds) => bounds.contains(coordinate),
+            Geofence::Oriented(oriented) => oriented.contains(coordinate),
+        }
+    }
+
+    /// # Summary
+    /// Get the approximate bounding box of the geofence. Returns `None` if it can not be represented,
+    /// such as an empty polygon
+    ///
+    /// ## Notes
+    /// - Circles reaching a pole are clamped to it and span every longitude
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Geofence, Polygon};
+    ///
+    /// let fence = Geofence::from(Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]));
+    /// assert_eq!(1.0, fence.bounds().unwrap().max_latitude());
+    ///
+    /// let polar = Geofence::from(Circle::new(Coordinate::new(89.5, 0.0), 200.0, DistanceUnit::Kilometers));
+    /// let bounds = polar.bounds().unwrap();
+    /// assert_eq!(90.0, bounds.max_latitude());
+    /// assert_eq!((-180.0, 180.0), (bounds.min_longitude(), bounds.max_longitude()));
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        match self {
+            Geofence::Circle(circle) => Bounded::bounds(circle),
+            Geofence::Polygon(polygon) => polygon.bounds(),
+            Geofence::Bounds(bounds) => Some(*bounds),
+            Geofence::Oriented(oriented) => Polygon::new(oriented.corners().to_vec()).bounds(),
+        }
+    }
+}
+
+impl From<Circle> for Geofence {
+    fn from(value: Circle) -> Self {
+        Geofence::Circle(value)
+    }
+}
+
+impl From<Polygon> for Geofence {
+    fn from(value: Polygon) -> Self {
+        Geofence::Polygon(value)
+    }
+}
+
+impl From<CoordinateBoundaries> for Geofence {
+    fn from(value: CoordinateBoundaries) -> Self {
+        Geofence::Bounds(value)
+    }
+}
+
+impl From<OrientedBounds> for Geofence {
+    fn from(value: OrientedBounds) -> Self {
+        Geofence::Oriented(value)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// ## Summary
+/// A position found inside a geofence, see `GeofenceSet::evaluate_batch`
+pub struct Hit<P, F> {
+    pub position_id: P,
+    pub fence_id: F,
+}
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// A collection of identified geofences with a spatial index, for evaluating many positions at once
+///
+/// ## Notes
+/// - Fences are indexed in a uniform grid by their bounding box, each position is only checked
+///   against the fences overlapping its grid cell
+/// - Fences without a bounding box, or spanning more than a few thousand cells, are checked against
+///   every position
+pub struct GeofenceSet<F> {
+    fences: Vec<(F, Geofence)>,
+    index: GridIndex,
+}
+
+impl<F> Default for GeofenceSet<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> GeofenceSet<F> {
+    /// # Summary
+    /// Construct an empty GeofenceSet with a 0.1 degree index grid
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::GeofenceSet;
+    ///
+    /// let fences: GeofenceSet<u32> = GeofenceSet::new();
+    /// assert!(fences.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_cell_size(DEFAULT_CELL_SIZE)
+    }
+
+    /// # Summary
+    /// Construct an empty GeofenceSet with index grid cells `cell_size` degrees wide
+    ///
+    /// ## Notes
+    /// - Cells around the size of a typical fence give the best performance
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::GeofenceSet;
+    ///
+    /// // Country sized fences
+    /// let fences: GeofenceSet<&str> = GeofenceSet::with_cell_size(5.0);
+    /// assert_eq!(0, fences.len());
+    /// ```
+    pub fn with_cell_size(cell_size: f64) -> Self {
+        Self {
+            fences: Vec::new(),
+            index: GridIndex::new(cell_size),
+        }
+    }
+
+    /// # Summary
+    /// Add a geofence identified by `id`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    ///
+    /// assert_eq!(1, fences.len());
+    /// ```
+    pub fn insert(&mut self, id: F, fence: impl Into<Geofence>) {
+        let fence = fence.into();
+        self.index.insert(self.fences.len(), fence.bounds());
+        self.fences.push((id, fence));
+    }
+
+    /// # Summary
+    /// Get the number of geofences in the set
+    pub fn len(&self) -> usize {
+        self.fences.len()
+    }
+
+    /// # Summary
+    /// Checks if the set has no geofences
+    pub fn is_empty(&self) -> bool {
+        self.fences.is_empty()
+    }
+
+    /// # Summary
+    /// Get the ids of every geofence containing `coordinate`, in insertion order
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert(1, Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// fences.insert(2, Circle::new(Coordinate::new(0.0, 0.005), 500.0, DistanceUnit::Meters));
+    /// fences.insert(3, Circle::new(Coordinate::new(1.0, 1.0), 500.0, DistanceUnit::Meters));
+    ///
+    /// assert_eq!(vec![&1, &2], fences.containing(&Coordinate::new(0.0, 0.0025)));
+    /// ```
+    pub fn containing(&self, coordinate: &Coordinate) -> Vec<&F> {
+        self.index
+            .candidates(coordinate)
+            .into_iter()
+            .map(|item| &self.fences[item])
+            .filter(|(_, fence)| fence.contains(coordinate))
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
+
+impl<F: Clone> GeofenceSet<F> {
+    /// # Summary
+    /// Evaluate identified positions against every geofence, returning a `Hit` for each position
+    /// inside a fence
+    ///
+    /// ## Notes
+    /// - Hits are ordered by position, then by fence insertion order
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, Hit};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    ///
+    /// let positions = vec![
+    ///     (7, Coordinate::new(0.001, 0.001)),
+    ///     (8, Coordinate::new(0.5, 0.5)),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     vec![Hit { position_id: 7, fence_id: "depot" }],
+    ///     fences.evaluate_batch(&positions)
+    /// );
+    /// ```
+    pub fn evaluate_batch<P: Clone>(&self, positions: &[(P, Coordinate)]) -> Vec<Hit<P, F>> {
+        positions
+            .iter()
+            .flat_map(|(position_id, coordinate)| {
+                self.containing(coordinate)
+                    .into_iter()
+                    .map(move |fence_id| Hit {
+                        position_id: position_id.clone(),
+                        fence_id: fence_id.clone(),
+                    })
+            })
+            .collect()
+    }
+}
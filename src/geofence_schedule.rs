@@ -0,0 +1,234 @@
+use crate::solar::{daylight, Daylight, DAY};
+use crate::{Coordinate, Geofence};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Milliseconds in a minute
+const MINUTE: i64 = 60_000;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// A day of the week
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// # Summary
+    /// Get the day of the week of a timestamp in milliseconds since the Unix epoch, in a local time
+    /// `utc_offset` minutes ahead of UTC
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Weekday;
+    ///
+    /// // 1970-01-01 was a Thursday, 23:00 UTC is already Friday two hours east
+    /// assert_eq!(Weekday::Thursday, Weekday::from_timestamp(0, 0));
+    /// assert_eq!(Weekday::Friday, Weekday::from_timestamp(23 * 3_600_000, 120));
+    /// ```
+    pub fn from_timestamp(timestamp: i64, utc_offset: i32) -> Self {
+        let local = timestamp + utc_offset as i64 * MINUTE;
+        // The Unix epoch was a Thursday
+        match (local.div_euclid(DAY) + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// The part of a day during which a `Schedule` is active
+pub enum ActiveWindow {
+    /// The whole day
+    AllDay,
+    /// From `start` up to but excluding `end`, both in minutes after local midnight. Windows
+    /// which end before they start run past midnight
+    Hours { start: u32, end: u32 },
+    /// From sunrise to sunset at the checked coordinate
+    Daylight,
+    /// From sunset to sunrise at the checked coordinate
+    Darkness,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// When a rule applies, as the days of the week and the part of those days it is active
+pub struct Schedule {
+    pub window: ActiveWindow,
+    /// Days of the week the schedule is active on, as the local date of the checked time
+    pub days: Vec<Weekday>,
+    /// Minutes the local time is ahead of UTC, used for `days` and `ActiveWindow::Hours`
+    pub utc_offset: i32,
+}
+
+impl Schedule {
+    /// # Summary
+    /// Create a schedule active during `window` on every day of the week, in UTC
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{ActiveWindow, Schedule};
+    ///
+    /// let schedule = Schedule::new(ActiveWindow::Daylight);
+    ///
+    /// assert_eq!(7, schedule.days.len());
+    /// assert_eq!(0, schedule.utc_offset);
+    /// ```
+    pub fn new(window: ActiveWindow) -> Self {
+        Self {
+            window,
+            days: vec![
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+                Weekday::Saturday,
+                Weekday::Sunday,
+            ],
+            utc_offset: 0,
+        }
+    }
+
+    /// # Summary
+    /// Check if the schedule is active at `coordinate` at a timestamp in milliseconds since the
+    /// Unix epoch
+    ///
+    /// ## Notes
+    /// - `ActiveWindow::Daylight` and `ActiveWindow::Darkness` use the sunrise and sunset at
+    ///   `coordinate`, with the sun counting as up all day during the polar day and down all day
+    ///   during the polar night
+    /// - A window running past midnight is matched against the day it is checked on, not the day
+    ///   it started
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{ActiveWindow, Coordinate, Schedule, Weekday};
+    ///
+    /// // Weekdays from 07:00 to 19:00 in Central European Summer Time
+    /// let schedule = Schedule {
+    ///     window: ActiveWindow::Hours { start: 7 * 60, end: 19 * 60 },
+    ///     days: vec![Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday],
+    ///     utc_offset: 120,
+    /// };
+    /// let berlin = Coordinate::new(52.52, 13.405);
+    ///
+    /// // Monday 2024-06-17 at 08:00 and 20:00 local time, Saturday 2024-06-22 at 08:00
+    /// let monday = 1_718_575_200_000;
+    /// assert!(schedule.is_active(&berlin, monday + 8 * 3_600_000));
+    /// assert!(!schedule.is_active(&berlin, monday + 20 * 3_600_000));
+    /// assert!(!schedule.is_active(&berlin, monday + 5 * 86_400_000 + 8 * 3_600_000));
+    ///
+    /// // Sunrise in Berlin is just before 05:00 local time in June
+    /// let daylight = Schedule::new(ActiveWindow::Daylight);
+    /// assert!(!daylight.is_active(&berlin, monday + 4 * 3_600_000));
+    /// assert!(daylight.is_active(&berlin, monday + 6 * 3_600_000));
+    /// ```
+    pub fn is_active(&self, coordinate: &Coordinate, timestamp: i64) -> bool {
+        if !self
+            .days
+            .contains(&Weekday::from_timestamp(timestamp, self.utc_offset))
+        {
+            return false;
+        }
+
+        let sun_up = || match daylight(coordinate, timestamp) {
+            Daylight::Between(sunrise, sunset) => sunrise <= timestamp && timestamp < sunset,
+            Daylight::PolarDay => true,
+            Daylight::PolarNight => false,
+        };
+        match self.window {
+            ActiveWindow::AllDay => true,
+            ActiveWindow::Hours { start, end } => {
+                let local = timestamp + self.utc_offset as i64 * MINUTE;
+                let minute = local.rem_euclid(DAY) / MINUTE;
+                let (start, end) = (start as i64, end as i64);
+                if start <= end {
+                    start <= minute && minute < end
+                } else {
+                    start <= minute || minute < end
+                }
+            }
+            ActiveWindow::Daylight => sun_up(),
+            ActiveWindow::Darkness => !sun_up(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A geofence which only applies while its schedule is active, such as a construction zone
+/// enforced from 7am to 7pm
+pub struct ScheduledGeofence {
+    pub fence: Geofence,
+    pub schedule: Schedule,
+}
+
+impl ScheduledGeofence {
+    /// # Summary
+    /// Create a geofence which only applies while `schedule` is active
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{ActiveWindow, Circle, Coordinate, DistanceUnit, Schedule, ScheduledGeofence};
+    ///
+    /// let zone = ScheduledGeofence::new(
+    ///     Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers),
+    ///     Schedule::new(ActiveWindow::AllDay),
+    /// );
+    ///
+    /// assert!(zone.contains(&Coordinate::new(0.0, 0.0), 0));
+    /// ```
+    pub fn new(fence: impl Into<Geofence>, schedule: Schedule) -> Self {
+        Self {
+            fence: fence.into(),
+            schedule,
+        }
+    }
+
+    /// # Summary
+    /// Check if a coordinate lies within the geofence while it is active, at a timestamp in
+    /// milliseconds since the Unix epoch
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{ActiveWindow, Circle, Coordinate, DistanceUnit, Schedule, ScheduledGeofence};
+    ///
+    /// let mut schedule = Schedule::new(ActiveWindow::Hours { start: 7 * 60, end: 19 * 60 });
+    /// schedule.utc_offset = -300;
+    /// let zone = ScheduledGeofence::new(
+    ///     Circle::new(Coordinate::new(40.7128, -74.006), 200.0, DistanceUnit::Meters),
+    ///     schedule,
+    /// );
+    /// let inside = Coordinate::new(40.713, -74.006);
+    ///
+    /// // 2024-01-15 at 12:00 and 23:00 local time
+    /// let noon = 1_705_338_000_000;
+    /// assert!(zone.contains(&inside, noon));
+    /// assert!(!zone.contains(&inside, noon + 11 * 3_600_000));
+    /// assert!(!zone.contains(&Coordinate::new(40.8, -74.006), noon));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate, timestamp: i64) -> bool {
+        self.schedule.is_active(coordinate, timestamp) && self.fence.contains(coordinate)
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{Coordinate, GeofenceSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// Whether an object moved into or out of a geofence
+pub enum GeofenceEventKind {
+    Enter,
+    Exit,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// ## Summary
+/// An object entering or leaving a geofence, see `GeofenceTracker::update`
+pub struct GeofenceEvent<F, O> {
+    pub fence_id: F,
+    pub object_id: O,
+    pub kind: GeofenceEventKind,
+    /// Milliseconds since the Unix epoch of the position which observed the change
+    pub timestamp: i64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A position of an object at a point in time, the input of `GeofenceTracker::update`. Storing the
+/// reports of a stream allows replaying it later with `GeofenceTracker::replay`
+pub struct PositionReport<O> {
+    pub object_id: O,
+    pub coordinate: Coordinate,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// The state of a `GeofenceTracker` without its fences, see `GeofenceTracker::snapshot`
+///
+/// ## Notes
+/// - Entries of `inside`, `last_seen` and `dwell` are in no particular order
+pub struct GeofenceTrackerSnapshot<F, O> {
+    /// Fences each object is inside of, with the timestamp it entered them, in entry order
+    pub inside: Vec<(O, Vec<(F, i64)>)>,
+    /// Latest timestamp seen for each object
+    pub last_seen: Vec<(O, i64)>,
+    /// Milliseconds of completed stays per fence and object
+    pub dwell: Vec<(F, O, i64)>,
+}
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// Follows moving objects through a `GeofenceSet`, reporting when they enter or leave fences and
+/// accumulating how long each object spent inside each fence
+///
+/// ## Notes
+/// - A stay runs from the first position reported inside a fence to the first position reported
+///   outside of it, no crossing time is interpolated between positions
+/// - Stays still in progress count up to the latest position of the object
+pub struct GeofenceTracker<F, O> {
+    fences: GeofenceSet<F>,
+    /// Fences each object is inside of, with the timestamp it entered them, in entry order
+    inside: HashMap<O, Vec<(F, i64)>>,
+    /// Latest timestamp seen for each object
+    last_seen: HashMap<O, i64>,
+    /// Milliseconds of completed stays per fence and object
+    dwell: HashMap<(F, O), i64>,
+}
+
+impl<F: Clone + Eq + Hash, O: Clone + Eq + Hash> GeofenceTracker<F, O> {
+    /// # Summary
+    /// Construct a tracker over a set of geofences, with no objects inside any fence
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    ///
+    /// let tracker: GeofenceTracker<&str, u32> = GeofenceTracker::new(fences);
+    /// assert_eq!(1, tracker.fences().len());
+    /// ```
+    pub fn new(fences: GeofenceSet<F>) -> Self {
+        Self {
+            fences,
+            inside: HashMap::new(),
+            last_seen: HashMap::new(),
+            dwell: HashMap::new(),
+        }
+    }
+
+    /// # Summary
+    /// Get the tracked geofences
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{GeofenceSet, GeofenceTracker};
+    ///
+    /// let tracker: GeofenceTracker<u32, u32> = GeofenceTracker::new(GeofenceSet::new());
+    /// assert!(tracker.fences().is_empty());
+    /// ```
+    pub fn fences(&self) -> &GeofenceSet<F> {
+        &self.fences
+    }
+
+    /// # Summary
+    /// Record the position of an object at `timestamp` milliseconds since the Unix epoch,
+    /// returning the fences it left followed by the fences it entered
+    ///
+    /// ## Notes
+    /// - Exits are ordered by when the fences were entered, entries by fence insertion order
+    /// - Positions older than the latest position of the same object are ignored
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{
+    ///     Circle, Coordinate, DistanceUnit, GeofenceEvent, GeofenceEventKind, GeofenceSet, GeofenceTracker,
+    /// };
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    ///
+    /// assert!(tracker.update("truck", &Coordinate::new(1.0, 1.0), 0).is_empty());
+    /// assert_eq!(
+    ///     vec![GeofenceEvent { fence_id: "depot", object_id: "truck", kind: GeofenceEventKind::Enter, timestamp: 1_000 }],
+    ///     tracker.update("truck", &Coordinate::new(0.0, 0.0), 1_000)
+    /// );
+    /// assert_eq!(GeofenceEventKind::Exit, tracker.update("truck", &Coordinate::new(1.0, 1.0), 5_000)[0].kind);
+    /// ```
+    pub fn update(
+        &mut self,
+        object_id: O,
+        coordinate: &Coordinate,
+        timestamp: i64,
+    ) -> Vec<GeofenceEvent<F, O>> {
+        if self
+            .last_seen
+            .get(&object_id)
+            .is_some_and(|&last| timestamp < last)
+        {
+            return Vec::new();
+        }
+        self.last_seen.insert(object_id.clone(), timestamp);
+
+        let containing = self.fences.containing(coordinate);
+        let inside = self.inside.entry(object_id.clone()).or_default();
+        let mut events = Vec::new();
+
+        inside.retain(|(fence_id, entered)| {
+            if containing.contains(&fence_id) {
+                return true;
+            }
+            let dwell = self
+                .dwell
+                .entry((fence_id.clone(), object_id.clone()))
+                .or_default();
+            *dwell = dwell.saturating_add(timestamp.saturating_sub(*entered));
+            events.push(GeofenceEvent {
+                fence_id: fence_id.clone(),
+                object_id: object_id.clone(),
+                kind: GeofenceEventKind::Exit,
+                timestamp,
+            });
+            false
+        });
+
+        for fence_id in containing {
+            if inside.iter().any(|(inside_id, _)| inside_id == fence_id) {
+                continue;
+            }
+            inside.push((fence_id.clone(), timestamp));
+            events.push(GeofenceEvent {
+                fence_id: fence_id.clone(),
+                object_id: object_id.clone(),
+                kind: GeofenceEventKind::Enter,
+                timestamp,
+            });
+        }
+
+        events
+    }
+
+    /// # Summary
+    /// Checks if an object is currently inside a fence
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert(1, Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    /// tracker.update(7, &Coordinate::new(0.0, 0.0), 0);
+    ///
+    /// assert!(tracker.is_inside(&1, &7));
+    /// assert!(!tracker.is_inside(&1, &8));
+    /// ```
+    pub fn is_inside(&self, fence_id: &F, object_id: &O) -> bool {
+        self.inside
+            .get(object_id)
+            .is_some_and(|inside| inside.iter().any(|(inside_id, _)| inside_id == fence_id))
+    }
+
+    /// # Summary
+    /// Get the total time an object spent inside a fence, including a stay still in progress
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    /// use std::time::Duration;
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("zone", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    ///
+    /// let inside = Coordinate::new(0.0, 0.0);
+    /// let outside = Coordinate::new(1.0, 1.0);
+    /// tracker.update("scooter", &inside, 0);
+    /// tracker.update("scooter", &outside, 60_000);
+    /// tracker.update("scooter", &inside, 120_000);
+    /// tracker.update("scooter", &inside, 150_000);
+    ///
+    /// assert_eq!(Duration::from_secs(90), tracker.time_inside(&"zone", &"scooter"));
+    /// ```
+    pub fn time_inside(&self, fence_id: &F, object_id: &O) -> Duration {
+        let completed = self
+            .dwell
+            .get(&(fence_id.clone(), object_id.clone()))
+            .copied()
+            .unwrap_or(0);
+        let ongoing = self
+            .inside
+            .get(object_id)
+            .and_then(|inside| inside.iter().find(|(inside_id, _)| inside_id == fence_id))
+            .and_then(|(_, entered)| Some(self.last_seen.get(object_id)?.saturating_sub(*entered)))
+            .unwrap_or(0);
+
+        Duration::from_millis(completed.saturating_add(ongoing).max(0) as u64)
+    }
+
+    /// # Summary
+    /// Get the time spent inside fences for every fence and object pair which has been inside,
+    /// including stays still in progress
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    /// use std::time::Duration;
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("a", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// fences.insert("b", Circle::new(Coordinate::new(0.0, 0.005), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    ///
+    /// tracker.update(1, &Coordinate::new(0.0, 0.0), 0);
+    /// tracker.update(1, &Coordinate::new(0.0, 0.0025), 30_000);
+    /// tracker.update(1, &Coordinate::new(0.0, 0.005), 40_000);
+    ///
+    /// let report = tracker.dwell_report();
+    /// assert_eq!(Duration::from_secs(40), report[&("a", 1)]);
+    /// assert_eq!(Duration::from_secs(10), report[&("b", 1)]);
+    /// ```
+    pub fn dwell_report(&self) -> HashMap<(F, O), Duration> {
+        let mut report: HashMap<(F, O), Duration> = self
+            .dwell
+            .keys()
+            .map(|(fence_id, object_id)| {
+                let key = (fence_id.clone(), object_id.clone());
+                (key, self.time_inside(fence_id, object_id))
+            })
+            .collect();
+        for (object_id, inside) in &self.inside {
+            for (fence_id, _) in inside {
+                report
+                    .entry((fence_id.clone(), object_id.clone()))
+                    .or_insert_with(|| self.time_inside(fence_id, object_id));
+            }
+        }
+
+        report
+    }
+
+    /// # Summary
+    /// Capture the state of the tracker, which objects are inside which fences and how long they
+    /// stayed, so it can be persisted and restored with `GeofenceTracker::restore`
+    ///
+    /// ## Notes
+    /// - The fences are not part of the snapshot, they are usually persisted on their own
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    /// tracker.update("truck", &Coordinate::new(0.0, 0.0), 1_000);
+    ///
+    /// let snapshot = tracker.snapshot();
+    /// assert_eq!(vec![("truck", vec![("depot", 1_000)])], snapshot.inside);
+    /// assert_eq!(vec![("truck", 1_000)], snapshot.last_seen);
+    /// assert!(snapshot.dwell.is_empty());
+    /// ```
+    pub fn snapshot(&self) -> GeofenceTrackerSnapshot<F, O> {
+        GeofenceTrackerSnapshot {
+            inside: self
+                .inside
+                .iter()
+                .filter(|(_, inside)| !inside.is_empty())
+                .map(|(object_id, inside)| (object_id.clone(), inside.clone()))
+                .collect(),
+            last_seen: self
+                .last_seen
+                .iter()
+                .map(|(object_id, &timestamp)| (object_id.clone(), timestamp))
+                .collect(),
+            dwell: self
+                .dwell
+                .iter()
+                .map(|((fence_id, object_id), &dwell)| (fence_id.clone(), object_id.clone(), dwell))
+                .collect(),
+        }
+    }
+
+    /// # Summary
+    /// Construct a tracker over a set of geofences, continuing from a snapshot taken with
+    /// `GeofenceTracker::snapshot`
+    ///
+    /// ## Notes
+    /// - Fences of the snapshot missing from `fences` are left when the object next reports a
+    ///   position, the same as fences it moved out of
+    /// - The snapshot is not validated, an object inside fences without a `last_seen` entry counts
+    ///   no time for its ongoing stays until it next reports a position
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, GeofenceSet, GeofenceTracker};
+    /// use std::time::Duration;
+    ///
+    /// let fences = || {
+    ///     let mut fences = GeofenceSet::new();
+    ///     fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    ///     fences
+    /// };
+    /// let mut tracker = GeofenceTracker::new(fences());
+    /// tracker.update("truck", &Coordinate::new(0.0, 0.0), 0);
+    ///
+    /// let mut restored = GeofenceTracker::restore(fences(), tracker.snapshot());
+    /// assert!(restored.is_inside(&"depot", &"truck"));
+    /// assert!(restored.update("truck", &Coordinate::new(0.0, 0.0), 60_000).is_empty());
+    /// assert_eq!(Duration::from_secs(60), restored.time_inside(&"depot", &"truck"));
+    /// ```
+    pub fn restore(fences: GeofenceSet<F>, snapshot: GeofenceTrackerSnapshot<F, O>) -> Self {
+        Self {
+            fences,
+            inside: snapshot.inside.into_iter().collect(),
+            last_seen: snapshot.last_seen.into_iter().collect(),
+            dwell: snapshot
+                .dwell
+                .into_iter()
+                .map(|(fence_id, object_id, dwell)| ((fence_id, object_id), dwell))
+                .collect(),
+        }
+    }
+
+    /// # Summary
+    /// Feed a stream of position reports through the tracker in order, returning every event they
+    /// caused
+    ///
+    /// ## Notes
+    /// - Replaying the same reports from the same starting state, a new tracker or a restored
+    ///   snapshot, always produces the same events, which makes a stored stream an audit log of
+    ///   the events
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{
+    ///     Circle, Coordinate, DistanceUnit, GeofenceEventKind, GeofenceSet, GeofenceTracker, PositionReport,
+    /// };
+    ///
+    /// let mut fences = GeofenceSet::new();
+    /// fences.insert("depot", Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters));
+    /// let mut tracker = GeofenceTracker::new(fences);
+    ///
+    /// let reports = vec![
+    ///     PositionReport { object_id: "truck", coordinate: Coordinate::new(0.0, 0.0), timestamp: 0 },
+    ///     PositionReport { object_id: "truck", coordinate: Coordinate::new(1.0, 1.0), timestamp: 5_000 },
+    /// ];
+    /// let events = tracker.replay(reports);
+    ///
+    /// assert_eq!(2, events.len());
+    /// assert_eq!((GeofenceEventKind::Exit, 5_000), (events[1].kind, events[1].timestamp));
+    /// ```
+    pub fn replay(
+        &mut self,
+        reports: impl IntoIterator<Item = PositionReport<O>>,
+    ) -> Vec<GeofenceEvent<F, O>> {
+        reports
+            .into_iter()
+            .flat_map(|report| self.update(report.object_id, &report.coordinate, report.timestamp))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+    use crate::{Circle, DistanceUnit};
+
+    fn tracker() -> GeofenceTracker<&'static str, u32> {
+        let mut fences = GeofenceSet::new();
+        fences.insert(
+            "outer",
+            Circle::new(Coordinate::new(0.0, 0.0), 2.0, DistanceUnit::Kilometers),
+        );
+        fences.insert(
+            "inner",
+            Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters),
+        );
+        GeofenceTracker::new(fences)
+    }
+
+    #[test]
+    fn test_nested_fences_and_objects() {
+        let mut tracker = tracker();
+        let center = Coordinate::new(0.0, 0.0);
+        let ring = Coordinate::new(0.0, 0.01);
+        let away = Coordinate::new(0.0, 1.0);
+
+        let kinds = |events: Vec<GeofenceEvent<&'static str, u32>>| {
+            events
+                .into_iter()
+                .map(|event| (event.fence_id, event.kind))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![
+                ("outer", GeofenceEventKind::Enter),
+                ("inner", GeofenceEventKind::Enter)
+            ],
+            kinds(tracker.update(1, &center, 0))
+        );
+        assert_eq!(
+            vec![("outer", GeofenceEventKind::Enter)],
+            kinds(tracker.update(2, &ring, 5_000))
+        );
+        assert_eq!(
+            vec![("inner", GeofenceEventKind::Exit)],
+            kinds(tracker.update(1, &ring, 10_000))
+        );
+        // Out of order positions are ignored
+        assert!(tracker.update(1, &away, 8_000).is_empty());
+        assert_eq!(
+            vec![("outer", GeofenceEventKind::Exit)],
+            kinds(tracker.update(1, &away, 30_000))
+        );
+        assert!(tracker.update(2, &ring, 65_000).is_empty());
+
+        assert_eq!(Duration::from_secs(30), tracker.time_inside(&"outer", &1));
+        assert_eq!(Duration::from_secs(10), tracker.time_inside(&"inner", &1));
+        assert_eq!(Duration::from_secs(60), tracker.time_inside(&"outer", &2));
+        assert_eq!(Duration::ZERO, tracker.time_inside(&"inner", &2));
+        assert!(!tracker.is_inside(&"outer", &1));
+        assert!(tracker.is_inside(&"outer", &2));
+
+        let report = tracker.dwell_report();
+        assert_eq!(3, report.len());
+        assert_eq!(Duration::from_secs(60), report[&("outer", 2)]);
+    }
+
+    #[test]
+    fn test_restored_snapshot_replays_identically() {
+        let reports: Vec<_> = (0..400u64)
+            .map(|i| PositionReport {
+                object_id: (i % 3) as u32,
+                coordinate: Coordinate::new(random(2 * i, 0.05), random(2 * i + 1, 0.05)),
+                timestamp: (i / 3) as i64 * 1_000,
+            })
+            .collect();
+
+        let mut full = tracker();
+        let events = full.replay(reports.clone());
+        assert!(events.len() > 50);
+
+        let mut first = tracker();
+        let mut resumed = first.replay(reports[..200].to_vec());
+        let mut second = GeofenceTracker::restore(tracker().fences, first.snapshot());
+        resumed.extend(second.replay(reports[200..].to_vec()));
+
+        assert_eq!(events, resumed);
+        assert_eq!(full.dwell_report(), second.dwell_report());
+    }
+
+    #[test]
+    fn test_restore_without_last_seen() {
+        let mut fences = GeofenceSet::new();
+        fences.insert(
+            "zone",
+            Circle::new(Coordinate::new(0.0, 0.0), 500.0, DistanceUnit::Meters),
+        );
+        let snapshot = GeofenceTrackerSnapshot {
+            inside: vec![(1, vec![("zone", 1_000)])],
+            last_seen: vec![],
+            dwell: vec![("zone", 1, 5_000)],
+        };
+        let mut tracker = GeofenceTracker::restore(fences, snapshot);
+
+        assert_eq!(Duration::from_secs(5), tracker.time_inside(&"zone", &1));
+        assert_eq!(Duration::from_secs(5), tracker.dwell_report()[&("zone", 1)]);
+
+        tracker.update(1, &Coordinate::new(0.0, 0.0), 3_000);
+        assert_eq!(Duration::from_secs(7), tracker.time_inside(&"zone", &1));
+    }
+}
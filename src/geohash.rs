@@ -0,0 +1,233 @@
+use crate::spherical::{cross, dot, from_unit_vector, normalize, to_unit_vector};
+use crate::utils::mix64;
+use crate::{Coordinate, Geometry, Route};
+
+/// The geohash base32 alphabet, which skips `a`, `i`, `l` and `o`
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// # Summary
+/// Interleave the bits of a coordinate into an integer geohash of `bits` bits (at most 64),
+/// starting with a longitude bit as the standard geohash does
+pub fn encode_bits(coordinate: &Coordinate, bits: u32) -> u64 {
+    let (mut min_lat, mut max_lat) = (-90.0, 90.0);
+    let (mut min_lon, mut max_lon) = (-180.0, 180.0);
+    let mut hash = 0u64;
+
+    for bit in 0..bits.min(64) {
+        hash <<= 1;
+        if bit % 2 == 0 {
+            let middle = (min_lon + max_lon) / 2.0;
+            if coordinate.longitude >= middle {
+                hash |= 1;
+                min_lon = middle;
+            } else {
+                max_lon = middle;
+            }
+        } else {
+            let middle = (min_lat + max_lat) / 2.0;
+            if coordinate.latitude >= middle {
+                hash |= 1;
+                min_lat = middle;
+            } else {
+                max_lat = middle;
+            }
+        }
+    }
+
+    hash
+}
+
+/// # Summary
+/// Encode a coordinate as a base32 geohash string of `precision` characters (at most 12)
+pub fn encode(coordinate: &Coordinate, precision: usize) -> String {
+    let precision = precision.min(12);
+    let hash = encode_bits(coordinate, precision as u32 * 5);
+
+    (0..precision)
+        .rev()
+        .map(|index| BASE32[((hash >> (index * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+/// Number of geohash bits used to pick a shard, 20 bits is a 4 character geohash cell of roughly 39 x 20 km
+const SHARD_BITS: u32 = 20;
+
+/// # Summary
+/// Get a stable shard number in `0..num_shards` for a coordinate, so location events can be routed
+/// consistently across a distributed system
+///
+/// ## Notes
+/// The algorithm is stable across releases and simple to reimplement in other languages:
+/// 1. Compute the 20 bit integer geohash of the coordinate (a 4 character geohash cell of roughly
+///    39 x 20 km, longitude bit first, a bit is `1` when the value is `>=` the cell middle)
+/// 2. Mix it with the SplitMix64 finalizer so densely populated cells spread evenly:
+///    `z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9`, `z = (z ^ (z >> 27)) * 0x94d049bb133111eb`,
+///    `z = z ^ (z >> 31)` using wrapping 64 bit multiplication
+/// 3. Take `z % num_shards`
+///
+/// Every coordinate within the same geohash cell therefore lands on the same shard.
+///
+/// ## Panics
+/// Panics if `num_shards` is 0
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{shard_for, Coordinate};
+///
+/// let shard = shard_for(&Coordinate::new(51.5074, -0.1278), 16);
+/// assert!(shard < 16);
+///
+/// // Same cell, same shard
+/// assert_eq!(shard, shard_for(&Coordinate::new(51.5080, -0.1270), 16));
+/// ```
+pub fn shard_for(coordinate: &Coordinate, num_shards: u32) -> u32 {
+    assert!(num_shards > 0, "num_shards must be greater than 0");
+
+    let z = mix64(encode_bits(coordinate, SHARD_BITS));
+
+    (z % num_shards as u64) as u32
+}
+
+/// # Summary
+/// Get the smallest geohash cell containing the whole geometry, as a coarse routing or cache
+/// partitioning key. Returns `None` for empty geometries and for geometries which no cell smaller
+/// than the whole world contains, such as anything crossing the equator or the prime meridian
+///
+/// ## Notes
+/// - Route legs follow great circles, the part of a leg bulging towards a pole past its ends is
+///   included
+/// - Cells are at most 12 characters long
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{common_prefix_geohash, Coordinate, Geometry, Route};
+///
+/// let route = Route::new(vec![Coordinate::new(57.64911, 10.40744), Coordinate::new(57.6501, 10.4102)]);
+/// assert_eq!(Some("u4pruy".to_string()), common_prefix_geohash(&Geometry::LineString(route)));
+///
+/// let across_equator = vec![Coordinate::new(1.0, 10.0), Coordinate::new(-1.0, 10.0)];
+/// assert_eq!(None, common_prefix_geohash(&Geometry::MultiPoint(across_equator)));
+/// ```
+pub fn common_prefix_geohash(geometry: &Geometry) -> Option<String> {
+    let mut points: Vec<Coordinate> = Vec::new();
+    let add_route = |route: &Route, points: &mut Vec<Coordinate>| {
+        points.extend(&route.points);
+        points.extend(
+            route
+                .points
+                .windows(2)
+                .filter_map(|leg| leg_extreme(&leg[0], &leg[1])),
+        );
+    };
+    match geometry {
+        Geometry::Point(coordinate) => points.push(*coordinate),
+        Geometry::MultiPoint(coordinates) => points.extend(coordinates),
+        Geometry::LineString(route) => add_route(route, &mut points),
+        Geometry::MultiLineString(routes) => {
+            for route in routes {
+                add_route(route, &mut points);
+            }
+        }
+        Geometry::Polygon(polygon) => points.extend(&polygon.exterior),
+        Geometry::MultiPolygon(polygons) => {
+            points.extend(polygons.iter().flat_map(|polygon| &polygon.exterior))
+        }
+    }
+
+    let first = points.first()?;
+    let (mut min_lat, mut max_lat) = (first.latitude, first.latitude);
+    let (mut min_lon, mut max_lon) = (first.longitude, first.longitude);
+    for point in &points {
+        min_lat = min_lat.min(point.latitude);
+        max_lat = max_lat.max(point.latitude);
+        min_lon = min_lon.min(point.longitude);
+        max_lon = max_lon.max(point.longitude);
+    }
+
+    // Cells are rectangles, so a cell holding two opposite corners holds the whole bounding box
+    let south_west = encode(&Coordinate::new(min_lat, min_lon), 12);
+    let north_east = encode(&Coordinate::new(max_lat, max_lon), 12);
+    let prefix: String = south_west
+        .chars()
+        .zip(north_east.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .collect();
+
+    (!prefix.is_empty()).then_some(prefix)
+}
+
+/// # Summary
+/// Get the point of a great circle leg furthest north or south, if it lies between the ends
+fn leg_extreme(a: &Coordinate, b: &Coordinate) -> Option<Coordinate> {
+    let (a, b) = (to_unit_vector(a), to_unit_vector(b));
+    let normal = cross(a, b);
+    if dot(normal, normal) < 1e-30 {
+        return None;
+    }
+    let normal = normalize(normal);
+
+    // The northernmost point of the great circle, the southernmost is its antipode
+    let north = [
+        -normal[2] * normal[0],
+        -normal[2] * normal[1],
+        1.0 - normal[2] * normal[2],
+    ];
+    if dot(north, north) < 1e-30 {
+        return None;
+    }
+    let north = normalize(north);
+    let between =
+        |point: [f64; 3]| dot(cross(a, point), normal) > 0.0 && dot(cross(point, b), normal) > 0.0;
+
+    [north, north.map(|component| -component)]
+        .into_iter()
+        .find(|point| between(*point))
+        .map(from_unit_vector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_values() {
+        assert_eq!(
+            "u4pruydqqvj",
+            encode(&Coordinate::new(57.64911, 10.40744), 11)
+        );
+        assert_eq!("gcpvj0", encode(&Coordinate::new(51.5074, -0.1278), 6));
+    }
+
+    #[test]
+    fn test_shard_for_is_stable() {
+        // Pinned values, changing these breaks routing for existing deployments
+        assert_eq!(
+            0x7aebb,
+            encode_bits(&Coordinate::new(51.5074, -0.1278), SHARD_BITS)
+        );
+        assert_eq!(1, shard_for(&Coordinate::new(51.5074, -0.1278), 16));
+        assert_eq!(0, shard_for(&Coordinate::new(51.5074, -0.1278), 1));
+    }
+
+    #[test]
+    fn test_common_prefix_includes_great_circle_bulge() {
+        // Both ends sit in cell "9", the leg between them reaches past 45 degrees north into "c"
+        let route = Route::new(vec![
+            Coordinate::new(44.0, -134.0),
+            Coordinate::new(44.0, -94.0),
+        ]);
+        let ends = Geometry::MultiPoint(route.points.clone());
+
+        assert_eq!(
+            Some("9".to_string()),
+            common_prefix_geohash(&ends).map(|hash| hash[..1].to_string())
+        );
+        assert_eq!(None, common_prefix_geohash(&Geometry::LineString(route)));
+        assert_eq!(None, common_prefix_geohash(&Geometry::MultiPoint(vec![])));
+        assert_eq!(
+            Some("u4pruydqqvj8".to_string()),
+            common_prefix_geohash(&Geometry::Point(Coordinate::new(57.64911, 10.40744)))
+        );
+    }
+}
@@ -0,0 +1,634 @@
+use crate::{Coordinate, Geometry, Polygon, Route};
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Deepest nesting of arrays and objects accepted inside a single feature
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while reading a GeoJSON FeatureCollection
+pub enum GeoJsonError {
+    /// The input ended in the middle of a value
+    UnexpectedEnd,
+    /// The input is not valid JSON at the given byte offset
+    Syntax { offset: u64 },
+    /// The top level value is not an object with a `features` array
+    NotAFeatureCollection,
+    /// A feature's geometry is malformed or of an unsupported type, such as a GeometryCollection
+    InvalidGeometry(String),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::UnexpectedEnd => write!(f, "input ended unexpectedly"),
+            GeoJsonError::Syntax { offset } => write!(f, "invalid JSON at byte {}", offset),
+            GeoJsonError::NotAFeatureCollection => {
+                write!(f, "input is not a GeoJSON FeatureCollection")
+            }
+            GeoJsonError::InvalidGeometry(reason) => write!(f, "invalid geometry: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A single feature read from a GeoJSON FeatureCollection
+pub struct GeoJsonFeature {
+    /// The feature's `id` as raw JSON (a string including its quotes, or a number), if present
+    pub id: Option<String>,
+    /// The feature's geometry, `None` when it is `null`
+    pub geometry: Option<Geometry>,
+    /// The feature's `properties` as compact raw JSON, `null` when absent, for parsing with any
+    /// JSON library
+    pub properties: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// In-memory JSON value of a single feature. Numbers keep their source text so properties are
+/// written back unchanged
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    Features,
+    Done,
+}
+
+/// ## Summary
+/// A pull-based reader over the features of a GeoJSON FeatureCollection, holding only one
+/// feature in memory at a time
+///
+/// ## Notes
+/// - Members of the collection other than `features` are skipped without being kept in memory
+/// - Reading stops after the `features` array, the rest of the input is not validated
+/// - Malformed input is reported as `io::ErrorKind::InvalidData` wrapping a `GeoJsonError`,
+///   after which the reader yields nothing more
+pub struct GeoJsonReader<R> {
+    reader: R,
+    offset: u64,
+    state: State,
+}
+
+impl<R: BufRead> GeoJsonReader<R> {
+    /// # Summary
+    /// Construct a reader over a buffered source, such as a `BufReader<File>`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GeoJsonReader, Geometry};
+    ///
+    /// let input = r#"{
+    ///     "type": "FeatureCollection",
+    ///     "features": [
+    ///         {"type": "Feature", "id": 1, "geometry": {"type": "Point", "coordinates": [13.405, 52.52]}, "properties": {"name": "Berlin"}},
+    ///         {"type": "Feature", "geometry": null, "properties": null}
+    ///     ]
+    /// }"#;
+    ///
+    /// let features = GeoJsonReader::new(input.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, features.len());
+    /// assert_eq!(Some("1".to_string()), features[0].id);
+    /// assert_eq!(Some(Geometry::Point(Coordinate::new(52.52, 13.405))), features[0].geometry);
+    /// assert_eq!(r#"{"name":"Berlin"}"#, features[0].properties);
+    /// assert_eq!(None, features[1].geometry);
+    /// ```
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            offset: 0,
+            state: State::Start,
+        }
+    }
+
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn next_byte(&mut self) -> io::Result<u8> {
+        let byte = self
+            .peek()?
+            .ok_or_else(|| invalid(GeoJsonError::UnexpectedEnd))?;
+        self.reader.consume(1);
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    /// # Summary
+    /// Skip whitespace and return the next significant byte without consuming it
+    fn peek_token(&mut self) -> io::Result<u8> {
+        loop {
+            match self.peek()? {
+                Some(b' ' | b'\t' | b'\n' | b'\r') => {
+                    self.reader.consume(1);
+                    self.offset += 1;
+                }
+                Some(byte) => return Ok(byte),
+                None => return Err(invalid(GeoJsonError::UnexpectedEnd)),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> io::Result<()> {
+        if self.peek_token()? != expected {
+            return Err(self.syntax_error());
+        }
+        self.next_byte().map(|_| ())
+    }
+
+    fn syntax_error(&self) -> io::Error {
+        invalid(GeoJsonError::Syntax {
+            offset: self.offset,
+        })
+    }
+
+    /// # Summary
+    /// After an opening bracket or a value, consume a `,` and return `true`, or consume the
+    /// closing bracket and return `false`
+    fn more(&mut self, close: u8, first: &mut bool) -> io::Result<bool> {
+        let token = self.peek_token()?;
+        if token == close {
+            self.next_byte()?;
+            return Ok(false);
+        }
+        if !std::mem::take(first) {
+            self.expect(b',')?;
+        }
+        Ok(true)
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            match self.next_byte()? {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.next_byte()? {
+                        byte @ (b'"' | b'\\' | b'/') => byte as char,
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.syntax_error()),
+                    };
+                    bytes.extend(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte if byte < 0x20 => return Err(self.syntax_error()),
+                byte => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.syntax_error())
+    }
+
+    fn hex4(&mut self) -> io::Result<u32> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = (self.next_byte()? as char)
+                .to_digit(16)
+                .ok_or_else(|| self.syntax_error())?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn unicode_escape(&mut self) -> io::Result<char> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if self.next_byte()? != b'\\' || self.next_byte()? != b'u' {
+                return Err(self.syntax_error());
+            }
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.syntax_error());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.syntax_error())
+    }
+
+    fn number(&mut self) -> io::Result<String> {
+        let mut text = String::new();
+        while let Some(byte @ (b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')) = self.peek()? {
+            text.push(byte as char);
+            self.next_byte()?;
+        }
+        if text.parse::<f64>().is_err() {
+            return Err(self.syntax_error());
+        }
+        Ok(text)
+    }
+
+    fn literal(&mut self, word: &str) -> io::Result<()> {
+        for expected in word.bytes() {
+            if self.next_byte()? != expected {
+                return Err(self.syntax_error());
+            }
+        }
+        Ok(())
+    }
+
+    fn value(&mut self, depth: usize) -> io::Result<Json> {
+        if depth > MAX_DEPTH {
+            return Err(self.syntax_error());
+        }
+        match self.peek_token()? {
+            b'{' => {
+                self.next_byte()?;
+                let mut members = Vec::new();
+                let mut first = true;
+                while self.more(b'}', &mut first)? {
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    members.push((key, self.value(depth + 1)?));
+                }
+                Ok(Json::Object(members))
+            }
+            b'[' => {
+                self.next_byte()?;
+                let mut items = Vec::new();
+                let mut first = true;
+                while self.more(b']', &mut first)? {
+                    items.push(self.value(depth + 1)?);
+                }
+                Ok(Json::Array(items))
+            }
+            b'"' => self.string().map(Json::String),
+            b't' => self.literal("true").map(|_| Json::Bool(true)),
+            b'f' => self.literal("false").map(|_| Json::Bool(false)),
+            b'n' => self.literal("null").map(|_| Json::Null),
+            _ => self.number().map(Json::Number),
+        }
+    }
+
+    /// # Summary
+    /// Skip over a value of any size without keeping it, tracking only the nesting depth
+    fn skip_value(&mut self) -> io::Result<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek_token()? {
+                b'{' | b'[' => {
+                    self.next_byte()?;
+                    depth += 1;
+                }
+                b'}' | b']' => {
+                    self.next_byte()?;
+                    depth = depth.checked_sub(1).ok_or_else(|| self.syntax_error())?;
+                }
+                b',' | b':' if depth > 0 => {
+                    self.next_byte()?;
+                }
+                b'"' => {
+                    self.string()?;
+                }
+                b't' => self.literal("true")?,
+                b'f' => self.literal("false")?,
+                b'n' => self.literal("null")?,
+                _ => {
+                    self.number()?;
+                }
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// # Summary
+    /// Read the top level object up to the opening bracket of its `features` array
+    fn find_features(&mut self) -> io::Result<()> {
+        if self.peek_token()? != b'{' {
+            return Err(invalid(GeoJsonError::NotAFeatureCollection));
+        }
+        self.next_byte()?;
+        let mut first = true;
+        while self.more(b'}', &mut first)? {
+            let key = self.string()?;
+            self.expect(b':')?;
+            if key == "features" {
+                return self.expect(b'[');
+            }
+            self.skip_value()?;
+        }
+        Err(invalid(GeoJsonError::NotAFeatureCollection))
+    }
+
+    fn next_feature(&mut self) -> io::Result<Option<GeoJsonFeature>> {
+        if self.state == State::Start {
+            self.find_features()?;
+            self.state = State::Features;
+            if self.peek_token()? == b']' {
+                self.next_byte()?;
+                return Ok(None);
+            }
+        } else if self.peek_token()? == b']' {
+            self.next_byte()?;
+            return Ok(None);
+        } else {
+            self.expect(b',')?;
+        }
+
+        let Json::Object(members) = self.value(1)? else {
+            return Err(invalid(GeoJsonError::InvalidGeometry(
+                "feature is not an object".to_string(),
+            )));
+        };
+        let mut feature = GeoJsonFeature {
+            id: None,
+            geometry: None,
+            properties: "null".to_string(),
+        };
+        for (key, value) in members {
+            match key.as_str() {
+                "id" => feature.id = Some(write_json(&value)),
+                "geometry" => feature.geometry = to_geometry(&value).map_err(invalid)?,
+                "properties" => feature.properties = write_json(&value),
+                _ => {}
+            }
+        }
+        Ok(Some(feature))
+    }
+}
+
+impl<R: BufRead> Iterator for GeoJsonReader<R> {
+    type Item = io::Result<GeoJsonFeature>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+        match self.next_feature() {
+            Ok(Some(feature)) => Some(Ok(feature)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(error) => {
+                self.state = State::Done;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+fn invalid(error: GeoJsonError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn geometry_error(reason: &str) -> GeoJsonError {
+    GeoJsonError::InvalidGeometry(reason.to_string())
+}
+
+fn member<'a>(members: &'a [(String, Json)], key: &str) -> Option<&'a Json> {
+    members
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value)
+}
+
+fn items(value: &Json) -> Result<&[Json], GeoJsonError> {
+    match value {
+        Json::Array(items) => Ok(items),
+        _ => Err(geometry_error("coordinates must be arrays")),
+    }
+}
+
+/// # Summary
+/// Convert a GeoJSON position (`[longitude, latitude]`, any altitude ignored), rejecting
+/// positions outside of ±90 latitude or ±180 longitude instead of wrapping them
+fn position(value: &Json) -> Result<Coordinate, GeoJsonError> {
+    let ordinate = |value: Option<&Json>| match value {
+        Some(Json::Number(text)) => text
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| geometry_error("position values must be finite numbers")),
+        _ => Err(geometry_error("positions need a longitude and latitude")),
+    };
+    let position = items(value)?;
+    let longitude = ordinate(position.first())?;
+    let latitude = ordinate(position.get(1))?;
+    if latitude.abs() > 90.0 || longitude.abs() > 180.0 {
+        return Err(geometry_error(
+            "positions must be within ±180 longitude and ±90 latitude",
+        ));
+    }
+    Ok(Coordinate::new(latitude, longitude))
+}
+
+fn positions(value: &Json) -> Result<Vec<Coordinate>, GeoJsonError> {
+    items(value)?.iter().map(position).collect()
+}
+
+fn polygon(value: &Json) -> Result<Polygon, GeoJsonError> {
+    let mut rings = items(value)?.iter().map(positions);
+    let exterior = rings.next().transpose()?.unwrap_or_default();
+    Ok(Polygon::with_interiors(
+        exterior,
+        rings.collect::<Result<_, _>>()?,
+    ))
+}
+
+fn to_geometry(value: &Json) -> Result<Option<Geometry>, GeoJsonError> {
+    let members = match value {
+        Json::Null => return Ok(None),
+        Json::Object(members) => members,
+        _ => return Err(geometry_error("geometry must be an object or null")),
+    };
+    let kind = match member(members, "type") {
+        Some(Json::String(kind)) => kind.as_str(),
+        _ => return Err(geometry_error("geometry has no type")),
+    };
+    if !matches!(
+        kind,
+        "Point" | "MultiPoint" | "LineString" | "MultiLineString" | "Polygon" | "MultiPolygon"
+    ) {
+        return Err(GeoJsonError::InvalidGeometry(format!(
+            "geometry type {} is not supported",
+            kind
+        )));
+    }
+    let coordinates = member(members, "coordinates")
+        .ok_or_else(|| geometry_error("geometry has no coordinates"))?;
+
+    let geometry = match kind {
+        "Point" => Geometry::Point(position(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(positions(coordinates)?),
+        "LineString" => Geometry::LineString(Route::new(positions(coordinates)?)),
+        "MultiLineString" => Geometry::MultiLineString(
+            items(coordinates)?
+                .iter()
+                .map(|line| positions(line).map(Route::new))
+                .collect::<Result<_, _>>()?,
+        ),
+        "Polygon" => Geometry::Polygon(polygon(coordinates)?),
+        "MultiPolygon" => Geometry::MultiPolygon(
+            items(coordinates)?
+                .iter()
+                .map(polygon)
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => unreachable!("geometry types are checked above"),
+    };
+    Ok(Some(geometry))
+}
+
+fn write_json(value: &Json) -> String {
+    let mut output = String::new();
+    write_value(&mut output, value);
+    output
+}
+
+fn write_value(output: &mut String, value: &Json) {
+    match value {
+        Json::Null => output.push_str("null"),
+        Json::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Json::Number(text) => output.push_str(text),
+        Json::String(text) => write_string(output, text),
+        Json::Array(items) => {
+            output.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_value(output, item);
+            }
+            output.push(']');
+        }
+        Json::Object(members) => {
+            output.push('{');
+            for (index, (key, item)) in members.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_string(output, key);
+                output.push(':');
+                write_value(output, item);
+            }
+            output.push('}');
+        }
+    }
+}
+
+fn write_string(output: &mut String, text: &str) {
+    output.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            character if character < ' ' => {
+                output.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => output.push(character),
+        }
+    }
+    output.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(input: &str) -> Vec<io::Result<GeoJsonFeature>> {
+        GeoJsonReader::new(input.as_bytes()).collect()
+    }
+
+    #[test]
+    fn test_skips_other_members_and_reads_all_geometries() {
+        let input = r#"{
+            "type": "FeatureCollection",
+            "bbox": [0, 0, 10, 10],
+            "crs": {"type": "name", "properties": {"name": "urn:ogc:def:crs:OGC:1.3:CRS84"}},
+            "features": [
+                {"type": "Feature", "properties": {}, "geometry": {"type": "MultiPoint", "coordinates": [[1, 2], [3, 4, 100]]}},
+                {"type": "Feature", "geometry": {"type": "MultiLineString", "coordinates": [[[0, 0], [1, 1]]]}},
+                {"type": "Feature", "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 0]], [[1, 1], [2, 1], [2, 2], [1, 1]]]}},
+                {"type": "Feature", "geometry": {"type": "MultiPolygon", "coordinates": [[[[0, 0], [1, 0], [1, 1], [0, 0]]]]}}
+            ]
+        }"#;
+
+        let features: Vec<_> = read(input).into_iter().map(Result::unwrap).collect();
+
+        assert_eq!(4, features.len());
+        assert_eq!(
+            Some(Geometry::MultiPoint(vec![
+                Coordinate::new(2.0, 1.0),
+                Coordinate::new(4.0, 3.0)
+            ])),
+            features[0].geometry
+        );
+        assert_eq!("{}", features[0].properties);
+        assert_eq!("null", features[1].properties);
+        match &features[2].geometry {
+            Some(Geometry::Polygon(polygon)) => {
+                assert_eq!(4, polygon.exterior.len());
+                assert_eq!(1, polygon.interiors.len());
+            }
+            other => panic!("expected a polygon, got {:?}", other),
+        }
+        assert!(matches!(
+            features[3].geometry,
+            Some(Geometry::MultiPolygon(ref polygons)) if polygons.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_properties_are_written_back_unchanged() {
+        let input = r#"{"features": [{"properties": {"name": "Café \"Zo\"", "rank": 1.50e1, "tags": [true, null]}, "geometry": null}]}"#;
+
+        let features = read(input);
+        let feature = features[0].as_ref().unwrap();
+
+        assert_eq!(
+            r#"{"name":"Café \"Zo\"","rank":1.50e1,"tags":[true,null]}"#,
+            feature.properties
+        );
+    }
+
+    #[test]
+    fn test_errors_stop_the_reader() {
+        let invalid_data = |result: &io::Result<GeoJsonFeature>| matches!(result, Err(error) if error.kind() == io::ErrorKind::InvalidData);
+
+        let truncated = read(r#"{"features": [{"geometry": null}, {"geometry""#);
+        assert_eq!(2, truncated.len());
+        assert!(truncated[0].is_ok());
+        assert!(invalid_data(&truncated[1]));
+
+        let unsupported = read(
+            r#"{"features": [{"geometry": {"type": "GeometryCollection", "geometries": []}}]}"#,
+        );
+        assert_eq!(1, unsupported.len());
+        assert!(invalid_data(&unsupported[0]));
+
+        let out_of_range =
+            read(r#"{"features": [{"geometry": {"type": "Point", "coordinates": [1e300, 0]}}]}"#);
+        assert_eq!(1, out_of_range.len());
+        assert!(invalid_data(&out_of_range[0]));
+
+        assert!(invalid_data(&read(r#"[1, 2]"#)[0]));
+        assert!(invalid_data(&read(r#"{"type": "FeatureCollection"}"#)[0]));
+        assert!(read(r#"{"features": []}"#).is_empty());
+    }
+}
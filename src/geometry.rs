@@ -0,0 +1,35 @@
+use crate::{Coordinate, Polygon, Route};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// Any of the crate's geometry types, mirroring the GeoJSON / Simple Features geometry kinds
+pub enum Geometry {
+    Point(Coordinate),
+    MultiPoint(Vec<Coordinate>),
+    LineString(Route),
+    MultiLineString(Vec<Route>),
+    Polygon(Polygon),
+    MultiPolygon(Vec<Polygon>),
+}
+
+impl From<Coordinate> for Geometry {
+    fn from(coordinate: Coordinate) -> Self {
+        Geometry::Point(coordinate)
+    }
+}
+
+impl From<Route> for Geometry {
+    fn from(route: Route) -> Self {
+        Geometry::LineString(route)
+    }
+}
+
+impl From<Polygon> for Geometry {
+    fn from(polygon: Polygon) -> Self {
+        Geometry::Polygon(polygon)
+    }
+}
@@ -0,0 +1,180 @@
+use crate::math;
+use crate::spherical::{
+    angle_to_distance, cross, dot, from_unit_vector, normalize, to_unit_vector,
+};
+use crate::{Coordinate, DistanceUnit};
+
+/// Cross products shorter than this are treated as coincident or antipodal points
+const DEGENERATE_LENGTH: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// A great circle on the spherical Earth, represented by the unit normal of its plane
+///
+/// ## Notes
+/// - The circle is oriented, travelling along it the pole is always on the left
+pub struct GreatCircle {
+    normal: [f64; 3],
+}
+
+impl GreatCircle {
+    /// # Summary
+    /// Construct the great circle through `a` and `b`, oriented from `a` towards `b`.
+    /// Returns `None` if the points are identical or antipodal, which leaves the circle undefined
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let equator = GreatCircle::through(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 10.0));
+    /// assert!(equator.is_some());
+    ///
+    /// let undefined = GreatCircle::through(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 180.0));
+    /// assert!(undefined.is_none());
+    /// ```
+    pub fn through(a: &Coordinate, b: &Coordinate) -> Option<Self> {
+        Self::from_normal(cross(to_unit_vector(a), to_unit_vector(b)))
+    }
+
+    /// # Summary
+    /// Construct the great circle leaving `origin` towards `bearing`, in degrees clockwise from north
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let meridian = GreatCircle::from_bearing(&Coordinate::new(10.0, 20.0), 0.0);
+    /// let pole = meridian.pole();
+    ///
+    /// assert!(pole.latitude.abs() < 1e-9);
+    /// assert!((pole.longitude - -70.0).abs() < 1e-9);
+    /// ```
+    pub fn from_bearing(origin: &Coordinate, bearing: f64) -> Self {
+        let (sin_lat, cos_lat) = math::sin_cos(origin.latitude.to_radians());
+        let (sin_lon, cos_lon) = math::sin_cos(origin.longitude.to_radians());
+        let (sin_bearing, cos_bearing) = math::sin_cos(bearing.to_radians());
+
+        // East and north unit vectors of the origin's tangent plane
+        let east = [-sin_lon, cos_lon, 0.0];
+        let north = [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat];
+        let direction = [0, 1, 2].map(|axis| north[axis] * cos_bearing + east[axis] * sin_bearing);
+
+        Self {
+            normal: normalize(cross(to_unit_vector(origin), direction)),
+        }
+    }
+
+    /// # Summary
+    /// Construct the great circle whose pole is `pole`, the equator for the north pole
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let equator = GreatCircle::from_pole(&Coordinate::new(90.0, 0.0));
+    ///
+    /// assert!(equator.contains(&Coordinate::new(0.0, 123.0), 1e-9));
+    /// ```
+    pub fn from_pole(pole: &Coordinate) -> Self {
+        Self {
+            normal: to_unit_vector(pole),
+        }
+    }
+
+    /// # Summary
+    /// Get the pole of the great circle, on the left side when travelling along it
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let equator = GreatCircle::through(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 10.0)).unwrap();
+    ///
+    /// assert_eq!(90.0, equator.pole().latitude);
+    /// ```
+    pub fn pole(&self) -> Coordinate {
+        from_unit_vector(self.normal)
+    }
+
+    /// # Summary
+    /// Get the unit normal vector of the great circle's plane, see the `spherical` module
+    pub fn normal(&self) -> [f64; 3] {
+        self.normal
+    }
+
+    /// # Summary
+    /// Get the signed cross track distance from the great circle to `coordinate`, positive on the
+    /// pole (left) side
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, GreatCircle};
+    ///
+    /// let equator = GreatCircle::through(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 10.0)).unwrap();
+    ///
+    /// let north = equator.distance_to(&Coordinate::new(1.0, 50.0), &DistanceUnit::Kilometers);
+    /// let south = equator.distance_to(&Coordinate::new(-1.0, 50.0), &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(111.2, (north * 100.0).round() / 100.0);
+    /// assert_eq!(-111.2, (south * 100.0).round() / 100.0);
+    /// ```
+    pub fn distance_to(&self, coordinate: &Coordinate, unit: &DistanceUnit) -> f64 {
+        let sin_angle = dot(self.normal, to_unit_vector(coordinate)).clamp(-1.0, 1.0);
+        angle_to_distance(math::asin(sin_angle), unit)
+    }
+
+    /// # Summary
+    /// Checks if `coordinate` lies on the great circle, within `tolerance` radians
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let circle = GreatCircle::through(&Coordinate::new(0.0, 0.0), &Coordinate::new(45.0, 45.0)).unwrap();
+    ///
+    /// assert!(circle.contains(&Coordinate::new(-45.0, -135.0), 1e-9));
+    /// assert!(!circle.contains(&Coordinate::new(45.0, 0.0), 1e-9));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate, tolerance: f64) -> bool {
+        dot(self.normal, to_unit_vector(coordinate)).abs() <= math::sin(tolerance)
+    }
+
+    /// # Summary
+    /// Get the two antipodal points where this great circle crosses `other`.
+    /// Returns `None` if both describe the same circle
+    ///
+    /// ## Notes
+    /// - The first point is the one where `other` crosses this circle from its left to its right side
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, GreatCircle};
+    ///
+    /// let equator = GreatCircle::from_pole(&Coordinate::new(90.0, 0.0));
+    /// let meridian = GreatCircle::from_bearing(&Coordinate::new(10.0, 30.0), 0.0);
+    ///
+    /// let (first, second) = equator.intersection(&meridian).unwrap();
+    ///
+    /// assert!((first.longitude - 30.0).abs() < 1e-9);
+    /// assert!((second.longitude - -150.0).abs() < 1e-9);
+    /// assert!(equator.intersection(&equator).is_none());
+    /// ```
+    pub fn intersection(&self, other: &GreatCircle) -> Option<(Coordinate, Coordinate)> {
+        let direction = Self::from_normal(cross(self.normal, other.normal))?.normal;
+
+        Some((
+            from_unit_vector(direction),
+            from_unit_vector(direction.map(|component| -component)),
+        ))
+    }
+
+    fn from_normal(normal: [f64; 3]) -> Option<Self> {
+        if dot(normal, normal).sqrt() < DEGENERATE_LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            normal: normalize(normal),
+        })
+    }
+}
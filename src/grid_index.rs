@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::{Coordinate, CoordinateBoundaries};
+
+/// Largest number of cells a single item may cover before it is checked against every query instead
+const MAX_CELLS_PER_ITEM: i64 = 4096;
+
+/// Relative padding added to item bounds, so approximate bounding boxes never drop a true match
+const BOUNDS_PADDING: f64 = 0.005;
+
+/// # Summary
+/// Uniform latitude / longitude grid mapping cells to the indices of the items overlapping them,
+/// used to narrow down point in region checks to a handful of candidates
+#[derive(Debug, Clone)]
+pub(crate) struct GridIndex {
+    cell_size: f64,
+    columns: i64,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    /// Items without bounds, or covering too many cells, checked against every query
+    unindexed: Vec<usize>,
+}
+
+impl GridIndex {
+    /// # Summary
+    /// Construct an empty index of square cells, `cell_size` degrees wide
+    pub fn new(cell_size: f64) -> Self {
+        let cell_size = cell_size.clamp(1e-6, 360.0);
+        Self {
+            cell_size,
+            columns: (360.0 / cell_size).ceil() as i64,
+            cells: HashMap::new(),
+            unindexed: Vec::new(),
+        }
+    }
+
+    /// # Summary
+    /// Register `item` in every cell its bounds overlap, `None` bounds match every query
+    pub fn insert(&mut self, item: usize, bounds: Option<CoordinateBoundaries>) {
+        let Some(bounds) = bounds else {
+            self.unindexed.push(item);
+            return;
+        };
+
+        let lat_padding = (bounds.max_latitude() - bounds.min_latitude()) * BOUNDS_PADDING + 1e-9;
+        let lon_padding = (bounds.max_longitude() - bounds.min_longitude()) * BOUNDS_PADDING + 1e-9;
+        let min_row = self.row(bounds.min_latitude() - lat_padding);
+        let max_row = self.row(bounds.max_latitude() + lat_padding);
+        let min_column = self.column(bounds.min_longitude() - lon_padding);
+        let max_column = self.column(bounds.max_longitude() + lon_padding);
+
+        let columns = (max_column - min_column + 1).min(self.columns);
+        if columns * (max_row - min_row + 1) > MAX_CELLS_PER_ITEM {
+            self.unindexed.push(item);
+            return;
+        }
+
+        for row in min_row..=max_row {
+            for column in min_column..min_column + columns {
+                self.cells
+                    .entry((row, column.rem_euclid(self.columns)))
+                    .or_default()
+                    .push(item);
+            }
+        }
+    }
+
+    /// # Summary
+    /// Get the items which may contain `coordinate`, in ascending order without duplicates
+    pub fn candidates(&self, coordinate: &Coordinate) -> Vec<usize> {
+        let key = (
+            self.row(coordinate.latitude),
+            self.column(coordinate.longitude).rem_euclid(self.columns),
+        );
+        let mut candidates = self.unindexed.clone();
+        if let Some(items) = self.cells.get(&key) {
+            candidates.extend_from_slice(items);
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+    }
+
+    fn row(&self, latitude: f64) -> i64 {
+        ((latitude.clamp(-90.0, 90.0) + 90.0) / self.cell_size).floor() as i64
+    }
+
+    /// Unwrapped column, callers wrap it with `rem_euclid(self.columns)`
+    fn column(&self, longitude: f64) -> i64 {
+        ((longitude + 180.0) / self.cell_size).floor() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_candidates_include_every_overlapping_item() {
+        let mut index = GridIndex::new(0.5);
+        index.insert(
+            0,
+            CoordinateBoundaries::from_extents(10.0, 11.0, 20.0, 21.0),
+        );
+        // Crosses the antimeridian
+        index.insert(
+            1,
+            CoordinateBoundaries::from_extents(-1.0, 1.0, 179.0, 181.0),
+        );
+        index.insert(2, None);
+        // Too large to index cell by cell
+        index.insert(
+            3,
+            CoordinateBoundaries::from_extents(-80.0, 80.0, -170.0, 170.0),
+        );
+
+        assert_eq!(
+            vec![0, 2, 3],
+            index.candidates(&Coordinate::new(10.5, 20.5))
+        );
+        assert_eq!(
+            vec![1, 2, 3],
+            index.candidates(&Coordinate::new(0.0, -179.5))
+        );
+        assert_eq!(
+            vec![1, 2, 3],
+            index.candidates(&Coordinate::new(0.0, 179.5))
+        );
+        assert_eq!(vec![2, 3], index.candidates(&Coordinate::new(50.0, 50.0)));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let boxes: Vec<CoordinateBoundaries> = (0..200)
+            .filter_map(|seed| {
+                let lat = random(seed * 4, 160.0);
+                let lon = random(seed * 4 + 1, 360.0);
+                let height = random(seed * 4 + 2, 4.0).abs();
+                let width = random(seed * 4 + 3, 4.0).abs();
+                CoordinateBoundaries::from_extents(lat, lat + height, lon, lon + width)
+            })
+            .collect();
+
+        let mut index = GridIndex::new(1.0);
+        for (item, bounds) in boxes.iter().enumerate() {
+            index.insert(item, Some(*bounds));
+        }
+
+        for seed in 0..2000 {
+            let coordinate =
+                Coordinate::new(random(seed + 10_000, 180.0), random(seed + 20_000, 360.0));
+            let candidates = index.candidates(&coordinate);
+            for (item, bounds) in boxes.iter().enumerate() {
+                if bounds.contains(&coordinate) {
+                    assert!(candidates.contains(&item));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Conversions between crate collections and Apache Arrow arrays.
+//!
+//! `CoordinateArray` converts to and from a GeoArrow "separated" point array: a `StructArray` with
+//! non-null `Float64` fields `x` (longitude) and `y` (latitude). Geometry collections convert to
+//! and from `BinaryArray` columns of well-known binary, the encoding GeoParquet uses for geometry
+//! columns; `Geometry::wkb_field` and `Geometry::geoparquet_metadata` supply the matching schema
+//! field and file metadata.
+
+use crate::{CoordinateArray, Geometry, WkbError};
+use ::arrow_array::{Array, ArrayRef, BinaryArray, Float64Array, StructArray};
+use ::arrow_schema::{ArrowError, DataType, Field};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+impl From<&CoordinateArray> for StructArray {
+    fn from(coordinates: &CoordinateArray) -> Self {
+        let longitudes: ArrayRef = Arc::new(Float64Array::from(coordinates.longitudes().to_vec()));
+        let latitudes: ArrayRef = Arc::new(Float64Array::from(coordinates.latitudes().to_vec()));
+
+        StructArray::from(vec![
+            (
+                Arc::new(Field::new("x", DataType::Float64, false)),
+                longitudes,
+            ),
+            (
+                Arc::new(Field::new("y", DataType::Float64, false)),
+                latitudes,
+            ),
+        ])
+    }
+}
+
+impl TryFrom<&StructArray> for CoordinateArray {
+    type Error = ArrowError;
+
+    fn try_from(array: &StructArray) -> Result<Self, Self::Error> {
+        if array.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "point array contains null entries".to_string(),
+            ));
+        }
+        let column = |name: &str| {
+            let column = array
+                .column_by_name(name)
+                .and_then(|column| column.as_any().downcast_ref::<Float64Array>())
+                .ok_or_else(|| {
+                    ArrowError::SchemaError(format!("expected a Float64 field named `{}`", name))
+                })?;
+            if column.null_count() > 0 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "field `{}` contains null values",
+                    name
+                )));
+            }
+            Ok(column.values().to_vec())
+        };
+
+        let longitudes = column("x")?;
+        let latitudes = column("y")?;
+        // Checked the same way as well-known binary points, instead of wrapping them into range
+        if let Some(index) = latitudes
+            .iter()
+            .zip(&longitudes)
+            .position(|(latitude, longitude)| !(latitude.abs() <= 90.0 && longitude.abs() <= 180.0))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "point {} is not a finite longitude / latitude within ±180 / ±90",
+                index
+            )));
+        }
+        Ok(CoordinateArray::from_columns(latitudes, longitudes)
+            .expect("struct array children share the struct's length"))
+    }
+}
+
+impl Geometry {
+    /// # Summary
+    /// Encode the geometries as a `BinaryArray` of well-known binary, one non-null entry per
+    /// geometry
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Geometry};
+    ///
+    /// let geometries = vec![Geometry::Point(Coordinate::new(52.52, 13.405))];
+    /// let array = Geometry::to_wkb_array(&geometries);
+    ///
+    /// assert_eq!(vec![Some(geometries[0].clone())], Geometry::from_wkb_array(&array).unwrap());
+    /// ```
+    pub fn to_wkb_array(geometries: &[Geometry]) -> BinaryArray {
+        let encoded: Vec<Vec<u8>> = geometries.iter().map(Geometry::to_wkb).collect();
+        BinaryArray::from_iter_values(encoded)
+    }
+
+    /// # Summary
+    /// Decode a `BinaryArray` of well-known binary, such as a GeoParquet geometry column, keeping
+    /// null entries as `None`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use arrow_array::BinaryArray;
+    /// use geolocation_utils::{Coordinate, Geometry};
+    ///
+    /// let point = Geometry::Point(Coordinate::new(1.0, 2.0)).to_wkb();
+    /// let array = BinaryArray::from(vec![Some(point.as_slice()), None]);
+    ///
+    /// assert_eq!(
+    ///     vec![Some(Geometry::Point(Coordinate::new(1.0, 2.0))), None],
+    ///     Geometry::from_wkb_array(&array).unwrap()
+    /// );
+    /// ```
+    pub fn from_wkb_array(array: &BinaryArray) -> Result<Vec<Option<Geometry>>, WkbError> {
+        array
+            .iter()
+            .map(|value| value.map(Geometry::from_wkb).transpose())
+            .collect()
+    }
+
+    /// # Summary
+    /// Build a nullable `Binary` schema field for a WKB geometry column, tagged with the
+    /// `geoarrow.wkb` extension type
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Geometry;
+    ///
+    /// let field = Geometry::wkb_field("geometry");
+    ///
+    /// assert_eq!("geometry", field.name());
+    /// assert_eq!(
+    ///     Some(&"geoarrow.wkb".to_string()),
+    ///     field.metadata().get("ARROW:extension:name")
+    /// );
+    /// ```
+    pub fn wkb_field(name: &str) -> Field {
+        Field::new(name, DataType::Binary, true).with_metadata(HashMap::from([(
+            "ARROW:extension:name".to_string(),
+            "geoarrow.wkb".to_string(),
+        )]))
+    }
+
+    /// # Summary
+    /// Build the GeoParquet `geo` file metadata value for a single WKB geometry column holding
+    /// `geometries`, listing the geometry types present
+    ///
+    /// ## Notes
+    /// - Store the result under the `geo` key of the Parquet file's key-value metadata
+    /// - No `crs` is written, which GeoParquet defines as OGC:CRS84 (longitude / latitude on
+    ///   WGS 84)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Geometry};
+    ///
+    /// let geometries = vec![Geometry::Point(Coordinate::new(1.0, 2.0))];
+    ///
+    /// assert_eq!(
+    ///     r#"{"version":"1.1.0","primary_column":"geometry","columns":{"geometry":{"encoding":"WKB","geometry_types":["Point"]}}}"#,
+    ///     Geometry::geoparquet_metadata("geometry", &geometries)
+    /// );
+    /// ```
+    pub fn geoparquet_metadata(column: &str, geometries: &[Geometry]) -> String {
+        let types: BTreeSet<&str> = geometries.iter().map(geometry_type_name).collect();
+        let types: Vec<String> = types.iter().map(|name| format!("\"{}\"", name)).collect();
+        let column = escape_json(column);
+
+        format!(
+            r#"{{"version":"1.1.0","primary_column":"{column}","columns":{{"{column}":{{"encoding":"WKB","geometry_types":[{}]}}}}}}"#,
+            types.join(",")
+        )
+    }
+}
+
+fn geometry_type_name(geometry: &Geometry) -> &'static str {
+    match geometry {
+        Geometry::Point(_) => "Point",
+        Geometry::MultiPoint(_) => "MultiPoint",
+        Geometry::LineString(_) => "LineString",
+        Geometry::MultiLineString(_) => "MultiLineString",
+        Geometry::Polygon(_) => "Polygon",
+        Geometry::MultiPolygon(_) => "MultiPolygon",
+    }
+}
+
+fn escape_json(value: &str) -> String {
+    value
+        .chars()
+        .flat_map(|character| match character {
+            '"' | '\\' => vec!['\\', character],
+            character if character.is_control() => {
+                format!("\\u{:04x}", character as u32).chars().collect()
+            }
+            character => vec![character],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinate;
+
+    #[test]
+    fn test_point_array_round_trip() {
+        let coordinates: CoordinateArray = vec![
+            Coordinate::new(52.52, 13.405),
+            Coordinate::new(-33.87, 151.21),
+        ]
+        .into_iter()
+        .collect();
+
+        let array = StructArray::from(&coordinates);
+        assert_eq!(2, array.len());
+        assert_eq!(
+            coordinates,
+            CoordinateArray::try_from(&array).expect("round trip should succeed")
+        );
+    }
+
+    #[test]
+    fn test_point_array_requires_fields() {
+        let latitudes: ArrayRef = Arc::new(Float64Array::from(vec![1.0]));
+        let array = StructArray::from(vec![(
+            Arc::new(Field::new("y", DataType::Float64, false)),
+            latitudes,
+        )]);
+
+        assert!(CoordinateArray::try_from(&array).is_err());
+    }
+
+    #[test]
+    fn test_point_array_rejects_invalid_points() {
+        for (x, y) in [
+            (1e300, 0.0),
+            (0.0, 90.5),
+            (f64::NAN, 0.0),
+            (0.0, f64::INFINITY),
+        ] {
+            let array = StructArray::from(vec![
+                (
+                    Arc::new(Field::new("x", DataType::Float64, false)),
+                    Arc::new(Float64Array::from(vec![13.405, x])) as ArrayRef,
+                ),
+                (
+                    Arc::new(Field::new("y", DataType::Float64, false)),
+                    Arc::new(Float64Array::from(vec![52.52, y])) as ArrayRef,
+                ),
+            ]);
+
+            assert!(matches!(
+                CoordinateArray::try_from(&array),
+                Err(ArrowError::InvalidArgumentError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! `diesel` integration, loading a `Coordinate` from a `(latitude, longitude)` pair of `Double`
+//! columns and writing it back with `Coordinate::to_columns`. Rows which are not finite or lie
+//! outside of ±90 latitude / ±180 longitude fail to load instead of being wrapped.
+//!
+//! ```rust
+//! use diesel::prelude::*;
+//! use geolocation_utils::Coordinate;
+//!
+//! diesel::table! {
+//!     places (id) {
+//!         id -> Integer,
+//!         latitude -> Double,
+//!         longitude -> Double,
+//!     }
+//! }
+//!
+//! // Loaded with `query.load::<Coordinate>(&mut connection)`
+//! let query = places::table.select((places::latitude, places::longitude));
+//!
+//! let berlin = Coordinate::new(52.52, 13.405);
+//! let insert = diesel::insert_into(places::table)
+//!     .values(berlin.to_columns(places::latitude, places::longitude));
+//! let update = diesel::update(places::table.find(1))
+//!     .set(berlin.to_columns(places::latitude, places::longitude));
+//! ```
+
+use crate::{Coordinate, ValidationLevel};
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{FromStaticSqlRow, Queryable, Result};
+use ::diesel::dsl::Eq;
+use ::diesel::expression::Expression;
+use ::diesel::sql_types::Double;
+use ::diesel::ExpressionMethods;
+
+impl<DB> Queryable<(Double, Double), DB> for Coordinate
+where
+    DB: Backend,
+    (f64, f64): FromStaticSqlRow<(Double, Double), DB>,
+{
+    type Row = (f64, f64);
+
+    fn build((latitude, longitude): Self::Row) -> Result<Self> {
+        Ok(Coordinate::try_new(
+            latitude,
+            longitude,
+            ValidationLevel::Strict,
+        )?)
+    }
+}
+
+impl Coordinate {
+    /// # Summary
+    /// Assign the coordinate to a pair of `Double` latitude and longitude columns, for
+    /// `insert_into(..).values(..)` and `update(..).set(..)`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use diesel::prelude::*;
+    /// use geolocation_utils::Coordinate;
+    ///
+    /// diesel::table! {
+    ///     stops (id) {
+    ///         id -> Integer,
+    ///         lat -> Double,
+    ///         lon -> Double,
+    ///     }
+    /// }
+    ///
+    /// let insert = diesel::insert_into(stops::table)
+    ///     .values(Coordinate::new(48.1372, 11.5756).to_columns(stops::lat, stops::lon));
+    /// ```
+    pub fn to_columns<Latitude, Longitude>(
+        self,
+        latitude: Latitude,
+        longitude: Longitude,
+    ) -> (Eq<Latitude, f64>, Eq<Longitude, f64>)
+    where
+        Latitude: Expression<SqlType = Double> + ExpressionMethods,
+        Longitude: Expression<SqlType = Double> + ExpressionMethods,
+    {
+        (latitude.eq(self.latitude), longitude.eq(self.longitude))
+    }
+}
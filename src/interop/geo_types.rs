@@ -0,0 +1,138 @@
+//! Conversions between this crate's types and the `geo-types` crate used across the GeoRust ecosystem.
+//!
+//! `geo-types` stores positions as `x` (longitude) and `y` (latitude).
+
+use crate::{Coordinate, CoordinateBoundaries, Polygon, Route};
+
+impl From<Coordinate> for ::geo_types::Coord<f64> {
+    fn from(coordinate: Coordinate) -> Self {
+        ::geo_types::coord! { x: coordinate.longitude, y: coordinate.latitude }
+    }
+}
+
+impl From<::geo_types::Coord<f64>> for Coordinate {
+    fn from(coord: ::geo_types::Coord<f64>) -> Self {
+        Coordinate::new(coord.y, coord.x)
+    }
+}
+
+impl From<Coordinate> for ::geo_types::Point<f64> {
+    fn from(coordinate: Coordinate) -> Self {
+        ::geo_types::Point::new(coordinate.longitude, coordinate.latitude)
+    }
+}
+
+impl From<::geo_types::Point<f64>> for Coordinate {
+    fn from(point: ::geo_types::Point<f64>) -> Self {
+        Coordinate::new(point.y(), point.x())
+    }
+}
+
+impl From<&CoordinateBoundaries> for ::geo_types::Rect<f64> {
+    fn from(bounds: &CoordinateBoundaries) -> Self {
+        ::geo_types::Rect::new(
+            ::geo_types::coord! { x: bounds.min_longitude(), y: bounds.min_latitude() },
+            ::geo_types::coord! { x: bounds.max_longitude(), y: bounds.max_latitude() },
+        )
+    }
+}
+
+impl From<&CoordinateBoundaries> for ::geo_types::Polygon<f64> {
+    fn from(bounds: &CoordinateBoundaries) -> Self {
+        ::geo_types::Rect::from(bounds).to_polygon()
+    }
+}
+
+impl From<&Route> for ::geo_types::LineString<f64> {
+    fn from(route: &Route) -> Self {
+        route
+            .points
+            .iter()
+            .map(|&coordinate| ::geo_types::Coord::from(coordinate))
+            .collect()
+    }
+}
+
+impl From<::geo_types::LineString<f64>> for Route {
+    fn from(line_string: ::geo_types::LineString<f64>) -> Self {
+        Route::new(line_string.0.into_iter().map(Coordinate::from).collect())
+    }
+}
+
+impl From<&Polygon> for ::geo_types::Polygon<f64> {
+    fn from(polygon: &Polygon) -> Self {
+        let ring = |points: &Vec<Coordinate>| {
+            points
+                .iter()
+                .map(|&coordinate| ::geo_types::Coord::from(coordinate))
+                .collect::<::geo_types::LineString<f64>>()
+        };
+        ::geo_types::Polygon::new(
+            ring(&polygon.exterior),
+            polygon.interiors.iter().map(ring).collect(),
+        )
+    }
+}
+
+impl From<::geo_types::Polygon<f64>> for Polygon {
+    fn from(polygon: ::geo_types::Polygon<f64>) -> Self {
+        let (exterior, interiors) = polygon.into_inner();
+        let ring = |line_string: ::geo_types::LineString<f64>| {
+            line_string.0.into_iter().map(Coordinate::from).collect()
+        };
+        Polygon::with_interiors(ring(exterior), interiors.into_iter().map(ring).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_round_trip() {
+        let coordinate = Coordinate::new(34.8, -2.8);
+
+        let point: ::geo_types::Point<f64> = coordinate.into();
+        assert_eq!(-2.8, point.x());
+        assert_eq!(34.8, point.y());
+        assert_eq!(coordinate, Coordinate::from(point));
+
+        let coord: ::geo_types::Coord<f64> = coordinate.into();
+        assert_eq!(coordinate, Coordinate::from(coord));
+    }
+
+    #[test]
+    fn test_boundaries_to_rect() {
+        let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 1.0, None).unwrap();
+        let rect = ::geo_types::Rect::from(&bounds);
+
+        assert_eq!(bounds.min_longitude(), rect.min().x);
+        assert_eq!(bounds.max_latitude(), rect.max().y);
+
+        let polygon = ::geo_types::Polygon::from(&bounds);
+        assert_eq!(5, polygon.exterior().0.len());
+    }
+
+    #[test]
+    fn test_route_round_trip() {
+        let route = Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 2.0)]);
+        let line_string = ::geo_types::LineString::from(&route);
+
+        assert_eq!(2.0, line_string.0[1].x);
+        assert_eq!(route, Route::from(line_string));
+    }
+
+    #[test]
+    fn test_polygon_round_trip() {
+        let polygon = Polygon::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 1.0),
+            Coordinate::new(1.0, 0.0),
+        ]);
+        let converted = ::geo_types::Polygon::from(&polygon);
+
+        // geo-types closes the ring
+        assert_eq!(4, converted.exterior().0.len());
+        assert!(Polygon::from(converted).contains(&Coordinate::new(0.25, 0.25)));
+    }
+}
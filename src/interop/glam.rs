@@ -0,0 +1,45 @@
+//! Conversions between `Coordinate` and `glam` double precision 2D vectors.
+//!
+//! 2D values store positions as `x` (longitude) and `y` (latitude) in degrees. 3D ECEF and local
+//! tangent plane vectors convert through `Coordinate::to_ecef` / `Coordinate::to_local_tangent`,
+//! whose `[f64; 3]` results convert into `glam::DVec3` with `.into()`.
+
+use crate::Coordinate;
+
+impl From<Coordinate> for ::glam::DVec2 {
+    fn from(coordinate: Coordinate) -> Self {
+        ::glam::DVec2::new(coordinate.longitude, coordinate.latitude)
+    }
+}
+
+impl From<::glam::DVec2> for Coordinate {
+    fn from(vector: ::glam::DVec2) -> Self {
+        Coordinate::new(vector.y, vector.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        let coordinate = Coordinate::new(34.8, -2.8);
+
+        let vector: ::glam::DVec2 = coordinate.into();
+        assert_eq!(34.8, vector.y);
+        assert_eq!(coordinate, Coordinate::from(vector));
+    }
+
+    #[test]
+    fn test_local_tangent_vector() {
+        let origin = Coordinate::new(51.5, -0.12);
+        let enu: ::glam::DVec3 = Coordinate::new(51.51, -0.1)
+            .to_local_tangent(&origin)
+            .into();
+        let converted = Coordinate::from_local_tangent(&origin, enu);
+
+        assert!((converted.latitude - 51.51).abs() < 1e-9);
+        assert!((converted.longitude + 0.1).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,12 @@
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "geo-types")]
+mod geo_types;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "sqlx")]
+mod sqlx;
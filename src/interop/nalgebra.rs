@@ -0,0 +1,58 @@
+//! Conversions between `Coordinate` and `nalgebra` 2D vectors and points.
+//!
+//! 2D values store positions as `x` (longitude) and `y` (latitude) in degrees. 3D ECEF and local
+//! tangent plane vectors convert through `Coordinate::to_ecef` / `Coordinate::to_local_tangent`,
+//! whose `[f64; 3]` results convert into `nalgebra::Vector3` with `.into()`.
+
+use crate::Coordinate;
+
+impl From<Coordinate> for ::nalgebra::Vector2<f64> {
+    fn from(coordinate: Coordinate) -> Self {
+        ::nalgebra::Vector2::new(coordinate.longitude, coordinate.latitude)
+    }
+}
+
+impl From<::nalgebra::Vector2<f64>> for Coordinate {
+    fn from(vector: ::nalgebra::Vector2<f64>) -> Self {
+        Coordinate::new(vector.y, vector.x)
+    }
+}
+
+impl From<Coordinate> for ::nalgebra::Point2<f64> {
+    fn from(coordinate: Coordinate) -> Self {
+        ::nalgebra::Point2::new(coordinate.longitude, coordinate.latitude)
+    }
+}
+
+impl From<::nalgebra::Point2<f64>> for Coordinate {
+    fn from(point: ::nalgebra::Point2<f64>) -> Self {
+        Coordinate::new(point.y, point.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        let coordinate = Coordinate::new(34.8, -2.8);
+
+        let vector: ::nalgebra::Vector2<f64> = coordinate.into();
+        assert_eq!(-2.8, vector.x);
+        assert_eq!(coordinate, Coordinate::from(vector));
+
+        let point: ::nalgebra::Point2<f64> = coordinate.into();
+        assert_eq!(coordinate, Coordinate::from(point));
+    }
+
+    #[test]
+    fn test_ecef_vector() {
+        let coordinate = Coordinate::new(48.1372, 11.5756);
+        let ecef: ::nalgebra::Vector3<f64> = coordinate.to_ecef(0.0).into();
+        let (converted, _) = Coordinate::from_ecef(ecef);
+
+        assert!((converted.latitude - coordinate.latitude).abs() < 1e-9);
+        assert!((converted.longitude - coordinate.longitude).abs() < 1e-9);
+    }
+}
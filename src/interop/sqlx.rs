@@ -0,0 +1,38 @@
+//! `sqlx` integration, reading and writing `Coordinate` as a Postgres `point` column.
+//!
+//! The `point` is stored as `(x, y)` = `(longitude, latitude)`. Points which are not finite or lie
+//! outside of ±90 latitude / ±180 longitude fail to decode instead of being wrapped.
+
+use crate::{Coordinate, ValidationLevel};
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::postgres::types::PgPoint;
+use ::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use ::sqlx::{Decode, Encode, Type};
+
+impl Type<Postgres> for Coordinate {
+    fn type_info() -> PgTypeInfo {
+        <PgPoint as Type<Postgres>>::type_info()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Coordinate {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let point = <PgPoint as Decode<Postgres>>::decode(value)?;
+        Ok(Coordinate::try_new(
+            point.y,
+            point.x,
+            ValidationLevel::Strict,
+        )?)
+    }
+}
+
+impl<'q> Encode<'q, Postgres> for Coordinate {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let point = PgPoint {
+            x: self.longitude,
+            y: self.latitude,
+        };
+        <PgPoint as Encode<Postgres>>::encode_by_ref(&point, buf)
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Result of solving the inverse problem between two coordinates, see `Coordinate::inverse` and
+/// `geodesic::inverse`
+pub struct InverseResult {
+    /// Length of the shortest path, in the requested unit
+    pub distance: f64,
+    /// Bearing when leaving the origin, in degrees clockwise from north (0 - 360)
+    pub initial_bearing: f64,
+    /// Bearing when arriving at the destination, in degrees clockwise from north (0 - 360)
+    pub final_bearing: f64,
+}
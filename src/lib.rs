@@ -1,8 +1,145 @@
+mod assignment;
+mod centroid;
+mod cheap_ruler;
+mod circle;
+mod codec;
 mod coordinate;
+mod coordinate_array;
 mod coordinate_boundaries;
+mod coordinate_delta;
+mod coordinate_parsing;
+mod coordinate_token;
+#[cfg(feature = "countries")]
+mod countries;
+mod crossings;
+mod degree_length;
+mod distance;
 mod distance_unit;
+mod edge_policy;
+mod equal_area_tiling;
+mod feed_formats;
+mod fixed_coordinate;
+pub mod geodesic;
+mod geofence;
+mod geofence_schedule;
+mod geofence_tracker;
+mod geohash;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod geometry;
+mod great_circle;
+mod grid_index;
+mod interop;
+mod inverse_result;
+mod location_tracker;
+#[cfg(feature = "mmap")]
+mod mapped_coordinates;
+mod math;
+#[cfg(feature = "mvt")]
+mod mvt;
+mod nearest_route;
+mod oriented_bounds;
+mod pixel_bounds;
+mod platform;
+mod point_pairs;
+mod polygon;
+mod polygon_boundary;
+mod polygon_offset;
+#[cfg(feature = "rand")]
+mod polygon_sampling;
+mod polygon_simplify;
+mod prepared_polygon;
+mod privacy_zone;
+mod proximity;
+mod proximity_cache;
+mod radius_result;
+mod reachability;
+mod region;
+mod rotation;
+mod route;
+mod satellite;
+#[cfg(feature = "shapefile")]
+mod shapefile;
+#[cfg(feature = "shared")]
+mod shared_geofence;
+mod solar;
+mod speed;
+pub mod spherical;
+mod spherical_cap;
+mod tile;
+mod tile_pyramid;
+mod track;
+mod track_index;
+mod track_segmentation;
 mod utils;
+mod validation;
+mod visit_order;
+mod wkb;
 
+pub use assignment::{assign_to_nearest, assign_with_capacity};
+pub use centroid::weighted_centroid;
+pub use cheap_ruler::{CheapRuler, PointOnLine};
+pub use circle::Circle;
+pub use codec::{decode_coordinates, encode_coordinates, CodecError};
 pub use coordinate::Coordinate;
+pub use coordinate_array::CoordinateArray;
 pub use coordinate_boundaries::CoordinateBoundaries;
+pub use coordinate_delta::CoordinateDelta;
+pub use coordinate_parsing::{CoordinateParseError, DecimalSeparator};
+pub use coordinate_token::TokenError;
+pub use crossings::{crossing_meridian, crossing_parallels};
+pub use degree_length::{meters_per_degree_latitude, meters_per_degree_longitude};
+pub use distance::{Distance, DistanceParseError};
 pub use distance_unit::DistanceUnit;
+pub use edge_policy::{EdgeInclusion, EdgePolicy};
+pub use fixed_coordinate::FixedCoordinate;
+pub use geofence::{Geofence, GeofenceSet, Hit};
+pub use geofence_schedule::{ActiveWindow, Schedule, ScheduledGeofence, Weekday};
+pub use geofence_tracker::{
+    GeofenceEvent, GeofenceEventKind, GeofenceTracker, GeofenceTrackerSnapshot, PositionReport,
+};
+pub use geohash::{common_prefix_geohash, shard_for};
+#[cfg(feature = "geojson")]
+pub use geojson::{GeoJsonError, GeoJsonFeature, GeoJsonReader};
+pub use geometry::Geometry;
+pub use great_circle::GreatCircle;
+pub use inverse_result::InverseResult;
+pub use location_tracker::{KalmanSmoothing, LocationTracker, TrackedPosition};
+#[cfg(feature = "mmap")]
+pub use mapped_coordinates::MappedCoordinates;
+#[cfg(feature = "mvt")]
+pub use mvt::{encode_tile, MvtFeature, MvtLayer, MvtValue};
+pub use nearest_route::{nearest_route, RouteSet};
+pub use oriented_bounds::OrientedBounds;
+pub use pixel_bounds::PixelBounds;
+pub use platform::{Platform, RelativePosition};
+pub use point_pairs::{closest_pair, farthest_pair};
+pub use polygon::{EdgeInterpolation, Polygon};
+pub use polygon_offset::OffsetJoin;
+pub use polygon_simplify::simplify_polygons;
+pub use prepared_polygon::PreparedPolygon;
+pub use privacy_zone::{PrivacyAction, PrivacyZone};
+pub use proximity::{
+    dedup_within, diff_point_sets, spatial_join, PointSetDiff, ProximityMatch, ProximityQuery,
+};
+pub use proximity_cache::ProximityCache;
+pub use radius_result::RadiusResult;
+pub use reachability::{reachable_region, reachable_region_by_bearing};
+pub use region::{contains_with_prefilter, Bounded, Contains, Prefiltered, Region};
+pub use rotation::rotate_coordinates;
+pub use route::{Route, Turn, TurnDirection};
+pub use satellite::{ground_track, LookAngles, SatellitePosition};
+#[cfg(feature = "shapefile")]
+pub use shapefile::{open_shapefile, read_shapefile, Shape, ShapeRecord, ShapefileError};
+#[cfg(feature = "shared")]
+pub use shared_geofence::{GeofenceVersion, SharedGeofence};
+pub use speed::{Speed, SpeedUnit};
+pub use spherical_cap::SphericalCap;
+pub use tile::Tile;
+pub use tile_pyramid::{aggregate_to_tiles, aggregate_weighted_to_tiles, TileStats};
+pub use track::{Track, TrackPoint};
+pub use track_index::TrackIndex;
+pub use track_segmentation::{SpeedThresholds, TrackSegment, TransportMode};
+pub use validation::{ValidationError, ValidationLevel, ValidationReport};
+pub use visit_order::{order_by_nearest_neighbor, VisitOrder};
+pub use wkb::WkbError;
@@ -0,0 +1,360 @@
+use crate::utils::{linear_divisor, wrap_to_bounds, EARTH_RADIUS_KM};
+use crate::{math, Coordinate, DistanceUnit, Speed, SpeedUnit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Standard deviation of the speed of an object before its second fix, in meters per second
+const INITIAL_SPEED_DEVIATION: f64 = 100.0;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// Tuning of the Kalman filter a `LocationTracker` smooths positions with
+pub struct KalmanSmoothing {
+    /// Standard deviation of the acceleration of objects in meters per second squared, higher
+    /// values follow turns and changes of speed sooner
+    pub process_noise: f64,
+    /// Standard deviation of the error of position fixes in meters, higher values smooth harder
+    pub measurement_noise: f64,
+}
+
+impl Default for KalmanSmoothing {
+    /// # Summary
+    /// Accelerations of about 1 m/s² and fixes within about 10 meters, typical of vehicles
+    /// tracked by phone GPS
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::KalmanSmoothing;
+    ///
+    /// assert_eq!(10.0, KalmanSmoothing::default().measurement_noise);
+    /// ```
+    fn default() -> Self {
+        Self {
+            process_noise: 1.0,
+            measurement_noise: 10.0,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// The latest known position and movement of an object, see `LocationTracker::update`
+pub struct TrackedPosition {
+    pub coordinate: Coordinate,
+    pub speed: Speed,
+    /// Direction of travel in degrees clockwise from north (0 - 360)
+    pub bearing: f64,
+    /// Milliseconds since the Unix epoch of the latest fix
+    pub timestamp: i64,
+}
+
+/// ## Summary
+/// Filter state of a single object, the velocity in meters per second towards the east and
+/// north, with the covariance of position and velocity along each of the two axes
+#[derive(Debug, Clone, Copy)]
+struct ObjectState {
+    coordinate: Coordinate,
+    timestamp: i64,
+    velocity: [f64; 2],
+    /// Variance of the position, covariance of position and velocity, variance of the velocity
+    covariance: [[f64; 3]; 2],
+}
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// Follows the latest position, speed and direction of moving objects from a stream of position
+/// fixes, optionally smoothing each object with its own Kalman filter so jittery fixes do not
+/// make the position jump around or the speed spike
+///
+/// ## Notes
+/// - Without smoothing the reported position is the latest fix, with the speed and direction of
+///   travel from the fix before it
+/// - Smoothing uses a constant velocity model in the local east / north plane of each object
+pub struct LocationTracker<O> {
+    smoothing: Option<KalmanSmoothing>,
+    objects: HashMap<O, ObjectState>,
+}
+
+impl<O> Default for LocationTracker<O> {
+    fn default() -> Self {
+        Self {
+            smoothing: None,
+            objects: HashMap::new(),
+        }
+    }
+}
+
+impl<O: Eq + Hash> LocationTracker<O> {
+    /// # Summary
+    /// Construct a tracker which reports the raw position fixes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::LocationTracker;
+    ///
+    /// let tracker: LocationTracker<u32> = LocationTracker::new();
+    /// assert!(tracker.position(&1).is_none());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            smoothing: None,
+            objects: HashMap::new(),
+        }
+    }
+
+    /// # Summary
+    /// Construct a tracker which smooths the positions and speeds of every object with a Kalman
+    /// filter tuned by `smoothing`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{KalmanSmoothing, LocationTracker};
+    ///
+    /// let tracker: LocationTracker<&str> = LocationTracker::with_smoothing(KalmanSmoothing {
+    ///     process_noise: 0.5,
+    ///     measurement_noise: 25.0,
+    /// });
+    /// assert!(tracker.position(&"bus").is_none());
+    /// ```
+    pub fn with_smoothing(smoothing: KalmanSmoothing) -> Self {
+        Self {
+            smoothing: Some(smoothing),
+            objects: HashMap::new(),
+        }
+    }
+
+    /// # Summary
+    /// Record a position fix of an object at `timestamp` milliseconds since the Unix epoch,
+    /// returning its updated position
+    ///
+    /// ## Notes
+    /// - Fixes no newer than the latest fix of the same object are ignored, returning the position
+    ///   unchanged
+    /// - The first fix of an object has no speed yet
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, KalmanSmoothing, LocationTracker, SpeedUnit};
+    ///
+    /// let mut tracker = LocationTracker::with_smoothing(KalmanSmoothing::default());
+    ///
+    /// // Heading east at about 10 m/s, every other fix 20 meters off to the north
+    /// let mut position = tracker.update("van", &Coordinate::new(0.0, 0.0), 0);
+    /// for second in 1..=60 {
+    ///     let jitter = if second % 2 == 0 { 20.0 / 111_195.0 } else { 0.0 };
+    ///     let fix = Coordinate::new(jitter, second as f64 * 10.0 / 111_195.0);
+    ///     position = tracker.update("van", &fix, second * 1_000);
+    /// }
+    ///
+    /// let speed = position.speed.to_unit(&SpeedUnit::MetersPerSecond).value;
+    /// assert!((speed - 10.0).abs() < 0.5);
+    /// assert!((position.bearing - 90.0).abs() < 5.0);
+    /// ```
+    pub fn update(
+        &mut self,
+        object_id: O,
+        coordinate: &Coordinate,
+        timestamp: i64,
+    ) -> TrackedPosition {
+        let measurement_variance = self
+            .smoothing
+            .map_or(0.0, |smoothing| smoothing.measurement_noise.powi(2));
+        let state = self
+            .objects
+            .entry(object_id)
+            .or_insert_with(|| ObjectState {
+                coordinate: *coordinate,
+                timestamp,
+                velocity: [0.0; 2],
+                covariance: [[measurement_variance, 0.0, INITIAL_SPEED_DEVIATION.powi(2)]; 2],
+            });
+        if timestamp <= state.timestamp {
+            return state.position();
+        }
+
+        let dt = (timestamp - state.timestamp) as f64 / 1000.0;
+        let offset = local_offset(&state.coordinate, coordinate);
+        let Some(smoothing) = self.smoothing else {
+            *state = ObjectState {
+                coordinate: *coordinate,
+                timestamp,
+                velocity: [offset[0] / dt, offset[1] / dt],
+                ..*state
+            };
+            return state.position();
+        };
+
+        // Predict with constant velocity and white noise acceleration, then correct towards the
+        // fix, separately along the east and north axes
+        let process_variance = smoothing.process_noise.powi(2);
+        let mut moved = [0.0; 2];
+        for axis in 0..2 {
+            let [p, pv, v] = state.covariance[axis];
+            let predicted_p = p + 2.0 * dt * pv + dt * dt * v + process_variance * dt.powi(4) / 4.0;
+            let predicted_pv = pv + dt * v + process_variance * dt.powi(3) / 2.0;
+            let predicted_v = v + process_variance * dt * dt;
+
+            let innovation_variance = predicted_p + measurement_variance;
+            let (position_gain, velocity_gain) = if innovation_variance > 0.0 {
+                (
+                    predicted_p / innovation_variance,
+                    predicted_pv / innovation_variance,
+                )
+            } else {
+                (1.0, 0.0)
+            };
+            let predicted = state.velocity[axis] * dt;
+            let residual = offset[axis] - predicted;
+
+            moved[axis] = predicted + position_gain * residual;
+            state.velocity[axis] += velocity_gain * residual;
+            state.covariance[axis] = [
+                (1.0 - position_gain) * predicted_p,
+                (1.0 - position_gain) * predicted_pv,
+                predicted_v - velocity_gain * predicted_pv,
+            ];
+        }
+        state.coordinate = apply_offset(&state.coordinate, moved);
+        state.timestamp = timestamp;
+
+        state.position()
+    }
+
+    /// # Summary
+    /// Get the latest position of an object, if it has reported any fix
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, LocationTracker};
+    ///
+    /// let mut tracker = LocationTracker::new();
+    /// tracker.update(7, &Coordinate::new(1.0, 2.0), 0);
+    ///
+    /// assert_eq!(Coordinate::new(1.0, 2.0), tracker.position(&7).unwrap().coordinate);
+    /// assert!(tracker.position(&8).is_none());
+    /// ```
+    pub fn position(&self, object_id: &O) -> Option<TrackedPosition> {
+        self.objects.get(object_id).map(ObjectState::position)
+    }
+}
+
+impl ObjectState {
+    fn position(&self) -> TrackedPosition {
+        let [east, north] = self.velocity;
+        TrackedPosition {
+            coordinate: self.coordinate,
+            speed: Speed::new(math::hypot(east, north), SpeedUnit::MetersPerSecond),
+            bearing: math::atan2(east, north).to_degrees().rem_euclid(360.0),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+/// # Summary
+/// Meters per degree of latitude on the spherical Earth
+fn meters_per_degree() -> f64 {
+    EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers) * std::f64::consts::PI / 180.0
+}
+
+/// # Summary
+/// Get the offset of `to` from `from` in meters towards the east and north, in the local plane of
+/// `from`
+fn local_offset(from: &Coordinate, to: &Coordinate) -> [f64; 2] {
+    let scale = meters_per_degree();
+    let east = wrap_to_bounds(to.longitude - from.longitude, 180.0)
+        * scale
+        * math::cos(from.latitude.to_radians());
+    [east, (to.latitude - from.latitude) * scale]
+}
+
+/// # Summary
+/// Move a coordinate by an offset in meters towards the east and north, the inverse of
+/// `local_offset`
+fn apply_offset(from: &Coordinate, offset: [f64; 2]) -> Coordinate {
+    let scale = meters_per_degree();
+    let cos_latitude = math::cos(from.latitude.to_radians());
+    let east = if cos_latitude > 0.0 {
+        offset[0] / (scale * cos_latitude)
+    } else {
+        0.0
+    };
+    Coordinate::new(
+        (from.latitude + offset[1] / scale).clamp(-90.0, 90.0),
+        wrap_to_bounds(from.longitude + east, 180.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_smoothing_steadies_jittery_fixes() {
+        let mut raw = LocationTracker::new();
+        let mut smoothed = LocationTracker::with_smoothing(KalmanSmoothing {
+            process_noise: 0.2,
+            measurement_noise: 12.0,
+        });
+
+        // Heading north east at 15 m/s with fixes up to 20 meters off in each direction
+        let velocity = [15.0 * 0.6, 15.0 * 0.8];
+        let (mut raw_error, mut smoothed_error) = (0.0, 0.0);
+        let (mut worst_raw_speed, mut worst_speed, mut worst_bearing) = (0.0f64, 0.0f64, 0.0f64);
+        for second in 0..600i64 {
+            let truth = apply_offset(
+                &Coordinate::new(51.5, -0.12),
+                [velocity[0] * second as f64, velocity[1] * second as f64],
+            );
+            let fix = apply_offset(
+                &truth,
+                [
+                    random(2 * second as u64, 40.0),
+                    random(2 * second as u64 + 1, 40.0),
+                ],
+            );
+            let raw_position = raw.update(1, &fix, second * 1_000);
+            let position = smoothed.update(1, &fix, second * 1_000);
+
+            if second >= 60 {
+                let error = |coordinate: &Coordinate| {
+                    let [east, north] = local_offset(&truth, coordinate);
+                    east * east + north * north
+                };
+                raw_error += error(&fix);
+                smoothed_error += error(&position.coordinate);
+                worst_raw_speed = worst_raw_speed.max((raw_position.speed.value - 15.0).abs());
+                worst_speed = worst_speed.max((position.speed.value - 15.0).abs());
+                worst_bearing = worst_bearing.max((position.bearing - 36.87).abs());
+            }
+        }
+
+        assert!(
+            smoothed_error < raw_error / 4.0,
+            "{} {}",
+            smoothed_error,
+            raw_error
+        );
+        // Raw speeds swing by tens of meters per second from fix to fix
+        assert!(worst_raw_speed > 20.0, "{}", worst_raw_speed);
+        assert!(worst_speed < 1.5, "{}", worst_speed);
+        assert!(worst_bearing < 5.0, "{}", worst_bearing);
+    }
+
+    #[test]
+    fn test_out_of_order_fixes_are_ignored() {
+        let mut tracker = LocationTracker::with_smoothing(KalmanSmoothing::default());
+        tracker.update("a", &Coordinate::new(0.0, 0.0), 10_000);
+        let position = tracker.update("a", &Coordinate::new(1.0, 1.0), 5_000);
+
+        assert_eq!(Coordinate::new(0.0, 0.0), position.coordinate);
+        assert_eq!(10_000, position.timestamp);
+        assert_eq!(0.0, position.speed.value);
+    }
+}
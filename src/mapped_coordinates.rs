@@ -0,0 +1,196 @@
+use crate::{Coordinate, CoordinateBoundaries, DistanceUnit};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Size in bytes of a single record, a little endian `f64` latitude followed by a little endian `f64` longitude
+const RECORD_SIZE: usize = 16;
+
+/// ## Summary
+/// A read-only coordinate dataset backed by a memory-mapped file, allowing datasets larger than
+/// RAM to be iterated and filtered while the OS pages records in and out on demand
+///
+/// ## Notes
+/// - The file is a plain sequence of fixed-width 16 byte records, each a little endian `f64`
+///   latitude followed by a little endian `f64` longitude, with no header
+/// - Files can be produced with `MappedCoordinates::write` or any tool writing that layout
+/// - The file must not be modified by other processes while it is mapped
+pub struct MappedCoordinates {
+    map: Mmap,
+}
+
+impl MappedCoordinates {
+    /// # Summary
+    /// Memory-map a coordinate dataset file. Fails if the file can't be mapped or its size is not
+    /// a multiple of the 16 byte record size
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MappedCoordinates};
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("geolocation_utils_open_example.bin");
+    /// let coordinates = vec![Coordinate::new(1.0, 2.0), Coordinate::new(3.0, 4.0)];
+    /// MappedCoordinates::write(File::create(&path).unwrap(), &coordinates).unwrap();
+    ///
+    /// let dataset = MappedCoordinates::open(&path).unwrap();
+    /// assert_eq!(2, dataset.len());
+    /// assert_eq!(Some(Coordinate::new(3.0, 4.0)), dataset.get(1));
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the map is only ever read, callers are documented to not modify the file while mapped
+        let map = unsafe { Mmap::map(&file)? };
+        if map.len() % RECORD_SIZE != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "file size {} is not a multiple of the {} byte record size",
+                    map.len(),
+                    RECORD_SIZE
+                ),
+            ));
+        }
+        Ok(Self { map })
+    }
+
+    /// # Summary
+    /// Write coordinates to `writer` using the dataset record layout
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MappedCoordinates};
+    ///
+    /// let mut buffer = Vec::new();
+    /// MappedCoordinates::write(&mut buffer, &[Coordinate::new(1.0, 2.0)]).unwrap();
+    ///
+    /// assert_eq!(16, buffer.len());
+    /// ```
+    pub fn write<'a>(
+        mut writer: impl Write,
+        coordinates: impl IntoIterator<Item = &'a Coordinate>,
+    ) -> io::Result<()> {
+        for coordinate in coordinates {
+            writer.write_all(&coordinate.latitude.to_le_bytes())?;
+            writer.write_all(&coordinate.longitude.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// # Summary
+    /// Get the number of records in the dataset
+    pub fn len(&self) -> usize {
+        self.map.len() / RECORD_SIZE
+    }
+
+    /// # Summary
+    /// Checks if the dataset holds no records
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// # Summary
+    /// Get the coordinate at `index`, or `None` if out of range
+    pub fn get(&self, index: usize) -> Option<Coordinate> {
+        let start = index.checked_mul(RECORD_SIZE)?;
+        let record = self.map.get(start..start + RECORD_SIZE)?;
+        Some(read_record(record))
+    }
+
+    /// # Summary
+    /// Iterate over every coordinate of the dataset
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MappedCoordinates};
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("geolocation_utils_iter_example.bin");
+    /// let coordinates = vec![Coordinate::new(1.0, 2.0), Coordinate::new(3.0, 4.0)];
+    /// MappedCoordinates::write(File::create(&path).unwrap(), &coordinates).unwrap();
+    ///
+    /// let dataset = MappedCoordinates::open(&path).unwrap();
+    /// assert_eq!(coordinates, dataset.iter().collect::<Vec<_>>());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        self.map.chunks_exact(RECORD_SIZE).map(read_record)
+    }
+
+    /// # Summary
+    /// Iterate over the index and coordinate of every record inside `bounds`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, MappedCoordinates};
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("geolocation_utils_bounds_example.bin");
+    /// let coordinates = vec![Coordinate::new(1.0, 1.0), Coordinate::new(0.1, 0.1)];
+    /// MappedCoordinates::write(File::create(&path).unwrap(), &coordinates).unwrap();
+    ///
+    /// let dataset = MappedCoordinates::open(&path).unwrap();
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 20.0, None).unwrap();
+    ///
+    /// let found: Vec<_> = dataset.iter_in_bounds(&bounds).collect();
+    /// assert_eq!(vec![(1, Coordinate::new(0.1, 0.1))], found);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn iter_in_bounds<'a>(
+        &'a self,
+        bounds: &'a CoordinateBoundaries,
+    ) -> impl Iterator<Item = (usize, Coordinate)> + 'a {
+        self.iter()
+            .enumerate()
+            .filter(move |(_, coordinate)| bounds.contains(coordinate))
+    }
+
+    /// # Summary
+    /// Iterate over the index and coordinate of every record within `radius` of `origin`
+    ///
+    /// ## Notes
+    /// - Records are prefiltered with the radius bounding box before the exact Haversine check
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, MappedCoordinates};
+    /// use std::fs::File;
+    ///
+    /// let path = std::env::temp_dir().join("geolocation_utils_radius_example.bin");
+    /// let coordinates = vec![Coordinate::new(1.0, 1.0), Coordinate::new(0.1, 0.1)];
+    /// MappedCoordinates::write(File::create(&path).unwrap(), &coordinates).unwrap();
+    ///
+    /// let dataset = MappedCoordinates::open(&path).unwrap();
+    /// let found: Vec<_> = dataset
+    ///     .iter_in_radius(Coordinate::new(0.0, 0.0), 50.0, DistanceUnit::Kilometers)
+    ///     .collect();
+    ///
+    /// assert_eq!(vec![(1, Coordinate::new(0.1, 0.1))], found);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn iter_in_radius(
+        &self,
+        origin: impl Into<Coordinate>,
+        radius: f64,
+        unit: DistanceUnit,
+    ) -> impl Iterator<Item = (usize, Coordinate)> + '_ {
+        let origin = origin.into();
+        let bounds = CoordinateBoundaries::new(origin, radius, Some(unit));
+        self.iter().enumerate().filter(move |(_, coordinate)| {
+            bounds
+                .as_ref()
+                .is_none_or(|bounds| bounds.contains(coordinate))
+                && coordinate.get_distance_from(origin, &unit) <= radius
+        })
+    }
+}
+
+fn read_record(record: &[u8]) -> Coordinate {
+    let (latitude, longitude) = record.split_at(8);
+    Coordinate {
+        latitude: f64::from_le_bytes(latitude.try_into().unwrap()),
+        longitude: f64::from_le_bytes(longitude.try_into().unwrap()),
+    }
+}
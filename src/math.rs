@@ -0,0 +1,84 @@
+//! Transcendental functions used by the crate's calculations.
+//!
+//! The standard library's `f64` methods call the platform's math library, whose results may
+//! differ in the last bit between Linux, macOS, Windows and WASM. With the `deterministic`
+//! feature every function here uses the pure Rust `libm` port instead, so the same inputs give
+//! bit-identical results everywhere. Basic arithmetic and `sqrt` are correctly rounded by IEEE
+//! 754 and need no replacement.
+
+macro_rules! unary {
+    ($($name:ident => $libm:ident),* $(,)?) => {
+        $(
+            #[inline]
+            pub(crate) fn $name(x: f64) -> f64 {
+                #[cfg(feature = "deterministic")]
+                return libm::$libm(x);
+                #[cfg(not(feature = "deterministic"))]
+                return x.$name();
+            }
+        )*
+    };
+}
+
+macro_rules! binary {
+    ($($name:ident => $libm:ident),* $(,)?) => {
+        $(
+            #[inline]
+            pub(crate) fn $name(x: f64, y: f64) -> f64 {
+                #[cfg(feature = "deterministic")]
+                return libm::$libm(x, y);
+                #[cfg(not(feature = "deterministic"))]
+                return x.$name(y);
+            }
+        )*
+    };
+}
+
+unary! {
+    sin => sin,
+    cos => cos,
+    tan => tan,
+    asin => asin,
+    acos => acos,
+    atan => atan,
+    cbrt => cbrt,
+    sinh => sinh,
+    asinh => asinh,
+}
+
+binary! {
+    atan2 => atan2,
+    hypot => hypot,
+}
+
+/// # Summary
+/// Sine and cosine of `x`, in that order
+#[inline]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    #[cfg(feature = "deterministic")]
+    return libm::sincos(x);
+    #[cfg(not(feature = "deterministic"))]
+    return x.sin_cos();
+}
+
+#[cfg(all(test, feature = "deterministic"))]
+mod tests {
+    use crate::{Coordinate, DistanceUnit};
+
+    #[test]
+    fn test_distance_bits_are_pinned() {
+        let new_york = Coordinate::new(40.7128, -74.006);
+        let london = Coordinate::new(51.5074, -0.1278);
+
+        let result = new_york.inverse(london, &DistanceUnit::Meters);
+
+        assert_eq!(0x41553fad77e9fd0c, result.distance.to_bits());
+        assert_eq!(0x40499b37073140ce, result.initial_bearing.to_bits());
+        assert_eq!(
+            result.distance.to_bits(),
+            new_york
+                .get_distance_from(london, &DistanceUnit::Meters)
+                .to_bits()
+        );
+    }
+}
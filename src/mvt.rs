@@ -0,0 +1,520 @@
+use crate::codec::{write_varint, zigzag_encode};
+use crate::tile::world_position;
+use crate::{Coordinate, Geometry, Polygon, Tile};
+use std::collections::HashMap;
+
+/// Extent of the tile coordinate space recommended by the vector tile specification
+const DEFAULT_EXTENT: u32 = 4096;
+/// Vector tile specification version written to every layer
+const VERSION: u64 = 2;
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+const POINT: u64 = 1;
+const LINESTRING: u64 = 2;
+const POLYGON: u64 = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A feature property value
+pub enum MvtValue {
+    String(String),
+    Double(f64),
+    Int(i64),
+    Bool(bool),
+}
+
+impl From<&str> for MvtValue {
+    fn from(value: &str) -> Self {
+        MvtValue::String(value.to_string())
+    }
+}
+
+impl From<String> for MvtValue {
+    fn from(value: String) -> Self {
+        MvtValue::String(value)
+    }
+}
+
+impl From<f64> for MvtValue {
+    fn from(value: f64) -> Self {
+        MvtValue::Double(value)
+    }
+}
+
+impl From<i64> for MvtValue {
+    fn from(value: i64) -> Self {
+        MvtValue::Int(value)
+    }
+}
+
+impl From<bool> for MvtValue {
+    fn from(value: bool) -> Self {
+        MvtValue::Bool(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A geometry with an optional id and properties, to be encoded in a vector tile layer
+pub struct MvtFeature {
+    pub id: Option<u64>,
+    pub geometry: Geometry,
+    pub properties: Vec<(String, MvtValue)>,
+}
+
+impl MvtFeature {
+    /// # Summary
+    /// Construct a new MvtFeature without id or properties
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MvtFeature};
+    ///
+    /// let feature = MvtFeature::new(Coordinate::new(52.52, 13.405)).with_property("name", "Berlin");
+    /// assert_eq!(1, feature.properties.len());
+    /// ```
+    pub fn new(geometry: impl Into<Geometry>) -> Self {
+        Self {
+            id: None,
+            geometry: geometry.into(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// # Summary
+    /// Set the feature id
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MvtFeature};
+    ///
+    /// let feature = MvtFeature::new(Coordinate::new(52.52, 13.405)).with_id(7);
+    /// assert_eq!(Some(7), feature.id);
+    /// ```
+    pub fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// # Summary
+    /// Add a property to the feature
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MvtFeature, MvtValue};
+    ///
+    /// let feature = MvtFeature::new(Coordinate::new(52.52, 13.405)).with_property("population", 3_878_100i64);
+    /// assert_eq!(("population".to_string(), MvtValue::Int(3_878_100)), feature.properties[0]);
+    /// ```
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<MvtValue>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A named layer of features, encoded with `encode_tile`
+pub struct MvtLayer {
+    pub name: String,
+    /// Size of the tile coordinate space, 4096 by default
+    pub extent: u32,
+    pub features: Vec<MvtFeature>,
+}
+
+impl MvtLayer {
+    /// # Summary
+    /// Construct a new empty MvtLayer with the default extent of 4096
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::MvtLayer;
+    ///
+    /// let layer = MvtLayer::new("places");
+    /// assert_eq!(4096, layer.extent);
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            extent: DEFAULT_EXTENT,
+            features: Vec::new(),
+        }
+    }
+
+    /// # Summary
+    /// Add a feature to the layer
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, MvtFeature, MvtLayer};
+    ///
+    /// let mut layer = MvtLayer::new("places");
+    /// layer.push(MvtFeature::new(Coordinate::new(52.52, 13.405)));
+    ///
+    /// assert_eq!(1, layer.features.len());
+    /// ```
+    pub fn push(&mut self, feature: MvtFeature) {
+        self.features.push(feature);
+    }
+
+    /// # Summary
+    /// Encode the layer as a protobuf `Layer` message for `tile`
+    fn encode(&self, tile: &Tile) -> Vec<u8> {
+        let mut keys: Vec<&str> = Vec::new();
+        let mut key_indices: HashMap<&str, u32> = HashMap::new();
+        let mut values: Vec<Vec<u8>> = Vec::new();
+        let mut value_indices: HashMap<Vec<u8>, u32> = HashMap::new();
+
+        let mut buffer = Vec::new();
+        write_varint_field(&mut buffer, 15, VERSION);
+        write_bytes_field(&mut buffer, 1, self.name.as_bytes());
+
+        for feature in &self.features {
+            let Some((geometry_type, commands)) =
+                encode_geometry(&feature.geometry, tile, self.extent)
+            else {
+                continue;
+            };
+
+            let mut tags = Vec::with_capacity(feature.properties.len() * 2);
+            for (key, value) in &feature.properties {
+                let key_index = *key_indices.entry(key).or_insert_with(|| {
+                    keys.push(key);
+                    keys.len() as u32 - 1
+                });
+                let encoded = encode_value(value);
+                let value_index = *value_indices.entry(encoded.clone()).or_insert_with(|| {
+                    values.push(encoded);
+                    values.len() as u32 - 1
+                });
+                tags.extend([key_index, value_index]);
+            }
+
+            let mut message = Vec::new();
+            if let Some(id) = feature.id {
+                write_varint_field(&mut message, 1, id);
+            }
+            if !tags.is_empty() {
+                write_packed_field(&mut message, 2, &tags);
+            }
+            write_varint_field(&mut message, 3, geometry_type);
+            write_packed_field(&mut message, 4, &commands);
+            write_bytes_field(&mut buffer, 2, &message);
+        }
+
+        for key in keys {
+            write_bytes_field(&mut buffer, 3, key.as_bytes());
+        }
+        for value in values {
+            write_bytes_field(&mut buffer, 4, &value);
+        }
+        write_varint_field(&mut buffer, 5, self.extent as u64);
+
+        buffer
+    }
+}
+
+/// # Summary
+/// Encode layers of features as a Mapbox Vector Tile (protobuf, specification version 2.1) for
+/// `tile`
+///
+/// ## Notes
+/// - Coordinates are projected to Web Mercator and snapped to the layer's extent grid,
+///   consecutive points collapsing onto the same grid cell are merged
+/// - Geometries are not clipped to the tile, only pass features overlapping it (for example by
+///   filtering against `Tile::bounds`) and let the renderer clip. Features with steps between
+///   points too large for the 32 bit command parameters, which only happens for huge
+///   geometries at deep zoom levels, are dropped rather than written as corrupt geometry
+/// - Polygon rings are rewound as the specification requires, exterior rings clockwise and
+///   holes counterclockwise in tile coordinates
+/// - Lines with fewer than 2 points and rings with fewer than 3 are dropped, as are features left
+///   without any geometry
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{encode_tile, Coordinate, MvtFeature, MvtLayer, Tile};
+///
+/// let berlin = Coordinate::new(52.52, 13.405);
+/// let tile = Tile::for_coordinate(&berlin, 10);
+///
+/// let mut layer = MvtLayer::new("places");
+/// layer.push(MvtFeature::new(berlin).with_id(1).with_property("name", "Berlin"));
+///
+/// let encoded = encode_tile(&[layer], &tile);
+///
+/// // A single `layers` field holding the layer message
+/// assert_eq!(0x1a, encoded[0]);
+/// assert_eq!(encoded.len() - 2, encoded[1] as usize);
+/// ```
+pub fn encode_tile(layers: &[MvtLayer], tile: &Tile) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    for layer in layers {
+        write_bytes_field(&mut buffer, 3, &layer.encode(tile));
+    }
+    buffer
+}
+
+/// # Summary
+/// Encode a geometry as its feature type and command stream, or `None` if nothing is left of it
+/// on the extent grid
+fn encode_geometry(geometry: &Geometry, tile: &Tile, extent: u32) -> Option<(u64, Vec<u32>)> {
+    let project = |points: &[Coordinate]| -> Vec<(i64, i64)> {
+        let mut projected: Vec<(i64, i64)> = points
+            .iter()
+            .map(|coordinate| {
+                let (x, y) = world_position(coordinate, tile.z);
+                (
+                    ((x - tile.x as f64) * extent as f64).round() as i64,
+                    ((y - tile.y as f64) * extent as f64).round() as i64,
+                )
+            })
+            .collect();
+        projected.dedup();
+        projected
+    };
+
+    let mut commands = CommandWriter::default();
+    let geometry_type = match geometry {
+        Geometry::Point(point) => {
+            commands.points(&project(std::slice::from_ref(point)));
+            POINT
+        }
+        Geometry::MultiPoint(points) => {
+            commands.points(&project(points));
+            POINT
+        }
+        Geometry::LineString(route) => {
+            commands.line(&project(&route.points));
+            LINESTRING
+        }
+        Geometry::MultiLineString(routes) => {
+            for route in routes {
+                commands.line(&project(&route.points));
+            }
+            LINESTRING
+        }
+        Geometry::Polygon(polygon) => {
+            commands.polygon(polygon, &project);
+            POLYGON
+        }
+        Geometry::MultiPolygon(polygons) => {
+            for polygon in polygons {
+                commands.polygon(polygon, &project);
+            }
+            POLYGON
+        }
+    };
+
+    (!commands.commands.is_empty() && !commands.overflowed)
+        .then_some((geometry_type, commands.commands))
+}
+
+#[derive(Default)]
+/// ## Summary
+/// Builds a geometry command stream, tracking the cursor so parameters are written as deltas
+struct CommandWriter {
+    commands: Vec<u32>,
+    cursor: (i64, i64),
+    /// Set once a delta did not fit the 32 bit zigzag encoded parameters
+    overflowed: bool,
+}
+
+impl CommandWriter {
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push((id & 0x7) | ((count as u32) << 3));
+    }
+
+    fn move_cursor(&mut self, point: (i64, i64)) {
+        for delta in [point.0 - self.cursor.0, point.1 - self.cursor.1] {
+            match i32::try_from(delta) {
+                Ok(delta) => self.commands.push(zigzag_encode(delta as i64) as u32),
+                Err(_) => self.overflowed = true,
+            }
+        }
+        self.cursor = point;
+    }
+
+    fn points(&mut self, points: &[(i64, i64)]) {
+        if points.is_empty() {
+            return;
+        }
+        self.command(MOVE_TO, points.len());
+        points.iter().for_each(|&point| self.move_cursor(point));
+    }
+
+    fn line(&mut self, points: &[(i64, i64)]) {
+        if points.len() < 2 {
+            return;
+        }
+        self.command(MOVE_TO, 1);
+        self.move_cursor(points[0]);
+        self.command(LINE_TO, points.len() - 1);
+        points[1..]
+            .iter()
+            .for_each(|&point| self.move_cursor(point));
+    }
+
+    fn polygon(&mut self, polygon: &Polygon, project: &impl Fn(&[Coordinate]) -> Vec<(i64, i64)>) {
+        let exterior = project(&polygon.exterior);
+        if !self.ring(exterior, true) {
+            return;
+        }
+        for interior in &polygon.interiors {
+            self.ring(project(interior), false);
+        }
+    }
+
+    /// # Summary
+    /// Write a ring wound as the specification requires, returning `false` if it was dropped
+    fn ring(&mut self, mut ring: Vec<(i64, i64)>, exterior: bool) -> bool {
+        if ring.len() > 1 && ring.first() == ring.last() {
+            ring.pop();
+        }
+        if ring.len() < 3 {
+            return false;
+        }
+        // Twice the surveyor's formula area, positive for clockwise rings as y grows downward
+        let area: i128 = ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 as i128 * b.1 as i128 - b.0 as i128 * a.1 as i128)
+            .sum();
+        if area == 0 {
+            return false;
+        }
+        if (area > 0) != exterior {
+            ring.reverse();
+        }
+
+        self.line(&ring);
+        self.command(CLOSE_PATH, 1);
+        true
+    }
+}
+
+fn encode_value(value: &MvtValue) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    match value {
+        MvtValue::String(value) => write_bytes_field(&mut buffer, 1, value.as_bytes()),
+        MvtValue::Double(value) => {
+            write_varint(&mut buffer, (3 << 3) | 1);
+            buffer.extend(value.to_le_bytes());
+        }
+        MvtValue::Int(value) => write_varint_field(&mut buffer, 6, zigzag_encode(*value)),
+        MvtValue::Bool(value) => write_varint_field(&mut buffer, 7, *value as u64),
+    }
+    buffer
+}
+
+fn write_varint_field(buffer: &mut Vec<u8>, field: u64, value: u64) {
+    write_varint(buffer, field << 3);
+    write_varint(buffer, value);
+}
+
+fn write_bytes_field(buffer: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    write_varint(buffer, (field << 3) | 2);
+    write_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+fn write_packed_field(buffer: &mut Vec<u8>, field: u64, values: &[u32]) {
+    let mut packed = Vec::with_capacity(values.len());
+    for &value in values {
+        write_varint(&mut packed, value as u64);
+    }
+    write_bytes_field(buffer, field, &packed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Route;
+
+    #[test]
+    fn test_polygon_commands_follow_specification() {
+        let tile = Tile::new(0, 0, 0);
+        // Counterclockwise, so its area is negative in tile coordinates and it must be rewound
+        let polygon = Polygon::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 90.0),
+            Coordinate::new(45.0, 90.0),
+            Coordinate::new(45.0, 0.0),
+            Coordinate::new(0.0, 0.0),
+        ]);
+
+        let (geometry_type, commands) =
+            encode_geometry(&Geometry::Polygon(polygon), &tile, 4096).unwrap();
+
+        let top = (world_position(&Coordinate::new(45.0, 0.0), 0).1 * 4096.0).round() as u32;
+        assert_eq!(POLYGON, geometry_type);
+        // MoveTo(2048, top), LineTo(+1024, 0), (0, +(2048 - top)), (-1024, 0), ClosePath
+        assert_eq!(
+            vec![
+                9,
+                4096,
+                top * 2,
+                26,
+                2048,
+                0,
+                0,
+                (2048 - top) * 2,
+                2047,
+                0,
+                15
+            ],
+            commands
+        );
+    }
+
+    #[test]
+    fn test_oversized_geometries_are_dropped() {
+        // Half way around the world is ~2^31 extent units at zoom 20 and ~2^32 at zoom 21
+        let tile = Tile::for_coordinate(&Coordinate::new(0.0, 0.0), 21);
+        let route = Route::new(vec![
+            Coordinate::new(0.0, -179.0),
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 179.0),
+        ]);
+        let polygon = Polygon::new(vec![
+            Coordinate::new(-60.0, -170.0),
+            Coordinate::new(60.0, -170.0),
+            Coordinate::new(60.0, 170.0),
+            Coordinate::new(-60.0, 170.0),
+        ]);
+
+        assert_eq!(
+            None,
+            encode_geometry(&Geometry::LineString(route.clone()), &tile, 4096)
+        );
+        assert_eq!(
+            None,
+            encode_geometry(&Geometry::Polygon(polygon), &tile, 4096)
+        );
+        let shallower = Tile::for_coordinate(&Coordinate::new(0.0, 0.0), 19);
+        assert!(encode_geometry(&Geometry::LineString(route), &shallower, 4096).is_some());
+    }
+
+    #[test]
+    fn test_layer_deduplicates_keys_and_values() {
+        let mut layer = MvtLayer::new("places");
+        for name in ["a", "b", "a"] {
+            layer.push(MvtFeature::new(Coordinate::new(1.0, 1.0)).with_property("name", name));
+        }
+
+        let encoded = layer.encode(&Tile::new(0, 0, 0));
+
+        let count = |pattern: &[u8]| {
+            encoded
+                .windows(pattern.len())
+                .filter(|w| *w == pattern)
+                .count()
+        };
+        // Key "name" once, values "a" and "b" once each
+        assert_eq!(1, count(&[0x1a, 4, b'n', b'a', b'm', b'e']));
+        assert_eq!(1, count(&[0x22, 3, 0x0a, 1, b'a']));
+        assert_eq!(1, count(&[0x22, 3, 0x0a, 1, b'b']));
+    }
+}
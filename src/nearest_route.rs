@@ -0,0 +1,320 @@
+use crate::spherical::{
+    angle_between, angle_to_distance, cross, dot, from_unit_vector, normalize, to_unit_vector,
+};
+use crate::{Coordinate, DistanceUnit, Route};
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// A set of routes prepared for repeated nearest route searches, such as matching a stream of
+/// vehicle positions to the road network
+///
+/// ## Notes
+/// - Every route is bounded by a spherical cap, the equivalent of a bounding box which also holds
+///   across the antimeridian and the poles, computed once when the set is built. Routes are
+///   searched nearest cap first and the search stops once no remaining cap can hold a closer route,
+///   so large sets of mostly distant routes are cheap to search
+pub struct RouteSet {
+    routes: Vec<PreparedRoute>,
+}
+
+#[derive(Debug, Clone)]
+struct PreparedRoute {
+    index: usize,
+    center: [f64; 3],
+    radius: f64,
+    vertices: Vec<[f64; 3]>,
+}
+
+impl RouteSet {
+    /// # Summary
+    /// Prepare routes for repeated nearest route searches. Empty routes are skipped but keep their
+    /// index
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Route, RouteSet};
+    ///
+    /// let roads = RouteSet::new(&[
+    ///     Route::new(vec![]),
+    ///     Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)]),
+    /// ]);
+    ///
+    /// let (index, _, _) = roads.nearest(&Coordinate::new(0.1, 0.5), &DistanceUnit::Kilometers).unwrap();
+    /// assert_eq!(1, index);
+    /// ```
+    pub fn new(routes: &[Route]) -> Self {
+        let routes = routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route)| !route.points.is_empty())
+            .map(|(index, route)| {
+                let vertices: Vec<[f64; 3]> = route.points.iter().map(to_unit_vector).collect();
+                let (center, radius) = bounding_cap(&vertices);
+                PreparedRoute {
+                    index,
+                    center,
+                    radius,
+                    vertices,
+                }
+            })
+            .collect();
+
+        Self { routes }
+    }
+
+    /// # Summary
+    /// Find the route passing closest to `point`, returning the index of the route, the great
+    /// circle distance to it and the nearest point on it. Returns `None` if every route is empty
+    ///
+    /// ## Notes
+    /// - Ties go to the route with the lower index
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Route, RouteSet};
+    ///
+    /// let roads = RouteSet::new(&[
+    ///     Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)]),
+    ///     Route::new(vec![Coordinate::new(1.0, 0.0), Coordinate::new(1.0, 1.0)]),
+    /// ]);
+    ///
+    /// for (latitude, expected) in [(0.2, 0), (0.8, 1)] {
+    ///     let (index, _, _) = roads.nearest(&Coordinate::new(latitude, 0.5), &DistanceUnit::Kilometers).unwrap();
+    ///     assert_eq!(expected, index);
+    /// }
+    /// ```
+    pub fn nearest(
+        &self,
+        point: &Coordinate,
+        unit: &DistanceUnit,
+    ) -> Option<(usize, f64, Coordinate)> {
+        let target = to_unit_vector(point);
+
+        let mut candidates: Vec<(f64, &PreparedRoute)> = self
+            .routes
+            .iter()
+            .map(|route| (angle_between(target, route.center) - route.radius, route))
+            .collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.index.cmp(&b.1.index)));
+
+        let mut best: Option<(usize, f64, [f64; 3])> = None;
+        for (lower_bound, route) in candidates {
+            if best.is_some_and(|(_, angle, _)| lower_bound > angle) {
+                break;
+            }
+            let Some((angle, nearest)) =
+                nearest_on_route(target, &route.vertices, best.map(|best| best.1))
+            else {
+                continue;
+            };
+            let closer = match best {
+                Some((best_index, best_angle, _)) => {
+                    angle < best_angle || (angle == best_angle && route.index < best_index)
+                }
+                None => true,
+            };
+            if closer {
+                best = Some((route.index, angle, nearest));
+            }
+        }
+
+        best.map(|(index, angle, nearest)| {
+            (
+                index,
+                angle_to_distance(angle, unit),
+                from_unit_vector(nearest),
+            )
+        })
+    }
+}
+
+/// # Summary
+/// Find the route passing closest to `point`, such as the road a vehicle is most likely on,
+/// returning the index of the route, the great circle distance to it and the nearest point on it.
+/// Returns `None` if every route is empty
+///
+/// ## Notes
+/// - Prepares the routes on every call, build a `RouteSet` once when searching the same routes
+///   repeatedly
+/// - Ties go to the route with the lower index
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{nearest_route, Coordinate, DistanceUnit, Route};
+///
+/// let roads = vec![
+///     Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)]),
+///     Route::new(vec![Coordinate::new(1.0, 0.0), Coordinate::new(1.0, 1.0)]),
+/// ];
+///
+/// let (index, distance, nearest) = nearest_route(&Coordinate::new(0.8, 0.5), &roads, &DistanceUnit::Kilometers).unwrap();
+///
+/// assert_eq!(1, index);
+/// assert_eq!(22.2, (distance * 10.0).round() / 10.0);
+/// assert_eq!(0.5, (nearest.longitude * 1e6).round() / 1e6);
+/// assert!(nearest_route(&Coordinate::new(0.0, 0.0), &[], &DistanceUnit::Kilometers).is_none());
+/// ```
+pub fn nearest_route(
+    point: &Coordinate,
+    routes: &[Route],
+    unit: &DistanceUnit,
+) -> Option<(usize, f64, Coordinate)> {
+    RouteSet::new(routes).nearest(point, unit)
+}
+
+/// # Summary
+/// Get a cap, as a center and an angular radius, containing every vertex and leg of a route
+fn bounding_cap(vertices: &[[f64; 3]]) -> ([f64; 3], f64) {
+    let sum = vertices.iter().fold([0.0; 3], |sum, vertex| {
+        [sum[0] + vertex[0], sum[1] + vertex[1], sum[2] + vertex[2]]
+    });
+    let center = if dot(sum, sum) > 1e-24 {
+        normalize(sum)
+    } else {
+        vertices[0]
+    };
+
+    // Every point of a leg is within half its length of one of its ends
+    let farthest_vertex = vertices
+        .iter()
+        .map(|vertex| angle_between(center, *vertex))
+        .fold(0.0, f64::max);
+    let longest_leg = vertices
+        .windows(2)
+        .map(|leg| angle_between(leg[0], leg[1]))
+        .fold(0.0, f64::max);
+
+    (center, farthest_vertex + longest_leg / 2.0)
+}
+
+/// # Summary
+/// Get the angle to and the nearest point of a route from `target`, skipping legs which cannot be
+/// closer than `limit`. Returns `None` if no leg is closer than `limit`
+fn nearest_on_route(
+    target: [f64; 3],
+    vertices: &[[f64; 3]],
+    limit: Option<f64>,
+) -> Option<(f64, [f64; 3])> {
+    let mut best: Option<(f64, [f64; 3])> = None;
+    if vertices.len() == 1 {
+        best = Some((angle_between(target, vertices[0]), vertices[0]));
+    }
+    for leg in vertices.windows(2) {
+        let bound = best.map(|best| best.0).or(limit);
+        let closest_end = angle_between(target, leg[0]).min(angle_between(target, leg[1]));
+        if bound.is_some_and(|bound| closest_end - angle_between(leg[0], leg[1]) / 2.0 > bound) {
+            continue;
+        }
+
+        let candidate = nearest_on_leg(target, leg[0], leg[1]);
+        if best.is_none_or(|best| candidate.0 < best.0) {
+            best = Some(candidate);
+        }
+    }
+
+    best.filter(|best| limit.is_none_or(|limit| best.0 <= limit))
+}
+
+/// # Summary
+/// Get the angle to and the nearest point of the great circle arc from `a` to `b`
+fn nearest_on_leg(target: [f64; 3], a: [f64; 3], b: [f64; 3]) -> (f64, [f64; 3]) {
+    let normal = cross(a, b);
+    if dot(normal, normal) > 1e-30 {
+        let normal = normalize(normal);
+        let along = dot(target, normal);
+        let projected = [
+            target[0] - along * normal[0],
+            target[1] - along * normal[1],
+            target[2] - along * normal[2],
+        ];
+        if dot(projected, projected) > 1e-30 {
+            let projected = normalize(projected);
+            // The projection only counts if it lies between the two ends of the arc
+            if dot(cross(a, projected), normal) >= 0.0 && dot(cross(projected, b), normal) >= 0.0 {
+                return (angle_between(target, projected), projected);
+            }
+        }
+    }
+
+    let (to_a, to_b) = (angle_between(target, a), angle_between(target, b));
+    if to_a <= to_b {
+        (to_a, a)
+    } else {
+        (to_b, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spherical::interpolate;
+    use crate::utils::{mix64, random};
+
+    #[test]
+    fn test_matches_brute_force() {
+        let routes: Vec<Route> = (0..300u64)
+            .map(|route| {
+                let start = Coordinate::new(random(route * 7, 170.0), random(route * 7 + 1, 360.0));
+                let length = mix64(route * 7 + 2) % 6;
+                Route::new(
+                    (0..length)
+                        .map(|step| {
+                            let seed = route * 1000 + step * 2;
+                            Coordinate::new(
+                                start.latitude + random(seed, 10.0),
+                                start.longitude + random(seed + 1, 10.0),
+                            )
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        for seed in 0..200u64 {
+            let point = Coordinate::new(
+                random(seed * 2 + 10_000, 180.0),
+                random(seed * 2 + 10_001, 360.0),
+            );
+            let (index, distance, nearest) =
+                nearest_route(&point, &routes, &DistanceUnit::Kilometers).unwrap();
+
+            // Brute force over finely sampled legs can only be slightly further away
+            let sampled = routes
+                .iter()
+                .flat_map(|route| {
+                    let single = (route.points.len() == 1).then(|| route.points[0]);
+                    route
+                        .points
+                        .windows(2)
+                        .flat_map(|leg| {
+                            (0..=200)
+                                .map(move |step| interpolate(&leg[0], &leg[1], step as f64 / 200.0))
+                        })
+                        .chain(single)
+                        .collect::<Vec<_>>()
+                })
+                .map(|coordinate| point.get_distance_from(coordinate, &DistanceUnit::Kilometers))
+                .fold(f64::INFINITY, f64::min);
+
+            assert!(
+                distance <= sampled + 1e-6,
+                "{} {} {}",
+                seed,
+                distance,
+                sampled
+            );
+            assert!(
+                sampled - distance < 5.0,
+                "{} {} {}",
+                seed,
+                distance,
+                sampled
+            );
+            assert!(
+                (point.get_distance_from(nearest, &DistanceUnit::Kilometers) - distance).abs()
+                    < 1e-6
+            );
+            assert!(!routes[index].points.is_empty());
+        }
+    }
+}
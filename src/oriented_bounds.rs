@@ -0,0 +1,115 @@
+use crate::math;
+use crate::utils::linear_divisor;
+use crate::{Coordinate, DistanceUnit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Struct representing a rectangle which is not necessarily aligned to north, such as a runway,
+/// a dock or a rotated geofence
+///
+/// ## Notes
+/// - `height` is measured along `bearing` (degrees clockwise from north), `width` across it
+/// - The rectangle lives on the local tangent plane of `center`, suited to regions up to tens of kilometers
+pub struct OrientedBounds {
+    pub center: Coordinate,
+    pub width: f64,
+    pub height: f64,
+    pub bearing: f64,
+    pub unit: DistanceUnit,
+}
+
+impl OrientedBounds {
+    /// # Summary
+    /// Construct a new OrientedBounds
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, OrientedBounds};
+    ///
+    /// // A 3 km x 60 m runway heading 045
+    /// let runway = OrientedBounds::new(Coordinate::new(40.0, -73.0), 60.0, 3000.0, 45.0, DistanceUnit::Meters);
+    /// assert_eq!(45.0, runway.bearing);
+    /// ```
+    pub fn new(
+        center: impl Into<Coordinate>,
+        width: f64,
+        height: f64,
+        bearing: f64,
+        unit: DistanceUnit,
+    ) -> Self {
+        Self {
+            center: center.into(),
+            width,
+            height,
+            bearing,
+            unit,
+        }
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the rectangle, edges included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, OrientedBounds};
+    ///
+    /// let runway = OrientedBounds::new(Coordinate::new(0.0, 0.0), 60.0, 3000.0, 45.0, DistanceUnit::Meters);
+    ///
+    /// // 1 km north-east along the centerline
+    /// assert!(runway.contains(&Coordinate::new(0.00636, 0.00636)));
+    /// // 1 km north, well off the centerline
+    /// assert!(!runway.contains(&Coordinate::new(0.009, 0.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        let (along, across) = self.rectangle_frame_position(coordinate);
+        let meters = linear_divisor(&self.unit);
+
+        along.abs() <= self.height * meters / 2.0 && across.abs() <= self.width * meters / 2.0
+    }
+
+    /// # Summary
+    /// Get the 4 corners of the rectangle, clockwise starting from the front left corner
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, OrientedBounds};
+    ///
+    /// let bounds = OrientedBounds::new(Coordinate::new(0.0, 0.0), 2.0, 2.0, 0.0, DistanceUnit::Kilometers);
+    /// let [front_left, front_right, _, _] = bounds.corners();
+    ///
+    /// assert!(front_left.longitude < 0.0 && front_left.latitude > 0.0);
+    /// assert!(front_right.longitude > 0.0 && front_right.latitude > 0.0);
+    /// ```
+    pub fn corners(&self) -> [Coordinate; 4] {
+        let meters = linear_divisor(&self.unit);
+        let half_height = self.height * meters / 2.0;
+        let half_width = self.width * meters / 2.0;
+        let (sin, cos) = math::sin_cos(self.bearing.to_radians());
+
+        [
+            (half_height, -half_width),
+            (half_height, half_width),
+            (-half_height, half_width),
+            (-half_height, -half_width),
+        ]
+        .map(|(along, across)| {
+            let east = along * sin + across * cos;
+            let north = along * cos - across * sin;
+            Coordinate::from_local_tangent(&self.center, [east, north, 0.0])
+        })
+    }
+
+    /// # Summary
+    /// Get the `(along, across)` position of a coordinate in meters relative to the center,
+    /// `along` pointing towards `bearing` and `across` to its right
+    fn rectangle_frame_position(&self, coordinate: &Coordinate) -> (f64, f64) {
+        let [east, north, _] = coordinate.to_local_tangent(&self.center);
+        let (sin, cos) = math::sin_cos(self.bearing.to_radians());
+
+        (north * cos + east * sin, east * cos - north * sin)
+    }
+}
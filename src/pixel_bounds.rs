@@ -0,0 +1,184 @@
+use crate::math;
+use crate::tile::{world_position, MAX_ZOOM};
+use crate::{Coordinate, CoordinateBoundaries};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Width and height of a Web Mercator tile in pixels
+const TILE_SIZE: f64 = 256.0;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+/// ## Summary
+/// A rectangle in Web Mercator world pixel coordinates at some zoom level, using 256 pixel tiles
+/// with `x` growing eastward and `y` growing southward from the north-west corner of the map
+///
+/// ## Notes
+/// - The world is `256 * 2^zoom` pixels wide, `x` reaches past it for bounds crossing the
+///   antimeridian
+pub struct PixelBounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl PixelBounds {
+    /// # Summary
+    /// Construct new PixelBounds
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::PixelBounds;
+    ///
+    /// let bounds = PixelBounds::new(10.0, 20.0, 110.0, 40.0);
+    /// assert_eq!(100.0, bounds.width());
+    /// ```
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    /// # Summary
+    /// Get the width of the bounds in pixels
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::PixelBounds;
+    ///
+    /// assert_eq!(5.0, PixelBounds::new(0.0, 0.0, 5.0, 2.0).width());
+    /// ```
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    /// # Summary
+    /// Get the height of the bounds in pixels
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::PixelBounds;
+    ///
+    /// assert_eq!(2.0, PixelBounds::new(0.0, 0.0, 5.0, 2.0).height());
+    /// ```
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    /// # Summary
+    /// Checks if two rectangles overlap, such as two labels placed on a map. Rectangles only
+    /// touching along an edge do not overlap
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::PixelBounds;
+    ///
+    /// let label = PixelBounds::new(0.0, 0.0, 40.0, 12.0);
+    ///
+    /// assert!(label.intersects(&PixelBounds::new(30.0, 10.0, 70.0, 22.0)));
+    /// assert!(!label.intersects(&PixelBounds::new(40.0, 0.0, 80.0, 12.0)));
+    /// ```
+    pub fn intersects(&self, other: &PixelBounds) -> bool {
+        self.min_x < other.max_x
+            && other.min_x < self.max_x
+            && self.min_y < other.max_y
+            && other.min_y < self.max_y
+    }
+}
+
+impl CoordinateBoundaries {
+    /// # Summary
+    /// Project the bounds into world pixel coordinates at `zoom` (at most 32)
+    ///
+    /// ## Notes
+    /// - Latitudes beyond the Web Mercator limit of ±85.0511° are clamped to the top or bottom
+    ///   edge of the map
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::CoordinateBoundaries;
+    ///
+    /// let bounds = CoordinateBoundaries::from_extents(0.0, 85.0511287798066, 0.0, 180.0).unwrap();
+    /// let pixels = bounds.to_pixel_bounds(1);
+    ///
+    /// assert_eq!((256.0, 512.0), (pixels.min_x, pixels.max_x));
+    /// assert_eq!(256.0, pixels.max_y);
+    /// assert!(pixels.min_y.abs() < 1e-9);
+    /// ```
+    pub fn to_pixel_bounds(&self, zoom: u8) -> PixelBounds {
+        let (min_x, max_y) = world_position(
+            &Coordinate {
+                latitude: self.min_latitude(),
+                longitude: self.min_longitude(),
+            },
+            zoom,
+        );
+        let (max_x, min_y) = world_position(
+            &Coordinate {
+                latitude: self.max_latitude(),
+                longitude: self.max_longitude(),
+            },
+            zoom,
+        );
+
+        PixelBounds::new(
+            min_x * TILE_SIZE,
+            min_y * TILE_SIZE,
+            max_x * TILE_SIZE,
+            max_y * TILE_SIZE,
+        )
+    }
+
+    /// # Summary
+    /// Get the bounds covered by a rectangle of world pixel coordinates at `zoom` (at most 32),
+    /// the inverse of `to_pixel_bounds`. Returns `None` if a minimum is greater than its maximum
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CoordinateBoundaries, PixelBounds};
+    ///
+    /// let bounds = CoordinateBoundaries::from_pixel_bounds(&PixelBounds::new(256.0, 0.0, 512.0, 256.0), 1).unwrap();
+    ///
+    /// assert_eq!((0.0, 180.0), (bounds.min_longitude(), bounds.max_longitude()));
+    /// assert_eq!(0.0, bounds.min_latitude());
+    /// assert_eq!(85.0511, (bounds.max_latitude() * 10000.0).round() / 10000.0);
+    /// ```
+    pub fn from_pixel_bounds(pixels: &PixelBounds, zoom: u8) -> Option<CoordinateBoundaries> {
+        let world = TILE_SIZE * (1u64 << zoom.min(MAX_ZOOM)) as f64;
+        let longitude = |x: f64| x / world * 360.0 - 180.0;
+        let latitude = |y: f64| math::atan(math::sinh(PI * (1.0 - 2.0 * y / world))).to_degrees();
+
+        CoordinateBoundaries::from_extents(
+            latitude(pixels.max_y),
+            latitude(pixels.min_y),
+            longitude(pixels.min_x),
+            longitude(pixels.max_x),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bounds = CoordinateBoundaries::from_extents(-33.9, 52.5, 170.0, 190.0).unwrap();
+
+        for zoom in [0, 5, 18, 32] {
+            let pixels = bounds.to_pixel_bounds(zoom);
+            let back = CoordinateBoundaries::from_pixel_bounds(&pixels, zoom).unwrap();
+
+            assert!((back.min_latitude() - bounds.min_latitude()).abs() < 1e-9);
+            assert!((back.max_latitude() - bounds.max_latitude()).abs() < 1e-9);
+            assert!((back.min_longitude() - bounds.min_longitude()).abs() < 1e-9);
+            assert!((back.max_longitude() - bounds.max_longitude()).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::spherical::{destination, distance_to_angle};
+use crate::{rotate_coordinates, Coordinate, DistanceUnit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Position of a target as seen from a moving platform, see `Platform::relative_position`
+pub struct RelativePosition {
+    /// Degrees clockwise from the platform's heading, between 0 and 360
+    pub bearing: f64,
+    /// Distance to the target, in the requested unit
+    pub range: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// A moving platform such as a ship or aircraft, for converting between true coordinates and
+/// positions relative to its heading, as shown on radar and AIS displays
+pub struct Platform {
+    pub position: Coordinate,
+    /// Degrees clockwise from north the platform is pointing towards
+    pub heading: f64,
+}
+
+impl Platform {
+    /// # Summary
+    /// Construct a new Platform
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Platform};
+    ///
+    /// let ship = Platform::new(Coordinate::new(54.0, 7.0), 45.0);
+    /// assert_eq!(45.0, ship.heading);
+    /// ```
+    pub fn new(position: impl Into<Coordinate>, heading: f64) -> Self {
+        Self {
+            position: position.into(),
+            heading,
+        }
+    }
+
+    /// # Summary
+    /// Get the bearing relative to the platform's heading and the range of a target
+    ///
+    /// ## Notes
+    /// - A relative bearing of 0 is dead ahead, 90 to starboard, 180 astern and 270 to port
+    /// - Uses the same spherical model and Haversine distance as `Coordinate::get_distance_from`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Platform};
+    ///
+    /// let ship = Platform::new(Coordinate::new(0.0, 0.0), 90.0);
+    /// let target = ship.relative_position(&Coordinate::new(0.1, 0.0), &DistanceUnit::NauticalMiles);
+    ///
+    /// assert_eq!(270.0, target.bearing.round());
+    /// assert_eq!(6.0, target.range.round());
+    /// ```
+    pub fn relative_position(&self, target: &Coordinate, unit: &DistanceUnit) -> RelativePosition {
+        let inverse = self.position.inverse(target, unit);
+
+        RelativePosition {
+            bearing: (inverse.initial_bearing - self.heading).rem_euclid(360.0),
+            range: inverse.distance,
+        }
+    }
+
+    /// # Summary
+    /// Get the coordinate of a target at `range` along `bearing` degrees clockwise from the
+    /// platform's heading, the inverse of `relative_position`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Platform};
+    ///
+    /// let ship = Platform::new(Coordinate::new(0.0, 0.0), 90.0);
+    /// let target = ship.target_at(270.0, 6.0, &DistanceUnit::NauticalMiles);
+    ///
+    /// assert_eq!(0.1, (target.latitude * 1000.0).round() / 1000.0);
+    /// assert!(target.longitude.abs() < 1e-12);
+    /// ```
+    pub fn target_at(&self, bearing: f64, range: f64, unit: &DistanceUnit) -> Coordinate {
+        destination(
+            &self.position,
+            self.heading + bearing,
+            distance_to_angle(range, unit),
+        )
+    }
+
+    /// # Summary
+    /// Rotate coordinates for a heading-up display, turning them around the platform so that
+    /// straight ahead points north
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Platform};
+    ///
+    /// let car = Platform::new(Coordinate::new(0.0, 0.0), 90.0);
+    /// let ahead = car.heading_up(&[Coordinate::new(0.0, 0.01)]);
+    ///
+    /// assert!((ahead[0].latitude - 0.01).abs() < 1e-9);
+    /// assert!(ahead[0].longitude.abs() < 1e-9);
+    /// ```
+    pub fn heading_up(&self, points: &[Coordinate]) -> Vec<Coordinate> {
+        rotate_coordinates(points, &self.position, -self.heading)
+    }
+
+    /// # Summary
+    /// Rotate coordinates of a heading-up display back to north-up, the inverse of `heading_up`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Platform};
+    ///
+    /// let car = Platform::new(Coordinate::new(48.137, 11.575), 30.0);
+    /// let tap = Coordinate::new(48.14, 11.58);
+    /// let back = car.north_up(&car.heading_up(&[tap]));
+    ///
+    /// assert!((back[0].latitude - tap.latitude).abs() < 1e-9);
+    /// assert!((back[0].longitude - tap.longitude).abs() < 1e-9);
+    /// ```
+    pub fn north_up(&self, points: &[Coordinate]) -> Vec<Coordinate> {
+        rotate_coordinates(points, &self.position, self.heading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_round_trip() {
+        let unit = DistanceUnit::Kilometers;
+
+        for seed in 0..100u64 {
+            let ship = Platform::new(
+                Coordinate::new(random(seed * 5, 160.0), random(seed * 5 + 1, 360.0)),
+                random(seed * 5 + 2, 720.0),
+            );
+            let bearing = random(seed * 5 + 3, 360.0).rem_euclid(360.0);
+            let range = random(seed * 5 + 4, 100.0).abs() + 0.1;
+
+            let target = ship.target_at(bearing, range, &unit);
+            let relative = ship.relative_position(&target, &unit);
+
+            assert!((relative.range - range).abs() < 1e-6, "range {}", seed);
+            let error = (relative.bearing - bearing + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(error.abs() < 1e-6, "bearing {}", seed);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::spherical::{angle_to_distance, cross, dot, normalize, to_unit_vector};
+use crate::{math, Coordinate, DistanceUnit};
+
+/// # Summary
+/// Find the two points closest to each other, such as two depots suspiciously close together,
+/// returning their indices in ascending order and the great circle distance between them.
+/// Returns `None` for fewer than two points
+///
+/// ## Notes
+/// - Points are swept in order along the axis they are most spread out on, only comparing points
+///   closer along that axis than the closest pair so far
+/// - Ties go to the pair with the lowest indices
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{closest_pair, Coordinate, DistanceUnit};
+///
+/// let depots = vec![
+///     Coordinate::new(51.5, -0.12),
+///     Coordinate::new(48.85, 2.35),
+///     Coordinate::new(51.501, -0.121),
+///     Coordinate::new(52.52, 13.4),
+/// ];
+///
+/// let (first, second, distance) = closest_pair(&depots, &DistanceUnit::Meters).unwrap();
+/// assert_eq!((0, 2), (first, second));
+/// assert_eq!(131.0, distance.round());
+/// assert!(closest_pair(&depots[..1], &DistanceUnit::Meters).is_none());
+/// ```
+pub fn closest_pair(points: &[Coordinate], unit: &DistanceUnit) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let vectors: Vec<[f64; 3]> = points.iter().map(to_unit_vector).collect();
+
+    let spread = |axis: usize| {
+        let (min, max) = vectors
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), vector| {
+                (min.min(vector[axis]), max.max(vector[axis]))
+            });
+        max - min
+    };
+    let axis = (0..3)
+        .max_by(|&a, &b| spread(a).total_cmp(&spread(b)))
+        .unwrap_or(0);
+    let mut order: Vec<usize> = (0..vectors.len()).collect();
+    order.sort_by(|&a, &b| vectors[a][axis].total_cmp(&vectors[b][axis]));
+
+    // Squared chord lengths grow with the great circle distance, so they rank pairs the same
+    let mut best = (f64::INFINITY, usize::MAX, usize::MAX);
+    for (position, &i) in order.iter().enumerate() {
+        for &j in order[..position].iter().rev() {
+            let gap = vectors[i][axis] - vectors[j][axis];
+            if gap * gap > best.0 {
+                break;
+            }
+            let candidate = (chord_squared(vectors[i], vectors[j]), i.min(j), i.max(j));
+            if candidate < best {
+                best = candidate;
+            }
+        }
+    }
+
+    let (chord, first, second) = best;
+    Some((first, second, chord_to_distance(chord, unit)))
+}
+
+/// # Summary
+/// Find the two points farthest from each other, the diameter of the set, returning their indices
+/// in ascending order and the great circle distance between them. Returns `None` for fewer than
+/// two points
+///
+/// ## Notes
+/// - While every pair of points is within a quarter of the Earth's circumference of each other,
+///   the farthest pair is found among the corners of their convex hull. Sets spreading further
+///   compare every pair of points
+/// - Ties go to the pair with the lowest indices
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{farthest_pair, Coordinate, DistanceUnit};
+///
+/// let depots = vec![
+///     Coordinate::new(51.5, -0.12),
+///     Coordinate::new(48.85, 2.35),
+///     Coordinate::new(51.501, -0.121),
+///     Coordinate::new(52.52, 13.4),
+/// ];
+///
+/// let (first, second, distance) = farthest_pair(&depots, &DistanceUnit::Kilometers).unwrap();
+/// assert_eq!((2, 3), (first, second));
+/// assert_eq!(931.0, distance.round());
+/// ```
+pub fn farthest_pair(points: &[Coordinate], unit: &DistanceUnit) -> Option<(usize, usize, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let vectors: Vec<[f64; 3]> = points.iter().map(to_unit_vector).collect();
+    let farthest_among = |indices: &[usize]| {
+        let mut best = (f64::NEG_INFINITY, usize::MAX, usize::MAX);
+        for (position, &i) in indices.iter().enumerate() {
+            for &j in &indices[position + 1..] {
+                let chord = chord_squared(vectors[i], vectors[j]);
+                let (first, second) = (i.min(j), i.max(j));
+                if chord > best.0 || (chord == best.0 && (first, second) < (best.1, best.2)) {
+                    best = (chord, first, second);
+                }
+            }
+        }
+        best
+    };
+
+    // A chord of sqrt(2) is a quarter of a great circle
+    let best = match hull_corners(&vectors) {
+        Some(corners) => Some(farthest_among(&corners)).filter(|best| best.0 <= 2.0),
+        None => None,
+    };
+    let (chord, first, second) =
+        best.unwrap_or_else(|| farthest_among(&(0..vectors.len()).collect::<Vec<_>>()));
+
+    Some((first, second, chord_to_distance(chord, unit)))
+}
+
+/// # Summary
+/// Get the indices of the corners of the spherical convex hull of the points, every point tied
+/// for a corner position included. Returns `None` if the points are not all within an open
+/// hemisphere, where they have no convex hull
+///
+/// ## Notes
+/// - The gnomonic projection around the mean of the points maps great circles to straight
+///   lines, so the hull is the planar convex hull of the projected points
+fn hull_corners(vectors: &[[f64; 3]]) -> Option<Vec<usize>> {
+    let sum = vectors.iter().fold([0.0; 3], |sum, vector| {
+        [sum[0] + vector[0], sum[1] + vector[1], sum[2] + vector[2]]
+    });
+    let center = normalize(sum);
+    if center.iter().any(|component| component.is_nan())
+        || vectors.iter().any(|vector| dot(*vector, center) < 1e-9)
+    {
+        return None;
+    }
+
+    // Any axis not parallel to the center spans the projection plane with it
+    let helper = if center[0].abs() < 0.9 {
+        [1.0, 0.0, 0.0]
+    } else {
+        [0.0, 1.0, 0.0]
+    };
+    let east = normalize(cross(helper, center));
+    let north = cross(center, east);
+    let mut projected: Vec<(f64, f64, usize)> = vectors
+        .iter()
+        .enumerate()
+        .map(|(index, vector)| {
+            let scale = dot(*vector, center);
+            (
+                dot(*vector, east) / scale,
+                dot(*vector, north) / scale,
+                index,
+            )
+        })
+        .collect();
+    projected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    // Monotone chain, keeping points on the hull's edges as ties for the farthest pair
+    let turn = |o: &(f64, f64, usize), a: &(f64, f64, usize), b: &(f64, f64, usize)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+    let chain = |points: &mut dyn Iterator<Item = &(f64, f64, usize)>| {
+        let mut hull: Vec<(f64, f64, usize)> = Vec::new();
+        for point in points {
+            while hull.len() >= 2 && turn(&hull[hull.len() - 2], &hull[hull.len() - 1], point) < 0.0
+            {
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+        hull
+    };
+    let lower = chain(&mut projected.iter());
+    let upper = chain(&mut projected.iter().rev());
+
+    let mut corners: Vec<usize> = lower.iter().chain(&upper).map(|point| point.2).collect();
+    corners.sort_unstable();
+    corners.dedup();
+    Some(corners)
+}
+
+/// # Summary
+/// Squared straight line distance between two unit vectors
+fn chord_squared(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    dot(d, d)
+}
+
+/// # Summary
+/// Convert a squared chord between unit vectors into the great circle distance
+fn chord_to_distance(chord_squared: f64, unit: &DistanceUnit) -> f64 {
+    let angle = 2.0 * math::asin((chord_squared.sqrt() / 2.0).min(1.0));
+    angle_to_distance(angle, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{mix64, random};
+
+    /// Compare every pair, keeping the lowest indices on ties
+    fn brute_force(points: &[Coordinate], farthest: bool) -> (usize, usize, f64) {
+        let vectors: Vec<[f64; 3]> = points.iter().map(to_unit_vector).collect();
+        let mut best: Option<(usize, usize, f64)> = None;
+        for i in 0..vectors.len() {
+            for j in i + 1..vectors.len() {
+                let chord = chord_squared(vectors[i], vectors[j]);
+                let better = best.is_none_or(
+                    |(_, _, best)| {
+                        if farthest {
+                            chord > best
+                        } else {
+                            chord < best
+                        }
+                    },
+                );
+                if better {
+                    best = Some((i, j, chord));
+                }
+            }
+        }
+        let (i, j, chord) = best.unwrap();
+        (i, j, chord_to_distance(chord, &DistanceUnit::Meters))
+    }
+
+    #[test]
+    fn test_pairs_match_brute_force() {
+        for set in 0..300u64 {
+            let count = 2 + (mix64(set) % 60) as usize;
+            // Clusters of every size, from a city block to the whole globe
+            let spread = [0.01, 1.0, 30.0, 100.0, 360.0][set as usize % 5];
+            let center = (random(set * 7 + 1, 120.0), random(set * 7 + 2, 360.0));
+            let mut points: Vec<Coordinate> = (0..count as u64)
+                .map(|i| {
+                    let seed = set * 1_000 + i;
+                    Coordinate::new(
+                        (center.0 + random(2 * seed, spread)).clamp(-90.0, 90.0),
+                        center.1 + random(2 * seed + 1, spread),
+                    )
+                })
+                .collect();
+            if set % 3 == 0 {
+                points.push(points[count / 2]);
+            }
+
+            for farthest in [false, true] {
+                let expected = brute_force(&points, farthest);
+                let found = if farthest {
+                    farthest_pair(&points, &DistanceUnit::Meters)
+                } else {
+                    closest_pair(&points, &DistanceUnit::Meters)
+                }
+                .unwrap();
+
+                assert_eq!(
+                    expected.2, found.2,
+                    "{} {} {:?} {:?}",
+                    set, farthest, expected, found
+                );
+                if !farthest {
+                    assert_eq!((expected.0, expected.1), (found.0, found.1));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,636 @@
+use crate::math;
+use crate::spherical::angle_to_distance;
+use crate::{CheapRuler, Coordinate, CoordinateBoundaries, DistanceUnit, EdgePolicy};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// ## Summary
+/// How polygon edges are drawn between consecutive vertices
+pub enum EdgeInterpolation {
+    /// Straight lines in latitude / longitude space
+    #[default]
+    Linear,
+    /// Great-circle arcs, the shortest path over the sphere
+    GreatCircle,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Default)]
+/// ## Summary
+/// Struct representing a polygonal region, made of an exterior ring and optional interior rings (holes)
+///
+/// ## Notes
+/// - Rings may be given open or closed, the last point always connects back to the first
+/// - Edges are straight lines in latitude / longitude space, polygons must not cross the antimeridian
+pub struct Polygon {
+    pub exterior: Vec<Coordinate>,
+    pub interiors: Vec<Vec<Coordinate>>,
+}
+
+impl Polygon {
+    /// # Summary
+    /// Construct a new Polygon without holes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    /// assert!(triangle.interiors.is_empty());
+    /// ```
+    pub fn new(exterior: Vec<Coordinate>) -> Self {
+        Self {
+            exterior,
+            interiors: Vec::new(),
+        }
+    }
+
+    /// # Summary
+    /// Construct a new Polygon with holes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let square = |size: f64| vec![
+    ///     Coordinate::new(-size, -size),
+    ///     Coordinate::new(-size, size),
+    ///     Coordinate::new(size, size),
+    ///     Coordinate::new(size, -size),
+    /// ];
+    /// let frame = Polygon::with_interiors(square(2.0), vec![square(1.0)]);
+    ///
+    /// assert!(!frame.contains(&Coordinate::new(0.0, 0.0)));
+    /// assert!(frame.contains(&Coordinate::new(1.5, 0.0)));
+    /// ```
+    pub fn with_interiors(exterior: Vec<Coordinate>, interiors: Vec<Vec<Coordinate>>) -> Self {
+        Self {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the polygon and outside of all its holes
+    ///
+    /// ## Notes
+    /// - Uses the even-odd ray casting rule, points exactly on an edge may be reported either way
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert!(triangle.contains(&Coordinate::new(0.25, 0.25)));
+    /// assert!(!triangle.contains(&Coordinate::new(0.75, 0.75)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        ring_contains(&self.exterior, coordinate)
+            && !self
+                .interiors
+                .iter()
+                .any(|interior| ring_contains(interior, coordinate))
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the polygon and outside of all its holes, with the
+    /// edges interpolated as chosen by `edges`
+    ///
+    /// ## Notes
+    /// - `EdgeInterpolation::Linear` matches `contains`
+    /// - `EdgeInterpolation::GreatCircle` follows the shortest path over the sphere between
+    ///   vertices, which bulges poleward of the straight latitude / longitude line on long
+    ///   east-west edges. Rings may cross the antimeridian in this mode but must not enclose the
+    ///   north pole
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, EdgeInterpolation, Polygon};
+    ///
+    /// let region = Polygon::new(vec![
+    ///     Coordinate::new(0.0, -60.0),
+    ///     Coordinate::new(60.0, -60.0),
+    ///     Coordinate::new(60.0, 60.0),
+    ///     Coordinate::new(0.0, 60.0),
+    /// ]);
+    /// let point = Coordinate::new(65.0, 0.0);
+    ///
+    /// // The great circle between the two northern corners reaches 73.9° N
+    /// assert!(!region.contains_with(&point, EdgeInterpolation::Linear));
+    /// assert!(region.contains_with(&point, EdgeInterpolation::GreatCircle));
+    /// ```
+    pub fn contains_with(&self, coordinate: &Coordinate, edges: EdgeInterpolation) -> bool {
+        let ring_contains = match edges {
+            EdgeInterpolation::Linear => ring_contains,
+            EdgeInterpolation::GreatCircle => ring_contains_great_circle,
+        };
+
+        ring_contains(&self.exterior, coordinate)
+            && !self
+                .interiors
+                .iter()
+                .any(|interior| ring_contains(interior, coordinate))
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the polygon and outside of all its holes, with
+    /// coordinates exactly on an edge included or excluded according to `policy`
+    ///
+    /// ## Notes
+    /// - Each edge belongs to the side of `policy` its outward normal points most towards, so the
+    ///   northern edges of the polygon, including the southern edges of its holes, follow `north`
+    /// - Polygons sharing an edge, or meeting at a vertex, contain a point on it at most once with
+    ///   `EdgePolicy::half_open`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, EdgePolicy, Polygon};
+    ///
+    /// let lower = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 2.0),
+    ///     Coordinate::new(1.0, 1.0),
+    /// ]);
+    /// let upper = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 2.0),
+    ///     Coordinate::new(2.0, 2.0),
+    ///     Coordinate::new(1.0, 1.0),
+    /// ]);
+    /// let on_border = Coordinate::new(0.5, 1.5);
+    ///
+    /// assert!(!lower.contains_with_policy(&on_border, &EdgePolicy::half_open()));
+    /// assert!(upper.contains_with_policy(&on_border, &EdgePolicy::half_open()));
+    /// assert!(!upper.contains_with_policy(&on_border, &EdgePolicy::exclusive()));
+    /// ```
+    pub fn contains_with_policy(&self, coordinate: &Coordinate, policy: &EdgePolicy) -> bool {
+        let rings = std::iter::once((&self.exterior, false))
+            .chain(self.interiors.iter().map(|interior| (interior, true)));
+        let mut on_edge = false;
+        let mut included = true;
+        for (ring, hole) in rings {
+            if let Some(inclusive) = ring_edge_inclusion(ring, hole, coordinate, policy) {
+                on_edge = true;
+                included &= inclusive;
+            }
+        }
+
+        if on_edge {
+            included
+        } else {
+            self.contains(coordinate)
+        }
+    }
+
+    /// # Summary
+    /// Get the great-circle distance from a coordinate to the nearest edge of the polygon, or `0`
+    /// if the coordinate is inside it. Returns infinity for a polygon without points
+    ///
+    /// ## Notes
+    /// - Coordinates inside a hole are measured to the nearest edge of the hole
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(0.0, triangle.distance_to(&Coordinate::new(0.25, 0.25), &DistanceUnit::Kilometers));
+    /// assert_eq!(55.6, (triangle.distance_to(&Coordinate::new(-0.5, 0.5), &DistanceUnit::Kilometers) * 10.0).round() / 10.0);
+    /// ```
+    pub fn distance_to(&self, coordinate: &Coordinate, unit: &DistanceUnit) -> f64 {
+        if self.contains(coordinate) {
+            return 0.0;
+        }
+        edge_distance(self, coordinate, unit)
+    }
+
+    /// # Summary
+    /// Get the bounding box of the exterior ring. Returns `None` if the exterior is empty
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    /// let bounds = triangle.bounds().unwrap();
+    ///
+    /// assert_eq!(0.0, bounds.min_latitude());
+    /// assert_eq!(1.0, bounds.max_longitude());
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        let first = self.exterior.first()?;
+        let (mut min_lat, mut max_lat) = (first.latitude, first.latitude);
+        let (mut min_lon, mut max_lon) = (first.longitude, first.longitude);
+        for coordinate in &self.exterior {
+            min_lat = min_lat.min(coordinate.latitude);
+            max_lat = max_lat.max(coordinate.latitude);
+            min_lon = min_lon.min(coordinate.longitude);
+            max_lon = max_lon.max(coordinate.longitude);
+        }
+
+        CoordinateBoundaries::from_extents(min_lat, max_lat, min_lon, max_lon)
+    }
+
+    /// # Summary
+    /// Get the signed area of the polygon on the spherical Earth, in square `unit`s.
+    /// Positive when the exterior ring is counterclockwise, negative when it is clockwise
+    ///
+    /// ## Notes
+    /// - The area of the holes is subtracted regardless of their winding
+    /// - GeoJSON (RFC 7946) expects counterclockwise exteriors, KML expects clockwise ones
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let mut square = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    /// assert_eq!(12364.0, square.signed_area(&DistanceUnit::Kilometers).round());
+    ///
+    /// square.reverse();
+    /// assert_eq!(-12364.0, square.signed_area(&DistanceUnit::Kilometers).round());
+    /// ```
+    pub fn signed_area(&self, unit: &DistanceUnit) -> f64 {
+        let exterior = ring_signed_area(&self.exterior);
+        let holes: f64 = self
+            .interiors
+            .iter()
+            .map(|interior| ring_signed_area(interior).abs())
+            .sum();
+
+        let radius = angle_to_distance(1.0, unit);
+        exterior.signum() * (exterior.abs() - holes) * radius * radius
+    }
+
+    /// # Summary
+    /// Get the area of the polygon on the spherical Earth, in square `unit`s
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let square = |size: f64| vec![
+    ///     Coordinate::new(-size, -size),
+    ///     Coordinate::new(size, -size),
+    ///     Coordinate::new(size, size),
+    ///     Coordinate::new(-size, size),
+    /// ];
+    /// let frame = Polygon::with_interiors(square(1.0), vec![square(0.5)]);
+    ///
+    /// assert_eq!(37091.0, frame.area(&DistanceUnit::Kilometers).round());
+    /// ```
+    pub fn area(&self, unit: &DistanceUnit) -> f64 {
+        self.signed_area(unit).abs()
+    }
+
+    /// # Summary
+    /// Checks if the exterior ring winds clockwise, looking down on the map with north up
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(1.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    /// ]);
+    ///
+    /// assert!(triangle.is_clockwise());
+    /// ```
+    pub fn is_clockwise(&self) -> bool {
+        ring_signed_area(&self.exterior) < 0.0
+    }
+
+    /// # Summary
+    /// Reverse the winding of the exterior and every interior ring
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let mut triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(1.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    /// ]);
+    /// triangle.reverse();
+    ///
+    /// assert!(!triangle.is_clockwise());
+    /// ```
+    pub fn reverse(&mut self) {
+        self.exterior.reverse();
+        for interior in &mut self.interiors {
+            interior.reverse();
+        }
+    }
+
+    /// # Summary
+    /// Render the polygon, holes included, as a PostGIS EWKT polygon string in the given SRID
+    ///
+    /// ## Notes
+    /// - Coordinates are written in `longitude latitude` order as PostGIS expects
+    /// - Rings are closed in the output if they were given open
+    /// - Polygons without an exterior are written as `POLYGON EMPTY`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!("SRID=4326;POLYGON((0 0,1 0,0 1,0 0))", triangle.to_ewkt(4326));
+    /// ```
+    pub fn to_ewkt(&self, srid: u32) -> String {
+        if self.exterior.is_empty() {
+            return format!("SRID={};POLYGON EMPTY", srid);
+        }
+        let ring = |points: &[Coordinate]| {
+            let closing = points.first().filter(|first| points.last() != Some(first));
+            let points: Vec<String> = points
+                .iter()
+                .chain(closing)
+                .map(|point| format!("{} {}", point.longitude, point.latitude))
+                .collect();
+            format!("({})", points.join(","))
+        };
+        let rings: Vec<String> = std::iter::once(&self.exterior)
+            .chain(&self.interiors)
+            .map(|points| ring(points))
+            .collect();
+
+        format!("SRID={};POLYGON({})", srid, rings.join(","))
+    }
+}
+
+/// # Summary
+/// Signed spherical area of a ring on the unit sphere, positive when counterclockwise
+///
+/// ## Notes
+/// - Uses the spherical excess approximation `sum((λ2 - λ1) * (2 + sin φ1 + sin φ2)) / 2` from
+///   Chamberlain & Duquette, "Some Algorithms for Polygons on a Sphere"
+fn ring_signed_area(ring: &[Coordinate]) -> f64 {
+    if ring.len() < 3 {
+        return 0.0;
+    }
+
+    let sum: f64 = ring
+        .iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(current, next)| {
+            (next.longitude - current.longitude).to_radians()
+                * (2.0
+                    + math::sin(current.latitude.to_radians())
+                    + math::sin(next.latitude.to_radians()))
+        })
+        .sum();
+
+    -sum / 2.0
+}
+
+/// # Summary
+/// Great-circle distance from a coordinate to the nearest edge of any of the polygon's rings, or
+/// infinity for a polygon without points. The nearest point of each edge is picked in a local flat
+/// projection around `coordinate`, matching the straight latitude / longitude edges
+fn edge_distance(polygon: &Polygon, coordinate: &Coordinate, unit: &DistanceUnit) -> f64 {
+    let ruler = CheapRuler::new(coordinate.latitude, *unit);
+
+    std::iter::once(&polygon.exterior)
+        .chain(&polygon.interiors)
+        .flat_map(|ring| {
+            ring.iter()
+                .zip(ring.iter().cycle().skip(1))
+                .map(|(current, next)| {
+                    let nearest = ruler.nearest_on_segment(coordinate, current, next);
+                    coordinate.get_distance_from(nearest, unit)
+                })
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// # Summary
+/// If the coordinate lies exactly on edges of the ring, check whether all of those edges are
+/// inclusive under `policy`. Returns `None` for coordinates off the ring
+fn ring_edge_inclusion(
+    ring: &[Coordinate],
+    hole: bool,
+    coordinate: &Coordinate,
+    policy: &EdgePolicy,
+) -> Option<bool> {
+    let (x, y) = (coordinate.longitude, coordinate.latitude);
+    // The polygon's interior lies left of counterclockwise exterior edges and right of
+    // counterclockwise hole edges
+    let interior_left = (ring_signed_area(ring) > 0.0) != hole;
+    let mut inclusion = None;
+
+    for (index, current) in ring.iter().enumerate() {
+        let next = &ring[(index + 1) % ring.len()];
+        let (x1, y1, x2, y2) = (
+            current.longitude,
+            current.latitude,
+            next.longitude,
+            next.latitude,
+        );
+        let (dx, dy) = (x2 - x1, y2 - y1);
+
+        let within = x >= x1.min(x2) && x <= x1.max(x2) && y >= y1.min(y2) && y <= y1.max(y2);
+        let (a, b) = (dx * (y - y1), dy * (x - x1));
+        // Tolerates the rounding of the cross product, so polygons sharing a diagonal edge agree
+        if !within
+            || (dx == 0.0 && dy == 0.0)
+            || (a - b).abs() > 4.0 * f64::EPSILON * (a.abs() + b.abs())
+        {
+            continue;
+        }
+
+        let (east, north) = if interior_left { (dy, -dx) } else { (-dy, dx) };
+        *inclusion.get_or_insert(true) &= policy.includes(east, north);
+    }
+
+    inclusion
+}
+
+/// # Summary
+/// Even-odd ray casting test of a coordinate against a single ring
+pub(crate) fn ring_contains(ring: &[Coordinate], coordinate: &Coordinate) -> bool {
+    let (x, y) = (coordinate.longitude, coordinate.latitude);
+    let mut inside = false;
+
+    for (index, current) in ring.iter().enumerate() {
+        let previous = &ring[(index + ring.len() - 1) % ring.len()];
+        let (x1, y1) = (current.longitude, current.latitude);
+        let (x2, y2) = (previous.longitude, previous.latitude);
+
+        if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// # Summary
+/// Ray casting test of a coordinate against a single ring with great-circle edges, counting the
+/// edges crossed by the meridian arc running from the coordinate to the north pole
+pub(crate) fn ring_contains_great_circle(ring: &[Coordinate], coordinate: &Coordinate) -> bool {
+    let latitude = coordinate.latitude.to_radians();
+    // Longitude relative to the coordinate, in -π..π
+    let relative = |point: &Coordinate| {
+        let difference = (point.longitude - coordinate.longitude).rem_euclid(360.0);
+        (if difference > 180.0 {
+            difference - 360.0
+        } else {
+            difference
+        })
+        .to_radians()
+    };
+    let mut inside = false;
+
+    for (index, current) in ring.iter().enumerate() {
+        let previous = &ring[(index + ring.len() - 1) % ring.len()];
+        let (x1, x2) = (relative(current), relative(previous));
+
+        // The edge must cross the coordinate's meridian rather than the opposite one
+        if (x1 > 0.0) == (x2 > 0.0) || (x1 - x2).abs() >= std::f64::consts::PI {
+            continue;
+        }
+        let (tan1, tan2) = (
+            math::tan(current.latitude.to_radians()),
+            math::tan(previous.latitude.to_radians()),
+        );
+        let crossing =
+            math::atan((tan1 * math::sin(x2) - tan2 * math::sin(x1)) / math::sin(x2 - x1));
+        if crossing > latitude {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_great_circle_edges_cross_the_antimeridian() {
+        let pacific = Polygon::new(vec![
+            Coordinate::new(-10.0, 170.0),
+            Coordinate::new(-10.0, -170.0),
+            Coordinate::new(10.0, -170.0),
+            Coordinate::new(10.0, 170.0),
+        ]);
+
+        for longitude in [175.0, 180.0, -175.0] {
+            let point = Coordinate::new(0.0, longitude);
+            assert!(pacific.contains_with(&point, EdgeInterpolation::GreatCircle));
+        }
+        assert!(!pacific.contains_with(&Coordinate::new(0.0, 0.0), EdgeInterpolation::GreatCircle));
+        assert!(
+            !pacific.contains_with(&Coordinate::new(0.0, 165.0), EdgeInterpolation::GreatCircle)
+        );
+    }
+
+    #[test]
+    fn test_great_circle_edges_match_linear_along_meridians_and_equator() {
+        // Samples stay clear of the edges along parallels, which bulge poleward as great circles
+        let square = Polygon::with_interiors(
+            vec![
+                Coordinate::new(0.0, 0.0),
+                Coordinate::new(40.0, 0.0),
+                Coordinate::new(40.0, 40.0),
+                Coordinate::new(0.0, 40.0),
+            ],
+            vec![vec![
+                Coordinate::new(10.0, 10.0),
+                Coordinate::new(20.0, 10.0),
+                Coordinate::new(20.0, 20.0),
+                Coordinate::new(10.0, 20.0),
+            ]],
+        );
+
+        for latitude in (-5..=35).step_by(5) {
+            for longitude in (-5..=45).step_by(5) {
+                let point = Coordinate::new(latitude as f64 + 0.5, longitude as f64 + 0.5);
+                assert_eq!(
+                    square.contains(&point),
+                    square.contains_with(&point, EdgeInterpolation::GreatCircle),
+                    "{:?}",
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_half_open_policy_counts_shared_borders_once() {
+        let cell = |row: i32, column: i32| {
+            let (lat, lon) = (row as f64 * 0.5, column as f64 * 0.5);
+            Polygon::new(vec![
+                Coordinate::new(lat, lon),
+                Coordinate::new(lat, lon + 0.5),
+                Coordinate::new(lat + 0.5, lon + 0.5),
+                Coordinate::new(lat + 0.5, lon),
+            ])
+        };
+        let mut cells: Vec<Polygon> = (0..4)
+            .flat_map(|row| (0..4).map(move |column| cell(row, column)))
+            .collect();
+        // A frame with a hole, filled by another polygon
+        cells.push(Polygon::with_interiors(
+            vec![
+                Coordinate::new(-3.0, -3.0),
+                Coordinate::new(-3.0, -1.0),
+                Coordinate::new(-1.0, -1.0),
+                Coordinate::new(-1.0, -3.0),
+            ],
+            vec![cell(-5, -5).exterior],
+        ));
+        cells.push(cell(-5, -5));
+
+        let policy = EdgePolicy::half_open();
+        for point in [
+            Coordinate::new(0.5, 0.5),
+            Coordinate::new(1.0, 0.75),
+            Coordinate::new(0.25, 1.5),
+            Coordinate::new(-2.5, -2.5),
+            Coordinate::new(-2.25, -2.0),
+            Coordinate::new(-2.0, -2.25),
+        ] {
+            let containing = cells
+                .iter()
+                .filter(|cell| cell.contains_with_policy(&point, &policy))
+                .count();
+            assert_eq!(1, containing, "{:?}", point);
+        }
+    }
+}
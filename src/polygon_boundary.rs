@@ -0,0 +1,63 @@
+use crate::spherical::interpolate;
+use crate::{Coordinate, DistanceUnit, Polygon};
+
+impl Polygon {
+    /// # Summary
+    /// Place coordinates every `spacing` along the exterior ring, such as patrol waypoints or
+    /// sensor positions around a perimeter
+    ///
+    /// ## Notes
+    /// - Starts at the first vertex and keeps walking the ring across vertices, so the spacing
+    ///   holds around corners. The gap from the last point back to the first is at most `spacing`
+    /// - Edges are followed along the great circle between vertices, matching
+    ///   `EdgeInterpolation::GreatCircle`
+    /// - Holes are not walked
+    /// - Returns an empty list for empty polygons or a `spacing` which is not positive and finite
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let square = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 0.01),
+    ///     Coordinate::new(0.01, 0.01),
+    ///     Coordinate::new(0.01, 0.0),
+    /// ]);
+    ///
+    /// // About 1112 meters per side
+    /// let waypoints = square.boundary_points(500.0, &DistanceUnit::Meters);
+    ///
+    /// assert_eq!(9, waypoints.len());
+    /// assert_eq!(Coordinate::new(0.0, 0.0), waypoints[0]);
+    /// assert_eq!(0.0, waypoints[2].latitude);
+    /// assert!(waypoints[2].longitude > 0.0089 && waypoints[2].longitude < 0.009);
+    /// ```
+    pub fn boundary_points(&self, spacing: f64, unit: &DistanceUnit) -> Vec<Coordinate> {
+        let Some(first) = self.exterior.first() else {
+            return Vec::new();
+        };
+        if !(spacing > 0.0 && spacing.is_finite()) {
+            return Vec::new();
+        }
+
+        let mut ring = self.exterior.clone();
+        if ring.len() > 1 && ring.last() != Some(first) {
+            ring.push(*first);
+        }
+
+        let mut points = vec![*first];
+        // Distance along the current edge of the next point to place
+        let mut next = spacing;
+        for edge in ring.windows(2) {
+            let length = edge[0].get_distance_from(edge[1], unit);
+            while next < length {
+                points.push(interpolate(&edge[0], &edge[1], next / length));
+                next += spacing;
+            }
+            next -= length;
+        }
+
+        points
+    }
+}
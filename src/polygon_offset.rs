@@ -0,0 +1,741 @@
+use crate::{math, CheapRuler, Coordinate, DistanceUnit, Polygon};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Number of segments approximating a full circle in round joins
+const ROUND_SEGMENTS: f64 = 32.0;
+/// Longest miter, as a multiple of the offset distance, before it is cut off
+const MITER_LIMIT: f64 = 4.0;
+/// Crossings closer than this fraction of an edge to its ends are ignored, so neighbouring edges
+/// of a ring never cross each other
+const CROSSING_EPSILON: f64 = 1e-9;
+
+type Point = (f64, f64);
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// ## Summary
+/// How `Polygon::offset` fills the gap opening between two offset edges at a corner
+pub enum OffsetJoin {
+    /// Extend both edges until they meet. Corners sharper than about 29 degrees, whose miter
+    /// would reach further than 4 times the offset distance, are cut off at that distance
+    #[default]
+    Miter,
+    /// Follow a circle around the corner, the same as buffering the boundary
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// ## Summary
+/// What a segment of a raw offset ring was made from
+enum Piece {
+    /// A shifted edge or a join, part of the result wherever nothing else swallows it
+    Boundary,
+    /// A connection between overlapping shifted edges, never part of the result
+    Join,
+}
+
+impl Polygon {
+    /// # Summary
+    /// Grow the polygon outwards by `distance` in `unit`, or shrink it inwards for a negative
+    /// `distance`, such as a safety margin inside a geofence. Returns the resulting polygons,
+    /// largest first
+    ///
+    /// ## Notes
+    /// - Shrinking can split the polygon into several parts or remove it entirely, growing can
+    ///   close holes and merge parts of the boundary
+    /// - The offset is computed on a plane scaled to the polygon's middle latitude, accurate for
+    ///   polygons up to a few hundred kilometers across
+    /// - Returns the polygon unchanged for a `distance` of zero and no polygons for an empty
+    ///   polygon or a `distance` which is not finite
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, OffsetJoin, Polygon};
+    ///
+    /// let site = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 0.01),
+    ///     Coordinate::new(0.01, 0.01),
+    ///     Coordinate::new(0.01, 0.0),
+    /// ]);
+    ///
+    /// let margin = site.offset(-100.0, &DistanceUnit::Meters, OffsetJoin::Miter);
+    /// assert_eq!(1, margin.len());
+    /// assert!(margin[0].contains(&Coordinate::new(0.005, 0.005)));
+    /// assert!(!margin[0].contains(&Coordinate::new(0.0005, 0.005)));
+    ///
+    /// let buffer = site.offset(100.0, &DistanceUnit::Meters, OffsetJoin::Round);
+    /// assert!(buffer[0].contains(&Coordinate::new(-0.0005, 0.005)));
+    /// assert!(!buffer[0].contains(&Coordinate::new(-0.0008, -0.0008)));
+    ///
+    /// assert!(site.offset(-1000.0, &DistanceUnit::Meters, OffsetJoin::Miter).is_empty());
+    /// ```
+    pub fn offset(&self, distance: f64, unit: &DistanceUnit, join: OffsetJoin) -> Vec<Polygon> {
+        let Some(bounds) = self.bounds() else {
+            return Vec::new();
+        };
+        if !distance.is_finite() {
+            return Vec::new();
+        }
+        if distance == 0.0 {
+            return vec![self.clone()];
+        }
+
+        let origin = self.exterior[0];
+        let ruler = CheapRuler::new((bounds.min_latitude() + bounds.max_latitude()) / 2.0, *unit);
+        let (kx, ky) = ruler.degree_lengths();
+        let project = |coordinate: &Coordinate| {
+            (
+                (coordinate.longitude - origin.longitude) * kx,
+                (coordinate.latitude - origin.latitude) * ky,
+            )
+        };
+        let unproject =
+            |(x, y): Point| Coordinate::new(origin.latitude + y / ky, origin.longitude + x / kx);
+
+        // Orient every ring with the polygon on its left, the exterior counterclockwise and the
+        // holes clockwise
+        let prepare = |ring: &Vec<Coordinate>, counterclockwise: bool| {
+            let mut ring: Vec<Point> = ring.iter().map(project).collect();
+            ring.dedup();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            if ring.len() < 3 {
+                return None;
+            }
+            if (signed_area(&ring) > 0.0) != counterclockwise {
+                ring.reverse();
+            }
+            Some(ring)
+        };
+        let Some(exterior) = prepare(&self.exterior, true) else {
+            return Vec::new();
+        };
+        let rings: Vec<Vec<Point>> = std::iter::once(exterior)
+            .chain(
+                self.interiors
+                    .iter()
+                    .filter_map(|interior| prepare(interior, false)),
+            )
+            .collect();
+
+        let mut fans = Vec::new();
+        let raw: Vec<Vec<(Point, Piece)>> = rings
+            .iter()
+            .map(|ring| offset_ring(ring, distance, join, &mut fans))
+            .collect();
+
+        let mut exteriors: Vec<(f64, Vec<Point>, Vec<Vec<Point>>)> = Vec::new();
+        let mut holes = Vec::new();
+        for points in boundary_rings(&raw, &rings, &fans, distance) {
+            let area = signed_area(&points);
+            if area > 0.0 {
+                exteriors.push((area, points, Vec::new()));
+            } else if area < 0.0 {
+                holes.push(points);
+            }
+        }
+        for hole in holes {
+            let probe = longest_edge_midpoint(&hole);
+            if let Some(exterior) = exteriors
+                .iter_mut()
+                .filter(|exterior| ring_contains(&exterior.1, probe))
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+            {
+                exterior.2.push(hole);
+            }
+        }
+        exteriors.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let to_coordinates =
+            |ring: Vec<Point>| -> Vec<Coordinate> { ring.into_iter().map(unproject).collect() };
+        exteriors
+            .into_iter()
+            .map(|(_, exterior, holes)| {
+                Polygon::with_interiors(
+                    to_coordinates(exterior),
+                    holes.into_iter().map(to_coordinates).collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// # Summary
+/// Shift every edge of a ring by `distance` to its right and connect the shifted edges, returning
+/// every point with the kind of segment leaving it. The result crosses itself wherever the offset
+/// swallows part of the ring. The area filled in by every join is added to `fans`
+fn offset_ring(
+    ring: &[Point],
+    distance: f64,
+    join: OffsetJoin,
+    fans: &mut Vec<Vec<Point>>,
+) -> Vec<(Point, Piece)> {
+    let count = ring.len();
+    let normal = |a: Point, b: Point| {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = math::hypot(dx, dy);
+        (dy / length, -dx / length)
+    };
+    let shift = |point: Point, direction: Point, length: f64| {
+        (
+            point.0 + direction.0 * length,
+            point.1 + direction.1 * length,
+        )
+    };
+    let dot = |a: Point, b: Point| a.0 * b.0 + a.1 * b.1;
+
+    let mut offset = Vec::new();
+    for index in 0..count {
+        let vertex = ring[index];
+        let incoming = normal(ring[(index + count - 1) % count], vertex);
+        let outgoing = normal(vertex, ring[(index + 1) % count]);
+        let start = shift(vertex, incoming, distance);
+        let end = shift(vertex, outgoing, distance);
+        // Positive where the ring turns left, and the cosine of the turn
+        let turn = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+        let cosine = dot(incoming, outgoing);
+
+        if turn.abs() < 1e-12 && cosine > 0.0 {
+            offset.push((end, Piece::Boundary));
+            continue;
+        }
+        if turn * distance < 0.0 {
+            // The shifted edges overlap. Cut both at their crossing if it is near the corner,
+            // otherwise connect them and leave the swallowed parts to be removed later
+            let previous = ring[(index + count - 1) % count];
+            let next = ring[(index + 1) % count];
+            let trim = distance.abs() * turn.abs() / (1.0 + cosine);
+            if trim <= length(previous, vertex) / 2.0 && trim <= length(vertex, next) / 2.0 {
+                let direction = (incoming.0 + outgoing.0, incoming.1 + outgoing.1);
+                offset.push((
+                    shift(vertex, direction, distance / (1.0 + cosine)),
+                    Piece::Boundary,
+                ));
+            } else {
+                offset.push((start, Piece::Join));
+                offset.push((end, Piece::Boundary));
+            }
+            continue;
+        }
+
+        let corner: Vec<Point> = if join == OffsetJoin::Round {
+            let sign = distance.signum();
+            let from = math::atan2(incoming.1 * sign, incoming.0 * sign);
+            let sweep = if turn == 0.0 {
+                PI * sign
+            } else {
+                math::atan2(turn, cosine)
+            };
+            let steps = (sweep.abs() * ROUND_SEGMENTS / (2.0 * PI)).ceil().max(1.0);
+            (0..steps as usize)
+                .map(|step| {
+                    let (sin, cos) = math::sin_cos(from + sweep * step as f64 / steps);
+                    shift(vertex, (cos, sin), distance.abs())
+                })
+                .collect()
+        } else if (2.0 / (1.0 + cosine)).sqrt() <= MITER_LIMIT {
+            let direction = (incoming.0 + outgoing.0, incoming.1 + outgoing.1);
+            vec![start, shift(vertex, direction, distance / (1.0 + cosine))]
+        } else {
+            // Cut the miter off square to the corner's bisector at the limit
+            let sum = (incoming.0 + outgoing.0, incoming.1 + outgoing.1);
+            let sum_length = math::hypot(sum.0, sum.1);
+            let forward = (-incoming.1, incoming.0);
+            let bisector = if sum_length > 1e-12 {
+                let sign = distance.signum() / sum_length;
+                (sum.0 * sign, sum.1 * sign)
+            } else {
+                forward
+            };
+            let limit = MITER_LIMIT * distance.abs();
+            std::iter::once(start)
+                .chain(
+                    [(incoming, forward), (outgoing, (-outgoing.1, outgoing.0))].map(
+                        |(normal, direction)| {
+                            let along = (limit - distance * dot(normal, bisector))
+                                / dot(direction, bisector);
+                            shift(shift(vertex, normal, distance), direction, along)
+                        },
+                    ),
+                )
+                .collect()
+        };
+
+        // The start of a miter lies on the incoming shifted edge and is left out of the ring
+        let skip = usize::from(join == OffsetJoin::Miter);
+        offset.extend(corner[skip..].iter().map(|point| (*point, Piece::Boundary)));
+        offset.push((end, Piece::Boundary));
+        fans.push(
+            std::iter::once(vertex)
+                .chain(corner)
+                .chain(std::iter::once(end))
+                .collect(),
+        );
+    }
+
+    offset
+}
+
+/// # Summary
+/// Cut the raw offset rings wherever they cross or touch, keep the pieces which are not swallowed
+/// by other parts of the offset, and link those back into closed rings
+fn boundary_rings(
+    raw: &[Vec<(Point, Piece)>],
+    original: &[Vec<Point>],
+    fans: &[Vec<Point>],
+    distance: f64,
+) -> Vec<Vec<Point>> {
+    let edges: Vec<(Point, Point, Piece)> = raw
+        .iter()
+        .flat_map(|ring| {
+            (0..ring.len()).map(move |index| {
+                let (start, piece) = ring[index];
+                (start, ring[(index + 1) % ring.len()].0, piece)
+            })
+        })
+        .collect();
+
+    // Sweep over the edges by their smallest x, only testing edges whose x ranges overlap
+    let min_x = |edge: usize| edges[edge].0 .0.min(edges[edge].1 .0);
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|a, b| min_x(*a).total_cmp(&min_x(*b)));
+    // Points where every edge is cut, as (fraction along the edge, point)
+    let mut cuts: Vec<Vec<(f64, Point)>> = vec![Vec::new(); edges.len()];
+    for (position, &first) in order.iter().enumerate() {
+        let (a, b, _) = edges[first];
+        let max_x = a.0.max(b.0);
+        for &second in &order[position + 1..] {
+            if min_x(second) > max_x {
+                break;
+            }
+            let (c, d, _) = edges[second];
+            if let Some((t, u)) = crossing(a, b, c, d) {
+                let point = (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+                cuts[first].push((t, point));
+                cuts[second].push((u, point));
+            }
+            // Ends touching the other edge, including where collinear edges overlap
+            if let Some(t) = fraction_on_segment(c, a, b) {
+                cuts[first].push((t, c));
+            }
+            if let Some(u) = fraction_on_segment(a, c, d) {
+                cuts[second].push((u, a));
+            }
+        }
+    }
+
+    let key = |point: Point| (point.0.to_bits(), point.1.to_bits());
+    let mut pieces: Vec<(Point, Point)> = Vec::new();
+    let mut removed: Vec<bool> = Vec::new();
+    let mut piece_index: HashMap<[u64; 4], usize> = HashMap::new();
+    for (edge, (start, end, piece)) in edges.iter().enumerate() {
+        let mut on_edge = std::mem::take(&mut cuts[edge]);
+        on_edge.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let points: Vec<Point> = std::iter::once(*start)
+            .chain(on_edge.into_iter().map(|(_, point)| point))
+            .chain(std::iter::once(*end))
+            .collect();
+
+        for pair in points.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if from == to || !is_kept(from, to, *piece, original, fans, distance) {
+                continue;
+            }
+            let (from_key, to_key) = (key(from), key(to));
+            // Overlapping pieces count once, opposite ones enclose nothing and cancel out
+            if let Some(&reverse) = piece_index.get(&[to_key.0, to_key.1, from_key.0, from_key.1]) {
+                removed[reverse] = true;
+                continue;
+            }
+            piece_index
+                .entry([from_key.0, from_key.1, to_key.0, to_key.1])
+                .or_insert_with(|| {
+                    pieces.push((from, to));
+                    removed.push(false);
+                    pieces.len() - 1
+                });
+        }
+    }
+
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (index, (from, _)) in pieces.iter().enumerate() {
+        if !removed[index] {
+            outgoing.entry(key(*from)).or_default().push(index);
+        }
+    }
+
+    let mut used = removed;
+    let mut rings = Vec::new();
+    for first in 0..pieces.len() {
+        let mut ring = Vec::new();
+        let mut current = first;
+        while !used[current] {
+            used[current] = true;
+            let (from, to) = pieces[current];
+            ring.push(from);
+            if key(to) == key(pieces[first].0) {
+                rings.push(simplify_collinear(ring));
+                break;
+            }
+
+            // Where several pieces leave a point, take the sharpest left turn so rings touching
+            // at a point stay apart
+            let heading = (to.0 - from.0, to.1 - from.1);
+            let Some(&next) = outgoing.get(&key(to)).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .filter(|&&candidate| !used[candidate])
+                    .max_by(|&&a, &&b| {
+                        let turn = |candidate: usize| {
+                            let (start, end) = pieces[candidate];
+                            let direction = (end.0 - start.0, end.1 - start.1);
+                            math::atan2(
+                                heading.0 * direction.1 - heading.1 * direction.0,
+                                heading.0 * direction.0 + heading.1 * direction.1,
+                            )
+                        };
+                        turn(a).total_cmp(&turn(b))
+                    })
+            }) else {
+                break;
+            };
+            current = next;
+        }
+    }
+
+    rings
+}
+
+/// # Summary
+/// Get the fractions along `a` to `b` and along `c` to `d` where the two segments cross, if they
+/// cross away from their ends
+fn crossing(a: Point, b: Point, c: Point, d: Point) -> Option<(f64, f64)> {
+    let r = (b.0 - a.0, b.1 - a.1);
+    let s = (d.0 - c.0, d.1 - c.1);
+    let denominator = r.0 * s.1 - r.1 * s.0;
+    if denominator.abs() < 1e-30 {
+        return None;
+    }
+    let ac = (c.0 - a.0, c.1 - a.1);
+    let t = (ac.0 * s.1 - ac.1 * s.0) / denominator;
+    let u = (ac.0 * r.1 - ac.1 * r.0) / denominator;
+    let inner = CROSSING_EPSILON..1.0 - CROSSING_EPSILON;
+
+    (inner.contains(&t) && inner.contains(&u)).then_some((t, u))
+}
+
+/// # Summary
+/// Get the fraction along `a` to `b` where `point` lies, if it lies on the segment away from its
+/// ends
+fn fraction_on_segment(point: Point, a: Point, b: Point) -> Option<f64> {
+    let r = (b.0 - a.0, b.1 - a.1);
+    let squared = r.0 * r.0 + r.1 * r.1;
+    if squared == 0.0 {
+        return None;
+    }
+    let (dx, dy) = (point.0 - a.0, point.1 - a.1);
+    let t = (dx * r.0 + dy * r.1) / squared;
+    let off_line = (r.0 * dy - r.1 * dx).abs();
+
+    ((CROSSING_EPSILON..1.0 - CROSSING_EPSILON).contains(&t)
+        && off_line <= CROSSING_EPSILON * squared)
+        .then_some(t)
+}
+
+/// # Summary
+/// Checks if a piece of the raw offset belongs to the result, lying on the requested side of the
+/// original rings and not within the area swept by any shifted edge or join
+fn is_kept(
+    from: Point,
+    to: Point,
+    piece: Piece,
+    original: &[Vec<Point>],
+    fans: &[Vec<Point>],
+    distance: f64,
+) -> bool {
+    if piece == Piece::Join {
+        return false;
+    }
+    let probe = midpoint(from, to);
+    // Pieces on the edge of a swept area count as outside of it
+    let tolerance = distance.abs() * 1e-9;
+
+    let inside = original
+        .iter()
+        .filter(|ring| ring_contains(ring, probe))
+        .count()
+        % 2
+        == 1;
+    if inside != (distance < 0.0) {
+        return false;
+    }
+
+    let swept_by_edge = original
+        .iter()
+        .flat_map(|ring| {
+            (0..ring.len()).map(move |index| (ring[index], ring[(index + 1) % ring.len()]))
+        })
+        .any(|(a, b)| {
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let length = math::hypot(dx, dy);
+            let (px, py) = (probe.0 - a.0, probe.1 - a.1);
+            let along = (px * dx + py * dy) / length;
+            let across = (dx * py - dy * px) / length;
+            along > 0.0 && along < length && across.abs() < distance.abs() - tolerance
+        });
+    let swept_by_join = fans.iter().any(|fan| {
+        let count = fan.len();
+        let sides: Vec<f64> = (0..count)
+            .filter_map(|index| {
+                let (a, b) = (fan[index], fan[(index + 1) % count]);
+                let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+                let length = math::hypot(dx, dy);
+                (length > 0.0).then(|| (dx * (probe.1 - a.1) - dy * (probe.0 - a.0)) / length)
+            })
+            .collect();
+        sides.iter().all(|side| *side > tolerance) || sides.iter().all(|side| *side < -tolerance)
+    });
+
+    !swept_by_edge && !swept_by_join
+}
+
+/// # Summary
+/// Remove vertices lying on the straight line between their neighbours, left over from cutting
+fn simplify_collinear(ring: Vec<Point>) -> Vec<Point> {
+    let count = ring.len();
+    (0..count)
+        .filter(|&index| {
+            let previous = ring[(index + count - 1) % count];
+            let next = ring[(index + 1) % count];
+            let (a, b) = (
+                (ring[index].0 - previous.0, ring[index].1 - previous.1),
+                (next.0 - ring[index].0, next.1 - ring[index].1),
+            );
+            let cross = a.0 * b.1 - a.1 * b.0;
+            cross.abs() > 1e-12 * math::hypot(a.0, a.1) * math::hypot(b.0, b.1)
+                || a.0 * b.0 + a.1 * b.1 < 0.0
+        })
+        .map(|index| ring[index])
+        .collect()
+}
+
+fn signed_area(ring: &[Point]) -> f64 {
+    (0..ring.len())
+        .map(|index| {
+            let (a, b) = (ring[index], ring[(index + 1) % ring.len()]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn ring_contains(ring: &[Point], point: Point) -> bool {
+    let mut inside = false;
+    for index in 0..ring.len() {
+        let (a, b) = (ring[index], ring[(index + 1) % ring.len()]);
+        if (a.1 > point.1) != (b.1 > point.1)
+            && point.0 < (b.0 - a.0) * (point.1 - a.1) / (b.1 - a.1) + a.0
+        {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+fn longest_edge_midpoint(ring: &[Point]) -> Point {
+    let end = |index: usize| ring[(index + 1) % ring.len()];
+    let index = (0..ring.len())
+        .max_by(|&a, &b| length(ring[a], end(a)).total_cmp(&length(ring[b], end(b))))
+        .unwrap_or(0);
+
+    midpoint(ring[index], end(index))
+}
+
+fn length(a: Point, b: Point) -> f64 {
+    math::hypot(b.0 - a.0, b.1 - a.1)
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{mix64, random};
+
+    fn square(center: Coordinate, half: f64) -> Vec<Coordinate> {
+        vec![
+            Coordinate::new(center.latitude - half, center.longitude - half),
+            Coordinate::new(center.latitude - half, center.longitude + half),
+            Coordinate::new(center.latitude + half, center.longitude + half),
+            Coordinate::new(center.latitude + half, center.longitude - half),
+        ]
+    }
+
+    #[test]
+    fn test_square_areas() {
+        let unit = DistanceUnit::Kilometers;
+        // A square of about 1 km by 1 km
+        let polygon = Polygon::new(square(Coordinate::new(0.0, 0.0), 0.0045));
+        let side = polygon.area(&unit).sqrt();
+        let area = |polygons: Vec<Polygon>| {
+            assert_eq!(1, polygons.len());
+            polygons[0].area(&unit)
+        };
+
+        let miter = area(polygon.offset(0.1, &unit, OffsetJoin::Miter));
+        assert!((miter - (side + 0.2).powi(2)).abs() < 5e-3, "{}", miter);
+        let round = area(polygon.offset(0.1, &unit, OffsetJoin::Round));
+        let expected = side * side + 4.0 * side * 0.1 + PI * 0.01;
+        assert!((round - expected).abs() < 5e-3, "{}", round);
+        let inset = area(polygon.offset(-0.1, &unit, OffsetJoin::Round));
+        assert!((inset - (side - 0.2).powi(2)).abs() < 5e-3, "{}", inset);
+
+        // Clockwise input gives the same result
+        let mut reversed = polygon.clone();
+        reversed.reverse();
+        assert!((area(reversed.offset(0.1, &unit, OffsetJoin::Miter)) - miter).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inset_splits_dumbbell() {
+        let unit = DistanceUnit::Kilometers;
+        // Two 1 km squares joined by a 100 m wide corridor
+        let polygon = Polygon::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 0.009),
+            Coordinate::new(0.00405, 0.009),
+            Coordinate::new(0.00405, 0.018),
+            Coordinate::new(0.0, 0.018),
+            Coordinate::new(0.0, 0.027),
+            Coordinate::new(0.009, 0.027),
+            Coordinate::new(0.009, 0.018),
+            Coordinate::new(0.00495, 0.018),
+            Coordinate::new(0.00495, 0.009),
+            Coordinate::new(0.009, 0.009),
+            Coordinate::new(0.009, 0.0),
+        ]);
+
+        assert_eq!(1, polygon.offset(-0.04, &unit, OffsetJoin::Miter).len());
+        let parts = polygon.offset(-0.1, &unit, OffsetJoin::Miter);
+        assert_eq!(2, parts.len());
+        for part in &parts {
+            assert!(
+                (part.area(&unit) - 0.64).abs() < 0.02,
+                "{}",
+                part.area(&unit)
+            );
+        }
+        assert_eq!(1, polygon.offset(0.1, &unit, OffsetJoin::Round).len());
+    }
+
+    #[test]
+    fn test_holes() {
+        let unit = DistanceUnit::Kilometers;
+        let center = Coordinate::new(45.0, 7.0);
+        let polygon = Polygon::with_interiors(square(center, 0.01), vec![square(center, 0.001)]);
+
+        let grown = polygon.offset(0.05, &unit, OffsetJoin::Miter);
+        assert_eq!(1, grown.len());
+        assert_eq!(1, grown[0].interiors.len());
+        assert!(!grown[0].contains(&center));
+        assert!(grown[0].area(&unit) > polygon.area(&unit));
+
+        // The hole is about 160 m across and closes up
+        let closed = polygon.offset(0.1, &unit, OffsetJoin::Round);
+        assert_eq!(1, closed.len());
+        assert!(closed[0].interiors.is_empty());
+        assert!(closed[0].contains(&center));
+
+        let shrunk = polygon.offset(-0.05, &unit, OffsetJoin::Miter);
+        assert_eq!(1, shrunk.len());
+        assert!(!shrunk[0].contains(&Coordinate::new(45.0, 7.0015)));
+        assert!(shrunk[0].contains(&Coordinate::new(45.0, 7.003)));
+    }
+
+    #[test]
+    fn test_matches_boundary_distance() {
+        let unit = DistanceUnit::Kilometers;
+        let center = Coordinate::new(50.0, 8.0);
+
+        for shape in 0..20u64 {
+            // Star shaped polygons with 5 to 16 vertices, up to about 1 km across
+            let count = 5 + mix64(shape * 1000) % 12;
+            let exterior: Vec<Coordinate> = (0..count)
+                .map(|index| {
+                    let seed = shape * 1000 + index * 2 + 1;
+                    let angle = (index as f64 + random(seed, 0.8)) / count as f64 * 2.0 * PI;
+                    let radius = 0.0045 + random(seed + 1, 0.005);
+                    Coordinate::new(
+                        center.latitude + radius * angle.sin(),
+                        center.longitude + radius * angle.cos() * 1.5,
+                    )
+                })
+                .collect();
+            let polygon = Polygon::new(exterior.clone());
+            let bounds = polygon.bounds().unwrap();
+            let ruler =
+                CheapRuler::new((bounds.min_latitude() + bounds.max_latitude()) / 2.0, unit);
+            let boundary_distance = |point: &Coordinate| {
+                (0..exterior.len())
+                    .map(|index| {
+                        ruler.point_to_segment_distance(
+                            point,
+                            &exterior[index],
+                            &exterior[(index + 1) % exterior.len()],
+                        )
+                    })
+                    .fold(f64::INFINITY, f64::min)
+            };
+
+            for (distance, join) in [
+                (0.08, OffsetJoin::Round),
+                (-0.08, OffsetJoin::Round),
+                (-0.03, OffsetJoin::Miter),
+            ] {
+                let result = polygon.offset(distance, &unit, join);
+                for sample in 0..500u64 {
+                    let seed = shape * 100_000 + sample * 2 + 50_000;
+                    let point = Coordinate::new(
+                        center.latitude + random(seed, 0.025),
+                        center.longitude + random(seed + 1, 0.035),
+                    );
+                    let gap = boundary_distance(&point);
+                    let inside = result.iter().any(|part| part.contains(&point));
+                    if (gap - distance.abs()).abs() < 0.02 * distance.abs() {
+                        continue;
+                    }
+                    // Miters reach up to the miter limit further than round joins
+                    let reach = match join {
+                        OffsetJoin::Round => distance.abs(),
+                        OffsetJoin::Miter => distance.abs() * MITER_LIMIT,
+                    };
+                    let contained = polygon.contains(&point);
+                    let expected = if distance > 0.0 {
+                        match (contained || gap < distance, gap > reach) {
+                            (true, _) => true,
+                            (false, true) => false,
+                            (false, false) => continue,
+                        }
+                    } else {
+                        match (contained && gap > -distance, gap > reach) {
+                            (false, _) if !contained || join == OffsetJoin::Round => false,
+                            (true, true) => true,
+                            (true, false) if join == OffsetJoin::Round => true,
+                            _ => continue,
+                        }
+                    };
+                    assert_eq!(expected, inside, "{} {} {:?}", shape, distance, point);
+                }
+            }
+        }
+    }
+}
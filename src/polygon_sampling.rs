@@ -0,0 +1,236 @@
+use crate::math;
+use crate::{Coordinate, Polygon};
+use rand::Rng;
+
+type Point = (f64, f64);
+
+/// Consecutive rejected samples after which the polygon is treated as having no area left
+const MAX_CONSECUTIVE_REJECTIONS: usize = 100_000;
+
+impl Polygon {
+    /// # Summary
+    /// Sample `n` random coordinates uniformly distributed over the polygon's surface area
+    ///
+    /// ## Notes
+    /// - The exterior ring is triangulated by ear clipping, a triangle is picked weighted by its
+    ///   area and a point drawn uniformly within it
+    /// - Points falling in a hole are rejected, as are points thinned out by `cos(latitude)` so the
+    ///   result is uniform on the sphere rather than in latitude / longitude space
+    /// - Returns an empty list for polygons without area
+    /// - Stops early with fewer than `n` points once `100_000` consecutive samples are rejected, so
+    ///   holes covering the whole exterior cannot hang the caller
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let square = |size: f64| vec![
+    ///     Coordinate::new(-size, -size),
+    ///     Coordinate::new(-size, size),
+    ///     Coordinate::new(size, size),
+    ///     Coordinate::new(size, -size),
+    /// ];
+    /// let frame = Polygon::with_interiors(square(2.0), vec![square(1.0)]);
+    ///
+    /// let points = frame.random_points(1000, &mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(1000, points.len());
+    /// assert!(points.iter().all(|point| frame.contains(point)));
+    /// ```
+    pub fn random_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<Coordinate> {
+        let triangles = triangulate(&self.exterior);
+        let mut cumulative_areas = Vec::with_capacity(triangles.len());
+        let mut total_area = 0.0;
+        for [a, b, c] in &triangles {
+            total_area += cross(*a, *b, *c).abs() / 2.0;
+            cumulative_areas.push(total_area);
+        }
+        if total_area <= 0.0 {
+            return Vec::new();
+        }
+
+        let max_cos_latitude = triangles
+            .iter()
+            .flatten()
+            .map(|(_, y)| *y)
+            .fold(None, |range: Option<Point>, y| {
+                range.map_or(Some((y, y)), |(min, max)| Some((min.min(y), max.max(y))))
+            })
+            .map_or(1.0, |(min, max)| {
+                if min <= 0.0 && max >= 0.0 {
+                    1.0
+                } else {
+                    math::cos(min.abs().min(max.abs()).to_radians())
+                }
+            });
+
+        let mut points = Vec::with_capacity(n);
+        let mut rejections = 0;
+        while points.len() < n && rejections < MAX_CONSECUTIVE_REJECTIONS {
+            let target = rng.random::<f64>() * total_area;
+            let index = cumulative_areas
+                .partition_point(|&area| area < target)
+                .min(triangles.len() - 1);
+            let [a, b, c] = triangles[index];
+
+            let (mut r1, mut r2) = (rng.random::<f64>(), rng.random::<f64>());
+            if r1 + r2 > 1.0 {
+                (r1, r2) = (1.0 - r1, 1.0 - r2);
+            }
+            let coordinate = Coordinate {
+                latitude: a.1 + r1 * (b.1 - a.1) + r2 * (c.1 - a.1),
+                longitude: a.0 + r1 * (b.0 - a.0) + r2 * (c.0 - a.0),
+            };
+
+            let keep = rng.random::<f64>() * max_cos_latitude
+                <= math::cos(coordinate.latitude.to_radians());
+            if keep && self.contains(&coordinate) {
+                points.push(coordinate);
+                rejections = 0;
+            } else {
+                rejections += 1;
+            }
+        }
+
+        points
+    }
+}
+
+/// # Summary
+/// Twice the signed area of the triangle `a`, `b`, `c`, positive when counterclockwise
+fn cross(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// # Summary
+/// Split a simple ring into triangles in longitude / latitude space by ear clipping
+fn triangulate(ring: &[Coordinate]) -> Vec<[Point; 3]> {
+    let mut points: Vec<Point> = ring
+        .iter()
+        .map(|coordinate| (coordinate.longitude, coordinate.latitude))
+        .collect();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    let twice_area: f64 = (0..points.len())
+        .map(|index| {
+            let (x1, y1) = points[index];
+            let (x2, y2) = points[(index + 1) % points.len()];
+            x1 * y2 - x2 * y1
+        })
+        .sum();
+    if twice_area < 0.0 {
+        points.reverse();
+    }
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::with_capacity(points.len() - 2);
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (0..count).find(|&position| {
+            let a = points[remaining[(position + count - 1) % count]];
+            let b = points[remaining[position]];
+            let c = points[remaining[(position + 1) % count]];
+            cross(a, b, c) > 0.0
+                && remaining.iter().all(|&other| {
+                    let p = points[other];
+                    p == a
+                        || p == b
+                        || p == c
+                        || cross(a, b, p) < 0.0
+                        || cross(b, c, p) < 0.0
+                        || cross(c, a, p) < 0.0
+                })
+        });
+
+        // Only degenerate rings (collinear or self intersecting) run out of ears
+        let Some(position) = ear else {
+            break;
+        };
+        triangles.push([
+            points[remaining[(position + count - 1) % count]],
+            points[remaining[position]],
+            points[remaining[(position + 1) % count]],
+        ]);
+        remaining.remove(position);
+    }
+    if remaining.len() == 3 {
+        triangles.push([
+            points[remaining[0]],
+            points[remaining[1]],
+            points[remaining[2]],
+        ]);
+    }
+
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangulation_covers_concave_ring() {
+        // L shaped ring with an area of 3 square degrees, clockwise
+        let ring = vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(2.0, 0.0),
+            Coordinate::new(2.0, 1.0),
+            Coordinate::new(1.0, 1.0),
+            Coordinate::new(1.0, 2.0),
+            Coordinate::new(0.0, 2.0),
+            Coordinate::new(0.0, 0.0),
+        ];
+        let triangles = triangulate(&ring);
+
+        assert_eq!(4, triangles.len());
+        let area: f64 = triangles
+            .iter()
+            .map(|[a, b, c]| cross(*a, *b, *c) / 2.0)
+            .sum();
+        assert_eq!(3.0, area);
+    }
+
+    #[test]
+    fn test_random_points_returns_when_holes_cover_exterior() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let square = |size: f64| {
+            vec![
+                Coordinate::new(-size, -size),
+                Coordinate::new(-size, size),
+                Coordinate::new(size, size),
+                Coordinate::new(size, -size),
+            ]
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let covered = Polygon::with_interiors(square(1.0), vec![square(1.0)]);
+        assert!(covered.random_points(10, &mut rng).is_empty());
+
+        // Two overlapping holes that together cover the exterior
+        let halves = Polygon::with_interiors(
+            square(1.0),
+            vec![
+                vec![
+                    Coordinate::new(-1.0, -1.0),
+                    Coordinate::new(-1.0, 0.5),
+                    Coordinate::new(1.0, 0.5),
+                    Coordinate::new(1.0, -1.0),
+                ],
+                vec![
+                    Coordinate::new(-1.0, -0.5),
+                    Coordinate::new(-1.0, 1.0),
+                    Coordinate::new(1.0, 1.0),
+                    Coordinate::new(1.0, -0.5),
+                ],
+            ],
+        );
+        assert!(halves.random_points(10, &mut rng).is_empty());
+    }
+}
@@ -0,0 +1,446 @@
+use crate::{CheapRuler, Coordinate, DistanceUnit, Polygon};
+use std::collections::{HashMap, HashSet};
+
+/// Exact identity of a vertex, shared borders are recognised by their vertices being bit for bit
+/// equal
+type VertexKey = (u64, u64);
+
+fn vertex_key(coordinate: &Coordinate) -> VertexKey {
+    // Adding zero turns -0.0 into 0.0 so both produce the same key
+    (
+        (coordinate.latitude + 0.0).to_bits(),
+        (coordinate.longitude + 0.0).to_bits(),
+    )
+}
+
+impl Polygon {
+    /// # Summary
+    /// Simplify the polygon with the Douglas-Peucker algorithm, removing vertices until no removed
+    /// vertex is further than `tolerance` from the simplified rings. See `simplify_polygons` for
+    /// simplifying neighbouring polygons together
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Polygon};
+    ///
+    /// let square = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 0.5),
+    ///     Coordinate::new(0.00001, 1.0),
+    ///     Coordinate::new(1.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(4, square.simplify(10.0, &DistanceUnit::Meters).exterior.len());
+    /// ```
+    pub fn simplify(&self, tolerance: f64, unit: &DistanceUnit) -> Polygon {
+        simplify_polygons(std::slice::from_ref(self), tolerance, unit).remove(0)
+    }
+}
+
+/// # Summary
+/// Simplify a set of neighbouring polygons, such as administrative boundaries, so that borders
+/// they share stay shared and no gaps or overlaps open up between them. Call once per tolerance to
+/// build a set for every zoom level
+///
+/// ## Notes
+/// - Borders are shared when both polygons have the same vertices along them, compared exactly.
+///   Junctions, where three or more edges meet, are never removed and every border between two
+///   junctions is simplified once, with the same result in both polygons
+/// - Uses Douglas-Peucker with `CheapRuler` distances, no removed vertex is further than
+///   `tolerance` from its simplified border
+/// - Rings are returned open, without repeating the first point. Rings which do not touch any
+///   other ring keep at least 3 vertices, a polygon whose borders are all straight between its
+///   junctions can collapse below that
+/// - Borders are simplified independently, large tolerances can still make different borders
+///   cross each other
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{simplify_polygons, Coordinate, DistanceUnit, Polygon};
+///
+/// // A wiggly border between a western and an eastern square
+/// let border = vec![
+///     Coordinate::new(0.0, 1.0),
+///     Coordinate::new(0.3, 1.00001),
+///     Coordinate::new(0.6, 0.99999),
+///     Coordinate::new(1.0, 1.0),
+/// ];
+/// let mut west = vec![Coordinate::new(1.0, 0.0), Coordinate::new(0.0, 0.0)];
+/// west.extend(border.iter().copied());
+/// let mut east = vec![Coordinate::new(0.0, 2.0), Coordinate::new(1.0, 2.0)];
+/// east.extend(border.iter().rev().copied());
+///
+/// let simplified = simplify_polygons(&[Polygon::new(west), Polygon::new(east)], 10.0, &DistanceUnit::Meters);
+///
+/// assert_eq!(4, simplified[0].exterior.len());
+/// assert_eq!(4, simplified[1].exterior.len());
+/// ```
+pub fn simplify_polygons(
+    polygons: &[Polygon],
+    tolerance: f64,
+    unit: &DistanceUnit,
+) -> Vec<Polygon> {
+    let cleaned: Vec<Polygon> = polygons
+        .iter()
+        .map(|polygon| {
+            Polygon::with_interiors(
+                clean_ring(&polygon.exterior),
+                polygon
+                    .interiors
+                    .iter()
+                    .map(|ring| clean_ring(ring))
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let mut neighbours: HashMap<VertexKey, HashSet<VertexKey>> = HashMap::new();
+    for ring in cleaned.iter().flat_map(rings) {
+        for (current, next) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            let (current, next) = (vertex_key(current), vertex_key(next));
+            neighbours.entry(current).or_default().insert(next);
+            neighbours.entry(next).or_default().insert(current);
+        }
+    }
+    let junctions: HashSet<VertexKey> = neighbours
+        .into_iter()
+        .filter(|(_, adjacent)| adjacent.len() > 2)
+        .map(|(key, _)| key)
+        .collect();
+
+    cleaned
+        .iter()
+        .map(|polygon| {
+            Polygon::with_interiors(
+                simplify_ring(&polygon.exterior, &junctions, tolerance, unit),
+                polygon
+                    .interiors
+                    .iter()
+                    .map(|ring| simplify_ring(ring, &junctions, tolerance, unit))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn rings(polygon: &Polygon) -> impl Iterator<Item = &Vec<Coordinate>> {
+    std::iter::once(&polygon.exterior).chain(&polygon.interiors)
+}
+
+/// # Summary
+/// Remove repeated consecutive vertices and the closing point of a ring
+fn clean_ring(ring: &[Coordinate]) -> Vec<Coordinate> {
+    let mut cleaned: Vec<Coordinate> = Vec::with_capacity(ring.len());
+    for coordinate in ring {
+        if cleaned.last().map(vertex_key) != Some(vertex_key(coordinate)) {
+            cleaned.push(*coordinate);
+        }
+    }
+    while cleaned.len() > 1 && vertex_key(&cleaned[0]) == vertex_key(&cleaned[cleaned.len() - 1]) {
+        cleaned.pop();
+    }
+
+    cleaned
+}
+
+fn simplify_ring(
+    ring: &[Coordinate],
+    junctions: &HashSet<VertexKey>,
+    tolerance: f64,
+    unit: &DistanceUnit,
+) -> Vec<Coordinate> {
+    if ring.len() < 4 {
+        return ring.to_vec();
+    }
+    let Some(start) = ring
+        .iter()
+        .position(|coordinate| junctions.contains(&vertex_key(coordinate)))
+    else {
+        return simplify_loop(ring, tolerance, unit);
+    };
+
+    // Walk the ring from its first junction, cutting it into arcs at every junction
+    let rotated: Vec<Coordinate> = ring[start..]
+        .iter()
+        .chain(&ring[..start])
+        .copied()
+        .collect();
+    let mut simplified = Vec::new();
+    let mut arc = vec![rotated[0]];
+    for coordinate in rotated[1..].iter().chain(std::iter::once(&rotated[0])) {
+        arc.push(*coordinate);
+        if junctions.contains(&vertex_key(coordinate)) {
+            let mut part = simplify_arc(&arc, tolerance, unit);
+            part.pop();
+            simplified.extend(part);
+            arc = vec![*coordinate];
+        }
+    }
+
+    simplified
+}
+
+/// # Summary
+/// Simplify an arc between two junctions in a direction which only depends on its vertices, so
+/// both polygons sharing it get the same result
+fn simplify_arc(arc: &[Coordinate], tolerance: f64, unit: &DistanceUnit) -> Vec<Coordinate> {
+    if arc
+        .iter()
+        .rev()
+        .map(vertex_key)
+        .lt(arc.iter().map(vertex_key))
+    {
+        let reversed: Vec<Coordinate> = arc.iter().rev().copied().collect();
+        let mut simplified = douglas_peucker(&reversed, tolerance, unit);
+        simplified.reverse();
+        simplified
+    } else {
+        douglas_peucker(arc, tolerance, unit)
+    }
+}
+
+/// # Summary
+/// Simplify a ring without junctions, anchored at its smallest vertex so an identical ring with a
+/// different start or winding, such as a hole filled by another polygon, gets the same result
+fn simplify_loop(ring: &[Coordinate], tolerance: f64, unit: &DistanceUnit) -> Vec<Coordinate> {
+    let start = (0..ring.len())
+        .min_by_key(|&index| vertex_key(&ring[index]))
+        .unwrap_or(0);
+    let forward: Vec<Coordinate> = ring[start..]
+        .iter()
+        .chain(&ring[..start])
+        .copied()
+        .collect();
+    let backward: Vec<Coordinate> = std::iter::once(forward[0])
+        .chain(forward[1..].iter().rev().copied())
+        .collect();
+    let reversed = backward
+        .iter()
+        .map(vertex_key)
+        .lt(forward.iter().map(vertex_key));
+    let canonical = if reversed { backward } else { forward };
+
+    let ruler = ruler_for(&canonical, unit);
+    let farthest = (1..canonical.len())
+        .max_by(|&a, &b| {
+            let distance = |index: usize| ruler.distance(&canonical[0], &canonical[index]);
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or(1);
+
+    let mut simplified = douglas_peucker(&canonical[..=farthest], tolerance, unit);
+    let mut second: Vec<Coordinate> = canonical[farthest..].to_vec();
+    second.push(canonical[0]);
+    simplified.pop();
+    simplified.extend(douglas_peucker(&second, tolerance, unit));
+    simplified.pop();
+
+    if simplified.len() < 3 {
+        // Keep the vertex furthest from the line through the two anchors so the ring keeps an area
+        let widest = (1..canonical.len())
+            .filter(|&index| index != farthest)
+            .max_by(|&a, &b| {
+                let distance = |index: usize| {
+                    ruler.point_to_segment_distance(
+                        &canonical[index],
+                        &canonical[0],
+                        &canonical[farthest],
+                    )
+                };
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(1);
+        let mut indices = [0, farthest, widest];
+        indices.sort_unstable();
+        simplified = indices.iter().map(|&index| canonical[index]).collect();
+    }
+    if reversed {
+        simplified.reverse();
+    }
+
+    simplified
+}
+
+/// # Summary
+/// Ruler for measuring along a run of vertices, centred on their latitude range
+fn ruler_for(points: &[Coordinate], unit: &DistanceUnit) -> CheapRuler {
+    let (min, max) = points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), point| {
+            (min.min(point.latitude), max.max(point.latitude))
+        });
+    CheapRuler::new((min + max) / 2.0, *unit)
+}
+
+/// # Summary
+/// Douglas-Peucker simplification of an open polyline, always keeping both ends
+fn douglas_peucker(points: &[Coordinate], tolerance: f64, unit: &DistanceUnit) -> Vec<Coordinate> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let ruler = ruler_for(points, unit);
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let (index, distance) = (first + 1..last)
+            .map(|index| {
+                let distance =
+                    ruler.point_to_segment_distance(&points[index], &points[first], &points[last]);
+                (index, distance)
+            })
+            .fold((first, f64::NEG_INFINITY), |best, current| {
+                if current.1 > best.1 {
+                    current
+                } else {
+                    best
+                }
+            });
+        if index != first && distance > tolerance {
+            keep[index] = true;
+            stack.push((first, index));
+            stack.push((index, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(point, _)| *point)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    fn wiggle(from: Coordinate, to: Coordinate, seed: u64) -> Vec<Coordinate> {
+        (0..=50)
+            .map(|step| {
+                let t = step as f64 / 50.0;
+                let offset = if step == 0 || step == 50 {
+                    0.0
+                } else {
+                    random(seed + step, 0.02)
+                };
+                Coordinate::new(
+                    from.latitude + (to.latitude - from.latitude) * t + offset,
+                    from.longitude + (to.longitude - from.longitude) * t - offset,
+                )
+            })
+            .collect()
+    }
+
+    fn keys(ring: &[Coordinate]) -> HashSet<VertexKey> {
+        ring.iter().map(vertex_key).collect()
+    }
+
+    #[test]
+    fn test_shared_borders_stay_shared() {
+        // Three countries meeting at a junction in the middle of a square
+        let middle = Coordinate::new(0.5, 0.5);
+        let north_border = wiggle(middle, Coordinate::new(1.0, 0.5), 100);
+        let west_border = wiggle(middle, Coordinate::new(0.5, 0.0), 200);
+        let east_border = wiggle(middle, Coordinate::new(0.5, 1.0), 300);
+
+        let mut north_west: Vec<Coordinate> = west_border.clone();
+        north_west.extend([Coordinate::new(1.0, 0.0)]);
+        north_west.extend(north_border.iter().rev().copied());
+        let mut north_east: Vec<Coordinate> = north_border.clone();
+        north_east.extend([Coordinate::new(1.0, 1.0)]);
+        north_east.extend(east_border.iter().rev().copied());
+        let mut south: Vec<Coordinate> = east_border.clone();
+        south.extend([Coordinate::new(0.0, 1.0), Coordinate::new(0.0, 0.0)]);
+        south.extend(west_border.iter().rev().copied());
+        // Closed ring with its first point repeated
+        south.push(south[0]);
+
+        let polygons = [
+            Polygon::new(north_west),
+            Polygon::new(north_east),
+            Polygon::new(south),
+        ];
+        let simplified = simplify_polygons(&polygons, 500.0, &DistanceUnit::Meters);
+
+        for border in [&north_border, &west_border, &east_border] {
+            let border = keys(border);
+            let kept: Vec<HashSet<VertexKey>> = simplified
+                .iter()
+                .map(|polygon| keys(&polygon.exterior))
+                .map(|ring| ring.intersection(&border).copied().collect())
+                .filter(|kept: &HashSet<VertexKey>| kept.len() > 1)
+                .collect();
+
+            assert_eq!(2, kept.len());
+            assert_eq!(kept[0], kept[1]);
+            assert!(kept[0].len() < border.len());
+        }
+        assert!(simplified
+            .iter()
+            .all(|polygon| keys(&polygon.exterior).contains(&vertex_key(&middle))));
+    }
+
+    #[test]
+    fn test_enclave_matches_hole() {
+        let mut ring = wiggle(Coordinate::new(0.2, 0.2), Coordinate::new(0.2, 0.8), 1);
+        ring.pop();
+        ring.extend(wiggle(
+            Coordinate::new(0.2, 0.8),
+            Coordinate::new(0.8, 0.5),
+            2,
+        ));
+        ring.pop();
+        ring.extend(wiggle(
+            Coordinate::new(0.8, 0.5),
+            Coordinate::new(0.2, 0.2),
+            3,
+        ));
+        ring.pop();
+
+        let mut hole = ring.clone();
+        hole.reverse();
+        hole.rotate_left(17);
+        let outer = vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 1.0),
+            Coordinate::new(1.0, 1.0),
+            Coordinate::new(1.0, 0.0),
+        ];
+
+        let simplified = simplify_polygons(
+            &[
+                Polygon::with_interiors(outer, vec![hole]),
+                Polygon::new(ring.clone()),
+            ],
+            1.0,
+            &DistanceUnit::Kilometers,
+        );
+
+        let hole = keys(&simplified[0].interiors[0]);
+        assert_eq!(hole, keys(&simplified[1].exterior));
+        assert!(hole.len() >= 3 && hole.len() < ring.len());
+    }
+
+    #[test]
+    fn test_collapsing_loop_keeps_three_vertices() {
+        let sliver = Polygon::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 0.5),
+            Coordinate::new(0.0, 1.0),
+            Coordinate::new(0.000001, 0.5),
+        ]);
+
+        assert_eq!(
+            3,
+            sliver
+                .simplify(1.0, &DistanceUnit::Kilometers)
+                .exterior
+                .len()
+        );
+    }
+}
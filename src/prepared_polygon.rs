@@ -0,0 +1,239 @@
+use crate::{Coordinate, CoordinateBoundaries, Polygon};
+
+/// Upper bound on the number of latitude bands edges are bucketed into
+const MAX_BANDS: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Edge {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// A polygon preprocessed for fast repeated `contains` tests, such as filtering millions of points
+///
+/// ## Notes
+/// - Points outside the bounding box are rejected immediately, the remaining points are only ray
+///   cast against the edges crossing their latitude band
+/// - Uses the even-odd rule over all rings at once, matching `Polygon::contains` for valid polygons
+///   whose holes lie inside the exterior without overlapping each other
+pub struct PreparedPolygon {
+    bounds: Option<CoordinateBoundaries>,
+    band_height: f64,
+    bands: Vec<Vec<Edge>>,
+}
+
+impl PreparedPolygon {
+    /// # Summary
+    /// Prepare a polygon for repeated `contains` tests
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon, PreparedPolygon};
+    ///
+    /// let triangle = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    /// let prepared = PreparedPolygon::new(&triangle);
+    ///
+    /// assert!(prepared.contains(&Coordinate::new(0.25, 0.25)));
+    /// ```
+    pub fn new(polygon: &Polygon) -> Self {
+        let bounds = polygon.bounds();
+        let edges: Vec<Edge> = std::iter::once(&polygon.exterior)
+            .chain(&polygon.interiors)
+            .flat_map(|ring| {
+                ring.iter().enumerate().map(|(index, current)| {
+                    let previous = &ring[(index + ring.len() - 1) % ring.len()];
+                    Edge {
+                        x1: current.longitude,
+                        y1: current.latitude,
+                        x2: previous.longitude,
+                        y2: previous.latitude,
+                    }
+                })
+            })
+            .collect();
+
+        let Some(bounds) = bounds else {
+            return Self {
+                bounds,
+                band_height: 1.0,
+                bands: Vec::new(),
+            };
+        };
+
+        let band_count = edges.len().clamp(1, MAX_BANDS);
+        let height = bounds.max_latitude() - bounds.min_latitude();
+        let band_height = if height > 0.0 {
+            height / band_count as f64
+        } else {
+            1.0
+        };
+
+        let mut prepared = Self {
+            bounds: Some(bounds),
+            band_height,
+            bands: vec![Vec::new(); band_count],
+        };
+        for edge in edges {
+            let first = prepared.band(edge.y1.min(edge.y2));
+            let last = prepared.band(edge.y1.max(edge.y2));
+            for band in &mut prepared.bands[first..=last] {
+                band.push(edge);
+            }
+        }
+
+        prepared
+    }
+
+    /// # Summary
+    /// Get the bounding box of the polygon. Returns `None` for an empty polygon
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon, PreparedPolygon};
+    ///
+    /// let triangle = PreparedPolygon::new(&Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]));
+    ///
+    /// assert_eq!(1.0, triangle.bounds().unwrap().max_longitude());
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        self.bounds
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the polygon and outside of all its holes
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon, PreparedPolygon};
+    ///
+    /// let square = |size: f64| vec![
+    ///     Coordinate::new(-size, -size),
+    ///     Coordinate::new(-size, size),
+    ///     Coordinate::new(size, size),
+    ///     Coordinate::new(size, -size),
+    /// ];
+    /// let frame = PreparedPolygon::new(&Polygon::with_interiors(square(2.0), vec![square(1.0)]));
+    ///
+    /// assert!(!frame.contains(&Coordinate::new(0.0, 0.0)));
+    /// assert!(frame.contains(&Coordinate::new(1.5, 0.0)));
+    /// assert!(!frame.contains(&Coordinate::new(3.0, 0.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        let Some(bounds) = &self.bounds else {
+            return false;
+        };
+        let (x, y) = (coordinate.longitude, coordinate.latitude);
+        if y < bounds.min_latitude()
+            || y > bounds.max_latitude()
+            || x < bounds.min_longitude()
+            || x > bounds.max_longitude()
+        {
+            return false;
+        }
+
+        let mut inside = false;
+        for edge in &self.bands[self.band(y)] {
+            if (edge.y1 > y) != (edge.y2 > y)
+                && x < (edge.x2 - edge.x1) * (y - edge.y1) / (edge.y2 - edge.y1) + edge.x1
+            {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    /// # Summary
+    /// Get the indices of the coordinates lying within the polygon
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon, PreparedPolygon};
+    ///
+    /// let triangle = PreparedPolygon::new(&Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]));
+    /// let points = vec![
+    ///     Coordinate::new(0.25, 0.25),
+    ///     Coordinate::new(0.75, 0.75),
+    ///     Coordinate::new(0.1, 0.5),
+    /// ];
+    ///
+    /// assert_eq!(vec![0, 2], triangle.filter_points(&points));
+    /// ```
+    pub fn filter_points(&self, points: &[Coordinate]) -> Vec<usize> {
+        points
+            .iter()
+            .enumerate()
+            .filter(|(_, coordinate)| self.contains(coordinate))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn band(&self, latitude: f64) -> usize {
+        let min_latitude = self.bounds.map_or(0.0, |bounds| bounds.min_latitude());
+        let band = ((latitude - min_latitude) / self.band_height).floor();
+
+        (band.max(0.0) as usize).min(self.bands.len().saturating_sub(1))
+    }
+}
+
+impl From<&Polygon> for PreparedPolygon {
+    fn from(value: &Polygon) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mix64;
+
+    #[test]
+    fn test_matches_polygon_contains() {
+        let random = |seed: u64| mix64(seed) as f64 / u64::MAX as f64;
+        // Irregular star shaped exterior with a star shaped hole
+        let star = |points: u64, radius: f64, offset: u64| -> Vec<Coordinate> {
+            (0..points)
+                .map(|index| {
+                    let angle = index as f64 / points as f64 * std::f64::consts::TAU;
+                    let distance = radius * (0.3 + 0.7 * random(index + offset));
+                    Coordinate::new(distance * angle.sin(), distance * angle.cos())
+                })
+                .collect()
+        };
+        let polygon = Polygon::with_interiors(star(200, 10.0, 0), vec![star(50, 2.0, 1000)]);
+        let prepared = PreparedPolygon::new(&polygon);
+
+        for seed in 0..20_000 {
+            let coordinate = Coordinate::new(
+                (random(seed * 2 + 5000) - 0.5) * 24.0,
+                (random(seed * 2 + 5001) - 0.5) * 24.0,
+            );
+            assert_eq!(
+                polygon.contains(&coordinate),
+                prepared.contains(&coordinate)
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_polygon_contains_nothing() {
+        let prepared = PreparedPolygon::new(&Polygon::default());
+        assert!(!prepared.contains(&Coordinate::new(0.0, 0.0)));
+    }
+}
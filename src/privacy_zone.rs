@@ -0,0 +1,230 @@
+use crate::spherical::{
+    angular_distance, destination, distance_to_angle, dot, from_unit_vector, normalize,
+    to_unit_vector,
+};
+use crate::{math, Coordinate, DistanceUnit, Track};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// ## Summary
+/// What happens to points recorded inside a `PrivacyZone`
+pub enum PrivacyAction {
+    /// Drop the point
+    #[default]
+    Remove,
+    /// Move the point outwards from the nearest home onto the edge of its zone
+    SnapToBoundary,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// Circles of `radius` around sensitive locations, such as a user's home, whose points are
+/// redacted from shared tracks
+pub struct PrivacyZone {
+    pub homes: Vec<Coordinate>,
+    pub radius: f64,
+    pub unit: DistanceUnit,
+    pub action: PrivacyAction,
+}
+
+impl PrivacyZone {
+    /// # Summary
+    /// Construct a new PrivacyZone
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, PrivacyAction, PrivacyZone};
+    ///
+    /// let zone = PrivacyZone::new(vec![Coordinate::new(52.52, 13.405)], 500.0, DistanceUnit::Meters, PrivacyAction::Remove);
+    /// assert_eq!(1, zone.homes.len());
+    /// ```
+    pub fn new(
+        homes: Vec<Coordinate>,
+        radius: f64,
+        unit: DistanceUnit,
+        action: PrivacyAction,
+    ) -> Self {
+        Self {
+            homes,
+            radius,
+            unit,
+            action,
+        }
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within `radius` of any home, edge included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, PrivacyAction, PrivacyZone};
+    ///
+    /// let zone = PrivacyZone::new(vec![Coordinate::new(0.0, 0.0)], 1.0, DistanceUnit::Kilometers, PrivacyAction::Remove);
+    ///
+    /// assert!(zone.contains(&Coordinate::new(0.005, 0.0)));
+    /// assert!(!zone.contains(&Coordinate::new(0.01, 0.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        self.homes
+            .iter()
+            .any(|home| home.get_distance_from(coordinate, &self.unit) <= self.radius)
+    }
+
+    /// # Summary
+    /// Redact a single coordinate. Coordinates outside of the zone are returned unchanged, those
+    /// inside are removed or snapped depending on `action`
+    ///
+    /// ## Notes
+    /// - Snapped points are moved along the great circle from the nearest home, keeping their
+    ///   bearing from it. A point exactly on a home is moved due north
+    /// - A snapped point which lands inside the zone of another home is removed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, PrivacyAction, PrivacyZone};
+    ///
+    /// let home = Coordinate::new(0.0, 0.0);
+    /// let mut zone = PrivacyZone::new(vec![home], 1.0, DistanceUnit::Kilometers, PrivacyAction::Remove);
+    ///
+    /// assert_eq!(None, zone.redact(&Coordinate::new(0.0, 0.005)));
+    /// assert_eq!(Some(Coordinate::new(0.0, 0.05)), zone.redact(&Coordinate::new(0.0, 0.05)));
+    ///
+    /// zone.action = PrivacyAction::SnapToBoundary;
+    /// let snapped = zone.redact(&Coordinate::new(0.0, 0.005)).unwrap();
+    ///
+    /// assert_eq!(0.0, snapped.latitude);
+    /// assert!((home.get_distance_from(&snapped, &DistanceUnit::Kilometers) - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn redact(&self, coordinate: &Coordinate) -> Option<Coordinate> {
+        let nearest = self
+            .homes
+            .iter()
+            .map(|home| (home, home.get_distance_from(coordinate, &self.unit)))
+            .filter(|(_, distance)| *distance <= self.radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((home, _)) = nearest else {
+            return Some(*coordinate);
+        };
+
+        match self.action {
+            PrivacyAction::Remove => None,
+            PrivacyAction::SnapToBoundary => {
+                let snapped = self.snap(home, coordinate);
+                let inside_other = self.homes.iter().any(|other| {
+                    other != home && other.get_distance_from(snapped, &self.unit) <= self.radius
+                });
+                (!inside_other).then_some(snapped)
+            }
+        }
+    }
+
+    /// # Summary
+    /// Redact every point of a track, see `PrivacyZone::redact`. Snapped points keep their
+    /// timestamps
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, PrivacyAction, PrivacyZone, Track, TrackPoint};
+    ///
+    /// let zone = PrivacyZone::new(vec![Coordinate::new(0.0, 0.0)], 1.0, DistanceUnit::Kilometers, PrivacyAction::Remove);
+    /// let track = Track::new(
+    ///     (0..10)
+    ///         .map(|i| TrackPoint::new(Coordinate::new(0.0, i as f64 * 0.005), i * 1000))
+    ///         .collect(),
+    /// );
+    ///
+    /// let redacted = zone.redact_track(&track);
+    /// assert_eq!(8, redacted.points.len());
+    /// assert_eq!(2000, redacted.points[0].timestamp);
+    /// ```
+    pub fn redact_track(&self, track: &Track) -> Track {
+        Track::new(
+            track
+                .points
+                .iter()
+                .filter_map(|point| {
+                    let mut point = *point;
+                    point.coordinate = self.redact(&point.coordinate)?;
+                    Some(point)
+                })
+                .collect(),
+        )
+    }
+
+    fn snap(&self, home: &Coordinate, coordinate: &Coordinate) -> Coordinate {
+        let radius = distance_to_angle(self.radius, &self.unit);
+        if angular_distance(home, coordinate) < 1e-12 {
+            return destination(home, 0.0, radius);
+        }
+
+        // Component of the coordinate perpendicular to the home, pointing away from it
+        let h = to_unit_vector(home);
+        let p = to_unit_vector(coordinate);
+        let along = dot(h, p);
+        let direction = normalize([
+            p[0] - along * h[0],
+            p[1] - along * h[1],
+            p[2] - along * h[2],
+        ]);
+        let (sin_radius, cos_radius) = math::sin_cos(radius);
+
+        from_unit_vector([
+            cos_radius * h[0] + sin_radius * direction[0],
+            cos_radius * h[1] + sin_radius * direction[1],
+            cos_radius * h[2] + sin_radius * direction[2],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_snapped_points_keep_bearing() {
+        let home = Coordinate::new(59.9, 10.7);
+        let zone = PrivacyZone::new(
+            vec![home],
+            400.0,
+            DistanceUnit::Meters,
+            PrivacyAction::SnapToBoundary,
+        );
+
+        for seed in 0..200u64 {
+            let point = Coordinate::new(
+                home.latitude + random(seed * 2, 0.006),
+                home.longitude + random(seed * 2 + 1, 0.012),
+            );
+            let redacted = zone.redact(&point).unwrap();
+            if !zone.contains(&point) {
+                assert_eq!(point, redacted);
+                continue;
+            }
+
+            let distance = home.get_distance_from(redacted, &DistanceUnit::Meters);
+            assert!((distance - 400.0).abs() < 1e-6, "distance {}", distance);
+            let moved = home.get_distance_from(point, &DistanceUnit::Meters)
+                + point.get_distance_from(redacted, &DistanceUnit::Meters);
+            assert!((moved - distance).abs() < 1e-6, "not moved outwards");
+        }
+    }
+
+    #[test]
+    fn test_snapping_into_another_zone_removes() {
+        let zone = PrivacyZone::new(
+            vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.015)],
+            1.0,
+            DistanceUnit::Kilometers,
+            PrivacyAction::SnapToBoundary,
+        );
+
+        assert_eq!(None, zone.redact(&Coordinate::new(0.0, 0.006)));
+        assert!(zone.redact(&Coordinate::new(0.0, -0.006)).is_some());
+    }
+}
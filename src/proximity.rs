@@ -0,0 +1,410 @@
+use crate::grid_index::GridIndex;
+use crate::spherical::{from_unit_vector, to_unit_vector};
+use crate::utils::linear_divisor;
+use crate::{meters_per_degree_latitude, Coordinate, CoordinateBoundaries, DistanceUnit};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// A coordinate matched by a proximity query, along with where it came from and how far away it is
+pub struct ProximityMatch {
+    /// Index of the matched coordinate in the queried slice
+    pub index: usize,
+    pub coordinate: Coordinate,
+    /// Distance from the query origin, in the query unit
+    pub distance: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// A fluent "within `radius` of `origin`" query, created with `Coordinate::within`
+pub struct ProximityQuery {
+    origin: Coordinate,
+    radius: f64,
+    unit: DistanceUnit,
+}
+
+impl ProximityQuery {
+    pub(crate) fn new(origin: Coordinate, radius: f64, unit: DistanceUnit) -> Self {
+        Self {
+            origin,
+            radius,
+            unit,
+        }
+    }
+
+    /// # Summary
+    /// Check a single coordinate, returning a match with index `0` if it is within the radius
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let origin = Coordinate::new(0.0, 0.0);
+    ///
+    /// assert!(origin.within(200.0, DistanceUnit::Kilometers).of(&Coordinate::new(1.0, 1.0)).is_some());
+    /// assert!(origin.within(100.0, DistanceUnit::Kilometers).of(&Coordinate::new(1.0, 1.0)).is_none());
+    /// ```
+    pub fn of(&self, coordinate: &Coordinate) -> Option<ProximityMatch> {
+        self.check(0, coordinate)
+    }
+
+    /// # Summary
+    /// Get the first coordinate, in slice order, which is within the radius.
+    /// Stops checking as soon as a match is found
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let points = vec![Coordinate::new(5.0, 5.0), Coordinate::new(0.01, 0.01), Coordinate::new(0.0, 0.02)];
+    /// let found = Coordinate::new(0.0, 0.0)
+    ///     .within(5.0, DistanceUnit::Kilometers)
+    ///     .of_any(&points)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, found.index);
+    /// ```
+    pub fn of_any(&self, points: &[Coordinate]) -> Option<ProximityMatch> {
+        points
+            .iter()
+            .enumerate()
+            .find_map(|(index, coordinate)| self.check(index, coordinate))
+    }
+
+    /// # Summary
+    /// Get every coordinate within the radius, sorted from nearest to farthest
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let points = vec![Coordinate::new(0.02, 0.0), Coordinate::new(5.0, 5.0), Coordinate::new(0.01, 0.0)];
+    /// let found = Coordinate::new(0.0, 0.0)
+    ///     .within(5.0, DistanceUnit::Kilometers)
+    ///     .all_of(&points);
+    ///
+    /// assert_eq!(vec![2, 0], found.iter().map(|m| m.index).collect::<Vec<_>>());
+    /// ```
+    pub fn all_of(&self, points: &[Coordinate]) -> Vec<ProximityMatch> {
+        let mut matches: Vec<ProximityMatch> = points
+            .iter()
+            .enumerate()
+            .filter_map(|(index, coordinate)| self.check(index, coordinate))
+            .collect();
+        matches.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        matches
+    }
+
+    /// # Summary
+    /// Get the closest coordinate, if any is within the radius
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let points = vec![Coordinate::new(0.02, 0.0), Coordinate::new(0.01, 0.0)];
+    /// let closest = Coordinate::new(0.0, 0.0)
+    ///     .within(5.0, DistanceUnit::Kilometers)
+    ///     .closest(&points)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(1, closest.index);
+    /// assert_eq!(1.11, (closest.distance * 100.0).round() / 100.0);
+    /// ```
+    pub fn closest(&self, points: &[Coordinate]) -> Option<ProximityMatch> {
+        points
+            .iter()
+            .enumerate()
+            .filter_map(|(index, coordinate)| self.check(index, coordinate))
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// # Summary
+    /// Count the coordinates within the radius
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let points = vec![Coordinate::new(0.02, 0.0), Coordinate::new(5.0, 5.0)];
+    /// let count = Coordinate::new(0.0, 0.0)
+    ///     .within(5.0, DistanceUnit::Kilometers)
+    ///     .count_of(&points);
+    ///
+    /// assert_eq!(1, count);
+    /// ```
+    pub fn count_of(&self, points: &[Coordinate]) -> usize {
+        points
+            .iter()
+            .filter(|coordinate| self.check(0, coordinate).is_some())
+            .count()
+    }
+
+    fn check(&self, index: usize, coordinate: &Coordinate) -> Option<ProximityMatch> {
+        let distance = self.origin.get_distance_from(coordinate, &self.unit);
+        (distance <= self.radius).then_some(ProximityMatch {
+            index,
+            coordinate: *coordinate,
+            distance,
+        })
+    }
+}
+
+/// # Summary
+/// Collapse coordinates which lie within `distance` of each other into a single representative
+///
+/// ## Notes
+/// - Points are clustered greedily in slice order: the first unclustered point claims every
+///   unclustered point within `distance` of it
+/// - Each cluster is represented by its member closest to the cluster's spherical mean, so the
+///   output only ever contains coordinates from the input
+/// - Runs in O(n²), intended for user submitted lists rather than bulk datasets
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{dedup_within, Coordinate, DistanceUnit};
+///
+/// let submissions = vec![
+///     Coordinate::new(51.50070, -0.12460),
+///     Coordinate::new(51.50075, -0.12455),
+///     Coordinate::new(51.50072, -0.12458),
+///     Coordinate::new(48.85837, 2.29448),
+/// ];
+///
+/// let deduplicated = dedup_within(&submissions, 25.0, &DistanceUnit::Meters);
+/// assert_eq!(vec![Coordinate::new(51.50072, -0.12458), Coordinate::new(48.85837, 2.29448)], deduplicated);
+/// ```
+pub fn dedup_within(points: &[Coordinate], distance: f64, unit: &DistanceUnit) -> Vec<Coordinate> {
+    let mut clustered = vec![false; points.len()];
+    let mut representatives = Vec::new();
+
+    for seed in 0..points.len() {
+        if clustered[seed] {
+            continue;
+        }
+        let members: Vec<usize> = (seed..points.len())
+            .filter(|&index| {
+                !clustered[index] && points[seed].get_distance_from(points[index], unit) <= distance
+            })
+            .collect();
+
+        let mut sum = [0.0; 3];
+        for &index in &members {
+            clustered[index] = true;
+            let vector = to_unit_vector(&points[index]);
+            (0..3).for_each(|axis| sum[axis] += vector[axis]);
+        }
+        let mean = from_unit_vector(sum);
+
+        let representative = members
+            .iter()
+            .map(|&index| points[index])
+            .min_by(|a, b| {
+                a.get_distance_from(mean, unit)
+                    .total_cmp(&b.get_distance_from(mean, unit))
+            })
+            .unwrap_or(points[seed]);
+        representatives.push(representative);
+    }
+
+    representatives
+}
+
+/// # Summary
+/// Match every `left` coordinate with every `right` coordinate within `max_distance` of it,
+/// returning `(left_index, right_index, distance)` triples
+///
+/// ## Notes
+/// - `right` is indexed in a grid sized to `max_distance`, so each left point is only compared
+///   against nearby right points instead of the whole slice
+/// - Results are ordered by left index, then right index
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{spatial_join, Coordinate, DistanceUnit};
+///
+/// let readings = vec![Coordinate::new(40.0, -105.0), Coordinate::new(41.0, -104.0)];
+/// let assets = vec![
+///     Coordinate::new(40.0005, -105.0),
+///     Coordinate::new(40.5, -105.0),
+///     Coordinate::new(40.0, -105.0005),
+/// ];
+///
+/// let matches = spatial_join(&readings, &assets, 100.0, &DistanceUnit::Meters);
+///
+/// assert_eq!(2, matches.len());
+/// assert_eq!((0, 0), (matches[0].0, matches[0].1));
+/// assert_eq!((0, 2), (matches[1].0, matches[1].1));
+/// assert_eq!(56.0, matches[0].2.round());
+/// ```
+pub fn spatial_join(
+    left: &[Coordinate],
+    right: &[Coordinate],
+    max_distance: f64,
+    unit: &DistanceUnit,
+) -> Vec<(usize, usize, f64)> {
+    let cell_size = max_distance * linear_divisor(unit) / meters_per_degree_latitude(0.0);
+    let mut index = GridIndex::new(cell_size.max(1e-4));
+    for (item, coordinate) in right.iter().enumerate() {
+        index.insert(
+            item,
+            CoordinateBoundaries::new(*coordinate, max_distance, Some(*unit)),
+        );
+    }
+
+    left.iter()
+        .enumerate()
+        .flat_map(|(left_index, coordinate)| {
+            index
+                .candidates(coordinate)
+                .into_iter()
+                .filter_map(move |right_index| {
+                    let distance = coordinate.get_distance_from(right[right_index], unit);
+                    (distance <= max_distance).then_some((left_index, right_index, distance))
+                })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// ## Summary
+/// The changes between two sets of points, see `diff_point_sets`
+pub struct PointSetDiff {
+    /// Indices of the new points without a match in the old set
+    pub added: Vec<usize>,
+    /// Indices of the old points without a match in the new set
+    pub removed: Vec<usize>,
+    /// `(old_index, new_index)` pairs of matched points whose coordinates differ
+    pub moved: Vec<(usize, usize)>,
+}
+
+/// # Summary
+/// Compare an old and a new set of points, matching points within `tolerance` of each other, to
+/// find the minimal set of additions, removals and moves turning one into the other
+///
+/// ## Notes
+/// - Pairs are matched closest first, each point being matched at most once, so identical points
+///   are always paired with each other
+/// - Matched points with identical coordinates are unchanged and not reported
+/// - Every list is sorted by index, `moved` by old index
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{diff_point_sets, Coordinate, DistanceUnit};
+///
+/// let old = vec![
+///     Coordinate::new(52.5200, 13.4050),
+///     Coordinate::new(48.8566, 2.3522),
+///     Coordinate::new(51.5074, -0.1278),
+/// ];
+/// let new = vec![
+///     Coordinate::new(48.8566, 2.3522),
+///     Coordinate::new(52.5201, 13.4050),
+///     Coordinate::new(40.4168, -3.7038),
+/// ];
+///
+/// let diff = diff_point_sets(&old, &new, 50.0, &DistanceUnit::Meters);
+///
+/// assert_eq!(vec![2], diff.added);
+/// assert_eq!(vec![2], diff.removed);
+/// assert_eq!(vec![(0, 1)], diff.moved);
+/// ```
+pub fn diff_point_sets(
+    old: &[Coordinate],
+    new: &[Coordinate],
+    tolerance: f64,
+    unit: &DistanceUnit,
+) -> PointSetDiff {
+    let mut pairs = spatial_join(old, new, tolerance, unit);
+    pairs.sort_by(|a, b| a.2.total_cmp(&b.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+
+    let mut old_matched = vec![false; old.len()];
+    let mut new_matched = vec![false; new.len()];
+    let mut moved = Vec::new();
+    for (old_index, new_index, _) in pairs {
+        if old_matched[old_index] || new_matched[new_index] {
+            continue;
+        }
+        old_matched[old_index] = true;
+        new_matched[new_index] = true;
+        if old[old_index] != new[new_index] {
+            moved.push((old_index, new_index));
+        }
+    }
+    moved.sort_unstable();
+
+    let unmatched = |matched: Vec<bool>| {
+        matched
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, matched)| (!matched).then_some(index))
+            .collect()
+    };
+    PointSetDiff {
+        added: unmatched(new_matched),
+        removed: unmatched(old_matched),
+        moved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_spatial_join_matches_brute_force() {
+        // Clustered near the pole and the antimeridian, where bounding boxes are least accurate
+        let points = |offset: u64| -> Vec<Coordinate> {
+            (0..300)
+                .map(|seed| {
+                    Coordinate::new(
+                        89.0 + random(seed * 2 + offset, 2.0),
+                        180.0 + random(seed * 2 + offset + 1, 2.0),
+                    )
+                })
+                .collect()
+        };
+        let (left, right) = (points(0), points(10_000));
+
+        let joined = spatial_join(&left, &right, 20.0, &DistanceUnit::Kilometers);
+
+        let mut expected = Vec::new();
+        for (left_index, a) in left.iter().enumerate() {
+            for (right_index, b) in right.iter().enumerate() {
+                let distance = a.get_distance_from(b, &DistanceUnit::Kilometers);
+                if distance <= 20.0 {
+                    expected.push((left_index, right_index, distance));
+                }
+            }
+        }
+        assert!(!expected.is_empty());
+        assert_eq!(expected, joined);
+    }
+
+    #[test]
+    fn test_diff_prefers_closest_matches() {
+        let old = vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 0.0005)];
+        // The first new point is within tolerance of both old points, but closest to the second
+        let new = vec![Coordinate::new(0.0, 0.0004), Coordinate::new(0.0, 0.0)];
+
+        let diff = diff_point_sets(&old, &new, 100.0, &DistanceUnit::Meters);
+
+        assert_eq!(
+            PointSetDiff {
+                added: vec![],
+                removed: vec![],
+                moved: vec![(1, 0)],
+            },
+            diff
+        );
+        assert_eq!(
+            PointSetDiff {
+                added: vec![0],
+                removed: vec![1],
+                moved: vec![],
+            },
+            diff_point_sets(&old, &new, 1.0, &DistanceUnit::Meters)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{Coordinate, DistanceUnit};
+
+#[derive(Debug, Clone)]
+struct CacheEntry<T> {
+    key: Coordinate,
+    value: T,
+    last_used: u64,
+}
+
+#[derive(Debug, Clone)]
+/// ## Summary
+/// A least-recently-used cache keyed by coordinate, which serves any lookup within a configured
+/// distance of a cached key. Useful to avoid repeated reverse geocoding calls for nearby positions
+///
+/// ## Notes
+/// - When several cached keys are in range, the value of the nearest one is returned
+/// - Lookups and evictions scan every entry, so the cache suits capacities up to a few thousand entries
+pub struct ProximityCache<T> {
+    capacity: usize,
+    distance: f64,
+    unit: DistanceUnit,
+    entries: Vec<CacheEntry<T>>,
+    clock: u64,
+}
+
+impl<T> ProximityCache<T> {
+    /// # Summary
+    /// Construct a new cache holding at most `capacity` entries (at least 1), serving lookups
+    /// within `distance` of a cached key
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{DistanceUnit, ProximityCache};
+    ///
+    /// let cache: ProximityCache<String> = ProximityCache::new(100, 50.0, DistanceUnit::Meters);
+    /// assert_eq!(100, cache.capacity());
+    /// ```
+    pub fn new(capacity: usize, distance: f64, unit: DistanceUnit) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            distance,
+            unit,
+            entries: Vec::with_capacity(capacity),
+            clock: 0,
+        }
+    }
+
+    /// # Summary
+    /// Get the maximum number of entries the cache holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// # Summary
+    /// Get the number of entries currently cached
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// # Summary
+    /// Checks if the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// # Summary
+    /// Remove every entry from the cache
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// # Summary
+    /// Get the value of the nearest cached key within range of `coordinate`, marking it as recently used
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, ProximityCache};
+    ///
+    /// let mut cache = ProximityCache::new(10, 50.0, DistanceUnit::Meters);
+    /// cache.insert(Coordinate::new(51.5007, -0.1246), "Westminster");
+    ///
+    /// assert_eq!(Some(&"Westminster"), cache.get(&Coordinate::new(51.5008, -0.1247)));
+    /// assert_eq!(None, cache.get(&Coordinate::new(51.51, -0.1246)));
+    /// ```
+    pub fn get(&mut self, coordinate: &Coordinate) -> Option<&T> {
+        let index = self.find(coordinate)?;
+        self.touch(index);
+        Some(&self.entries[index].value)
+    }
+
+    /// # Summary
+    /// Cache `value` under `coordinate`, returning the least recently used entry if it had to be
+    /// evicted to make room
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, ProximityCache};
+    ///
+    /// let mut cache = ProximityCache::new(1, 50.0, DistanceUnit::Meters);
+    /// cache.insert(Coordinate::new(0.0, 0.0), 1);
+    ///
+    /// let evicted = cache.insert(Coordinate::new(10.0, 10.0), 2);
+    /// assert_eq!(Some((Coordinate::new(0.0, 0.0), 1)), evicted);
+    /// assert_eq!(1, cache.len());
+    /// ```
+    pub fn insert(&mut self, coordinate: Coordinate, value: T) -> Option<(Coordinate, T)> {
+        let evicted = if self.entries.len() >= self.capacity {
+            let (index, _) = self
+                .entries
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, entry)| entry.last_used)?;
+            let entry = self.entries.swap_remove(index);
+            Some((entry.key, entry.value))
+        } else {
+            None
+        };
+
+        self.clock += 1;
+        self.entries.push(CacheEntry {
+            key: coordinate,
+            value,
+            last_used: self.clock,
+        });
+        evicted
+    }
+
+    /// # Summary
+    /// Get the cached value in range of `coordinate`, or compute it with `compute` and cache it
+    /// under `coordinate` when none is in range
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, ProximityCache};
+    ///
+    /// let mut cache = ProximityCache::new(10, 50.0, DistanceUnit::Meters);
+    /// let mut lookups = 0;
+    ///
+    /// for coordinate in [Coordinate::new(51.5007, -0.1246), Coordinate::new(51.5008, -0.1247)] {
+    ///     cache.get_or_insert_with(coordinate, || {
+    ///         lookups += 1;
+    ///         "Westminster"
+    ///     });
+    /// }
+    ///
+    /// assert_eq!(1, lookups);
+    /// ```
+    pub fn get_or_insert_with(
+        &mut self,
+        coordinate: Coordinate,
+        compute: impl FnOnce() -> T,
+    ) -> &T {
+        let index = match self.find(&coordinate) {
+            Some(index) => {
+                self.touch(index);
+                index
+            }
+            None => {
+                self.insert(coordinate, compute());
+                self.entries.len() - 1
+            }
+        };
+        &self.entries[index].value
+    }
+
+    fn find(&self, coordinate: &Coordinate) -> Option<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (index, entry.key.get_distance_from(coordinate, &self.unit)))
+            .filter(|(_, distance)| *distance <= self.distance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    fn touch(&mut self, index: usize) {
+        self.clock += 1;
+        self.entries[index].last_used = self.clock;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut cache = ProximityCache::new(2, 10.0, DistanceUnit::Meters);
+        cache.insert(Coordinate::new(0.0, 0.0), "a");
+        cache.insert(Coordinate::new(1.0, 1.0), "b");
+
+        // Touching "a" makes "b" the least recently used entry
+        assert_eq!(Some(&"a"), cache.get(&Coordinate::new(0.0, 0.0)));
+        let evicted = cache.insert(Coordinate::new(2.0, 2.0), "c");
+
+        assert_eq!(Some((Coordinate::new(1.0, 1.0), "b")), evicted);
+        assert_eq!(Some(&"a"), cache.get(&Coordinate::new(0.0, 0.0)));
+        assert_eq!(Some(&"c"), cache.get(&Coordinate::new(2.0, 2.0)));
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Result of checking a coordinate against a radius, see `Coordinate::radius_check`
+pub struct RadiusResult {
+    /// Whether the coordinate lies within the radius, including its edge
+    pub inside: bool,
+    /// Great circle distance between the coordinates, in the requested unit
+    pub distance: f64,
+    /// Radius minus the distance, in the requested unit. Positive inside the radius and negative
+    /// outside of it
+    pub margin: f64,
+}
@@ -0,0 +1,81 @@
+use crate::spherical::{destination, distance_to_angle};
+use crate::{Circle, Coordinate, DistanceUnit, Polygon, Speed, SpeedUnit};
+use std::time::Duration;
+
+/// # Summary
+/// Get the region reachable from `origin` within `duration` when travelling in a straight line
+/// ("as the crow flies") at `speed`
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{reachable_region, Coordinate, DistanceUnit, Speed, SpeedUnit};
+/// use std::time::Duration;
+///
+/// let region = reachable_region(
+///     &Coordinate::new(52.52, 13.405),
+///     &Speed::new(20.0, SpeedUnit::KilometersPerHour),
+///     Duration::from_secs(15 * 60),
+/// );
+///
+/// assert_eq!(5.0, region.radius);
+/// assert_eq!(DistanceUnit::Kilometers, region.unit);
+/// ```
+pub fn reachable_region(origin: &Coordinate, speed: &Speed, duration: Duration) -> Circle {
+    Circle::new(
+        *origin,
+        reachable_distance(speed, duration),
+        DistanceUnit::Kilometers,
+    )
+}
+
+/// # Summary
+/// Get the region reachable from `origin` within `duration` when the straight line speed depends on
+/// the direction of travel, e.g. with a prevailing wind or current
+///
+/// ## Notes
+/// - `speed_at` receives a bearing in degrees clockwise from north
+/// - The region is sampled with `vertices` bearings (at least 3) starting due north and going clockwise
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{reachable_region_by_bearing, Coordinate, Speed, SpeedUnit};
+/// use std::time::Duration;
+///
+/// // Twice as fast heading east than heading west
+/// let region = reachable_region_by_bearing(&Coordinate::new(0.0, 0.0), Duration::from_secs(3600), 36, |bearing| {
+///     Speed::new(15.0 + 5.0 * bearing.to_radians().sin(), SpeedUnit::KilometersPerHour)
+/// });
+///
+/// assert_eq!(36, region.exterior.len());
+/// assert!(region.contains(&Coordinate::new(0.0, 0.15)));
+/// assert!(!region.contains(&Coordinate::new(0.0, -0.15)));
+/// ```
+pub fn reachable_region_by_bearing(
+    origin: &Coordinate,
+    duration: Duration,
+    vertices: usize,
+    speed_at: impl Fn(f64) -> Speed,
+) -> Polygon {
+    let vertices = vertices.max(3);
+
+    Polygon::new(
+        (0..vertices)
+            .map(|index| {
+                let bearing = 360.0 * index as f64 / vertices as f64;
+                let distance = reachable_distance(&speed_at(bearing), duration);
+                destination(
+                    origin,
+                    bearing,
+                    distance_to_angle(distance, &DistanceUnit::Kilometers),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// # Summary
+/// Distance in kilometers covered at `speed` during `duration`, never negative
+fn reachable_distance(speed: &Speed, duration: Duration) -> f64 {
+    let kilometers_per_hour = speed.to_unit(&SpeedUnit::KilometersPerHour).value;
+    (kilometers_per_hour * duration.as_secs_f64() / 3600.0).max(0.0)
+}
@@ -0,0 +1,506 @@
+use crate::math;
+use crate::spherical::{angle_to_distance, destination, distance_to_angle};
+use crate::{
+    Circle, Coordinate, CoordinateBoundaries, DistanceUnit, Geofence, OrientedBounds, Polygon,
+    PreparedPolygon, ScheduledGeofence, SphericalCap, TrackPoint,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of evenly spaced samples taken along a ray before refining a boundary crossing
+const RAYCAST_STEPS: u32 = 4096;
+/// Number of halvings used to refine a crossing, well below a millimeter for any ray on Earth
+const RAYCAST_BISECTIONS: u32 = 48;
+
+/// ## Summary
+/// Types which can tell whether they contain a value, such as a region containing a coordinate
+pub trait Contains<T> {
+    /// # Summary
+    /// Checks if `value` lies within `self`
+    fn contains(&self, value: &T) -> bool;
+}
+
+impl Contains<Coordinate> for Circle {
+    fn contains(&self, value: &Coordinate) -> bool {
+        Circle::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for Polygon {
+    fn contains(&self, value: &Coordinate) -> bool {
+        Polygon::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for CoordinateBoundaries {
+    fn contains(&self, value: &Coordinate) -> bool {
+        CoordinateBoundaries::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for OrientedBounds {
+    fn contains(&self, value: &Coordinate) -> bool {
+        OrientedBounds::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for Geofence {
+    fn contains(&self, value: &Coordinate) -> bool {
+        Geofence::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for PreparedPolygon {
+    fn contains(&self, value: &Coordinate) -> bool {
+        PreparedPolygon::contains(self, value)
+    }
+}
+
+impl Contains<Coordinate> for SphericalCap {
+    fn contains(&self, value: &Coordinate) -> bool {
+        SphericalCap::contains(self, value)
+    }
+}
+
+impl Contains<TrackPoint> for ScheduledGeofence {
+    fn contains(&self, value: &TrackPoint) -> bool {
+        ScheduledGeofence::contains(self, &value.coordinate, value.timestamp)
+    }
+}
+
+/// ## Summary
+/// Regions which can give a bounding box around everything they contain, for rejecting far away
+/// coordinates before running the full containment check
+///
+/// ## Notes
+/// - The box always covers every contained coordinate, for circles and oriented bounds it is
+///   computed on the sphere and can be slightly wider than their inherent `bounds`
+pub trait Bounded {
+    /// # Summary
+    /// Get the bounding box of the region. Returns `None` if it can not be represented, such as an
+    /// unbounded region. Caps reaching a pole are clamped to it and span every longitude
+    fn bounds(&self) -> Option<CoordinateBoundaries>;
+}
+
+impl Bounded for Circle {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        cap_bounds(&self.center, distance_to_angle(self.radius, &self.unit))
+    }
+}
+
+impl Bounded for Polygon {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        Polygon::bounds(self)
+    }
+}
+
+impl Bounded for CoordinateBoundaries {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        Some(*self)
+    }
+}
+
+impl Bounded for OrientedBounds {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        // The circle through the corners, widened to cover the tangent plane drifting from the
+        // sphere towards the edges
+        let half_diagonal = math::hypot(self.width, self.height) / 2.0;
+        cap_bounds(
+            &self.center,
+            distance_to_angle(half_diagonal, &self.unit) * 1.01,
+        )
+    }
+}
+
+impl Bounded for SphericalCap {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        cap_bounds(&self.center, self.radius.to_radians())
+    }
+}
+
+impl Bounded for Geofence {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        match self {
+            Geofence::Circle(circle) => Bounded::bounds(circle),
+            Geofence::Polygon(polygon) => Bounded::bounds(polygon),
+            Geofence::Bounds(bounds) => Bounded::bounds(bounds),
+            Geofence::Oriented(oriented) => Bounded::bounds(oriented),
+        }
+    }
+}
+
+impl Bounded for PreparedPolygon {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        PreparedPolygon::bounds(self)
+    }
+}
+
+impl Bounded for Region {
+    /// # Summary
+    /// Get a bounding box around the region. Unions cover both sides, intersections the narrower
+    /// side and negations are unbounded
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        match self {
+            Region::Fence(fence) => Bounded::bounds(fence),
+            Region::Union(a, b) => {
+                let (a, b) = (a.bounds()?, b.bounds()?);
+                CoordinateBoundaries::from_extents(
+                    a.min_latitude().min(b.min_latitude()),
+                    a.max_latitude().max(b.max_latitude()),
+                    a.min_longitude().min(b.min_longitude()),
+                    a.max_longitude().max(b.max_longitude()),
+                )
+            }
+            Region::Intersection(a, b) => match (a.bounds(), b.bounds()) {
+                (Some(a), Some(b)) => {
+                    let size = |bounds: &CoordinateBoundaries| {
+                        (bounds.max_latitude() - bounds.min_latitude())
+                            * (bounds.max_longitude() - bounds.min_longitude())
+                    };
+                    Some(if size(&b) < size(&a) { b } else { a })
+                }
+                (a, b) => a.or(b),
+            },
+            Region::Not(_) => None,
+        }
+    }
+}
+
+/// # Summary
+/// Get the bounding box of every coordinate within `angle` radians of `center` on the sphere
+fn cap_bounds(center: &Coordinate, angle: f64) -> Option<CoordinateBoundaries> {
+    // Leave room for rounding in the distance checks of the regions
+    let angle = (angle * (1.0 + 1e-9)).to_degrees();
+    let min_latitude = center.latitude - angle;
+    let max_latitude = center.latitude + angle;
+    if min_latitude <= -90.0 || max_latitude >= 90.0 {
+        return CoordinateBoundaries::from_extents(
+            min_latitude.max(-90.0),
+            max_latitude.min(90.0),
+            -180.0,
+            180.0,
+        );
+    }
+
+    // The widest part of the cap lies where a meridian touches it
+    let longitude =
+        math::asin(math::sin(angle.to_radians()) / math::cos(center.latitude.to_radians()))
+            .to_degrees();
+    CoordinateBoundaries::from_extents(
+        min_latitude,
+        max_latitude,
+        center.longitude - longitude,
+        center.longitude + longitude,
+    )
+}
+
+/// # Summary
+/// Checks if a region contains a coordinate, rejecting coordinates outside of `bounds` before
+/// running the full check. Pass bounds computed once up front, such as from `Bounded::bounds`,
+/// when testing many coordinates against the same region
+///
+/// ## Notes
+/// - `None` bounds skip the pre-check
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{contains_with_prefilter, Bounded, Coordinate, Polygon};
+///
+/// let triangle = Polygon::new(vec![
+///     Coordinate::new(0.0, 0.0),
+///     Coordinate::new(0.0, 1.0),
+///     Coordinate::new(1.0, 0.0),
+/// ]);
+/// let bounds = triangle.bounds();
+///
+/// assert!(contains_with_prefilter(&triangle, bounds.as_ref(), &Coordinate::new(0.25, 0.25)));
+/// assert!(!contains_with_prefilter(&triangle, bounds.as_ref(), &Coordinate::new(5.0, 5.0)));
+/// ```
+pub fn contains_with_prefilter<R: Contains<Coordinate> + ?Sized>(
+    region: &R,
+    bounds: Option<&CoordinateBoundaries>,
+    coordinate: &Coordinate,
+) -> bool {
+    if bounds.is_some_and(|bounds| !bounds.contains(coordinate)) {
+        return false;
+    }
+    region.contains(coordinate)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A region with its bounding box computed once, every `contains` check first rejects coordinates
+/// outside of the box
+pub struct Prefiltered<R> {
+    region: R,
+    bounds: Option<CoordinateBoundaries>,
+}
+
+impl<R: Bounded> Prefiltered<R> {
+    /// # Summary
+    /// Wrap a region, computing its bounding box
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Contains, Coordinate, DistanceUnit, Prefiltered};
+    ///
+    /// let zone = Prefiltered::new(Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers));
+    ///
+    /// assert!(zone.contains(&Coordinate::new(0.05, 0.05)));
+    /// assert!(!zone.contains(&Coordinate::new(1.0, 1.0)));
+    /// ```
+    pub fn new(region: R) -> Self {
+        let bounds = region.bounds();
+        Self { region, bounds }
+    }
+}
+
+impl<R> Prefiltered<R> {
+    /// # Summary
+    /// Get the wrapped region
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Prefiltered};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers);
+    /// assert_eq!(&circle, Prefiltered::new(circle).region());
+    /// ```
+    pub fn region(&self) -> &R {
+        &self.region
+    }
+
+    /// # Summary
+    /// Unwrap the region
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Prefiltered};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers);
+    /// assert_eq!(circle, Prefiltered::new(circle).into_inner());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.region
+    }
+}
+
+impl<R: Contains<Coordinate>> Contains<Coordinate> for Prefiltered<R> {
+    fn contains(&self, value: &Coordinate) -> bool {
+        contains_with_prefilter(&self.region, self.bounds.as_ref(), value)
+    }
+}
+
+impl<R> Bounded for Prefiltered<R> {
+    fn bounds(&self) -> Option<CoordinateBoundaries> {
+        self.bounds
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A region composed from geofences with union, intersection and negation, such as "inside the city
+/// polygon but outside every exclusion circle"
+///
+/// ## Notes
+/// - Composition is evaluated lazily per coordinate, no geometry is computed and checks short-circuit
+pub enum Region {
+    Fence(Geofence),
+    Union(Box<Region>, Box<Region>),
+    Intersection(Box<Region>, Box<Region>),
+    Not(Box<Region>),
+}
+
+impl Region {
+    /// # Summary
+    /// Construct the region of coordinates in either `a` or `b`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Contains, Coordinate, DistanceUnit, Region};
+    ///
+    /// let west = Circle::new(Coordinate::new(0.0, -1.0), 10.0, DistanceUnit::Kilometers);
+    /// let east = Circle::new(Coordinate::new(0.0, 1.0), 10.0, DistanceUnit::Kilometers);
+    /// let region = Region::union(west, east);
+    ///
+    /// assert!(region.contains(&Coordinate::new(0.0, 1.0)));
+    /// assert!(!region.contains(&Coordinate::new(0.0, 0.0)));
+    /// ```
+    pub fn union(a: impl Into<Region>, b: impl Into<Region>) -> Region {
+        Region::Union(Box::new(a.into()), Box::new(b.into()))
+    }
+
+    /// # Summary
+    /// Construct the region of coordinates in both `a` and `b`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Contains, Coordinate, DistanceUnit, Region};
+    ///
+    /// let west = Circle::new(Coordinate::new(0.0, -0.1), 15.0, DistanceUnit::Kilometers);
+    /// let east = Circle::new(Coordinate::new(0.0, 0.1), 15.0, DistanceUnit::Kilometers);
+    /// let region = Region::intersection(west, east);
+    ///
+    /// assert!(region.contains(&Coordinate::new(0.0, 0.0)));
+    /// assert!(!region.contains(&Coordinate::new(0.0, 0.1)));
+    /// ```
+    pub fn intersection(a: impl Into<Region>, b: impl Into<Region>) -> Region {
+        Region::Intersection(Box::new(a.into()), Box::new(b.into()))
+    }
+
+    /// # Summary
+    /// Construct the region of coordinates outside `region`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Contains, Coordinate, DistanceUnit, Polygon, Region};
+    ///
+    /// let city = Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    /// ]);
+    /// let airport = Circle::new(Coordinate::new(0.5, 0.5), 5.0, DistanceUnit::Kilometers);
+    /// let delivery_zone = Region::intersection(city, Region::not(airport));
+    ///
+    /// assert!(delivery_zone.contains(&Coordinate::new(0.2, 0.2)));
+    /// assert!(!delivery_zone.contains(&Coordinate::new(0.5, 0.5)));
+    /// assert!(!delivery_zone.contains(&Coordinate::new(2.0, 2.0)));
+    /// ```
+    pub fn not(region: impl Into<Region>) -> Region {
+        Region::Not(Box::new(region.into()))
+    }
+
+    /// # Summary
+    /// Follow the great circle leaving `origin` at `bearing` degrees clockwise from north and get
+    /// the first point where it crosses the boundary of the region, exiting it if `origin` is
+    /// inside and entering it otherwise, along with the distance to that point in `unit`.
+    /// Returns `None` if the boundary is not crossed within `max_distance`
+    ///
+    /// ## Notes
+    /// - The ray is sampled at 4096 evenly spaced points before the crossing is refined, parts of
+    ///   the region narrower than `max_distance / 4096` along the ray can be missed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Region};
+    ///
+    /// let region = Region::from(Circle::new(Coordinate::new(0.0, 0.0), 10.0, DistanceUnit::Kilometers));
+    /// let unit = DistanceUnit::Kilometers;
+    ///
+    /// let (exit, distance) = region.raycast(&Coordinate::new(0.0, 0.0), 90.0, 50.0, &unit).unwrap();
+    /// assert!((distance - 10.0).abs() < 1e-6);
+    /// assert!(exit.longitude > 0.0);
+    ///
+    /// let (_, distance) = region.raycast(&Coordinate::new(0.0, -0.5), 90.0, 50.0, &unit).unwrap();
+    /// assert!((distance - 45.6).abs() < 0.01);
+    ///
+    /// assert_eq!(None, region.raycast(&Coordinate::new(0.0, -0.5), 270.0, 50.0, &unit));
+    /// ```
+    pub fn raycast(
+        &self,
+        origin: &Coordinate,
+        bearing: f64,
+        max_distance: f64,
+        unit: &DistanceUnit,
+    ) -> Option<(Coordinate, f64)> {
+        if !max_distance.is_finite() || max_distance <= 0.0 {
+            return None;
+        }
+        // Thousands of samples are tested, compute the bounds once to skip far away ones quickly
+        let bounds = self.bounds();
+        let contains =
+            |coordinate: &Coordinate| contains_with_prefilter(self, bounds.as_ref(), coordinate);
+        let inside = contains(origin);
+        let max_angle = distance_to_angle(max_distance, unit);
+        let at = |angle: f64| destination(origin, bearing, angle);
+
+        let mut low = 0.0;
+        for step in 1..=RAYCAST_STEPS {
+            let mut high = max_angle * step as f64 / RAYCAST_STEPS as f64;
+            if contains(&at(high)) == inside {
+                low = high;
+                continue;
+            }
+
+            for _ in 0..RAYCAST_BISECTIONS {
+                let middle = (low + high) / 2.0;
+                if contains(&at(middle)) == inside {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            return Some((at(high), angle_to_distance(high, unit)));
+        }
+
+        None
+    }
+}
+
+impl Contains<Coordinate> for Region {
+    fn contains(&self, value: &Coordinate) -> bool {
+        match self {
+            Region::Fence(fence) => fence.contains(value),
+            Region::Union(a, b) => a.contains(value) || b.contains(value),
+            Region::Intersection(a, b) => a.contains(value) && b.contains(value),
+            Region::Not(region) => !region.contains(value),
+        }
+    }
+}
+
+impl<T: Into<Geofence>> From<T> for Region {
+    fn from(value: T) -> Self {
+        Region::Fence(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_bounds_cover_region() {
+        let unit = DistanceUnit::Kilometers;
+        let regions: Vec<Region> = vec![
+            Circle::new(Coordinate::new(60.0, 10.0), 300.0, unit).into(),
+            Circle::new(Coordinate::new(-85.0, 0.0), 800.0, unit).into(),
+            OrientedBounds::new(Coordinate::new(45.0, 179.9), 40.0, 10.0, 30.0, unit).into(),
+            Region::union(
+                Circle::new(Coordinate::new(0.0, 0.0), 100.0, unit),
+                Circle::new(Coordinate::new(1.0, 3.0), 50.0, unit),
+            ),
+            Region::intersection(
+                Circle::new(Coordinate::new(0.0, 0.0), 100.0, unit),
+                Region::not(Circle::new(Coordinate::new(0.0, 0.5), 20.0, unit)),
+            ),
+        ];
+
+        for (index, region) in regions.iter().enumerate() {
+            let prefiltered = Prefiltered::new(region.clone());
+            let center = match region {
+                Region::Fence(Geofence::Circle(circle)) => circle.center,
+                Region::Fence(Geofence::Oriented(oriented)) => oriented.center,
+                _ => Coordinate::new(0.0, 0.0),
+            };
+            for seed in 0..5000u64 {
+                let seed = index as u64 * 100_000 + seed * 2;
+                let point = Coordinate::new(
+                    (center.latitude + random(seed, 20.0)).clamp(-90.0, 90.0),
+                    center.longitude + random(seed + 1, 20.0),
+                );
+                assert_eq!(
+                    region.contains(&point),
+                    prefiltered.contains(&point),
+                    "{} {:?}",
+                    index,
+                    point
+                );
+            }
+        }
+    }
+}
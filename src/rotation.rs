@@ -0,0 +1,72 @@
+use crate::spherical::{cross, dot, from_unit_vector, to_unit_vector};
+use crate::{math, Coordinate};
+
+impl Coordinate {
+    /// # Summary
+    /// Rotate the coordinate around `center` by `angle` degrees clockwise, turning the local
+    /// tangent plane of `center` about its vertical axis
+    ///
+    /// ## Notes
+    /// - Keeps the distance from `center` and turns the bearing from `center` by `angle`, so shapes
+    ///   keep their angles, as needed for switching a map between north-up and heading-up
+    /// - Uses the same spherical Earth as `Coordinate::get_distance_from`, the rotation is exact on
+    ///   it at any distance
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit};
+    ///
+    /// let center = Coordinate::new(52.52, 13.405);
+    /// let north = Coordinate::new(52.53, 13.405);
+    /// let east = north.rotate_around(&center, 90.0);
+    ///
+    /// let inverse = center.inverse(&east, &DistanceUnit::Meters);
+    /// assert_eq!(90.0, inverse.initial_bearing.round());
+    /// assert_eq!(
+    ///     center.get_distance_from(&north, &DistanceUnit::Meters).round(),
+    ///     inverse.distance.round()
+    /// );
+    /// ```
+    pub fn rotate_around(&self, center: &Coordinate, angle: f64) -> Coordinate {
+        rotate_coordinates(std::slice::from_ref(self), center, angle)[0]
+    }
+}
+
+/// # Summary
+/// Rotate every coordinate around `center` by `angle` degrees clockwise, see
+/// `Coordinate::rotate_around`
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{rotate_coordinates, Coordinate};
+///
+/// let center = Coordinate::new(0.0, 0.0);
+/// let rotated = rotate_coordinates(&[Coordinate::new(0.0, 0.01), Coordinate::new(0.01, 0.0)], &center, 180.0);
+///
+/// assert!((rotated[0].longitude + 0.01).abs() < 1e-9);
+/// assert!((rotated[1].latitude + 0.01).abs() < 1e-9);
+/// ```
+pub fn rotate_coordinates(
+    points: &[Coordinate],
+    center: &Coordinate,
+    angle: f64,
+) -> Vec<Coordinate> {
+    // Rodrigues' rotation about the axis through the center, negative angles turn clockwise as
+    // seen from above like bearings do
+    let axis = to_unit_vector(center);
+    let (sin, cos) = math::sin_cos(-angle.to_radians());
+
+    points
+        .iter()
+        .map(|point| {
+            let vector = to_unit_vector(point);
+            let perpendicular = cross(axis, vector);
+            let along = dot(axis, vector) * (1.0 - cos);
+            from_unit_vector([
+                vector[0] * cos + perpendicular[0] * sin + axis[0] * along,
+                vector[1] * cos + perpendicular[1] * sin + axis[1] * along,
+                vector[2] * cos + perpendicular[2] * sin + axis[2] * along,
+            ])
+        })
+        .collect()
+}
@@ -0,0 +1,525 @@
+use crate::spherical::interpolate;
+use crate::{crossing_meridian, Coordinate, DistanceUnit, Speed, SpeedUnit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// The side a route turns towards, see `Route::turns`
+pub enum TurnDirection {
+    Left,
+    Right,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// A change of direction along a route, see `Route::turns`
+pub struct Turn {
+    /// Index of the route point where the turn happens
+    pub index: usize,
+    /// How far the direction changes, in degrees between 0 and 180
+    pub angle: f64,
+    pub direction: TurnDirection,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Default)]
+/// ## Summary
+/// Struct representing a fixed path through a sequence of coordinates, each leg following the
+/// great circle between consecutive points
+pub struct Route {
+    pub points: Vec<Coordinate>,
+}
+
+impl Route {
+    /// # Summary
+    /// Construct a new Route from its points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)]);
+    /// assert_eq!(2, route.points.len());
+    /// ```
+    pub fn new(points: Vec<Coordinate>) -> Self {
+        Self { points }
+    }
+
+    /// # Summary
+    /// Get the total length of the route
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Route};
+    ///
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(0.0, 2.0),
+    /// ]);
+    ///
+    /// assert_eq!(222.39, (route.length(&DistanceUnit::Kilometers) * 100.0).round() / 100.0);
+    /// ```
+    pub fn length(&self, unit: &DistanceUnit) -> f64 {
+        self.segment_lengths(unit).iter().sum()
+    }
+
+    /// # Summary
+    /// Get the travel time of the route, moving along leg `i` at `speed_profile[i]`.
+    /// Returns `None` unless there is exactly one positive speed per leg, or when the travel time
+    /// is too long to fit in a `Duration`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route, Speed, SpeedUnit};
+    ///
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(0.0, 2.0),
+    /// ]);
+    /// let profile = [
+    ///     Speed::new(100.0, SpeedUnit::KilometersPerHour),
+    ///     Speed::new(50.0, SpeedUnit::KilometersPerHour),
+    /// ];
+    ///
+    /// let eta = route.eta(&profile).unwrap();
+    /// assert_eq!(12009, eta.as_secs());
+    /// ```
+    pub fn eta(&self, speed_profile: &[Speed]) -> Option<Duration> {
+        let seconds = self.segment_durations(speed_profile)?.iter().sum();
+        Duration::try_from_secs_f64(seconds).ok()
+    }
+
+    /// # Summary
+    /// Get the position reached `elapsed` after starting the route, moving along leg `i` at
+    /// `speed_profile[i]`. Positions past the end of the route stay at the last point.
+    /// Returns `None` unless there is exactly one positive speed per leg
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route, Speed, SpeedUnit};
+    /// use std::time::Duration;
+    ///
+    /// let route = Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 2.0)]);
+    /// let profile = [Speed::new(100.0, SpeedUnit::KilometersPerHour)];
+    /// let eta = route.eta(&profile).unwrap();
+    ///
+    /// let halfway = route.position_at_time(&profile, eta / 2).unwrap();
+    /// assert!((halfway.longitude - 1.0).abs() < 1e-9);
+    ///
+    /// let after = route.position_at_time(&profile, eta * 2).unwrap();
+    /// assert_eq!(Coordinate::new(0.0, 2.0), after);
+    /// ```
+    pub fn position_at_time(
+        &self,
+        speed_profile: &[Speed],
+        elapsed: Duration,
+    ) -> Option<Coordinate> {
+        let durations = self.segment_durations(speed_profile)?;
+        let mut remaining = elapsed.as_secs_f64();
+
+        for (index, duration) in durations.iter().enumerate() {
+            if remaining < *duration {
+                return Some(interpolate(
+                    &self.points[index],
+                    &self.points[index + 1],
+                    remaining / duration,
+                ));
+            }
+            remaining -= duration;
+        }
+
+        self.points.last().copied()
+    }
+
+    /// # Summary
+    /// Get the initial bearing of every leg of the route, in degrees clockwise from north
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 1.0),
+    /// ]);
+    ///
+    /// assert_eq!(vec![90.0, 0.0], route.bearings());
+    /// ```
+    pub fn bearings(&self) -> Vec<f64> {
+        self.points
+            .windows(2)
+            .map(|leg| leg[0].bearing_to(leg[1]))
+            .collect()
+    }
+
+    /// # Summary
+    /// Get every point where the route changes direction by at least `threshold` degrees, for
+    /// generating simple turn-by-turn cues
+    ///
+    /// ## Notes
+    /// - The turn angle compares the bearing the route arrives at a point with, following the
+    ///   great circle of the previous leg, to the bearing it leaves with
+    /// - Legs of zero length are skipped, the turn is measured against the last leg that moved
+    /// - A complete reversal counts as a 180 degree left turn
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Route, TurnDirection};
+    ///
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 0.01),
+    ///     Coordinate::new(0.01, 0.01),
+    ///     Coordinate::new(0.02, 0.0102),
+    /// ]);
+    /// let turns = route.turns(30.0);
+    ///
+    /// assert_eq!(1, turns.len());
+    /// assert_eq!(1, turns[0].index);
+    /// assert_eq!(TurnDirection::Left, turns[0].direction);
+    /// assert_eq!(90.0, turns[0].angle.round());
+    ///
+    /// let distance = Route::new(route.points[..=turns[0].index].to_vec()).length(&DistanceUnit::Meters);
+    /// assert_eq!(1112.0, distance.round());
+    /// ```
+    pub fn turns(&self, threshold: f64) -> Vec<Turn> {
+        let mut turns = Vec::new();
+        let mut arriving: Option<f64> = None;
+
+        for (index, leg) in self.points.windows(2).enumerate() {
+            let inverse = leg[0].inverse(leg[1], &DistanceUnit::Meters);
+            if inverse.distance == 0.0 {
+                continue;
+            }
+            if let Some(arriving) = arriving {
+                let change = (inverse.initial_bearing - arriving + 540.0).rem_euclid(360.0) - 180.0;
+                if change.abs() >= threshold {
+                    turns.push(Turn {
+                        index,
+                        angle: change.abs(),
+                        direction: if change > 0.0 {
+                            TurnDirection::Right
+                        } else {
+                            TurnDirection::Left
+                        },
+                    });
+                }
+            }
+            arriving = Some(inverse.final_bearing);
+        }
+
+        turns
+    }
+
+    /// # Summary
+    /// Split the route wherever a leg crosses the antimeridian, ending one part at the exact
+    /// crossing point on one side and starting the next at the same point on the other side, so
+    /// renderers working in longitude / latitude space don't draw lines around the whole map
+    ///
+    /// ## Notes
+    /// - Crossing points have a longitude of exactly `180.0` or `-180.0`, matching the side of the
+    ///   part they belong to
+    /// - Points already on the antimeridian take the sign of the side their part lies on
+    /// - Routes with fewer than 2 points are returned as a single part
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(50.0, 170.0),
+    ///     Coordinate::new(50.0, -170.0),
+    ///     Coordinate::new(40.0, -160.0),
+    /// ]);
+    /// let parts = route.split_at_antimeridian();
+    ///
+    /// assert_eq!(2, parts.len());
+    /// assert_eq!(180.0, parts[0].points[1].longitude);
+    /// assert_eq!(-180.0, parts[1].points[0].longitude);
+    /// assert_eq!(parts[0].points[1].latitude, parts[1].points[0].latitude);
+    /// assert_eq!(3, parts[1].points.len());
+    /// ```
+    pub fn split_at_antimeridian(&self) -> Vec<Route> {
+        let Some((first, rest)) = self.points.split_first() else {
+            return vec![self.clone()];
+        };
+        let side = |latitude: f64, east: bool| Coordinate {
+            latitude,
+            longitude: if east { 180.0 } else { -180.0 },
+        };
+        let on_antimeridian = |coordinate: &Coordinate| coordinate.longitude.abs() == 180.0;
+
+        let mut parts = Vec::new();
+        let mut current = vec![*first];
+        for point in rest {
+            let previous = current[current.len() - 1];
+            let previous_east = previous.longitude > 0.0;
+
+            if on_antimeridian(point) {
+                current.push(side(point.latitude, previous_east));
+                continue;
+            }
+            let point_east = point.longitude > 0.0;
+
+            if on_antimeridian(&previous) {
+                if previous_east != point_east {
+                    if current.len() > 1 {
+                        parts.push(Route::new(std::mem::take(&mut current)));
+                    } else {
+                        current.clear();
+                    }
+                    current.push(side(previous.latitude, point_east));
+                }
+            } else if let Some(crossing) = crossing_meridian(&[previous, *point], 180.0).first() {
+                current.push(side(crossing.latitude, previous_east));
+                parts.push(Route::new(std::mem::take(&mut current)));
+                current.push(side(crossing.latitude, point_east));
+            }
+            current.push(*point);
+        }
+        parts.push(Route::new(current));
+
+        parts
+    }
+
+    /// # Summary
+    /// Get markers every `distance` along the route with their distance from the start, such as
+    /// kilometer posts or the points of a pacing chart
+    ///
+    /// ## Notes
+    /// - Markers are placed at every multiple of `distance` from the start, including the start
+    ///   itself, up to and including the end of the route
+    /// - Legs are followed along the great circle between points
+    /// - Returns no markers for empty routes or a `distance` which is not positive and finite
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Route};
+    ///
+    /// // About 222 km along the equator
+    /// let route = Route::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(0.0, 2.0),
+    /// ]);
+    /// let markers = route.markers_every(50.0, &DistanceUnit::Kilometers);
+    ///
+    /// assert_eq!(5, markers.len());
+    /// assert_eq!((Coordinate::new(0.0, 0.0), 0.0), markers[0]);
+    /// assert_eq!(150.0, markers[3].1);
+    /// assert_eq!(1.349, (markers[3].0.longitude * 1000.0).round() / 1000.0);
+    /// ```
+    pub fn markers_every(&self, distance: f64, unit: &DistanceUnit) -> Vec<(Coordinate, f64)> {
+        if self.points.is_empty() || !(distance > 0.0 && distance.is_finite()) {
+            return Vec::new();
+        }
+
+        let mut markers = vec![(self.points[0], 0.0)];
+        let mut travelled = 0.0;
+        for (leg, length) in self.points.windows(2).zip(self.segment_lengths(unit)) {
+            // Markers are placed at multiples of `distance` so rounding does not add up
+            loop {
+                let along = markers.len() as f64 * distance;
+                if along > travelled + length {
+                    break;
+                }
+                let fraction = if length > 0.0 {
+                    (along - travelled) / length
+                } else {
+                    1.0
+                };
+                markers.push((interpolate(&leg[0], &leg[1], fraction), along));
+            }
+            travelled += length;
+        }
+
+        markers
+    }
+
+    /// # Summary
+    /// Render the route as a PostGIS EWKT line string in the given SRID
+    ///
+    /// ## Notes
+    /// - Coordinates are written in `longitude latitude` order as PostGIS expects
+    /// - Routes without points are written as `LINESTRING EMPTY`, a single point is repeated since a
+    ///   line string needs at least two
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Route};
+    ///
+    /// let route = Route::new(vec![Coordinate::new(48.1372, 11.5756), Coordinate::new(48.2, 11.6)]);
+    ///
+    /// assert_eq!(
+    ///     "SRID=4326;LINESTRING(11.5756 48.1372,11.6 48.2)",
+    ///     route.to_ewkt(4326)
+    /// );
+    /// assert_eq!("SRID=4326;LINESTRING EMPTY", Route::new(vec![]).to_ewkt(4326));
+    /// assert_eq!(
+    ///     "SRID=4326;LINESTRING(11.5756 48.1372,11.5756 48.1372)",
+    ///     Route::new(vec![Coordinate::new(48.1372, 11.5756)]).to_ewkt(4326)
+    /// );
+    /// ```
+    pub fn to_ewkt(&self, srid: u32) -> String {
+        if self.points.is_empty() {
+            return format!("SRID={};LINESTRING EMPTY", srid);
+        }
+        let mut points: Vec<String> = self
+            .points
+            .iter()
+            .map(|point| format!("{} {}", point.longitude, point.latitude))
+            .collect();
+        if let [point] = points.as_slice() {
+            points.push(point.clone());
+        }
+
+        format!("SRID={};LINESTRING({})", srid, points.join(","))
+    }
+
+    /// # Summary
+    /// Get the length of every leg of the route
+    fn segment_lengths(&self, unit: &DistanceUnit) -> Vec<f64> {
+        self.points
+            .windows(2)
+            .map(|leg| leg[0].get_distance_from(leg[1], unit))
+            .collect()
+    }
+
+    /// # Summary
+    /// Get the travel time in seconds of every leg, or `None` if the profile does not match the legs
+    fn segment_durations(&self, speed_profile: &[Speed]) -> Option<Vec<f64>> {
+        if self.points.is_empty() || speed_profile.len() != self.points.len() - 1 {
+            return None;
+        }
+
+        self.segment_lengths(&DistanceUnit::Meters)
+            .iter()
+            .zip(speed_profile)
+            .map(|(meters, speed)| {
+                let meters_per_second = speed.to_unit(&SpeedUnit::MetersPerSecond).value;
+                (meters_per_second > 0.0).then(|| meters / meters_per_second)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiny_speeds_do_not_overflow_duration() {
+        let route = Route::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)]);
+        let crawl = [Speed::new(1e-300, SpeedUnit::MetersPerSecond)];
+
+        assert_eq!(None, route.eta(&crawl));
+        let position = route.position_at_time(&crawl, Duration::MAX).unwrap();
+        assert!(position.longitude < 1e-9);
+    }
+
+    #[test]
+    fn test_split_at_points_on_antimeridian() {
+        let route = Route::new(vec![
+            Coordinate::new(0.0, 180.0),
+            Coordinate::new(1.0, -170.0),
+            Coordinate::new(2.0, -180.0),
+            Coordinate::new(3.0, 170.0),
+        ]);
+        let parts = route.split_at_antimeridian();
+
+        assert_eq!(2, parts.len());
+        assert_eq!(
+            vec![-180.0, -170.0, -180.0],
+            parts[0]
+                .points
+                .iter()
+                .map(|point| point.longitude)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![180.0, 170.0],
+            parts[1]
+                .points
+                .iter()
+                .map(|point| point.longitude)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            1,
+            Route::new(vec![
+                Coordinate::new(0.0, 10.0),
+                Coordinate::new(0.0, -10.0)
+            ])
+            .split_at_antimeridian()
+            .len()
+        );
+    }
+
+    #[test]
+    fn test_turns_skip_zero_length_legs() {
+        let route = Route::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 0.01),
+            Coordinate::new(0.0, 0.01),
+            Coordinate::new(-0.01, 0.01),
+            Coordinate::new(-0.01, 0.0),
+            Coordinate::new(-0.01, 0.01),
+        ]);
+        let turns = route.turns(45.0);
+
+        assert_eq!(
+            vec![
+                (2, TurnDirection::Right),
+                (3, TurnDirection::Right),
+                (4, TurnDirection::Left)
+            ],
+            turns
+                .iter()
+                .map(|turn| (turn.index, turn.direction))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(90.0, turns[0].angle.round());
+        assert_eq!(180.0, turns[2].angle.round());
+    }
+
+    #[test]
+    fn test_markers_keep_spacing_across_legs() {
+        let route = Route::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 0.01),
+            Coordinate::new(0.0, 0.01),
+            Coordinate::new(0.013, 0.01),
+            Coordinate::new(0.013, 0.03),
+        ]);
+        let unit = DistanceUnit::Meters;
+        let markers = route.markers_every(100.0, &unit);
+
+        assert_eq!(
+            (route.length(&unit) / 100.0).floor() as usize + 1,
+            markers.len()
+        );
+        // Only the two pairs around the corners cut across them
+        let mut cut_corners = 0;
+        for pair in markers.windows(2) {
+            assert_eq!(100.0, pair[1].1 - pair[0].1);
+            let straight = pair[0].0.get_distance_from(pair[1].0, &unit);
+            assert!(straight < 100.0 + 1e-6);
+            if straight < 100.0 - 1e-6 {
+                cut_corners += 1;
+            }
+        }
+        assert_eq!(2, cut_corners);
+        assert!(Route::default().markers_every(100.0, &unit).is_empty());
+        assert!(route.markers_every(0.0, &unit).is_empty());
+    }
+}
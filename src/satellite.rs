@@ -0,0 +1,239 @@
+use crate::math;
+use crate::spherical::{angle_to_distance, angular_distance};
+use crate::utils::{linear_divisor, EARTH_RADIUS_KM};
+use crate::{Circle, Coordinate, DistanceUnit, Route};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Altitude of a geostationary orbit above the equator, in meters
+const GEOSTATIONARY_ALTITUDE_M: f64 = 35_786_000.0;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Direction and distance from an observer on the ground to a satellite, see
+/// `SatellitePosition::look_angles`
+pub struct LookAngles {
+    /// Degrees clockwise from north, between 0 and 360
+    pub azimuth: f64,
+    /// Degrees above the horizon, negative when the satellite is below it
+    pub elevation: f64,
+    /// Straight line distance in meters
+    pub range: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// A satellite's position at a point in time, given by the point on the ground directly below it
+/// (the sub-satellite point) and its altitude above it
+///
+/// ## Notes
+/// - Uses the same spherical Earth as `Coordinate::get_distance_from`, which is accurate to a
+///   fraction of a degree for look angles, enough for planning passes but not for pointing
+///   narrow-beam antennas
+pub struct SatellitePosition {
+    pub coordinate: Coordinate,
+    /// Altitude above the surface in meters
+    pub altitude: f64,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+impl SatellitePosition {
+    /// # Summary
+    /// Construct a new SatellitePosition from its sub-satellite point, altitude in meters and a
+    /// timestamp in milliseconds since the Unix epoch
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SatellitePosition};
+    ///
+    /// let iss = SatellitePosition::new(Coordinate::new(51.2, -30.5), 420_000.0, 0);
+    /// assert_eq!(420_000.0, iss.altitude);
+    /// ```
+    pub fn new(coordinate: impl Into<Coordinate>, altitude: f64, timestamp: i64) -> Self {
+        Self {
+            coordinate: coordinate.into(),
+            altitude,
+            timestamp,
+        }
+    }
+
+    /// # Summary
+    /// Construct the position of a geostationary satellite parked above the equator at
+    /// `longitude`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::SatellitePosition;
+    ///
+    /// let satellite = SatellitePosition::geostationary(19.2, 0);
+    ///
+    /// assert_eq!(0.0, satellite.coordinate.latitude);
+    /// assert_eq!(35_786_000.0, satellite.altitude);
+    /// ```
+    pub fn geostationary(longitude: f64, timestamp: i64) -> Self {
+        Self::new(
+            Coordinate::new(0.0, longitude),
+            GEOSTATIONARY_ALTITUDE_M,
+            timestamp,
+        )
+    }
+
+    /// # Summary
+    /// Get the region of the ground from which the satellite is at least `min_elevation` degrees
+    /// above the horizon, as a circle around the sub-satellite point with a radius in kilometers
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SatellitePosition};
+    ///
+    /// let satellite = SatellitePosition::new(Coordinate::new(0.0, 0.0), 500_000.0, 0);
+    ///
+    /// assert_eq!(2445.0, satellite.footprint(0.0).radius.round());
+    /// assert_eq!(1044.0, satellite.footprint(20.0).radius.round());
+    /// ```
+    pub fn footprint(&self, min_elevation: f64) -> Circle {
+        let elevation = min_elevation.clamp(0.0, 90.0).to_radians();
+        let central_angle = math::acos(math::cos(elevation) / self.orbit_ratio()) - elevation;
+
+        Circle::new(
+            self.coordinate,
+            angle_to_distance(central_angle.max(0.0), &DistanceUnit::Kilometers),
+            DistanceUnit::Kilometers,
+        )
+    }
+
+    /// # Summary
+    /// Get the azimuth, elevation and range of the satellite as seen by an observer at sea level
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SatellitePosition};
+    ///
+    /// let satellite = SatellitePosition::geostationary(19.2, 0);
+    /// let angles = satellite.look_angles(&Coordinate::new(52.52, 13.405));
+    ///
+    /// assert_eq!(173.0, angles.azimuth.round());
+    /// assert_eq!(30.0, angles.elevation.round());
+    /// assert_eq!(38_634.0, (angles.range / 1000.0).round());
+    /// ```
+    pub fn look_angles(&self, observer: &Coordinate) -> LookAngles {
+        let central_angle = angular_distance(observer, &self.coordinate);
+        let (sin_angle, cos_angle) = math::sin_cos(central_angle);
+        let ratio = self.orbit_ratio();
+
+        let earth_radius = EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers);
+        let range = earth_radius * (1.0 + ratio * ratio - 2.0 * ratio * cos_angle).sqrt();
+        let azimuth = if central_angle < 1e-12 {
+            0.0
+        } else {
+            observer
+                .inverse(self.coordinate, &DistanceUnit::Meters)
+                .initial_bearing
+        };
+
+        LookAngles {
+            azimuth,
+            elevation: math::atan2(cos_angle - 1.0 / ratio, sin_angle).to_degrees(),
+            range,
+        }
+    }
+
+    /// # Summary
+    /// Checks if the satellite is at least `min_elevation` degrees above the horizon of an
+    /// observer
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SatellitePosition};
+    ///
+    /// let satellite = SatellitePosition::new(Coordinate::new(0.0, 0.0), 500_000.0, 0);
+    ///
+    /// assert!(satellite.is_visible(&Coordinate::new(5.0, 0.0), 10.0));
+    /// assert!(!satellite.is_visible(&Coordinate::new(25.0, 0.0), 0.0));
+    /// ```
+    pub fn is_visible(&self, observer: &Coordinate, min_elevation: f64) -> bool {
+        self.look_angles(observer).elevation >= min_elevation
+    }
+
+    /// # Summary
+    /// Get the angular velocity of the sub-satellite point in degrees per second, measured at the
+    /// Earth's center between this position and `next`. Returns `None` if both share a timestamp
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SatellitePosition};
+    ///
+    /// let start = SatellitePosition::new(Coordinate::new(0.0, 0.0), 420_000.0, 0);
+    /// let end = SatellitePosition::new(Coordinate::new(0.0, 0.64), 420_000.0, 10_000);
+    ///
+    /// assert_eq!(0.064, (start.angular_velocity(&end).unwrap() * 1e6).round() / 1e6);
+    /// ```
+    pub fn angular_velocity(&self, next: &SatellitePosition) -> Option<f64> {
+        let seconds = (next.timestamp - self.timestamp) as f64 / 1000.0;
+        (seconds != 0.0).then(|| {
+            angular_distance(&self.coordinate, &next.coordinate).to_degrees() / seconds.abs()
+        })
+    }
+
+    /// # Summary
+    /// Ratio of the satellite's distance from the Earth's center to the Earth's radius
+    fn orbit_ratio(&self) -> f64 {
+        let earth_radius = EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers);
+        (earth_radius + self.altitude.max(0.0)) / earth_radius
+    }
+}
+
+/// # Summary
+/// Get the ground track of a satellite from its positions in time order, split into parts at the
+/// antimeridian so each part can be drawn directly on a map
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{ground_track, Coordinate, SatellitePosition};
+///
+/// let positions: Vec<SatellitePosition> = (0..10)
+///     .map(|minute| {
+///         SatellitePosition::new(Coordinate::new(0.0, 150.0 + minute as f64 * 4.0), 420_000.0, minute * 60_000)
+///     })
+///     .collect();
+/// let track = ground_track(&positions);
+///
+/// assert_eq!(2, track.len());
+/// assert_eq!(180.0, track[0].points.last().unwrap().longitude);
+/// ```
+pub fn ground_track(positions: &[SatellitePosition]) -> Vec<Route> {
+    Route::new(
+        positions
+            .iter()
+            .map(|position| position.coordinate)
+            .collect(),
+    )
+    .split_at_antimeridian()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footprint_edge_is_at_min_elevation() {
+        let satellite = SatellitePosition::new(Coordinate::new(45.0, 10.0), 800_000.0, 0);
+
+        for min_elevation in [0.0, 5.0, 30.0, 60.0] {
+            let footprint = satellite.footprint(min_elevation);
+            let edge = crate::spherical::destination(
+                &satellite.coordinate,
+                123.0,
+                crate::spherical::distance_to_angle(footprint.radius, &footprint.unit),
+            );
+            let elevation = satellite.look_angles(&edge).elevation;
+            assert!((elevation - min_elevation).abs() < 1e-9, "{}", elevation);
+        }
+        assert_eq!(90.0, satellite.look_angles(&satellite.coordinate).elevation);
+    }
+}
@@ -0,0 +1,444 @@
+use crate::polygon::ring_contains;
+use crate::{Coordinate, Polygon, Route};
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Magic number at the start of every `.shp` file
+const FILE_CODE: i32 = 9994;
+/// Size in bytes of the `.shp` file header
+const HEADER_SIZE: usize = 100;
+/// Size in bytes of a `.dbf` field descriptor
+const FIELD_DESCRIPTOR_SIZE: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while reading a shapefile
+pub enum ShapefileError {
+    /// A file ended in the middle of a header or record
+    UnexpectedEnd,
+    /// The `.shp` file does not start with the shapefile file code
+    InvalidFileCode(i32),
+    /// A record uses a shape type this reader does not support, such as MultiPatch
+    UnsupportedShapeType(i32),
+    /// The `.dbf` file has a different number of records than the `.shp` file
+    RecordCountMismatch { shapes: usize, attributes: usize },
+    /// A point is not finite or lies outside of ±180 longitude or ±90 latitude, as in a
+    /// shapefile using a projected coordinate system
+    CoordinateOutOfRange,
+}
+
+impl fmt::Display for ShapefileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapefileError::UnexpectedEnd => write!(f, "file ended unexpectedly"),
+            ShapefileError::InvalidFileCode(code) => {
+                write!(f, "file code {} is not a shapefile file code", code)
+            }
+            ShapefileError::UnsupportedShapeType(shape_type) => {
+                write!(f, "shape type {} is not supported", shape_type)
+            }
+            ShapefileError::RecordCountMismatch { shapes, attributes } => {
+                write!(f, "{} shapes but {} attribute records", shapes, attributes)
+            }
+            ShapefileError::CoordinateOutOfRange => {
+                write!(f, "coordinate is not a valid longitude / latitude")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShapefileError {}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// The geometry of a single shapefile record
+pub enum Shape {
+    Null,
+    Point(Coordinate),
+    MultiPoint(Vec<Coordinate>),
+    /// One route per part of the polyline
+    Polyline(Vec<Route>),
+    /// One polygon per exterior ring, with its holes
+    Polygon(Vec<Polygon>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A shapefile record: its geometry and the matching `.dbf` attributes, if any were read
+pub struct ShapeRecord {
+    pub shape: Shape,
+    /// Field name to trimmed field text, empty when no `.dbf` was read
+    pub attributes: HashMap<String, String>,
+}
+
+/// # Summary
+/// Read the records of a shapefile from the contents of its `.shp` file and, optionally, its
+/// `.dbf` attribute file
+///
+/// ## Notes
+/// - Coordinates are expected in longitude / latitude degrees (WGS84), the `.prj` file is not
+///   read and no reprojection is done. Points which are not valid longitude / latitude degrees,
+///   as in projected shapefiles, fail with `ShapefileError::CoordinateOutOfRange`
+/// - Z and M values are ignored
+/// - Polygon rings are grouped following the shapefile convention: clockwise rings are
+///   exteriors and counterclockwise rings are holes of the exterior containing them
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{read_shapefile, Coordinate, Shape};
+///
+/// // A shapefile holding a single point record
+/// let mut shp = vec![0u8; 100];
+/// shp[0..4].copy_from_slice(&9994i32.to_be_bytes());
+/// shp[24..28].copy_from_slice(&64i32.to_be_bytes());
+/// shp[28..32].copy_from_slice(&1000i32.to_le_bytes());
+/// shp[32..36].copy_from_slice(&1i32.to_le_bytes());
+/// shp.extend(1i32.to_be_bytes());
+/// shp.extend(10i32.to_be_bytes());
+/// shp.extend(1i32.to_le_bytes());
+/// shp.extend(13.405f64.to_le_bytes());
+/// shp.extend(52.52f64.to_le_bytes());
+///
+/// let records = read_shapefile(&shp, None).unwrap();
+///
+/// assert_eq!(1, records.len());
+/// assert_eq!(Shape::Point(Coordinate::new(52.52, 13.405)), records[0].shape);
+/// ```
+pub fn read_shapefile(shp: &[u8], dbf: Option<&[u8]>) -> Result<Vec<ShapeRecord>, ShapefileError> {
+    let shapes = read_shapes(shp)?;
+    let attributes = match dbf {
+        Some(dbf) => read_attributes(dbf)?,
+        None => vec![HashMap::new(); shapes.len()],
+    };
+    if attributes.len() != shapes.len() {
+        return Err(ShapefileError::RecordCountMismatch {
+            shapes: shapes.len(),
+            attributes: attributes.len(),
+        });
+    }
+
+    Ok(shapes
+        .into_iter()
+        .zip(attributes)
+        .map(|(shape, attributes)| ShapeRecord { shape, attributes })
+        .collect())
+}
+
+/// # Summary
+/// Read a shapefile from disk, along with the `.dbf` attribute file next to it if there is one
+///
+/// ## Notes
+/// - See `read_shapefile` for how the records are interpreted
+/// - Malformed files are reported as `io::ErrorKind::InvalidData`
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::open_shapefile;
+///
+/// let path = std::env::temp_dir().join("geolocation_utils_missing.shp");
+/// assert!(open_shapefile(&path).is_err());
+/// ```
+pub fn open_shapefile(path: impl AsRef<Path>) -> io::Result<Vec<ShapeRecord>> {
+    let path = path.as_ref();
+    let shp = std::fs::read(path)?;
+    let dbf = match std::fs::read(path.with_extension("dbf")) {
+        Ok(dbf) => Some(dbf),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+
+    read_shapefile(&shp, dbf.as_deref())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+fn read_shapes(shp: &[u8]) -> Result<Vec<Shape>, ShapefileError> {
+    let file_code = read_i32_be(shp, 0)?;
+    if file_code != FILE_CODE {
+        return Err(ShapefileError::InvalidFileCode(file_code));
+    }
+    // The header stores the file length in 16-bit words
+    let file_length = (read_i32_be(shp, 24)?.max(0) as usize * 2).min(shp.len());
+
+    let mut shapes = Vec::new();
+    let mut offset = HEADER_SIZE;
+    while offset + 8 <= file_length {
+        let content_length = read_i32_be(shp, offset + 4)?.max(0) as usize * 2;
+        let content = shp
+            .get(offset + 8..offset + 8 + content_length)
+            .ok_or(ShapefileError::UnexpectedEnd)?;
+        shapes.push(read_shape(content)?);
+        offset += 8 + content_length;
+    }
+
+    Ok(shapes)
+}
+
+fn read_shape(content: &[u8]) -> Result<Shape, ShapefileError> {
+    let shape_type = read_i32_le(content, 0)?;
+    match shape_type {
+        0 => Ok(Shape::Null),
+        1 | 11 | 21 => Ok(Shape::Point(read_point(content, 4)?)),
+        8 | 18 | 28 => {
+            // Bounding box, then the point count
+            let count = read_count(content, 36)?;
+            let points = (0..count)
+                .map(|index| read_point(content, 40 + index * 16))
+                .collect::<Result<_, _>>()?;
+            Ok(Shape::MultiPoint(points))
+        }
+        3 | 13 | 23 => Ok(Shape::Polyline(
+            read_parts(content)?.into_iter().map(Route::new).collect(),
+        )),
+        5 | 15 | 25 => Ok(Shape::Polygon(assemble_polygons(read_parts(content)?))),
+        _ => Err(ShapefileError::UnsupportedShapeType(shape_type)),
+    }
+}
+
+/// # Summary
+/// Read the parts of a polyline or polygon record, each a list of coordinates
+fn read_parts(content: &[u8]) -> Result<Vec<Vec<Coordinate>>, ShapefileError> {
+    // Bounding box, then the part and point counts
+    let part_count = read_count(content, 36)?;
+    let point_count = read_count(content, 40)?;
+    let points_offset = 44 + part_count * 4;
+
+    let starts = (0..part_count)
+        .map(|index| Ok(read_count(content, 44 + index * 4)?.min(point_count)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(index, &start)| {
+            let end = starts.get(index + 1).copied().unwrap_or(point_count);
+            (start..end.max(start))
+                .map(|point| read_point(content, points_offset + point * 16))
+                .collect()
+        })
+        .collect()
+}
+
+/// # Summary
+/// Group polygon rings into polygons, clockwise rings being exteriors and counterclockwise rings
+/// holes. A hole outside every exterior is kept as a polygon of its own
+fn assemble_polygons(rings: Vec<Vec<Coordinate>>) -> Vec<Polygon> {
+    let (exteriors, holes): (Vec<Polygon>, Vec<Polygon>) = rings
+        .into_iter()
+        .map(Polygon::new)
+        .partition(Polygon::is_clockwise);
+    let mut polygons = exteriors;
+
+    for hole in holes {
+        let owner = hole.exterior.first().and_then(|first| {
+            polygons
+                .iter_mut()
+                .find(|polygon| ring_contains(&polygon.exterior, first))
+        });
+        match owner {
+            Some(polygon) => polygon.interiors.push(hole.exterior),
+            None => polygons.push(hole),
+        }
+    }
+
+    polygons
+}
+
+fn read_attributes(dbf: &[u8]) -> Result<Vec<HashMap<String, String>>, ShapefileError> {
+    let record_count = read_u32_le(dbf, 4)? as usize;
+    let header_length = read_u16_le(dbf, 8)? as usize;
+    let record_length = read_u16_le(dbf, 10)? as usize;
+
+    // Field descriptors follow the 32 byte header until the 0x0D terminator
+    let mut fields = Vec::new();
+    let mut offset = FIELD_DESCRIPTOR_SIZE;
+    while dbf.get(offset).is_some_and(|&byte| byte != 0x0D) && offset < header_length {
+        let descriptor = dbf
+            .get(offset..offset + FIELD_DESCRIPTOR_SIZE)
+            .ok_or(ShapefileError::UnexpectedEnd)?;
+        let name_length = descriptor[..11]
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(11);
+        let name = String::from_utf8_lossy(&descriptor[..name_length]).into_owned();
+        fields.push((name, descriptor[16] as usize));
+        offset += FIELD_DESCRIPTOR_SIZE;
+    }
+
+    (0..record_count)
+        .map(|index| {
+            let start = header_length + index * record_length;
+            let record = dbf
+                .get(start..start + record_length)
+                .ok_or(ShapefileError::UnexpectedEnd)?;
+            // Skip the deletion flag
+            let mut position = 1;
+            let mut attributes = HashMap::with_capacity(fields.len());
+            for (name, length) in &fields {
+                let value = record
+                    .get(position..position + length)
+                    .ok_or(ShapefileError::UnexpectedEnd)?;
+                attributes.insert(
+                    name.clone(),
+                    String::from_utf8_lossy(value).trim().to_string(),
+                );
+                position += length;
+            }
+            Ok(attributes)
+        })
+        .collect()
+}
+
+fn read_point(bytes: &[u8], offset: usize) -> Result<Coordinate, ShapefileError> {
+    let x = read_f64_le(bytes, offset)?;
+    let y = read_f64_le(bytes, offset + 8)?;
+    if !(x.abs() <= 180.0 && y.abs() <= 90.0) {
+        return Err(ShapefileError::CoordinateOutOfRange);
+    }
+    Ok(Coordinate::new(y, x))
+}
+
+fn read_count(bytes: &[u8], offset: usize) -> Result<usize, ShapefileError> {
+    Ok(read_i32_le(bytes, offset)?.max(0) as usize)
+}
+
+fn read_array<const N: usize>(bytes: &[u8], offset: usize) -> Result<[u8; N], ShapefileError> {
+    bytes
+        .get(offset..offset + N)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or(ShapefileError::UnexpectedEnd)
+}
+
+fn read_i32_be(bytes: &[u8], offset: usize) -> Result<i32, ShapefileError> {
+    read_array(bytes, offset).map(i32::from_be_bytes)
+}
+
+fn read_i32_le(bytes: &[u8], offset: usize) -> Result<i32, ShapefileError> {
+    read_array(bytes, offset).map(i32::from_le_bytes)
+}
+
+fn read_u32_le(bytes: &[u8], offset: usize) -> Result<u32, ShapefileError> {
+    read_array(bytes, offset).map(u32::from_le_bytes)
+}
+
+fn read_u16_le(bytes: &[u8], offset: usize) -> Result<u16, ShapefileError> {
+    read_array(bytes, offset).map(u16::from_le_bytes)
+}
+
+fn read_f64_le(bytes: &[u8], offset: usize) -> Result<f64, ShapefileError> {
+    read_array(bytes, offset).map(f64::from_le_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shp_file(records: &[Vec<u8>]) -> Vec<u8> {
+        let mut shp = vec![0u8; HEADER_SIZE];
+        shp[0..4].copy_from_slice(&FILE_CODE.to_be_bytes());
+        shp[28..32].copy_from_slice(&1000i32.to_le_bytes());
+        for (index, content) in records.iter().enumerate() {
+            shp.extend((index as i32 + 1).to_be_bytes());
+            shp.extend((content.len() as i32 / 2).to_be_bytes());
+            shp.extend(content);
+        }
+        let words = shp.len() as i32 / 2;
+        shp[24..28].copy_from_slice(&words.to_be_bytes());
+        shp
+    }
+
+    fn parts_record(shape_type: i32, parts: &[&[(f64, f64)]]) -> Vec<u8> {
+        let mut content = shape_type.to_le_bytes().to_vec();
+        content.extend([0u8; 32]);
+        content.extend((parts.len() as i32).to_le_bytes());
+        let point_count: usize = parts.iter().map(|part| part.len()).sum();
+        content.extend((point_count as i32).to_le_bytes());
+        let mut start = 0;
+        for part in parts {
+            content.extend((start as i32).to_le_bytes());
+            start += part.len();
+        }
+        for (x, y) in parts.iter().flat_map(|part| part.iter()) {
+            content.extend(x.to_le_bytes());
+            content.extend(y.to_le_bytes());
+        }
+        content
+    }
+
+    #[test]
+    fn test_read_polygons_with_holes_and_attributes() {
+        // Clockwise exterior and counterclockwise hole, as written by GIS tools
+        let exterior = [(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0), (0.0, 0.0)];
+        let hole = [(0.5, 0.5), (1.5, 0.5), (1.5, 1.5), (0.5, 1.5), (0.5, 0.5)];
+        let line = [(10.0, 20.0), (11.0, 21.0)];
+        let shp = shp_file(&[
+            parts_record(5, &[&exterior, &hole]),
+            parts_record(3, &[&line]),
+            0i32.to_le_bytes().to_vec(),
+        ]);
+
+        let mut dbf = vec![0u8; 32];
+        dbf[4..8].copy_from_slice(&3u32.to_le_bytes());
+        dbf[8..10].copy_from_slice(&(32u16 + 32 + 1).to_le_bytes());
+        dbf[10..12].copy_from_slice(&(1u16 + 6).to_le_bytes());
+        let mut descriptor = [0u8; 32];
+        descriptor[..4].copy_from_slice(b"NAME");
+        descriptor[11] = b'C';
+        descriptor[16] = 6;
+        dbf.extend(descriptor);
+        dbf.push(0x0D);
+        for name in ["zone", "road", "none"] {
+            dbf.extend(format!(" {name:<6}").bytes());
+        }
+
+        let records = read_shapefile(&shp, Some(&dbf)).unwrap();
+
+        assert_eq!(3, records.len());
+        let Shape::Polygon(polygons) = &records[0].shape else {
+            panic!("expected a polygon, got {:?}", records[0].shape);
+        };
+        assert_eq!(1, polygons.len());
+        assert_eq!(1, polygons[0].interiors.len());
+        assert!(polygons[0].contains(&Coordinate::new(0.25, 0.25)));
+        assert!(!polygons[0].contains(&Coordinate::new(1.0, 1.0)));
+        assert_eq!(
+            Shape::Polyline(vec![Route::new(vec![
+                Coordinate::new(20.0, 10.0),
+                Coordinate::new(21.0, 11.0)
+            ])]),
+            records[1].shape
+        );
+        assert_eq!(Shape::Null, records[2].shape);
+        assert_eq!("zone", records[0].attributes["NAME"]);
+        assert_eq!("road", records[1].attributes["NAME"]);
+    }
+
+    #[test]
+    fn test_read_rejects_malformed_files() {
+        assert_eq!(
+            Err(ShapefileError::InvalidFileCode(0)),
+            read_shapefile(&[0u8; 100], None)
+        );
+        let mut truncated = shp_file(&[parts_record(5, &[&[(0.0, 0.0), (1.0, 1.0)]])]);
+        truncated.truncate(truncated.len() - 4);
+        let words = truncated.len() as i32 / 2 + 2;
+        truncated[24..28].copy_from_slice(&words.to_be_bytes());
+        assert_eq!(
+            Err(ShapefileError::UnexpectedEnd),
+            read_shapefile(&truncated, None)
+        );
+
+        // Web Mercator meters and non-finite values are not longitude / latitude
+        for point in [
+            (1_491_000.0, 6_894_000.0),
+            (f64::INFINITY, 0.0),
+            (0.0, f64::NAN),
+        ] {
+            assert_eq!(
+                Err(ShapefileError::CoordinateOutOfRange),
+                read_shapefile(&shp_file(&[parts_record(3, &[&[point]])]), None)
+            );
+        }
+    }
+}
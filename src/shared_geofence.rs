@@ -0,0 +1,266 @@
+use crate::{Coordinate, CoordinateBoundaries, Geofence};
+use arc_swap::ArcSwap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// An immutable version of a `SharedGeofence`, holding the fence along with the bounds computed
+/// for it when it was stored
+pub struct GeofenceVersion {
+    version: u64,
+    fence: Geofence,
+    bounds: Option<CoordinateBoundaries>,
+}
+
+impl GeofenceVersion {
+    fn new(version: u64, fence: Geofence) -> Self {
+        let bounds = fence.bounds();
+        Self {
+            version,
+            fence,
+            bounds,
+        }
+    }
+
+    /// # Summary
+    /// Get the version number, starting at 0 and incremented by every update
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, SharedGeofence};
+    ///
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 1.0, None).unwrap();
+    /// let shared = SharedGeofence::new(bounds);
+    ///
+    /// assert_eq!(0, shared.load().version());
+    /// ```
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// # Summary
+    /// Get the fence of this version
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, Geofence, SharedGeofence};
+    ///
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 1.0, None).unwrap();
+    /// let shared = SharedGeofence::new(bounds);
+    ///
+    /// assert_eq!(&Geofence::Bounds(bounds), shared.load().fence());
+    /// ```
+    pub fn fence(&self) -> &Geofence {
+        &self.fence
+    }
+
+    /// # Summary
+    /// Get the bounding box of the fence, computed once when the version was stored. See
+    /// `Geofence::bounds`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, Geofence, SharedGeofence};
+    ///
+    /// let circle = Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers);
+    /// let shared = SharedGeofence::new(circle);
+    ///
+    /// assert_eq!(Geofence::from(circle).bounds(), shared.load().bounds());
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        self.bounds
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the fence, rejecting coordinates outside of the stored
+    /// bounds before running the full check
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, SharedGeofence};
+    ///
+    /// let shared = SharedGeofence::new(Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers));
+    /// let version = shared.load();
+    ///
+    /// assert!(version.contains(&Coordinate::new(0.001, 0.001)));
+    /// assert!(!version.contains(&Coordinate::new(0.1, 0.1)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        if let Some(bounds) = &self.bounds {
+            if !bounds.contains(coordinate) {
+                return false;
+            }
+        }
+        self.fence.contains(coordinate)
+    }
+}
+
+#[derive(Debug)]
+/// ## Summary
+/// A geofence which can be replaced while other threads are evaluating it, for services which
+/// hot-update their fences. Share it between threads with an `Arc`
+///
+/// ## Notes
+/// - Reads never block and never see a partially updated fence, each one works on a complete
+///   `GeofenceVersion`
+/// - The bounds of a new fence are computed before it is swapped in, so readers never pay for it
+/// - Use `load` to evaluate several coordinates against the same version
+pub struct SharedGeofence {
+    current: ArcSwap<GeofenceVersion>,
+}
+
+impl SharedGeofence {
+    /// # Summary
+    /// Construct a SharedGeofence holding version 0 of `fence`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, SharedGeofence};
+    ///
+    /// let shared = SharedGeofence::new(Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers));
+    /// assert_eq!(0, shared.version());
+    /// ```
+    pub fn new(fence: impl Into<Geofence>) -> Self {
+        Self {
+            current: ArcSwap::from_pointee(GeofenceVersion::new(0, fence.into())),
+        }
+    }
+
+    /// # Summary
+    /// Get the current version of the fence. The returned version stays valid, and unchanged, when
+    /// the fence is updated afterwards
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, SharedGeofence};
+    ///
+    /// let shared = SharedGeofence::new(CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 1.0, None).unwrap());
+    /// let before = shared.load();
+    /// shared.update(CoordinateBoundaries::new(Coordinate::new(10.0, 10.0), 1.0, None).unwrap());
+    ///
+    /// assert!(before.contains(&Coordinate::new(0.0, 0.0)));
+    /// assert!(!shared.load().contains(&Coordinate::new(0.0, 0.0)));
+    /// ```
+    pub fn load(&self) -> Arc<GeofenceVersion> {
+        self.current.load_full()
+    }
+
+    /// # Summary
+    /// Get the current version number
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, CoordinateBoundaries, SharedGeofence};
+    ///
+    /// let bounds = CoordinateBoundaries::new(Coordinate::new(0.0, 0.0), 1.0, None).unwrap();
+    /// let shared = SharedGeofence::new(bounds);
+    /// shared.update(bounds);
+    ///
+    /// assert_eq!(1, shared.version());
+    /// ```
+    pub fn version(&self) -> u64 {
+        self.current.load().version
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the current version of the fence
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, SharedGeofence};
+    ///
+    /// let shared = SharedGeofence::new(Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers));
+    ///
+    /// assert!(shared.contains(&Coordinate::new(0.001, 0.001)));
+    /// assert!(!shared.contains(&Coordinate::new(0.1, 0.1)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        self.current.load().contains(coordinate)
+    }
+
+    /// # Summary
+    /// Replace the fence, returning the new version number
+    ///
+    /// ## Notes
+    /// - Concurrent updates are applied one after another, each one receiving its own version
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Circle, Coordinate, DistanceUnit, SharedGeofence};
+    /// use std::sync::Arc;
+    ///
+    /// let shared = Arc::new(SharedGeofence::new(Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Kilometers)));
+    ///
+    /// let writer = Arc::clone(&shared);
+    /// std::thread::spawn(move || {
+    ///     writer.update(Circle::new(Coordinate::new(0.0, 0.0), 50.0, DistanceUnit::Kilometers))
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// assert_eq!(1, shared.version());
+    /// assert!(shared.contains(&Coordinate::new(0.1, 0.1)));
+    /// ```
+    pub fn update(&self, fence: impl Into<Geofence>) -> u64 {
+        let fence = fence.into();
+        let bounds = fence.bounds();
+        let previous = self.current.rcu(|current| GeofenceVersion {
+            version: current.version + 1,
+            fence: fence.clone(),
+            bounds,
+        });
+        previous.version + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circle, DistanceUnit};
+    use std::thread;
+
+    #[test]
+    fn test_concurrent_updates_and_reads() {
+        let fence =
+            |radius: f64| Circle::new(Coordinate::new(0.0, 0.0), radius, DistanceUnit::Kilometers);
+        let shared = Arc::new(SharedGeofence::new(fence(1.0)));
+
+        let writers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    (1..=250)
+                        .map(|radius| shared.update(fence(radius as f64)))
+                        .collect::<Vec<u64>>()
+                })
+            })
+            .collect();
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    let mut last = 0;
+                    for _ in 0..1000 {
+                        let version = shared.load();
+                        // Versions only move forwards and always carry the bounds of their fence
+                        assert!(version.version() >= last);
+                        assert_eq!(version.fence().bounds(), version.bounds());
+                        last = version.version();
+                    }
+                })
+            })
+            .collect();
+
+        let mut versions: Vec<u64> = writers
+            .into_iter()
+            .flat_map(|writer| writer.join().unwrap())
+            .collect();
+        readers
+            .into_iter()
+            .for_each(|reader| reader.join().unwrap());
+
+        versions.sort_unstable();
+        assert_eq!((1..=1000).collect::<Vec<u64>>(), versions);
+        assert_eq!(1000, shared.version());
+    }
+}
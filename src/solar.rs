@@ -0,0 +1,140 @@
+use crate::{math, Coordinate};
+
+/// Milliseconds in a day
+pub(crate) const DAY: i64 = 86_400_000;
+/// Julian date of the Unix epoch
+const UNIX_EPOCH: f64 = 2_440_587.5;
+/// Julian date of noon on 2000-01-01, the J2000 epoch
+const J2000: f64 = 2_451_545.0;
+/// Days from the Unix epoch to the J2000 epoch
+const J2000_DAYS: i64 = 10_957;
+/// Obliquity of the ecliptic in degrees
+const OBLIQUITY: f64 = 23.4397;
+/// Altitude of the center of the sun at sunrise and sunset in degrees, accounting for refraction
+/// and the radius of the sun
+const HORIZON: f64 = -0.833;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// ## Summary
+/// Daylight of a single solar day at a location
+pub(crate) enum Daylight {
+    /// Sunrise and sunset in milliseconds since the Unix epoch
+    Between(i64, i64),
+    /// The sun stays above the horizon all day
+    PolarDay,
+    /// The sun stays below the horizon all day
+    PolarNight,
+}
+
+/// # Summary
+/// Get the sunrise and sunset at `coordinate` on the solar day holding `timestamp`, the day running
+/// from one local solar midnight to the next
+///
+/// ## Notes
+/// - Uses the sunrise equation with the usual series for the equation of time, which is within a
+///   minute or two of the NOAA tables outside the polar regions
+pub(crate) fn daylight(coordinate: &Coordinate, timestamp: i64) -> Daylight {
+    // Days since the J2000 epoch of the local solar noon, shifted by the longitude
+    let solar_offset = coordinate.longitude / 360.0;
+    let day = (timestamp as f64 / DAY as f64 + solar_offset).floor() as i64;
+    let mean_noon = (day - J2000_DAYS) as f64 - solar_offset;
+
+    let anomaly = (357.5291 + 0.985_600_28 * mean_noon)
+        .rem_euclid(360.0)
+        .to_radians();
+    let center = 1.9148 * math::sin(anomaly)
+        + 0.02 * math::sin(2.0 * anomaly)
+        + 0.0003 * math::sin(3.0 * anomaly);
+    let ecliptic_longitude = (anomaly.to_degrees() + center + 180.0 + 102.9372)
+        .rem_euclid(360.0)
+        .to_radians();
+    let transit = J2000 + mean_noon + 0.0053 * math::sin(anomaly)
+        - 0.0069 * math::sin(2.0 * ecliptic_longitude);
+
+    let declination = math::asin(math::sin(ecliptic_longitude) * math::sin(OBLIQUITY.to_radians()));
+    let (sin_latitude, cos_latitude) = math::sin_cos(coordinate.latitude.to_radians());
+    let cos_hour_angle = (math::sin(HORIZON.to_radians()) - sin_latitude * math::sin(declination))
+        / (cos_latitude * math::cos(declination));
+
+    if cos_hour_angle.is_nan() || cos_hour_angle < -1.0 {
+        return Daylight::PolarDay;
+    }
+    if cos_hour_angle > 1.0 {
+        return Daylight::PolarNight;
+    }
+    let half_day = math::acos(cos_hour_angle).to_degrees() / 360.0;
+    let to_timestamp = |julian: f64| ((julian - UNIX_EPOCH) * DAY as f64).round() as i64;
+
+    Daylight::Between(
+        to_timestamp(transit - half_day),
+        to_timestamp(transit + half_day),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-06-21 and 2024-12-21 at midnight UTC
+    const SUMMER: i64 = 19_895 * DAY;
+    const WINTER: i64 = 20_078 * DAY;
+
+    #[test]
+    fn test_london_solstices() {
+        let london = Coordinate::new(51.5074, -0.1278);
+        let minutes = |timestamp: i64| (timestamp.rem_euclid(DAY) as f64 / 60_000.0).round();
+
+        // Published times are 03:43 to 20:21 and 08:03 to 15:53 UTC
+        let Daylight::Between(sunrise, sunset) = daylight(&london, SUMMER + 12 * 3_600_000) else {
+            panic!("London has sunrise and sunset");
+        };
+        assert!(
+            (minutes(sunrise) - 223.0).abs() <= 2.0,
+            "{}",
+            minutes(sunrise)
+        );
+        assert!(
+            (minutes(sunset) - 1221.0).abs() <= 2.0,
+            "{}",
+            minutes(sunset)
+        );
+        assert!(sunrise > SUMMER && sunset < SUMMER + DAY);
+
+        let Daylight::Between(sunrise, sunset) = daylight(&london, WINTER) else {
+            panic!("London has sunrise and sunset");
+        };
+        assert!(
+            (minutes(sunrise) - 483.0).abs() <= 2.0,
+            "{}",
+            minutes(sunrise)
+        );
+        assert!(
+            (minutes(sunset) - 953.0).abs() <= 2.0,
+            "{}",
+            minutes(sunset)
+        );
+    }
+
+    #[test]
+    fn test_solar_day_follows_longitude() {
+        // Late evening in Los Angeles is already the next day in UTC, sunset is still the same day
+        let los_angeles = Coordinate::new(34.05, -118.24);
+        let evening = SUMMER + DAY + 2 * 3_600_000;
+        let Daylight::Between(sunrise, sunset) = daylight(&los_angeles, evening) else {
+            panic!("Los Angeles has sunrise and sunset");
+        };
+        assert!(sunrise < SUMMER + DAY && sunset > SUMMER + DAY);
+        assert!(sunrise <= evening && evening < sunset);
+    }
+
+    #[test]
+    fn test_polar_days() {
+        let tromso = Coordinate::new(69.65, 18.96);
+        assert_eq!(Daylight::PolarDay, daylight(&tromso, SUMMER));
+        assert_eq!(Daylight::PolarNight, daylight(&tromso, WINTER));
+        assert_eq!(
+            Daylight::PolarDay,
+            daylight(&Coordinate::new(90.0, 0.0), SUMMER)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::distance::decimals_for;
+use crate::utils::linear_divisor;
+use crate::DistanceUnit;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpeedUnit {
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    MetersPerSecond,
+}
+
+impl SpeedUnit {
+    /// # Summary
+    /// Get the short symbol used when displaying a value in this unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::SpeedUnit;
+    ///
+    /// assert_eq!("km/h", SpeedUnit::KilometersPerHour.abbreviation());
+    /// assert_eq!("kn", SpeedUnit::Knots.abbreviation());
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            SpeedUnit::KilometersPerHour => "km/h",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::Knots => "kn",
+            SpeedUnit::MetersPerSecond => "m/s",
+        }
+    }
+
+    /// # Summary
+    /// Get the number of meters per second in one of this unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::SpeedUnit;
+    ///
+    /// const KNOT: f64 = SpeedUnit::Knots.meters_per_second();
+    ///
+    /// assert_eq!(1852.0 / 3600.0, KNOT);
+    /// assert_eq!(1.0, SpeedUnit::MetersPerSecond.meters_per_second());
+    /// ```
+    pub const fn meters_per_second(&self) -> f64 {
+        match self {
+            SpeedUnit::KilometersPerHour => linear_divisor(&DistanceUnit::Kilometers) / 3600.0,
+            SpeedUnit::MilesPerHour => linear_divisor(&DistanceUnit::Miles) / 3600.0,
+            SpeedUnit::Knots => linear_divisor(&DistanceUnit::NauticalMiles) / 3600.0,
+            SpeedUnit::MetersPerSecond => linear_divisor(&DistanceUnit::Meters),
+        }
+    }
+
+    /// # Summary
+    /// Convert a `value` measured in this unit into `unit`. This is a `const fn`, so limits can be
+    /// declared in one unit and stored as constants in another
+    ///
+    /// ## Notes
+    /// - Gives exactly the same result as `Speed::to_unit`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::SpeedUnit;
+    ///
+    /// const SPEED_LIMIT_MPS: f64 = SpeedUnit::KilometersPerHour.convert(36.0, &SpeedUnit::MetersPerSecond);
+    ///
+    /// assert_eq!(10.0, SPEED_LIMIT_MPS);
+    /// ```
+    pub const fn convert(&self, value: f64, unit: &SpeedUnit) -> f64 {
+        value * self.meters_per_second() / unit.meters_per_second()
+    }
+}
+
+impl fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// Struct representing a speed value along with the unit it is measured in
+pub struct Speed {
+    pub value: f64,
+    pub unit: SpeedUnit,
+}
+
+impl Speed {
+    /// # Summary
+    /// Construct a new Speed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Speed, SpeedUnit};
+    ///
+    /// let speed = Speed::new(12.0, SpeedUnit::Knots);
+    /// assert_eq!(12.0, speed.value);
+    /// assert_eq!(SpeedUnit::Knots, speed.unit);
+    /// ```
+    pub const fn new(value: f64, unit: SpeedUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// # Summary
+    /// Convert this speed into another unit
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Speed, SpeedUnit};
+    ///
+    /// let speed = Speed::new(36.0, SpeedUnit::KilometersPerHour);
+    /// let converted = speed.to_unit(&SpeedUnit::MetersPerSecond);
+    ///
+    /// assert_eq!(10.0, converted.value);
+    /// ```
+    pub const fn to_unit(&self, unit: &SpeedUnit) -> Speed {
+        Speed::new(self.unit.convert(self.value, unit), *unit)
+    }
+
+    /// # Summary
+    /// Format the speed for display using consistent rounding rules
+    ///
+    /// ## Notes
+    /// - Uses 1 decimal below 10 and none above, in the speed's own unit
+    /// - Output never depends on the system locale
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Speed, SpeedUnit};
+    ///
+    /// assert_eq!("5.4 mph", Speed::new(5.43, SpeedUnit::MilesPerHour).humanize());
+    /// assert_eq!("88 km/h", Speed::new(87.6, SpeedUnit::KilometersPerHour).humanize());
+    /// ```
+    pub fn humanize(&self) -> String {
+        format!(
+            "{:.*} {}",
+            decimals_for(self.value, false),
+            self.value,
+            self.unit
+        )
+    }
+}
+
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
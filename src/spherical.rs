@@ -0,0 +1,222 @@
+//! Low-level spherical Earth math, for composing custom algorithms on top of the same building
+//! blocks the crate uses internally.
+//!
+//! Positions are unit vectors `[x, y, z]` with `x` towards latitude 0 / longitude 0 and `z` towards
+//! the north pole, distances are angles in radians on the mean Earth radius sphere.
+
+use crate::math;
+use crate::utils::{linear_divisor, EARTH_RADIUS_KM};
+use crate::{Coordinate, DistanceUnit};
+
+/// # Summary
+/// Convert a coordinate into a unit vector on the sphere, `[x, y, z]` with `z` towards the north pole
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::to_unit_vector;
+/// use geolocation_utils::Coordinate;
+///
+/// let [x, y, z] = to_unit_vector(&Coordinate::new(90.0, 0.0));
+///
+/// assert!(x.abs() < 1e-12 && y.abs() < 1e-12);
+/// assert_eq!(1.0, z);
+/// ```
+pub fn to_unit_vector(coordinate: &Coordinate) -> [f64; 3] {
+    let (sin_lat, cos_lat) = math::sin_cos(coordinate.latitude.to_radians());
+    let (sin_lon, cos_lon) = math::sin_cos(coordinate.longitude.to_radians());
+    [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat]
+}
+
+/// # Summary
+/// Convert a (not necessarily normalized) vector back into the coordinate it points at
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::from_unit_vector;
+///
+/// let coordinate = from_unit_vector([0.0, 2.0, 0.0]);
+///
+/// assert_eq!(0.0, coordinate.latitude);
+/// assert_eq!(90.0, coordinate.longitude);
+/// ```
+pub fn from_unit_vector(vector: [f64; 3]) -> Coordinate {
+    let [x, y, z] = vector;
+    Coordinate::new(
+        math::atan2(z, (x * x + y * y).sqrt()).to_degrees(),
+        math::atan2(y, x).to_degrees(),
+    )
+}
+
+/// # Summary
+/// Get the dot product of two vectors
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::dot;
+///
+/// assert_eq!(0.0, dot([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]));
+/// ```
+pub fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// # Summary
+/// Get the cross product of two vectors, the normal of the great circle through both when they are
+/// positions
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::cross;
+///
+/// assert_eq!([0.0, 0.0, 1.0], cross([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]));
+/// ```
+pub fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// # Summary
+/// Scale a vector to unit length
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::normalize;
+///
+/// assert_eq!([0.6, 0.8, 0.0], normalize([3.0, 4.0, 0.0]));
+/// ```
+pub fn normalize(vector: [f64; 3]) -> [f64; 3] {
+    let length = dot(vector, vector).sqrt();
+    vector.map(|component| component / length)
+}
+
+/// # Summary
+/// Get the angle in radians between two coordinates, as seen from the Earth's center
+///
+/// ## Notes
+/// - Uses `atan2(|a × b|, a · b)`, accurate for both tiny and nearly antipodal separations
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::angular_distance;
+/// use geolocation_utils::Coordinate;
+///
+/// let angle = angular_distance(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 90.0));
+///
+/// assert_eq!(std::f64::consts::FRAC_PI_2, angle);
+/// ```
+pub fn angular_distance(a: &Coordinate, b: &Coordinate) -> f64 {
+    angle_between(to_unit_vector(a), to_unit_vector(b))
+}
+
+/// # Summary
+/// Get the angle in radians between two unit vectors, as seen from the Earth's center
+///
+/// ## Notes
+/// - Uses `atan2(|a × b|, a · b)`, accurate for both tiny and nearly antipodal separations
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::angle_between;
+///
+/// assert_eq!(std::f64::consts::FRAC_PI_2, angle_between([1.0, 0.0, 0.0], [0.0, 0.0, 1.0]));
+/// ```
+pub fn angle_between(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let normal = cross(a, b);
+    math::atan2(dot(normal, normal).sqrt(), dot(a, b))
+}
+
+/// # Summary
+/// Convert a distance into the angle in radians it spans on the Earth's surface
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::distance_to_angle;
+/// use geolocation_utils::DistanceUnit;
+///
+/// let angle = distance_to_angle(6371.0088, &DistanceUnit::Kilometers);
+///
+/// assert_eq!(1.0, angle);
+/// ```
+pub fn distance_to_angle(distance: f64, unit: &DistanceUnit) -> f64 {
+    distance * linear_divisor(unit) / (EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers))
+}
+
+/// # Summary
+/// Convert an angle in radians on the Earth's surface into the distance it spans, in `unit`
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::angle_to_distance;
+/// use geolocation_utils::DistanceUnit;
+///
+/// let distance = angle_to_distance(1f64.to_radians(), &DistanceUnit::Kilometers);
+///
+/// assert_eq!(111.195, (distance * 1e3).round() / 1e3);
+/// ```
+pub fn angle_to_distance(angle: f64, unit: &DistanceUnit) -> f64 {
+    angle * EARTH_RADIUS_KM * linear_divisor(&DistanceUnit::Kilometers) / linear_divisor(unit)
+}
+
+/// # Summary
+/// Get the point `fraction` of the way from `from` to `to` along the great circle between them
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::interpolate;
+/// use geolocation_utils::Coordinate;
+///
+/// let middle = interpolate(&Coordinate::new(0.0, 0.0), &Coordinate::new(0.0, 90.0), 0.5);
+///
+/// assert!((middle.longitude - 45.0).abs() < 1e-12);
+/// ```
+pub fn interpolate(from: &Coordinate, to: &Coordinate, fraction: f64) -> Coordinate {
+    let a = to_unit_vector(from);
+    let b = to_unit_vector(to);
+    let angle = math::acos(dot(a, b).clamp(-1.0, 1.0));
+    if angle < 1e-12 {
+        return *from;
+    }
+
+    let sin_angle = math::sin(angle);
+    let weight_a = math::sin((1.0 - fraction) * angle) / sin_angle;
+    let weight_b = math::sin(fraction * angle) / sin_angle;
+    from_unit_vector([
+        weight_a * a[0] + weight_b * b[0],
+        weight_a * a[1] + weight_b * b[1],
+        weight_a * a[2] + weight_b * b[2],
+    ])
+}
+
+/// # Summary
+/// Get the point reached by travelling `angular_distance` radians from `origin` along the great
+/// circle with initial `bearing` in degrees clockwise from north
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::spherical::destination;
+/// use geolocation_utils::Coordinate;
+///
+/// let north_pole = destination(&Coordinate::new(0.0, 0.0), 0.0, std::f64::consts::FRAC_PI_2);
+///
+/// assert!((north_pole.latitude - 90.0).abs() < 1e-9);
+/// ```
+pub fn destination(origin: &Coordinate, bearing: f64, angular_distance: f64) -> Coordinate {
+    let lat1 = origin.latitude.to_radians();
+    let lon1 = origin.longitude.to_radians();
+    let bearing = bearing.to_radians();
+
+    let lat2 = math::asin(
+        math::sin(lat1) * math::cos(angular_distance)
+            + math::cos(lat1) * math::sin(angular_distance) * math::cos(bearing),
+    );
+    let lon2 = lon1
+        + math::atan2(
+            math::sin(bearing) * math::sin(angular_distance) * math::cos(lat1),
+            math::cos(angular_distance) - math::sin(lat1) * math::sin(lat2),
+        );
+
+    Coordinate::new(lat2.to_degrees(), lon2.to_degrees())
+}
@@ -0,0 +1,446 @@
+use crate::spherical::{
+    angle_between, angular_distance, cross, dot, from_unit_vector, normalize, to_unit_vector,
+};
+use crate::utils::mix64;
+use crate::{math, Coordinate, Polygon};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Most far edge points added to the center search of `Polygon::bounding_cap`
+const MAX_REFINEMENTS: usize = 32;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// Struct representing every point within an angle of a center on the sphere. Unlike bounding
+/// boxes caps do not depend on where the poles and the antimeridian are, which makes them a good
+/// prefilter for spherical indexes
+pub struct SphericalCap {
+    pub center: Coordinate,
+    /// Angular radius in degrees, between 0 and 180
+    pub radius: f64,
+}
+
+impl SphericalCap {
+    /// # Summary
+    /// Construct a new SphericalCap from its center and angular radius in degrees
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SphericalCap};
+    ///
+    /// let cap = SphericalCap::new((0.0, 0.0), 10.0);
+    ///
+    /// assert_eq!(Coordinate::new(0.0, 0.0), cap.center);
+    /// assert_eq!(10.0, cap.radius);
+    /// ```
+    pub fn new(center: impl Into<Coordinate>, radius: f64) -> Self {
+        Self {
+            center: center.into(),
+            radius,
+        }
+    }
+
+    /// # Summary
+    /// Checks if a coordinate lies within the cap, edge included
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SphericalCap};
+    ///
+    /// // Every point north of 80 degrees
+    /// let cap = SphericalCap::new((90.0, 0.0), 10.0);
+    ///
+    /// assert!(cap.contains(&Coordinate::new(81.0, -120.0)));
+    /// assert!(!cap.contains(&Coordinate::new(79.0, 60.0)));
+    /// ```
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        angular_distance(&self.center, coordinate) <= self.radius.to_radians()
+    }
+
+    /// # Summary
+    /// Checks if two caps share at least one point
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::SphericalCap;
+    ///
+    /// let cap = SphericalCap::new((0.0, 0.0), 10.0);
+    ///
+    /// assert!(cap.intersects(&SphericalCap::new((0.0, 15.0), 6.0)));
+    /// assert!(!cap.intersects(&SphericalCap::new((0.0, 15.0), 4.0)));
+    /// ```
+    pub fn intersects(&self, other: &SphericalCap) -> bool {
+        angular_distance(&self.center, &other.center) <= (self.radius + other.radius).to_radians()
+    }
+}
+
+impl Polygon {
+    /// # Summary
+    /// Get the smallest spherical cap containing the polygon, or `None` for an empty polygon
+    ///
+    /// ## Notes
+    /// - The cap holds the polygon with its edges drawn either way of `EdgeInterpolation`, the
+    ///   radius is only larger than the smallest cap around the vertices where a straight edge
+    ///   in latitude / longitude bulges past it
+    /// - Holes lie within the exterior ring and are not needed for the cap
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Polygon};
+    ///
+    /// let square = Polygon::new(vec![
+    ///     Coordinate::new(-1.0, -1.0),
+    ///     Coordinate::new(-1.0, 1.0),
+    ///     Coordinate::new(1.0, 1.0),
+    ///     Coordinate::new(1.0, -1.0),
+    /// ]);
+    /// let cap = square.bounding_cap().unwrap();
+    ///
+    /// assert!(cap.center.latitude.abs() < 1e-9 && cap.center.longitude.abs() < 1e-9);
+    /// assert_eq!(1.414, (cap.radius * 1000.0).round() / 1000.0);
+    /// assert!(Polygon::default().bounding_cap().is_none());
+    /// ```
+    pub fn bounding_cap(&self) -> Option<SphericalCap> {
+        let ring = &self.exterior;
+        if ring.is_empty() {
+            return None;
+        }
+        let edges: Vec<(&Coordinate, &Coordinate)> = ring
+            .iter()
+            .enumerate()
+            .map(|(index, from)| (from, &ring[(index + 1) % ring.len()]))
+            .collect();
+
+        // A straight edge in latitude / longitude stays within the box spanned by its ends, so
+        // the far corners of those boxes join the vertices when looking for the center
+        let mut points: Vec<[f64; 3]> = ring.iter().map(to_unit_vector).collect();
+        for (from, to) in &edges {
+            points.push(to_unit_vector(&Coordinate::new(
+                from.latitude,
+                to.longitude,
+            )));
+            points.push(to_unit_vector(&Coordinate::new(
+                to.latitude,
+                from.longitude,
+            )));
+        }
+        let mut cap = smallest_cap(&points);
+
+        // Where an edge reaches farthest depends on the center, such as a long edge along a
+        // parallel passing over the far side of a pole, so those points join until none is left
+        // outside
+        for _ in 0..MAX_REFINEMENTS {
+            let (farthest, angle) = farthest_on_edges(cap.0, &edges);
+            if angle <= cap.1 * (1.0 + 1e-9) {
+                break;
+            }
+            points.push(farthest);
+            cap = smallest_cap(&points);
+        }
+
+        // The radius is measured against the exact edges from the final center, so the cap holds
+        // the polygon even where the center search fell short
+        let center = from_unit_vector(cap.0);
+        let (_, radius) = farthest_on_edges(to_unit_vector(&center), &edges);
+        Some(SphericalCap {
+            center,
+            // Leave room for rounding when coordinates are converted, a few nanometers
+            radius: (radius * (1.0 + 1e-9) + 1e-15).to_degrees().min(180.0),
+        })
+    }
+}
+
+/// # Summary
+/// Get the point of the polygon edges farthest from `axis` and its angle, with every edge drawn
+/// both as a straight line in latitude / longitude and as a great circle arc
+fn farthest_on_edges(axis: [f64; 3], edges: &[(&Coordinate, &Coordinate)]) -> ([f64; 3], f64) {
+    let center = from_unit_vector(axis);
+    edges
+        .iter()
+        .flat_map(|(from, to)| {
+            let (a, b) = (to_unit_vector(from), to_unit_vector(to));
+            box_candidates(&center, from, to)
+                .into_iter()
+                .chain([a, b])
+                .chain(farthest_on_arc(axis, a, b))
+        })
+        .map(|point| (point, angle_between(axis, point)))
+        .fold(([0.0; 3], -1.0), |farthest, candidate| {
+            if candidate.1 > farthest.1 {
+                candidate
+            } else {
+                farthest
+            }
+        })
+}
+
+/// # Summary
+/// Get the smallest cap around unit vectors as its center and angular radius, using Welzl's
+/// algorithm on the sphere. Exact when the points fit in a hemisphere
+fn smallest_cap(points: &[[f64; 3]]) -> ([f64; 3], f64) {
+    // A fixed shuffle keeps the expected linear running time on sorted input
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by_key(|&index| mix64(index as u64));
+    let points: Vec<[f64; 3]> = order.into_iter().map(|index| points[index]).collect();
+    let inside = |cap: ([f64; 3], f64), point: [f64; 3]| {
+        angle_between(cap.0, point) <= cap.1 * (1.0 + 1e-12) + 1e-15
+    };
+
+    let mut cap = (points[0], 0.0);
+    for i in 1..points.len() {
+        if inside(cap, points[i]) {
+            continue;
+        }
+        cap = (points[i], 0.0);
+        for j in 0..i {
+            if inside(cap, points[j]) {
+                continue;
+            }
+            cap = cap_of_two(points[i], points[j]);
+            for k in 0..j {
+                if !inside(cap, points[k]) {
+                    cap = cap_of_three(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+
+    cap
+}
+
+/// # Summary
+/// Get the smallest cap with two unit vectors on its edge
+fn cap_of_two(a: [f64; 3], b: [f64; 3]) -> ([f64; 3], f64) {
+    let sum = [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+    if dot(sum, sum) < 1e-24 {
+        // Antipodal points, only the whole sphere is sure to hold every point between them
+        return (a, std::f64::consts::PI);
+    }
+    let center = normalize(sum);
+
+    (
+        center,
+        angle_between(center, a).max(angle_between(center, b)),
+    )
+}
+
+/// # Summary
+/// Get the cap with three unit vectors on its edge, or the largest cap of two of them if they lie
+/// on one great circle
+fn cap_of_three(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> ([f64; 3], f64) {
+    let (to_b, to_c) = (
+        [b[0] - a[0], b[1] - a[1], b[2] - a[2]],
+        [c[0] - a[0], c[1] - a[1], c[2] - a[2]],
+    );
+    let normal = cross(to_b, to_c);
+    if dot(normal, normal) <= dot(to_b, to_b) * dot(to_c, to_c) * 1e-24 {
+        return [cap_of_two(a, b), cap_of_two(a, c), cap_of_two(b, c)]
+            .into_iter()
+            .max_by(|x, y| x.1.total_cmp(&y.1))
+            .unwrap_or((a, 0.0));
+    }
+    // The circle through the points lies in their plane, centered at the foot of the
+    // perpendicular from the center of the Earth. Working from the short sides keeps the
+    // precision for points close together
+    let (length_b, length_c) = (dot(to_b, to_b), dot(to_c, to_c));
+    let arm = [
+        length_b * to_c[0] - length_c * to_b[0],
+        length_b * to_c[1] - length_c * to_b[1],
+        length_b * to_c[2] - length_c * to_b[2],
+    ];
+    let offset = cross(arm, normal).map(|component| component / (2.0 * dot(normal, normal)));
+    let foot = [a[0] + offset[0], a[1] + offset[1], a[2] + offset[2]];
+    let center = if dot(foot, foot) > 1e-24 {
+        normalize(foot)
+    } else {
+        // The points lie on a great circle
+        let normal = normalize(normal);
+        if dot(normal, a) < 0.0 {
+            normal.map(|component| -component)
+        } else {
+            normal
+        }
+    };
+
+    let radius = angle_between(center, a)
+        .max(angle_between(center, b))
+        .max(angle_between(center, c));
+    (center, radius)
+}
+
+/// # Summary
+/// Get the points of the box in latitude / longitude spanned by `from` and `to`, which holds the
+/// straight edge between them, where it can reach farthest from `center`
+fn box_candidates(center: &Coordinate, from: &Coordinate, to: &Coordinate) -> Vec<[f64; 3]> {
+    let (min_latitude, max_latitude) = (
+        from.latitude.min(to.latitude),
+        from.latitude.max(to.latitude),
+    );
+    let (min_longitude, max_longitude) = (
+        from.longitude.min(to.longitude),
+        from.longitude.max(to.longitude),
+    );
+
+    // The farthest longitude is at either end of the box, or opposite the center if the box spans
+    // that meridian
+    let opposite = (center.longitude + 180.0 - min_longitude).rem_euclid(360.0) + min_longitude;
+    let mut longitudes = vec![min_longitude, max_longitude];
+    if opposite <= max_longitude {
+        longitudes.push(opposite);
+    }
+
+    let (sin_center, cos_center) = math::sin_cos(center.latitude.to_radians());
+    let mut candidates = Vec::new();
+    for longitude in longitudes {
+        // Along a meridian the dot product with the center is a cosine of the latitude, lowest at
+        // either end of the box or where it bottoms out
+        let cos_longitude = math::cos((longitude - center.longitude).to_radians());
+        let bottom = math::atan2(-sin_center, -cos_center * cos_longitude).to_degrees();
+        let mut latitudes = vec![min_latitude, max_latitude];
+        if (min_latitude..=max_latitude).contains(&bottom) {
+            latitudes.push(bottom);
+        }
+        candidates.extend(
+            latitudes
+                .into_iter()
+                .map(|latitude| to_unit_vector(&Coordinate::new(latitude, longitude))),
+        );
+    }
+
+    candidates
+}
+
+/// # Summary
+/// Get the point inside the great circle arc from `a` to `b` farthest from `axis`, if it is not at
+/// either end
+fn farthest_on_arc(axis: [f64; 3], a: [f64; 3], b: [f64; 3]) -> Option<[f64; 3]> {
+    let normal = cross(a, b);
+    if dot(normal, normal) < 1e-30 {
+        return None;
+    }
+    let normal = normalize(normal);
+
+    // The farthest point of the whole great circle is the one nearest the opposite of the axis
+    let along = dot(axis, normal);
+    let farthest = [
+        along * normal[0] - axis[0],
+        along * normal[1] - axis[1],
+        along * normal[2] - axis[2],
+    ];
+    if dot(farthest, farthest) < 1e-30 {
+        return None;
+    }
+    let farthest = normalize(farthest);
+
+    (dot(cross(a, farthest), normal) >= 0.0 && dot(cross(farthest, b), normal) >= 0.0)
+        .then_some(farthest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spherical::{destination, interpolate};
+    use crate::utils::random;
+    use crate::EdgeInterpolation;
+
+    #[test]
+    fn test_cap_holds_polygon() {
+        for shape in 0..300u64 {
+            let seed = shape * 100;
+            let center = Coordinate::new(random(seed, 170.0), random(seed + 1, 300.0));
+            // From a few meters up to wide polygons reaching towards the poles
+            let size = 10f64.powf(random(seed + 2, 8.0) - 4.5);
+            let vertices = 3 + mix64(seed + 3) % 8;
+            let exterior: Vec<Coordinate> = (0..vertices)
+                .map(|vertex| {
+                    let bearing = vertex as f64 * 360.0 / vertices as f64;
+                    let reach = size * (0.3 + random(seed + 10 + vertex, 1.4).abs());
+                    destination(&center, bearing, reach)
+                })
+                .collect();
+            if exterior
+                .windows(2)
+                .any(|pair| (pair[0].longitude - pair[1].longitude).abs() > 180.0)
+                || (exterior[0].longitude - exterior[exterior.len() - 1].longitude).abs() > 180.0
+            {
+                // Straight edges may not cross the antimeridian
+                continue;
+            }
+            let polygon = Polygon::new(exterior.clone());
+            let cap = polygon.bounding_cap().unwrap();
+
+            for (index, from) in exterior.iter().enumerate() {
+                let to = &exterior[(index + 1) % exterior.len()];
+                for step in 0..=50 {
+                    let fraction = step as f64 / 50.0;
+                    let straight = Coordinate::new(
+                        from.latitude + (to.latitude - from.latitude) * fraction,
+                        from.longitude + (to.longitude - from.longitude) * fraction,
+                    );
+                    assert!(cap.contains(&straight), "{} {:?}", shape, straight);
+                    assert!(cap.contains(&interpolate(from, to, fraction)), "{}", shape);
+                }
+            }
+            for probe in 0..50u64 {
+                let point = destination(
+                    &center,
+                    random(seed + 50 + probe * 2, 720.0),
+                    random(seed + 51 + probe * 2, 4.0 * size).abs(),
+                );
+                if polygon.contains(&point)
+                    || polygon.contains_with(&point, EdgeInterpolation::GreatCircle)
+                {
+                    assert!(cap.contains(&point), "{} {:?}", shape, point);
+                }
+            }
+
+            // No cap around the average of the points searched may be smaller
+            let mut points: Vec<[f64; 3]> = exterior.iter().map(to_unit_vector).collect();
+            for (index, from) in exterior.iter().enumerate() {
+                let to = &exterior[(index + 1) % exterior.len()];
+                points.push(to_unit_vector(&Coordinate::new(
+                    from.latitude,
+                    to.longitude,
+                )));
+                points.push(to_unit_vector(&Coordinate::new(
+                    to.latitude,
+                    from.longitude,
+                )));
+            }
+            let covering = |center: [f64; 3]| {
+                points
+                    .iter()
+                    .map(|point| angle_between(center, *point))
+                    .fold(0.0, f64::max)
+            };
+            let sum = points.iter().fold([0.0; 3], |sum, point| {
+                [sum[0] + point[0], sum[1] + point[1], sum[2] + point[2]]
+            });
+            let found = covering(to_unit_vector(&cap.center));
+            assert!(
+                found <= covering(normalize(sum)) * (1.0 + 1e-9),
+                "{}",
+                shape
+            );
+            assert!(found <= cap.radius.to_radians(), "{}", shape);
+        }
+    }
+
+    #[test]
+    fn test_cap_across_pole() {
+        // Straight edges along a parallel pass over the far side of the pole
+        let polygon = Polygon::new(vec![
+            Coordinate::new(80.0, -170.0),
+            Coordinate::new(80.0, 170.0),
+            Coordinate::new(85.0, 170.0),
+            Coordinate::new(85.0, -170.0),
+        ]);
+        let cap = polygon.bounding_cap().unwrap();
+
+        assert!(cap.contains(&Coordinate::new(80.0, 0.0)));
+        assert!(cap.contains(&Coordinate::new(80.0, 180.0)));
+        assert!(cap.radius < 11.0);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::math;
+use crate::validation::WEB_MERCATOR_MAX_LATITUDE;
+use crate::{Coordinate, CoordinateBoundaries};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Deepest supported zoom level, tile indices at zoom 32 still fit in a `u32`
+pub(crate) const MAX_ZOOM: u8 = 32;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// ## Summary
+/// A Web Mercator (XYZ / slippy map) tile, `x` growing eastward and `y` growing southward from
+/// the north-west corner of the map
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub z: u8,
+}
+
+impl Tile {
+    /// # Summary
+    /// Construct a new Tile
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Tile;
+    ///
+    /// let tile = Tile::new(8800, 5373, 14);
+    /// assert_eq!(14, tile.z);
+    /// ```
+    pub fn new(x: u32, y: u32, z: u8) -> Self {
+        Self { x, y, z }
+    }
+
+    /// # Summary
+    /// Get the tile containing a coordinate at zoom level `z` (at most 32)
+    ///
+    /// ## Notes
+    /// - Latitudes beyond the Web Mercator limit of ±85.0511° are clamped to the top or bottom
+    ///   row of tiles
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Tile};
+    ///
+    /// let tile = Tile::for_coordinate(&Coordinate::new(52.52, 13.405), 14);
+    /// assert_eq!(Tile::new(8802, 5373, 14), tile);
+    /// ```
+    pub fn for_coordinate(coordinate: &Coordinate, z: u8) -> Self {
+        let z = z.min(MAX_ZOOM);
+        let last = ((1u64 << z) - 1) as f64;
+        let (x, y) = world_position(coordinate, z);
+
+        Self {
+            x: x.floor().clamp(0.0, last) as u32,
+            y: y.floor().clamp(0.0, last) as u32,
+            z,
+        }
+    }
+
+    /// # Summary
+    /// Get the bounds covered by the tile. Returns `None` if the tile lies outside its zoom level
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Tile;
+    ///
+    /// let bounds = Tile::new(1, 0, 1).bounds().unwrap();
+    ///
+    /// assert_eq!(0.0, bounds.min_latitude());
+    /// assert_eq!(85.0511, (bounds.max_latitude() * 10000.0).round() / 10000.0);
+    /// assert_eq!((0.0, 180.0), (bounds.min_longitude(), bounds.max_longitude()));
+    /// ```
+    pub fn bounds(&self) -> Option<CoordinateBoundaries> {
+        let tiles = (1u64 << self.z.min(MAX_ZOOM)) as f64;
+        if self.x as f64 >= tiles || self.y as f64 >= tiles {
+            return None;
+        }
+        let longitude = |x: f64| x / tiles * 360.0 - 180.0;
+        let latitude = |y: f64| math::atan(math::sinh(PI * (1.0 - 2.0 * y / tiles))).to_degrees();
+
+        CoordinateBoundaries::from_extents(
+            latitude(self.y as f64 + 1.0),
+            latitude(self.y as f64),
+            longitude(self.x as f64),
+            longitude(self.x as f64 + 1.0),
+        )
+    }
+
+    /// # Summary
+    /// Get the tile one zoom level up containing this one. Returns `None` at zoom level 0
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::Tile;
+    ///
+    /// assert_eq!(Some(Tile::new(4401, 2686, 13)), Tile::new(8802, 5373, 14).parent());
+    /// assert_eq!(None, Tile::new(0, 0, 0).parent());
+    /// ```
+    pub fn parent(&self) -> Option<Tile> {
+        let z = self.z.checked_sub(1)?;
+        Some(Self::new(self.x / 2, self.y / 2, z))
+    }
+
+    /// # Summary
+    /// Get the four tiles one zoom level down covering this one, in row order starting from the
+    /// north-west child. Returns `None` at the deepest supported zoom level of 32, or for tile
+    /// indices whose children do not fit in a `u32`
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Tile};
+    ///
+    /// let children = Tile::new(0, 0, 0).children().unwrap();
+    ///
+    /// assert_eq!(Tile::new(0, 0, 1), children[0]);
+    /// assert_eq!(Tile::new(1, 1, 1), children[3]);
+    /// assert_eq!(None, Tile::for_coordinate(&Coordinate::new(-80.0, 179.9), 32).children());
+    /// assert_eq!(None, Tile::new(0, 0, 255).children());
+    /// ```
+    pub fn children(&self) -> Option<[Tile; 4]> {
+        if self.z >= MAX_ZOOM {
+            return None;
+        }
+        // Doubled indices are even, so adding one can't overflow
+        let (x, y, z) = (self.x.checked_mul(2)?, self.y.checked_mul(2)?, self.z + 1);
+        Some([
+            Self::new(x, y, z),
+            Self::new(x + 1, y, z),
+            Self::new(x, y + 1, z),
+            Self::new(x + 1, y + 1, z),
+        ])
+    }
+}
+
+/// # Summary
+/// Fractional Web Mercator tile position of a coordinate at zoom level `z`, the integer part
+/// being the tile index and the fraction the position within the tile
+pub(crate) fn world_position(coordinate: &Coordinate, z: u8) -> (f64, f64) {
+    let tiles = (1u64 << z.min(MAX_ZOOM)) as f64;
+    let latitude = coordinate
+        .latitude
+        .clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE)
+        .to_radians();
+
+    let x = (coordinate.longitude + 180.0) / 360.0 * tiles;
+    let y = (1.0 - math::asinh(math::tan(latitude)) / PI) / 2.0 * tiles;
+    (x, y)
+}
@@ -0,0 +1,149 @@
+use crate::{Coordinate, Tile};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// ## Summary
+/// Aggregate of the points falling within a single tile
+pub struct TileStats {
+    /// Number of points in the tile
+    pub count: usize,
+    /// Sum of the weights of the points in the tile, equal to `count` for unweighted points
+    pub weight: f64,
+}
+
+/// # Summary
+/// Count the points falling in every tile of a zoom pyramid, for building heat map tiles
+///
+/// ## Notes
+/// - See `aggregate_weighted_to_tiles`, every point here has a weight of 1
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{aggregate_to_tiles, Coordinate, Tile};
+///
+/// let points = [
+///     Coordinate::new(52.52, 13.405),
+///     Coordinate::new(52.51, 13.39),
+///     Coordinate::new(48.857, 2.352),
+/// ];
+/// let tiles = aggregate_to_tiles(&points, 0..=14);
+///
+/// assert_eq!(3, tiles[&Tile::new(0, 0, 0)].count);
+/// assert_eq!(2, tiles[&Tile::for_coordinate(&points[0], 6)].count);
+/// assert_eq!(1, tiles[&Tile::for_coordinate(&points[2], 14)].count);
+/// ```
+pub fn aggregate_to_tiles(
+    points: &[Coordinate],
+    zoom_range: RangeInclusive<u8>,
+) -> HashMap<Tile, TileStats> {
+    aggregate(points.iter().map(|point| (point, 1.0)), zoom_range)
+}
+
+/// # Summary
+/// Count and sum the weights of the points falling in every tile of a zoom pyramid, in a
+/// single pass over the points
+///
+/// ## Notes
+/// - Each point's tile is computed once at the deepest zoom level of the range, the tiles of
+///   shallower levels are found by halving its indices
+/// - Only tiles containing at least one point are present in the result
+/// - Points with a non-finite latitude or longitude are skipped
+/// - Zoom levels above 32 are capped at 32, an empty range yields no tiles
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{aggregate_weighted_to_tiles, Coordinate, Tile};
+///
+/// let points = [
+///     (Coordinate::new(52.52, 13.405), 2.5),
+///     (Coordinate::new(-33.87, 151.21), 1.0),
+/// ];
+/// let tiles = aggregate_weighted_to_tiles(&points, 1..=2);
+///
+/// assert_eq!(4, tiles.len());
+/// assert_eq!(2.5, tiles[&Tile::new(1, 0, 1)].weight);
+/// assert_eq!(1, tiles[&Tile::new(3, 2, 2)].count);
+/// ```
+pub fn aggregate_weighted_to_tiles(
+    points: &[(Coordinate, f64)],
+    zoom_range: RangeInclusive<u8>,
+) -> HashMap<Tile, TileStats> {
+    aggregate(
+        points.iter().map(|(point, weight)| (point, *weight)),
+        zoom_range,
+    )
+}
+
+fn aggregate<'a>(
+    points: impl Iterator<Item = (&'a Coordinate, f64)>,
+    zoom_range: RangeInclusive<u8>,
+) -> HashMap<Tile, TileStats> {
+    let mut tiles = HashMap::new();
+    let (min_zoom, max_zoom) = (*zoom_range.start(), (*zoom_range.end()).min(32));
+    if min_zoom > max_zoom {
+        return tiles;
+    }
+
+    for (point, weight) in points {
+        if !point.latitude.is_finite() || !point.longitude.is_finite() {
+            continue;
+        }
+        let deepest = Tile::for_coordinate(point, max_zoom);
+        for z in min_zoom..=max_zoom {
+            let shift = max_zoom - z;
+            let tile = Tile::new(
+                (deepest.x as u64 >> shift) as u32,
+                (deepest.y as u64 >> shift) as u32,
+                z,
+            );
+            let stats: &mut TileStats = tiles.entry(tile).or_default();
+            stats.count += 1;
+            stats.weight += weight;
+        }
+    }
+
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mix64;
+
+    #[test]
+    fn test_pyramid_matches_per_level_tiles() {
+        let points: Vec<Coordinate> = (0..500u64)
+            .map(|index| {
+                let latitude = (mix64(index) % 170_000) as f64 / 1000.0 - 85.0;
+                let longitude = (mix64(index + 1_000_000) % 360_000) as f64 / 1000.0 - 180.0;
+                Coordinate::new(latitude, longitude)
+            })
+            .collect();
+
+        let tiles = aggregate_to_tiles(&points, 3..=12);
+
+        for z in 3..=12 {
+            let mut expected: HashMap<Tile, usize> = HashMap::new();
+            for point in &points {
+                *expected.entry(Tile::for_coordinate(point, z)).or_default() += 1;
+            }
+            for (tile, count) in expected {
+                assert_eq!(count, tiles[&tile].count, "tile {:?}", tile);
+            }
+        }
+        assert!(tiles.keys().all(|tile| (3..=12).contains(&tile.z)));
+        assert_eq!(
+            points.len(),
+            tiles
+                .iter()
+                .filter(|(tile, _)| tile.z == 3)
+                .map(|(_, stats)| stats.count)
+                .sum::<usize>()
+        );
+    }
+}
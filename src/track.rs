@@ -0,0 +1,215 @@
+use crate::codec::{
+    read_fixed_coordinate, read_varint, to_fixed, write_varint, zigzag_decode, zigzag_encode,
+    CodecError,
+};
+use crate::{Coordinate, DistanceUnit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Highest supported number of decimal places for compressed tracks
+const MAX_PRECISION: u8 = 9;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// ## Summary
+/// A single recorded position of a track
+pub struct TrackPoint {
+    pub coordinate: Coordinate,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+impl TrackPoint {
+    /// # Summary
+    /// Construct a new TrackPoint from a coordinate and a timestamp in milliseconds since the Unix epoch
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, TrackPoint};
+    ///
+    /// let point = TrackPoint::new(Coordinate::new(1.0, 2.0), 1_700_000_000_000);
+    /// assert_eq!(1_700_000_000_000, point.timestamp);
+    /// ```
+    pub fn new(coordinate: impl Into<Coordinate>, timestamp: i64) -> Self {
+        Self {
+            coordinate: coordinate.into(),
+            timestamp,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Default)]
+/// ## Summary
+/// Struct representing a recorded sequence of timestamped positions, ordered by time
+pub struct Track {
+    pub points: Vec<TrackPoint>,
+}
+
+impl Track {
+    /// # Summary
+    /// Construct a new Track from its points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Track, TrackPoint};
+    ///
+    /// let track = Track::new(vec![TrackPoint::new(Coordinate::new(1.0, 2.0), 0)]);
+    /// assert_eq!(1, track.points.len());
+    /// ```
+    pub fn new(points: Vec<TrackPoint>) -> Self {
+        Self { points }
+    }
+
+    /// # Summary
+    /// Compress the track into a compact binary buffer
+    ///
+    /// ## Notes
+    /// - Latitude and longitude are stored as fixed-point integers with `precision` decimal places
+    ///   (clamped to at most 9), timestamps keep full millisecond precision
+    /// - Every point is stored as zigzag varint deltas from the previous point, a 1 Hz GPS track
+    ///   typically takes 4-6 bytes per point at precision 5 versus 24 bytes uncompressed
+    ///   (see `examples/track_compression.rs` for a size / accuracy report)
+    /// - Precision 5 keeps ~1.1 m, 6 keeps ~11 cm and 7 keeps ~1.1 cm of accuracy at the equator
+    /// - Layout: `[precision: u8][count: varint]` followed by
+    ///   `[d_lat: varint][d_lon: varint][d_timestamp: varint]` per point
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Track, TrackPoint};
+    ///
+    /// let track = Track::new(
+    ///     (0..100)
+    ///         .map(|i| TrackPoint::new(
+    ///             Coordinate::new(52.52 + i as f64 * 0.00005, 13.405 + i as f64 * 0.00003),
+    ///             1_700_000_000_000 + i * 1000,
+    ///         ))
+    ///         .collect(),
+    /// );
+    ///
+    /// let compressed = track.compress(5);
+    /// assert!(compressed.len() < track.points.len() * 8);
+    ///
+    /// let decompressed = Track::decompress(&compressed).unwrap();
+    /// assert_eq!(track.points.len(), decompressed.points.len());
+    /// assert_eq!(track.points[99].timestamp, decompressed.points[99].timestamp);
+    /// ```
+    pub fn compress(&self, precision: u8) -> Vec<u8> {
+        let precision = precision.min(MAX_PRECISION);
+        let mut buffer = Vec::with_capacity(2 + self.points.len() * 6);
+        buffer.push(precision);
+        write_varint(&mut buffer, self.points.len() as u64);
+
+        let mut previous = (0i64, 0i64, 0i64);
+        for point in &self.points {
+            let current = (
+                to_fixed(point.coordinate.latitude, precision),
+                to_fixed(point.coordinate.longitude, precision),
+                point.timestamp,
+            );
+            write_varint(&mut buffer, zigzag_encode(current.0 - previous.0));
+            write_varint(&mut buffer, zigzag_encode(current.1 - previous.1));
+            write_varint(
+                &mut buffer,
+                zigzag_encode(current.2.wrapping_sub(previous.2)),
+            );
+            previous = current;
+        }
+
+        buffer
+    }
+
+    /// # Summary
+    /// Decompress a buffer produced by `Track::compress`
+    ///
+    /// ## Notes
+    /// - Points decoding outside of ±90 latitude or ±180 longitude are rejected with
+    ///   `CodecError::CoordinateOutOfRange` instead of being wrapped
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{CodecError, Track};
+    ///
+    /// assert_eq!(Err(CodecError::InvalidPrecision(12)), Track::decompress(&[12, 0]));
+    ///
+    /// // A longitude delta of 181 degrees at precision 0
+    /// assert_eq!(
+    ///     Err(CodecError::CoordinateOutOfRange),
+    ///     Track::decompress(&[0, 1, 0, 234, 2, 0])
+    /// );
+    /// ```
+    pub fn decompress(bytes: &[u8]) -> Result<Track, CodecError> {
+        let (&precision, mut bytes) = bytes.split_first().ok_or(CodecError::UnexpectedEnd)?;
+        if precision > MAX_PRECISION {
+            return Err(CodecError::InvalidPrecision(precision));
+        }
+        let count = read_varint(&mut bytes)?;
+
+        // Every point takes at least 3 bytes, never trust the header for the allocation size
+        let mut points = Vec::with_capacity((count as usize).min(bytes.len() / 3));
+        let mut previous = (0i64, 0i64);
+        let mut timestamp = 0i64;
+        for _ in 0..count {
+            let coordinate = read_fixed_coordinate(&mut bytes, &mut previous, precision)?;
+            timestamp = timestamp.wrapping_add(zigzag_decode(read_varint(&mut bytes)?));
+            points.push(TrackPoint::new(coordinate, timestamp));
+        }
+
+        Ok(Track::new(points))
+    }
+
+    /// # Summary
+    /// Downsample the track, keeping a point only once at least `min_time_gap` milliseconds have
+    /// passed or it has moved at least `min_distance_gap` since the last kept point
+    ///
+    /// ## Notes
+    /// - Complements shape based simplification such as Douglas-Peucker, which ignores time: long
+    ///   stops keep a point every `min_time_gap` and fast legs a point every `min_distance_gap`
+    /// - The first and last points are always kept
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, Track, TrackPoint};
+    ///
+    /// // A fix every second, standing still for a minute and then moving 10 meters a second
+    /// let points = (0..120)
+    ///     .map(|second| {
+    ///         let moved = (second - 60).max(0) as f64 * 10.0 / 111_000.0;
+    ///         TrackPoint::new(Coordinate::new(moved, 0.0), second * 1000)
+    ///     })
+    ///     .collect();
+    ///
+    /// let downsampled = Track::new(points).downsample(30_000, 100.0, &DistanceUnit::Meters);
+    ///
+    /// assert_eq!(9, downsampled.points.len());
+    /// assert_eq!(30_000, downsampled.points[1].timestamp);
+    /// assert_eq!(70_000, downsampled.points[3].timestamp);
+    /// assert_eq!(119_000, downsampled.points[8].timestamp);
+    /// ```
+    pub fn downsample(
+        &self,
+        min_time_gap: i64,
+        min_distance_gap: f64,
+        unit: &DistanceUnit,
+    ) -> Track {
+        let Some((last, rest)) = self.points.split_last() else {
+            return Track::default();
+        };
+
+        let mut points: Vec<TrackPoint> = Vec::new();
+        for point in rest {
+            let keep = points.last().is_none_or(|kept| {
+                point.timestamp - kept.timestamp >= min_time_gap
+                    || kept.coordinate.get_distance_from(point.coordinate, unit) >= min_distance_gap
+            });
+            if keep {
+                points.push(*point);
+            }
+        }
+        points.push(*last);
+
+        Track::new(points)
+    }
+}
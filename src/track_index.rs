@@ -0,0 +1,270 @@
+use crate::{Coordinate, CoordinateBoundaries, DistanceUnit, ProximityMatch, TrackPoint};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// ## Summary
+/// An index over timestamped positions, bucketing them by time and by a uniform latitude /
+/// longitude grid so "within `radius` during `window`" queries only visit nearby points of the
+/// matching period
+///
+/// ## Notes
+/// - Pick a `cell_size` around the typical query radius and a `bucket_duration` around the
+///   typical query window, queries spanning many more cells or buckets than there are points
+///   in the window fall back to scanning the window in time order
+#[derive(Debug, Clone)]
+pub struct TrackIndex {
+    points: Vec<TrackPoint>,
+    /// Indices into `points`, ordered by timestamp
+    by_time: Vec<usize>,
+    cell_size: f64,
+    columns: i64,
+    bucket_duration: i64,
+    /// `(time bucket, row, column)` to the indices of the points inside it
+    cells: HashMap<(i64, i64, i64), Vec<usize>>,
+}
+
+impl TrackIndex {
+    /// # Summary
+    /// Build an index over `points`, using grid cells `cell_size` degrees wide and time buckets
+    /// `bucket_duration` milliseconds long
+    ///
+    /// ## Notes
+    /// - `cell_size` is clamped to between 0.000001 and 360 degrees and `bucket_duration` to at
+    ///   least 1 millisecond
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, TrackIndex, TrackPoint};
+    ///
+    /// let index = TrackIndex::new(vec![TrackPoint::new(Coordinate::new(1.0, 2.0), 0)], 0.1, 60_000);
+    /// assert_eq!(1, index.len());
+    /// ```
+    pub fn new(points: Vec<TrackPoint>, cell_size: f64, bucket_duration: i64) -> Self {
+        let cell_size = cell_size.clamp(1e-6, 360.0);
+        let mut index = Self {
+            points: Vec::new(),
+            by_time: Vec::new(),
+            cell_size,
+            columns: (360.0 / cell_size).ceil() as i64,
+            bucket_duration: bucket_duration.max(1),
+            cells: HashMap::new(),
+        };
+
+        for (position, point) in points.iter().enumerate() {
+            let key = (
+                index.bucket(point.timestamp),
+                index.row(point.coordinate.latitude),
+                index
+                    .column(point.coordinate.longitude)
+                    .rem_euclid(index.columns),
+            );
+            index.cells.entry(key).or_default().push(position);
+        }
+        index.by_time = (0..points.len()).collect();
+        index
+            .by_time
+            .sort_by_key(|&position| points[position].timestamp);
+        index.points = points;
+        index
+    }
+
+    /// # Summary
+    /// Get the number of indexed points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::TrackIndex;
+    ///
+    /// assert_eq!(0, TrackIndex::new(Vec::new(), 0.1, 60_000).len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// # Summary
+    /// Checks if the index holds no points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::TrackIndex;
+    ///
+    /// assert!(TrackIndex::new(Vec::new(), 0.1, 60_000).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// # Summary
+    /// Get the point at `index`, in the order the points were given
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, TrackIndex, TrackPoint};
+    ///
+    /// let point = TrackPoint::new(Coordinate::new(1.0, 2.0), 0);
+    /// let index = TrackIndex::new(vec![point], 0.1, 60_000);
+    ///
+    /// assert_eq!(Some(&point), index.get(0));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&TrackPoint> {
+        self.points.get(index)
+    }
+
+    /// # Summary
+    /// Get every point within `radius` of `center` whose timestamp lies in `window`, in time
+    /// order. Match indices refer to the order the points were given
+    ///
+    /// ## Notes
+    /// - Distances use the Haversine formula, matching `Coordinate::get_distance_from`
+    /// - Returns no matches if `center` is not a valid coordinate
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, TrackIndex, TrackPoint};
+    ///
+    /// let index = TrackIndex::new(
+    ///     vec![
+    ///         TrackPoint::new(Coordinate::new(0.0, 0.0), 1_000),
+    ///         TrackPoint::new(Coordinate::new(0.0, 0.01), 2_000),
+    ///         TrackPoint::new(Coordinate::new(5.0, 5.0), 2_500),
+    ///         TrackPoint::new(Coordinate::new(0.0, 0.02), 9_000),
+    ///     ],
+    ///     0.1,
+    ///     1_000,
+    /// );
+    ///
+    /// let matches = index.within(&Coordinate::new(0.0, 0.0), 5.0, &DistanceUnit::Kilometers, 1_500..=10_000);
+    /// assert_eq!(vec![1, 3], matches.iter().map(|m| m.index).collect::<Vec<_>>());
+    /// ```
+    pub fn within(
+        &self,
+        center: &Coordinate,
+        radius: f64,
+        unit: &DistanceUnit,
+        window: RangeInclusive<i64>,
+    ) -> Vec<ProximityMatch> {
+        let (start, end) = (*window.start(), *window.end());
+        let Some(bounds) = CoordinateBoundaries::new(*center, radius, Some(*unit)) else {
+            return Vec::new();
+        };
+        if start > end {
+            return Vec::new();
+        }
+
+        let first = self
+            .by_time
+            .partition_point(|&position| self.points[position].timestamp < start);
+        let last = self
+            .by_time
+            .partition_point(|&position| self.points[position].timestamp <= end);
+        let in_window = &self.by_time[first..last];
+
+        let rows = self.row(bounds.min_latitude())..=self.row(bounds.max_latitude());
+        let min_column = self.column(bounds.min_longitude());
+        // Circles reaching over a pole cover every longitude
+        let columns = if bounds.min_latitude() <= -90.0 || bounds.max_latitude() >= 90.0 {
+            self.columns
+        } else {
+            (self.column(bounds.max_longitude()) - min_column + 1).min(self.columns)
+        };
+        let buckets = self.bucket(start)..=self.bucket(end);
+        let keys = (*buckets.end() as i128 - *buckets.start() as i128 + 1)
+            * (*rows.end() - *rows.start() + 1) as i128
+            * columns as i128;
+
+        let mut candidates: Vec<usize> = if keys > in_window.len() as i128 {
+            in_window.to_vec()
+        } else {
+            let mut candidates = Vec::new();
+            for bucket in buckets {
+                for row in rows.clone() {
+                    for column in min_column..min_column + columns {
+                        let key = (bucket, row, column.rem_euclid(self.columns));
+                        if let Some(positions) = self.cells.get(&key) {
+                            candidates.extend_from_slice(positions);
+                        }
+                    }
+                }
+            }
+            candidates
+        };
+        candidates.sort_by_key(|&position| (self.points[position].timestamp, position));
+
+        candidates
+            .into_iter()
+            .filter_map(|position| {
+                let point = &self.points[position];
+                if !window.contains(&point.timestamp) {
+                    return None;
+                }
+                let distance = center.get_distance_from(point.coordinate, unit);
+                (distance <= radius).then_some(ProximityMatch {
+                    index: position,
+                    coordinate: point.coordinate,
+                    distance,
+                })
+            })
+            .collect()
+    }
+
+    fn bucket(&self, timestamp: i64) -> i64 {
+        timestamp.div_euclid(self.bucket_duration)
+    }
+
+    fn row(&self, latitude: f64) -> i64 {
+        ((latitude.clamp(-90.0, 90.0) + 90.0) / self.cell_size).floor() as i64
+    }
+
+    /// Unwrapped column, callers wrap it with `rem_euclid(self.columns)`
+    fn column(&self, longitude: f64) -> i64 {
+        ((longitude + 180.0) / self.cell_size).floor() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random;
+
+    #[test]
+    fn test_matches_brute_force() {
+        let points: Vec<TrackPoint> = (0..2000)
+            .map(|seed| {
+                TrackPoint::new(
+                    Coordinate::new(random(seed * 3, 4.0), 179.0 + random(seed * 3 + 1, 4.0)),
+                    (random(seed * 3 + 2, 2.0) * 3_600_000.0) as i64,
+                )
+            })
+            .collect();
+        // Coarse enough to go through the grid, and fine enough to fall back to a time scan
+        let indexes = [
+            TrackIndex::new(points.clone(), 0.5, 600_000),
+            TrackIndex::new(points.clone(), 0.05, 60_000),
+        ];
+        let unit = DistanceUnit::Kilometers;
+
+        for seed in 0..50u64 {
+            let center = Coordinate::new(random(seed * 5, 4.0), 179.0 + random(seed * 5 + 1, 4.0));
+            let radius = random(seed * 5 + 2, 100.0).abs();
+            let start = (random(seed * 5 + 3, 2.0) * 3_600_000.0) as i64;
+            let window = start..=start + (random(seed * 5 + 4, 1.0).abs() * 3_600_000.0) as i64;
+
+            let mut expected: Vec<usize> = (0..points.len())
+                .filter(|&position| {
+                    window.contains(&points[position].timestamp)
+                        && center.get_distance_from(points[position].coordinate, &unit) <= radius
+                })
+                .collect();
+            expected.sort_by_key(|&position| (points[position].timestamp, position));
+
+            for index in &indexes {
+                let found: Vec<usize> = index
+                    .within(&center, radius, &unit, window.clone())
+                    .iter()
+                    .map(|found| found.index)
+                    .collect();
+                assert_eq!(expected, found, "query {}", seed);
+            }
+        }
+    }
+}
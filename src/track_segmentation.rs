@@ -0,0 +1,289 @@
+use crate::{DistanceUnit, Speed, SpeedUnit, Track};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of legs on either side of a leg used to smooth its speed and turn statistics
+const WINDOW: usize = 2;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// How a part of a track was travelled, as estimated by `Track::segment_by_speed`
+pub enum TransportMode {
+    Stationary,
+    Walking,
+    /// Anything faster than walking, including cycling and public transport
+    Driving,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// Limits used by `Track::segment_by_speed` to tell transport modes apart
+pub struct SpeedThresholds {
+    /// Highest speed still considered stationary
+    pub stationary: Speed,
+    /// Highest speed still considered walking
+    pub walking: Speed,
+    /// Average change of direction in degrees above which slow movement is treated as GPS jitter
+    /// around a stationary position
+    pub max_stationary_turn: f64,
+    /// Segments shorter than this many milliseconds are merged into a neighbouring segment
+    pub min_duration: i64,
+}
+
+impl Default for SpeedThresholds {
+    /// # Summary
+    /// Stationary below 1.8 km/h, walking below 8 km/h, jitter above 60 degrees of average
+    /// turning and segments of at least a minute
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{SpeedThresholds, SpeedUnit};
+    ///
+    /// let thresholds = SpeedThresholds::default();
+    /// assert_eq!(0.5, thresholds.stationary.to_unit(&SpeedUnit::MetersPerSecond).value);
+    /// ```
+    fn default() -> Self {
+        Self {
+            stationary: Speed::new(1.8, SpeedUnit::KilometersPerHour),
+            walking: Speed::new(8.0, SpeedUnit::KilometersPerHour),
+            max_stationary_turn: 60.0,
+            min_duration: 60_000,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// A part of a track travelled with a single transport mode
+pub struct TrackSegment {
+    pub mode: TransportMode,
+    /// Points of the segment, the last point is also the first point of the next segment
+    pub track: Track,
+}
+
+impl Track {
+    /// # Summary
+    /// Split the track into stationary, walking and driving segments, the usual first step of
+    /// mobility analytics
+    ///
+    /// ## Notes
+    /// - Every leg is classified by the median speed of the legs around it, so single noisy fixes
+    ///   do not split a segment
+    /// - Slow movement whose direction keeps changing by more than `max_stationary_turn` degrees
+    ///   on average is GPS jitter and counts as stationary
+    /// - Segments shorter than `min_duration` are merged into their longer neighbour
+    /// - Returns no segments for tracks with fewer than 2 points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, SpeedThresholds, Track, TrackPoint, TransportMode};
+    ///
+    /// // 5 minutes walking north at 1.4 m/s, then 5 minutes driving north at 14 m/s
+    /// let mut latitude = 0.0;
+    /// let points = (0..=60)
+    ///     .map(|step| {
+    ///         let point = TrackPoint::new(Coordinate::new(latitude, 0.0), step * 10_000);
+    ///         latitude += if step < 30 { 14.0 } else { 140.0 } / 111_195.0;
+    ///         point
+    ///     })
+    ///     .collect();
+    ///
+    /// let segments = Track::new(points).segment_by_speed(&SpeedThresholds::default());
+    ///
+    /// assert_eq!(2, segments.len());
+    /// assert_eq!(TransportMode::Walking, segments[0].mode);
+    /// assert_eq!(TransportMode::Driving, segments[1].mode);
+    /// ```
+    pub fn segment_by_speed(&self, thresholds: &SpeedThresholds) -> Vec<TrackSegment> {
+        if self.points.len() < 2 {
+            return Vec::new();
+        }
+        let to_meters_per_second = |speed: &Speed| speed.to_unit(&SpeedUnit::MetersPerSecond).value;
+        let stationary = to_meters_per_second(&thresholds.stationary);
+        let walking = to_meters_per_second(&thresholds.walking);
+
+        let legs: Vec<(f64, Option<f64>)> = self
+            .points
+            .windows(2)
+            .map(|leg| {
+                let inverse = leg[0]
+                    .coordinate
+                    .inverse(leg[1].coordinate, &DistanceUnit::Meters);
+                let seconds = (leg[1].timestamp - leg[0].timestamp) as f64 / 1000.0;
+                let speed = if seconds > 0.0 {
+                    inverse.distance / seconds
+                } else {
+                    0.0
+                };
+                (
+                    speed,
+                    (inverse.distance > 0.0).then_some(inverse.initial_bearing),
+                )
+            })
+            .collect();
+        // Turn between each leg and the one before it, if both moved
+        let turns: Vec<Option<f64>> = std::iter::once(None)
+            .chain(legs.windows(2).map(|pair| match (pair[0].1, pair[1].1) {
+                (Some(from), Some(to)) => Some((to - from + 180.0).rem_euclid(360.0) - 180.0),
+                _ => None,
+            }))
+            .collect();
+
+        let modes: Vec<TransportMode> = (0..legs.len())
+            .map(|index| {
+                let window = index.saturating_sub(WINDOW)..(index + WINDOW + 1).min(legs.len());
+                let mut speeds: Vec<f64> = legs[window.clone()].iter().map(|leg| leg.0).collect();
+                speeds.sort_by(f64::total_cmp);
+                let speed = speeds[speeds.len() / 2];
+
+                let window_turns: Vec<f64> = turns[window]
+                    .iter()
+                    .flatten()
+                    .map(|turn| turn.abs())
+                    .collect();
+                let jitter = !window_turns.is_empty()
+                    && window_turns.iter().sum::<f64>() / window_turns.len() as f64
+                        > thresholds.max_stationary_turn;
+
+                if speed <= stationary || (speed <= walking && jitter) {
+                    TransportMode::Stationary
+                } else if speed <= walking {
+                    TransportMode::Walking
+                } else {
+                    TransportMode::Driving
+                }
+            })
+            .collect();
+
+        // Runs of legs sharing a mode, as (mode, first leg, last leg)
+        let mut runs = coalesce(
+            modes
+                .iter()
+                .enumerate()
+                .map(|(index, mode)| (*mode, index, index)),
+        );
+        let duration = |run: &(TransportMode, usize, usize)| {
+            self.points[run.2 + 1].timestamp - self.points[run.1].timestamp
+        };
+        while runs.len() > 1 {
+            let Some(shortest) = (0..runs.len())
+                .filter(|&index| duration(&runs[index]) < thresholds.min_duration)
+                .min_by_key(|&index| duration(&runs[index]))
+            else {
+                break;
+            };
+            let neighbour = match (shortest.checked_sub(1), runs.get(shortest + 1)) {
+                (Some(previous), Some(next)) if duration(&runs[previous]) < duration(next) => {
+                    shortest + 1
+                }
+                (Some(previous), _) => previous,
+                (None, _) => shortest + 1,
+            };
+            runs[shortest].0 = runs[neighbour].0;
+            runs = coalesce(runs.into_iter());
+        }
+
+        runs.into_iter()
+            .map(|(mode, first, last)| TrackSegment {
+                mode,
+                track: Track::new(self.points[first..=last + 1].to_vec()),
+            })
+            .collect()
+    }
+}
+
+/// # Summary
+/// Merge neighbouring runs of legs which share a mode
+fn coalesce(
+    runs: impl Iterator<Item = (TransportMode, usize, usize)>,
+) -> Vec<(TransportMode, usize, usize)> {
+    let mut merged: Vec<(TransportMode, usize, usize)> = Vec::new();
+    for run in runs {
+        match merged.last_mut() {
+            Some(last) if last.0 == run.0 => last.2 = run.2,
+            _ => merged.push(run),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spherical::{destination, distance_to_angle};
+    use crate::utils::random;
+    use crate::{Coordinate, TrackPoint};
+
+    #[test]
+    fn test_commute() {
+        let home = Coordinate::new(48.137, 11.575);
+        let mut points = Vec::new();
+        let mut position = home;
+        let mut timestamp = 0;
+
+        // 10 minutes at home with a few meters of GPS jitter, fixes every 10 seconds
+        for step in 0..60u64 {
+            let jitter = destination(
+                &home,
+                random(step * 2, 720.0),
+                distance_to_angle(random(step * 2 + 1, 8.0).abs(), &DistanceUnit::Meters),
+            );
+            points.push(TrackPoint::new(jitter, timestamp));
+            timestamp += 10_000;
+        }
+        // 10 minutes walking at 1.4 m/s with a right turn, a red light where the sensor
+        // reports a single stationary fix, then 10 minutes driving at 15 m/s
+        for step in 0..120u64 {
+            let (bearing, speed) = match step {
+                0..=29 => (0.0, 1.4),
+                30 => (90.0, 0.0),
+                31..=59 => (90.0, 1.4),
+                _ => (45.0 + random(1000 + step, 20.0), 15.0),
+            };
+            position = destination(
+                &position,
+                bearing,
+                distance_to_angle(speed * 10.0, &DistanceUnit::Meters),
+            );
+            points.push(TrackPoint::new(position, timestamp));
+            timestamp += 10_000;
+        }
+
+        let segments = Track::new(points).segment_by_speed(&SpeedThresholds::default());
+        let modes: Vec<TransportMode> = segments.iter().map(|segment| segment.mode).collect();
+        assert_eq!(
+            vec![
+                TransportMode::Stationary,
+                TransportMode::Walking,
+                TransportMode::Driving
+            ],
+            modes
+        );
+
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].track.points.last(), pair[1].track.points.first());
+        }
+        let first = &segments[0].track.points;
+        let duration = first[first.len() - 1].timestamp - first[0].timestamp;
+        assert!((duration - 600_000).abs() <= 30_000, "{}", duration);
+    }
+
+    #[test]
+    fn test_short_tracks() {
+        let thresholds = SpeedThresholds::default();
+        assert!(Track::default().segment_by_speed(&thresholds).is_empty());
+
+        let point = TrackPoint::new(Coordinate::new(0.0, 0.0), 0);
+        let track = Track::new(vec![point, point]);
+        let segments = track.segment_by_speed(&thresholds);
+        assert_eq!(1, segments.len());
+        assert_eq!(TransportMode::Stationary, segments[0].mode);
+    }
+}
@@ -1,25 +1,19 @@
 use crate::DistanceUnit;
 
-const LATITUDE_DISTANCE_IN_MILES: f64 = 69.0;
-const LINEAR_DISTANCE_IN_MILES: f64 = 1609.0;
-const LATITUDE_DISTANCE_IN_NAUTICAL_MILES: f64 = 60.0;
+/// International mile, exactly 1609.344 m
+const LINEAR_DISTANCE_IN_MILES: f64 = 1609.344;
+/// International nautical mile, exactly 1852 m
 const LINEAR_DISTANCE_IN_NAUTICAL_MILES: f64 = 1852.0;
-const LATITUDE_DISTANCE_IN_KILOMETERS: f64 = 111.045;
 const LINEAR_DISTANCE_IN_KILOMETERS: f64 = 1000.0;
-const LATITUDE_DISTANCE_IN_METERS: f64 = 111045.0;
 const LINEAR_DISTANCE_IN_METERS: f64 = 1.0;
-pub const EARTH_RADIUS_KM: f64 = 6371.0;
+/// IUGG mean Earth radius (R1) of the WGS84 ellipsoid
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+pub const WGS84_SEMI_MAJOR_AXIS_M: f64 = 6378137.0;
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
 
-pub fn divisor(unit: &DistanceUnit) -> f64 {
-    match unit {
-        DistanceUnit::Miles => LATITUDE_DISTANCE_IN_MILES,
-        DistanceUnit::NauticalMiles => LATITUDE_DISTANCE_IN_NAUTICAL_MILES,
-        DistanceUnit::Kilometers => LATITUDE_DISTANCE_IN_KILOMETERS,
-        DistanceUnit::Meters => LATITUDE_DISTANCE_IN_METERS,
-    }
-}
-
-pub fn linear_divisor(unit: &DistanceUnit) -> f64 {
+/// # Summary
+/// Get the number of meters in one `unit`
+pub const fn linear_divisor(unit: &DistanceUnit) -> f64 {
     match unit {
         DistanceUnit::Miles => LINEAR_DISTANCE_IN_MILES,
         DistanceUnit::NauticalMiles => LINEAR_DISTANCE_IN_NAUTICAL_MILES,
@@ -32,24 +26,38 @@ pub fn linear_divisor(unit: &DistanceUnit) -> f64 {
 /// Takes input and wraps it between - and + of `neg_pos_bound`
 ///
 pub fn wrap_to_bounds(input: f64, neg_pos_bound: f64) -> f64 {
-    let abs_neg_pos = neg_pos_bound.abs();
-    let add_or_sub = neg_pos_bound * 2.0;
-    let mut wrapped = input;
-
-    while wrapped < -abs_neg_pos || wrapped > abs_neg_pos {
-        if wrapped <= -abs_neg_pos {
-            wrapped -= add_or_sub * -1.0;
-        } else if wrapped > abs_neg_pos {
-            wrapped += add_or_sub * -1.0;
-        }
+    let bound = neg_pos_bound.abs();
+    if (-bound..=bound).contains(&input) {
+        return input;
     }
 
-    wrapped
+    // Values above the bound wrap into (-bound, bound], values below it into [-bound, bound)
+    let wrapped = (input + bound).rem_euclid(2.0 * bound);
+    if input > bound && wrapped == 0.0 {
+        bound
+    } else {
+        wrapped - bound
+    }
+}
+
+/// # Summary
+/// The SplitMix64 finalizer, a stable 64 bit mixing function that spreads nearby inputs evenly
+///
+/// ## Notes
+/// `z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9`, `z = (z ^ (z >> 27)) * 0x94d049bb133111eb`,
+/// `z = z ^ (z >> 31)` using wrapping 64 bit multiplication
+pub fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
 }
 
-#[allow(dead_code)]
-fn wrap_to_bounds_wip(angle: f64, bounds: f64) -> f64 {
-    (angle + bounds).rem_euclid(2.0 * bounds) - bounds
+/// # Summary
+/// A deterministic pseudo random value in `[-range / 2, range / 2]` for `seed`, shared by the
+/// randomized tests
+#[cfg(test)]
+pub(crate) fn random(seed: u64, range: f64) -> f64 {
+    (mix64(seed) as f64 / u64::MAX as f64 - 0.5) * range
 }
 
 #[cfg(test)]
@@ -78,5 +86,23 @@ mod tests {
 
         let output = wrap_to_bounds(179.0, 90.0);
         assert_eq!(-1.0, output);
+
+        let output = wrap_to_bounds(540.0, 180.0);
+        assert_eq!(180.0, output);
+
+        let output = wrap_to_bounds(-540.0, 180.0);
+        assert_eq!(-180.0, output);
+
+        // Far out of range values wrap in constant time instead of subtracting forever
+        assert!(wrap_to_bounds(1e300, 180.0).abs() <= 180.0);
+        assert!(wrap_to_bounds(f64::INFINITY, 180.0).is_nan());
+    }
+
+    #[test]
+    fn test_linear_divisor_matches_unit_definitions() {
+        assert_eq!(1609.344, linear_divisor(&DistanceUnit::Miles));
+        assert_eq!(1852.0, linear_divisor(&DistanceUnit::NauticalMiles));
+        assert_eq!(1000.0, linear_divisor(&DistanceUnit::Kilometers));
+        assert_eq!(1.0, linear_divisor(&DistanceUnit::Meters));
     }
 }
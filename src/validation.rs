@@ -0,0 +1,115 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Largest latitude representable in Web Mercator (EPSG:3857) tiles
+pub(crate) const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// ## Summary
+/// How strictly raw latitude / longitude input is checked, see `Coordinate::try_new`
+pub enum ValidationLevel {
+    /// Accept any finite value and wrap it into range, matching `Coordinate::new`
+    #[default]
+    Wrap,
+    /// Reject latitudes outside -90 to 90 and longitudes outside -180 to 180
+    Strict,
+    /// Like `Strict`, but also reject latitudes outside the Web Mercator range of ~±85.0511
+    WebMercator,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// ## Summary
+/// Error returned when a coordinate does not pass a `ValidationLevel`
+pub enum ValidationError {
+    /// The latitude or longitude is NaN or infinite
+    NonFinite,
+    /// The latitude is outside the range allowed by the validation level
+    LatitudeOutOfRange(f64),
+    /// The longitude is outside -180 to 180
+    LongitudeOutOfRange(f64),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::NonFinite => write!(f, "latitude and longitude must be finite"),
+            ValidationError::LatitudeOutOfRange(latitude) => {
+                write!(f, "latitude {} is out of range", latitude)
+            }
+            ValidationError::LongitudeOutOfRange(longitude) => {
+                write!(f, "longitude {} is out of range", longitude)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// ## Summary
+/// Detailed validation results for a coordinate, see `Coordinate::validate`
+pub struct ValidationReport {
+    /// Both latitude and longitude are neither NaN nor infinite
+    pub is_finite: bool,
+    /// The latitude is within -90 to 90
+    pub latitude_in_range: bool,
+    /// The longitude is within -180 to 180
+    pub longitude_in_range: bool,
+    /// The latitude is within the Web Mercator range of ~±85.0511
+    pub within_web_mercator: bool,
+}
+
+impl ValidationReport {
+    /// # Summary
+    /// Build the report for a raw latitude / longitude pair
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::ValidationReport;
+    ///
+    /// let report = ValidationReport::new(91.0, 10.0);
+    ///
+    /// assert!(report.is_finite);
+    /// assert!(!report.latitude_in_range);
+    /// assert!(report.longitude_in_range);
+    /// ```
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            is_finite: latitude.is_finite() && longitude.is_finite(),
+            latitude_in_range: (-90.0..=90.0).contains(&latitude),
+            longitude_in_range: (-180.0..=180.0).contains(&longitude),
+            within_web_mercator: (-WEB_MERCATOR_MAX_LATITUDE..=WEB_MERCATOR_MAX_LATITUDE)
+                .contains(&latitude),
+        }
+    }
+
+    /// # Summary
+    /// Check if the report passes the given validation level
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{ValidationLevel, ValidationReport};
+    ///
+    /// let report = ValidationReport::new(89.0, 10.0);
+    ///
+    /// assert!(report.is_valid(ValidationLevel::Strict));
+    /// assert!(!report.is_valid(ValidationLevel::WebMercator));
+    /// ```
+    pub fn is_valid(&self, level: ValidationLevel) -> bool {
+        match level {
+            ValidationLevel::Wrap => self.is_finite,
+            ValidationLevel::Strict => {
+                self.is_finite && self.latitude_in_range && self.longitude_in_range
+            }
+            ValidationLevel::WebMercator => {
+                self.is_finite && self.within_web_mercator && self.longitude_in_range
+            }
+        }
+    }
+}
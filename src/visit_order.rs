@@ -0,0 +1,140 @@
+use crate::{Coordinate, DistanceUnit};
+
+#[derive(Debug, Clone, PartialEq)]
+/// ## Summary
+/// An order in which to visit a set of points, starting from a fixed position
+pub struct VisitOrder {
+    /// Indices into the visited points, in visiting order
+    pub order: Vec<usize>,
+    /// Distance travelled from the start through every point in order, without returning
+    pub total_distance: f64,
+}
+
+/// # Summary
+/// Order `points` by repeatedly visiting the nearest unvisited point, starting from `start`
+///
+/// ## Notes
+/// - A fast heuristic, typically within 25% of the optimal route; follow it with
+///   `VisitOrder::improve_two_opt` for better results
+///
+/// ## Example
+/// ```rust
+/// use geolocation_utils::{order_by_nearest_neighbor, Coordinate, DistanceUnit};
+///
+/// let stops = vec![
+///     Coordinate::new(0.0, 3.0),
+///     Coordinate::new(0.0, 1.0),
+///     Coordinate::new(0.0, 2.0),
+/// ];
+///
+/// let visit = order_by_nearest_neighbor(&Coordinate::new(0.0, 0.0), &stops, &DistanceUnit::Kilometers);
+/// assert_eq!(vec![1, 2, 0], visit.order);
+/// assert_eq!(333.59, (visit.total_distance * 100.0).round() / 100.0);
+/// ```
+pub fn order_by_nearest_neighbor(
+    start: &Coordinate,
+    points: &[Coordinate],
+    unit: &DistanceUnit,
+) -> VisitOrder {
+    let mut visited = vec![false; points.len()];
+    let mut order = Vec::with_capacity(points.len());
+    let mut total_distance = 0.0;
+    let mut current = *start;
+
+    while order.len() < points.len() {
+        let (next, distance) = points
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !visited[*index])
+            .map(|(index, point)| (index, current.get_distance_from(point, unit)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("an unvisited point remains while the order is incomplete");
+
+        visited[next] = true;
+        order.push(next);
+        total_distance += distance;
+        current = points[next];
+    }
+
+    VisitOrder {
+        order,
+        total_distance,
+    }
+}
+
+impl VisitOrder {
+    /// # Summary
+    /// Improve the order with the 2-opt heuristic, reversing sections of the route while doing so
+    /// shortens it
+    ///
+    /// ## Notes
+    /// - `start`, `points` and `unit` must be the ones the order was created with
+    /// - Precomputes all pairwise distances, intended for up to a few thousand points
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, DistanceUnit, VisitOrder};
+    ///
+    /// let stops = vec![
+    ///     Coordinate::new(0.0, 2.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(0.0, 3.0),
+    /// ];
+    /// let start = Coordinate::new(0.0, 0.0);
+    /// let unit = DistanceUnit::Kilometers;
+    ///
+    /// let zigzag = VisitOrder { order: vec![0, 1, 2], total_distance: 555.97 };
+    /// let improved = zigzag.improve_two_opt(&start, &stops, &unit);
+    ///
+    /// assert_eq!(vec![1, 0, 2], improved.order);
+    /// assert_eq!(333.59, (improved.total_distance * 100.0).round() / 100.0);
+    /// ```
+    pub fn improve_two_opt(
+        mut self,
+        start: &Coordinate,
+        points: &[Coordinate],
+        unit: &DistanceUnit,
+    ) -> VisitOrder {
+        // Node 0 is the start, node i + 1 is points[i]
+        let nodes: Vec<&Coordinate> = std::iter::once(start).chain(points).collect();
+        let distances: Vec<Vec<f64>> = nodes
+            .iter()
+            .map(|a| {
+                nodes
+                    .iter()
+                    .map(|b| a.get_distance_from(*b, unit))
+                    .collect()
+            })
+            .collect();
+        let distance = |a: usize, b: usize| distances[a][b];
+
+        let mut route: Vec<usize> = std::iter::once(0)
+            .chain(self.order.iter().map(|index| index + 1))
+            .collect();
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 1..route.len() {
+                for j in i + 1..route.len() {
+                    let before = distance(route[i - 1], route[i])
+                        + route
+                            .get(j + 1)
+                            .map_or(0.0, |&next| distance(route[j], next));
+                    let after = distance(route[i - 1], route[j])
+                        + route
+                            .get(j + 1)
+                            .map_or(0.0, |&next| distance(route[i], next));
+                    if after + 1e-9 < before {
+                        route[i..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+
+        self.total_distance = route.windows(2).map(|leg| distance(leg[0], leg[1])).sum();
+        self.order = route[1..].iter().map(|node| node - 1).collect();
+        self
+    }
+}
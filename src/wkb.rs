@@ -0,0 +1,420 @@
+use crate::{Coordinate, Geometry, Polygon, Route};
+use std::fmt;
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+
+/// EWKB flags marking Z, M and SRID presence in the geometry type
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// ## Summary
+/// Errors which can occur while decoding well-known binary
+pub enum WkbError {
+    /// The buffer ended in the middle of a geometry
+    UnexpectedEnd,
+    /// The byte order marker is neither `0` (big endian) nor `1` (little endian)
+    InvalidByteOrder(u8),
+    /// The geometry type is not one of the six simple feature types, such as a
+    /// GeometryCollection
+    UnsupportedGeometryType(u32),
+    /// A multi geometry holds a member of the wrong type
+    UnexpectedMemberType(u32),
+    /// A point lies outside of ±90 latitude or ±180 longitude, such as a projected coordinate
+    CoordinateOutOfRange,
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEnd => write!(f, "buffer ended unexpectedly"),
+            WkbError::InvalidByteOrder(order) => write!(f, "invalid byte order marker {}", order),
+            WkbError::UnsupportedGeometryType(geometry_type) => {
+                write!(f, "geometry type {} is not supported", geometry_type)
+            }
+            WkbError::UnexpectedMemberType(geometry_type) => {
+                write!(
+                    f,
+                    "geometry type {} is not valid in this collection",
+                    geometry_type
+                )
+            }
+            WkbError::CoordinateOutOfRange => {
+                write!(f, "coordinate is outside of the valid range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WkbError {}
+
+impl Geometry {
+    /// # Summary
+    /// Encode the geometry as little endian, two dimensional OGC well-known binary, with
+    /// longitude as `x` and latitude as `y`
+    ///
+    /// ## Notes
+    /// - Polygon rings are closed in the output if they were given open
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Geometry};
+    ///
+    /// let wkb = Geometry::Point(Coordinate::new(2.0, 1.0)).to_wkb();
+    ///
+    /// assert_eq!(21, wkb.len());
+    /// assert_eq!(&[1, 1, 0, 0, 0], &wkb[..5]);
+    /// assert_eq!(1.0f64.to_le_bytes(), wkb[5..13]);
+    /// ```
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_geometry(&mut buffer, self);
+        buffer
+    }
+
+    /// # Summary
+    /// Decode a geometry from OGC well-known binary, ISO WKB or PostGIS EWKB, in either byte
+    /// order
+    ///
+    /// ## Notes
+    /// - Z and M values are skipped, as is an EWKB SRID (coordinates are taken as longitude /
+    ///   latitude degrees regardless of it)
+    /// - GeometryCollections are not supported
+    /// - Points outside of ±90 latitude or ±180 longitude are rejected with
+    ///   `WkbError::CoordinateOutOfRange` instead of being wrapped. NaN is only accepted as the
+    ///   encoding of an empty point, not as a vertex of a line or ring
+    ///
+    /// ## Example
+    /// ```rust
+    /// use geolocation_utils::{Coordinate, Geometry, Polygon};
+    ///
+    /// let polygon = Geometry::Polygon(Polygon::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.0, 1.0),
+    ///     Coordinate::new(1.0, 0.0),
+    ///     Coordinate::new(0.0, 0.0),
+    /// ]));
+    ///
+    /// assert_eq!(Ok(polygon.clone()), Geometry::from_wkb(&polygon.to_wkb()));
+    /// assert!(Geometry::from_wkb(&[1, 7, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    /// ```
+    pub fn from_wkb(bytes: &[u8]) -> Result<Geometry, WkbError> {
+        let mut reader = Reader {
+            bytes,
+            little_endian: true,
+        };
+        reader.geometry()
+    }
+}
+
+fn write_geometry(buffer: &mut Vec<u8>, geometry: &Geometry) {
+    match geometry {
+        Geometry::Point(point) => {
+            write_header(buffer, POINT);
+            write_point(buffer, point);
+        }
+        Geometry::MultiPoint(points) => {
+            write_header(buffer, MULTI_POINT);
+            write_u32(buffer, points.len());
+            for point in points {
+                write_geometry(buffer, &Geometry::Point(*point));
+            }
+        }
+        Geometry::LineString(route) => {
+            write_header(buffer, LINE_STRING);
+            write_points(buffer, &route.points, false);
+        }
+        Geometry::MultiLineString(routes) => {
+            write_header(buffer, MULTI_LINE_STRING);
+            write_u32(buffer, routes.len());
+            for route in routes {
+                write_header(buffer, LINE_STRING);
+                write_points(buffer, &route.points, false);
+            }
+        }
+        Geometry::Polygon(polygon) => write_polygon(buffer, polygon),
+        Geometry::MultiPolygon(polygons) => {
+            write_header(buffer, MULTI_POLYGON);
+            write_u32(buffer, polygons.len());
+            for polygon in polygons {
+                write_polygon(buffer, polygon);
+            }
+        }
+    }
+}
+
+fn write_polygon(buffer: &mut Vec<u8>, polygon: &Polygon) {
+    write_header(buffer, POLYGON);
+    if polygon.exterior.is_empty() {
+        write_u32(buffer, 0);
+        return;
+    }
+    write_u32(buffer, 1 + polygon.interiors.len());
+    write_points(buffer, &polygon.exterior, true);
+    for interior in &polygon.interiors {
+        write_points(buffer, interior, true);
+    }
+}
+
+fn write_header(buffer: &mut Vec<u8>, geometry_type: u32) {
+    buffer.push(1);
+    buffer.extend(geometry_type.to_le_bytes());
+}
+
+fn write_u32(buffer: &mut Vec<u8>, value: usize) {
+    buffer.extend((value as u32).to_le_bytes());
+}
+
+fn write_point(buffer: &mut Vec<u8>, point: &Coordinate) {
+    buffer.extend(point.longitude.to_le_bytes());
+    buffer.extend(point.latitude.to_le_bytes());
+}
+
+/// # Summary
+/// Write a point count followed by the points, repeating the first point at the end when
+/// `close` is set and the points are not already closed
+fn write_points(buffer: &mut Vec<u8>, points: &[Coordinate], close: bool) {
+    let closing = points
+        .first()
+        .filter(|first| close && points.last() != Some(first));
+    write_u32(buffer, points.len() + closing.is_some() as usize);
+    for point in points.iter().chain(closing) {
+        write_point(buffer, point);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let (head, rest) = self
+            .bytes
+            .split_first_chunk::<N>()
+            .ok_or(WkbError::UnexpectedEnd)?;
+        self.bytes = rest;
+        Ok(*head)
+    }
+
+    fn u32(&mut self) -> Result<u32, WkbError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, WkbError> {
+        let bytes = self.take()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// # Summary
+    /// Read a byte order marker and geometry type, returning the base type and the number of
+    /// extra dimensions to skip per point
+    fn header(&mut self) -> Result<(u32, usize), WkbError> {
+        let [order] = self.take()?;
+        self.little_endian = match order {
+            0 => false,
+            1 => true,
+            _ => return Err(WkbError::InvalidByteOrder(order)),
+        };
+        let raw = self.u32()?;
+        if raw & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+        let flags = (raw & EWKB_Z != 0) as usize + (raw & EWKB_M != 0) as usize;
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for ZM
+        let iso = raw & 0x0FFF_FFFF;
+        let extra = match iso / 1000 {
+            1 | 2 => 1,
+            3 => 2,
+            _ => 0,
+        };
+        Ok((iso % 1000, flags + extra))
+    }
+
+    /// # Summary
+    /// Read a single position, only accepting the all NaN encoding of an empty point where a
+    /// whole POINT geometry is read rather than a vertex of a line or ring
+    fn point(&mut self, extra: usize, empty_allowed: bool) -> Result<Coordinate, WkbError> {
+        let longitude = self.f64()?;
+        let latitude = self.f64()?;
+        for _ in 0..extra {
+            self.f64()?;
+        }
+        let in_range = latitude.abs() <= 90.0 && longitude.abs() <= 180.0;
+        let empty = empty_allowed && latitude.is_nan() && longitude.is_nan();
+        if !(in_range || empty) {
+            return Err(WkbError::CoordinateOutOfRange);
+        }
+        Ok(Coordinate {
+            latitude,
+            longitude,
+        })
+    }
+
+    fn points(&mut self, extra: usize) -> Result<Vec<Coordinate>, WkbError> {
+        let count = self.u32()? as usize;
+        // Every point takes at least 16 bytes, so a corrupt count can't trigger a huge allocation
+        let mut points = Vec::with_capacity(count.min(self.bytes.len() / 16));
+        for _ in 0..count {
+            points.push(self.point(extra, false)?);
+        }
+        Ok(points)
+    }
+
+    fn polygon(&mut self, extra: usize) -> Result<Polygon, WkbError> {
+        let count = self.u32()? as usize;
+        let mut rings = Vec::with_capacity(count.min(self.bytes.len() / 4));
+        for _ in 0..count {
+            rings.push(self.points(extra)?);
+        }
+        let mut rings = rings.into_iter();
+        let exterior = rings.next().unwrap_or_default();
+        Ok(Polygon::with_interiors(exterior, rings.collect()))
+    }
+
+    fn member(&mut self, expected: u32) -> Result<usize, WkbError> {
+        let (geometry_type, extra) = self.header()?;
+        if geometry_type != expected {
+            return Err(WkbError::UnexpectedMemberType(geometry_type));
+        }
+        Ok(extra)
+    }
+
+    fn members<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, WkbError>,
+    ) -> Result<Vec<T>, WkbError> {
+        let count = self.u32()? as usize;
+        let mut members = Vec::with_capacity(count.min(self.bytes.len() / 9));
+        for _ in 0..count {
+            members.push(read(self)?);
+        }
+        Ok(members)
+    }
+
+    fn geometry(&mut self) -> Result<Geometry, WkbError> {
+        let (geometry_type, extra) = self.header()?;
+        match geometry_type {
+            POINT => Ok(Geometry::Point(self.point(extra, true)?)),
+            LINE_STRING => Ok(Geometry::LineString(Route::new(self.points(extra)?))),
+            POLYGON => Ok(Geometry::Polygon(self.polygon(extra)?)),
+            MULTI_POINT => Ok(Geometry::MultiPoint(self.members(|reader| {
+                let extra = reader.member(POINT)?;
+                reader.point(extra, true)
+            })?)),
+            MULTI_LINE_STRING => Ok(Geometry::MultiLineString(self.members(|reader| {
+                let extra = reader.member(LINE_STRING)?;
+                Ok(Route::new(reader.points(extra)?))
+            })?)),
+            MULTI_POLYGON => Ok(Geometry::MultiPolygon(self.members(|reader| {
+                let extra = reader.member(POLYGON)?;
+                reader.polygon(extra)
+            })?)),
+            _ => Err(WkbError::UnsupportedGeometryType(geometry_type)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wkb_round_trip() {
+        let square = |size: f64| {
+            vec![
+                Coordinate::new(-size, -size),
+                Coordinate::new(size, -size),
+                Coordinate::new(size, size),
+                Coordinate::new(-size, size),
+                Coordinate::new(-size, -size),
+            ]
+        };
+        let geometries = [
+            Geometry::Point(Coordinate::new(52.52, 13.405)),
+            Geometry::MultiPoint(vec![Coordinate::new(1.0, 2.0), Coordinate::new(3.0, 4.0)]),
+            Geometry::LineString(Route::new(square(1.0))),
+            Geometry::MultiLineString(vec![Route::new(square(1.0)), Route::new(square(2.0))]),
+            Geometry::Polygon(Polygon::with_interiors(square(2.0), vec![square(1.0)])),
+            Geometry::MultiPolygon(vec![Polygon::new(square(1.0)), Polygon::new(square(3.0))]),
+        ];
+
+        for geometry in geometries {
+            assert_eq!(Ok(geometry.clone()), Geometry::from_wkb(&geometry.to_wkb()));
+        }
+    }
+
+    #[test]
+    fn test_read_big_endian_ewkb_with_srid_and_z() {
+        // SRID=4326;POINT Z(13.405 52.52 34)
+        let mut ewkb = vec![0];
+        ewkb.extend((POINT | EWKB_Z | EWKB_SRID).to_be_bytes());
+        ewkb.extend(4326u32.to_be_bytes());
+        for value in [13.405f64, 52.52, 34.0] {
+            ewkb.extend(value.to_be_bytes());
+        }
+
+        assert_eq!(
+            Ok(Geometry::Point(Coordinate::new(52.52, 13.405))),
+            Geometry::from_wkb(&ewkb)
+        );
+        assert_eq!(
+            Err(WkbError::UnexpectedEnd),
+            Geometry::from_wkb(&ewkb[..ewkb.len() - 1])
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_points_are_rejected() {
+        for (longitude, latitude) in [
+            (1e300, 0.0f64),
+            (0.0, 90.5),
+            (f64::INFINITY, 0.0),
+            (f64::NAN, 0.0),
+        ] {
+            let mut wkb = vec![1];
+            wkb.extend(POINT.to_le_bytes());
+            wkb.extend(longitude.to_le_bytes());
+            wkb.extend(latitude.to_le_bytes());
+            assert_eq!(
+                Err(WkbError::CoordinateOutOfRange),
+                Geometry::from_wkb(&wkb)
+            );
+        }
+
+        // NaN only stands for an empty point, never for a vertex of a line
+        let mut empty = vec![1];
+        empty.extend(POINT.to_le_bytes());
+        empty.extend(f64::NAN.to_le_bytes());
+        empty.extend(f64::NAN.to_le_bytes());
+        let empty = Geometry::from_wkb(&empty);
+        assert!(matches!(empty, Ok(Geometry::Point(point)) if point.latitude.is_nan()));
+
+        let mut line = vec![1];
+        line.extend(LINE_STRING.to_le_bytes());
+        line.extend(2u32.to_le_bytes());
+        for value in [0.0, 0.0, f64::NAN, f64::NAN] {
+            line.extend(value.to_le_bytes());
+        }
+        assert_eq!(
+            Err(WkbError::CoordinateOutOfRange),
+            Geometry::from_wkb(&line)
+        );
+    }
+}
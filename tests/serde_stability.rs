@@ -0,0 +1,186 @@
+//! Pinned serde representations. Persisted data must stay readable across releases, so a failure
+//! here means the wire format changed and needs a major version bump, not an updated fixture.
+//! Renaming a field or variant in the code keeps its old name with `#[serde(rename)]` instead.
+#![cfg(feature = "serde")]
+
+use geolocation_utils::{
+    Circle, Coordinate, CoordinateBoundaries, DistanceUnit, Geofence, GeofenceTrackerSnapshot,
+    Geometry, Polygon, Region, Route, Track, TrackPoint,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+fn assert_stable<T>(value: &T, json: &str, postcard: &[u8])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(json, serde_json::to_string(value).unwrap());
+    assert_eq!(postcard, postcard::to_allocvec(value).unwrap().as_slice());
+
+    assert_eq!(value, &serde_json::from_str::<T>(json).unwrap());
+    assert_eq!(value, &postcard::from_bytes::<T>(postcard).unwrap());
+}
+
+#[test]
+fn test_coordinate() {
+    assert_stable(
+        &Coordinate::new(1.5, -2.25),
+        r#"{"latitude":1.5,"longitude":-2.25}"#,
+        &[0, 0, 0, 0, 0, 0, 248, 63, 0, 0, 0, 0, 0, 0, 2, 192],
+    );
+}
+
+#[test]
+fn test_distance_unit() {
+    assert_stable(&DistanceUnit::Miles, r#""Miles""#, &[0]);
+    assert_stable(&DistanceUnit::NauticalMiles, r#""NauticalMiles""#, &[1]);
+    assert_stable(&DistanceUnit::Kilometers, r#""Kilometers""#, &[2]);
+    assert_stable(&DistanceUnit::Meters, r#""Meters""#, &[3]);
+}
+
+#[test]
+fn test_coordinate_boundaries() {
+    // The fields are private and derived from the constructor's distance tables, so start from the
+    // fixture instead of constructing a value
+    let bounds: CoordinateBoundaries = serde_json::from_str(r#"{"latitude":1.5,"longitude":3.5,"distance":34.5,"distance_unit":"Miles","max_lon":4.0,"min_lon":3.0,"max_lat":2.0,"min_lat":1.0}"#).unwrap();
+    assert_eq!(1.0, bounds.min_latitude());
+    assert_eq!(4.0, bounds.max_longitude());
+
+    assert_stable(
+        &bounds,
+        r#"{"latitude":1.5,"longitude":3.5,"distance":34.5,"distance_unit":"Miles","max_lon":4.0,"min_lon":3.0,"max_lat":2.0,"min_lat":1.0}"#,
+        &[
+            0, 0, 0, 0, 0, 0, 248, 63, 0, 0, 0, 0, 0, 0, 12, 64, 0, 0, 0, 0, 0, 64, 65, 64, 0, 0,
+            0, 0, 0, 0, 0, 16, 64, 0, 0, 0, 0, 0, 0, 8, 64, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0,
+            0, 240, 63,
+        ],
+    );
+}
+
+#[test]
+fn test_circle() {
+    assert_stable(
+        &Circle::new(Coordinate::new(1.5, -2.25), 10.0, DistanceUnit::Kilometers),
+        r#"{"center":{"latitude":1.5,"longitude":-2.25},"radius":10.0,"unit":"Kilometers"}"#,
+        &[
+            0, 0, 0, 0, 0, 0, 248, 63, 0, 0, 0, 0, 0, 0, 2, 192, 0, 0, 0, 0, 0, 0, 36, 64, 2,
+        ],
+    );
+}
+
+#[test]
+fn test_polygon() {
+    assert_stable(
+        &Polygon::with_interiors(
+            vec![Coordinate::new(0.0, 0.0), Coordinate::new(0.0, 1.0)],
+            vec![vec![Coordinate::new(1.0, 0.0)]],
+        ),
+        r#"{"exterior":[{"latitude":0.0,"longitude":0.0},{"latitude":0.0,"longitude":1.0}],"interiors":[[{"latitude":1.0,"longitude":0.0}]]}"#,
+        &[
+            2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 240, 63, 1, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    );
+}
+
+#[test]
+fn test_region() {
+    let circle = Circle::new(Coordinate::new(0.0, 0.0), 1.0, DistanceUnit::Meters);
+    assert_stable(
+        &Region::not(Geofence::Circle(circle)),
+        r#"{"Not":{"Fence":{"Circle":{"center":{"latitude":0.0,"longitude":0.0},"radius":1.0,"unit":"Meters"}}}}"#,
+        &[
+            3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 3,
+        ],
+    );
+}
+
+#[test]
+fn test_route() {
+    assert_stable(
+        &Route::new(vec![Coordinate::new(1.0, 2.0), Coordinate::new(3.0, 4.0)]),
+        r#"{"points":[{"latitude":1.0,"longitude":2.0},{"latitude":3.0,"longitude":4.0}]}"#,
+        &[
+            2, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 8, 64, 0, 0,
+            0, 0, 0, 0, 16, 64,
+        ],
+    );
+}
+
+#[test]
+fn test_track() {
+    assert_stable(
+        &Track::new(vec![TrackPoint::new(Coordinate::new(1.0, 2.0), 1_000)]),
+        r#"{"points":[{"coordinate":{"latitude":1.0,"longitude":2.0},"timestamp":1000}]}"#,
+        &[
+            1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64, 208, 15,
+        ],
+    );
+}
+
+#[test]
+fn test_geometry() {
+    let point = Coordinate::new(1.0, 2.0);
+    let route = Route::new(vec![point]);
+    let polygon = Polygon::new(vec![point]);
+
+    assert_stable(
+        &Geometry::Point(point),
+        r#"{"Point":{"latitude":1.0,"longitude":2.0}}"#,
+        &[0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+    );
+    assert_stable(
+        &Geometry::MultiPoint(vec![point]),
+        r#"{"MultiPoint":[{"latitude":1.0,"longitude":2.0}]}"#,
+        &[1, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+    );
+    assert_stable(
+        &Geometry::LineString(route.clone()),
+        r#"{"LineString":{"points":[{"latitude":1.0,"longitude":2.0}]}}"#,
+        &[2, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+    );
+    assert_stable(
+        &Geometry::MultiLineString(vec![route]),
+        r#"{"MultiLineString":[{"points":[{"latitude":1.0,"longitude":2.0}]}]}"#,
+        &[3, 1, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+    );
+    assert_stable(
+        &Geometry::Polygon(polygon.clone()),
+        r#"{"Polygon":{"exterior":[{"latitude":1.0,"longitude":2.0}],"interiors":[]}}"#,
+        &[4, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64, 0],
+    );
+    assert_stable(
+        &Geometry::MultiPolygon(vec![polygon]),
+        r#"{"MultiPolygon":[{"exterior":[{"latitude":1.0,"longitude":2.0}],"interiors":[]}]}"#,
+        &[
+            5, 1, 1, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64, 0,
+        ],
+    );
+}
+
+#[test]
+fn test_geofence_tracker_snapshot() {
+    let snapshot: GeofenceTrackerSnapshot<u32, u32> = GeofenceTrackerSnapshot {
+        inside: vec![(7, vec![(1, 1_000)])],
+        last_seen: vec![(7, 1_000)],
+        dwell: vec![(2, 7, 500)],
+    };
+    assert_stable(
+        &snapshot,
+        r#"{"inside":[[7,[[1,1000]]]],"last_seen":[[7,1000]],"dwell":[[2,7,500]]}"#,
+        &[1, 7, 1, 1, 208, 15, 1, 7, 208, 15, 1, 2, 7, 232, 7],
+    );
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_coordinate_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(Coordinate)).unwrap();
+
+    assert_eq!(
+        serde_json::json!(["latitude", "longitude"]),
+        schema["required"]
+    );
+    assert_eq!(-90.0, schema["properties"]["latitude"]["minimum"]);
+    assert_eq!(180.0, schema["properties"]["longitude"]["maximum"]);
+}